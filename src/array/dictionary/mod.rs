@@ -21,6 +21,7 @@ mod mutable;
 use crate::array::specification::check_indexes_unchecked;
 mod typed_iterator;
 mod value_map;
+pub(crate) use value_map::{PassthroughHasher, ValueMap};
 
 use crate::array::dictionary::typed_iterator::{DictValue, DictionaryValuesIterTyped};
 pub use iterator::*;
@@ -5,7 +5,10 @@ use std::cmp::Ordering;
 use crate::datatypes::*;
 use crate::error::{Error, Result};
 use crate::offset::Offset;
-use crate::{array::*, types::NativeType};
+use crate::{
+    array::*,
+    types::{f16, i256, NativeType},
+};
 
 /// Compare the values at two arbitrary indices in two arrays.
 pub type DynComparator = Box<dyn Fn(usize, usize) -> Ordering + Send + Sync>;
@@ -38,6 +41,13 @@ pub fn total_cmp_f64(l: &f64, r: &f64) -> std::cmp::Ordering {
     left.cmp(&right)
 }
 
+/// implements comparison using IEEE 754 total ordering for f16
+#[inline]
+pub fn total_cmp_f16(l: &f16, r: &f16) -> std::cmp::Ordering {
+    // every `f16` is exactly representable as an `f32`
+    total_cmp_f32(&l.to_f32(), &r.to_f32())
+}
+
 /// Total order of all native types whose Rust implementation
 /// that support total order.
 #[inline]
@@ -104,6 +114,20 @@ fn compare_f64(left: &dyn Array, right: &dyn Array) -> DynComparator {
     Box::new(move |i, j| total_cmp_f64(&left.value(i), &right.value(j)))
 }
 
+fn compare_f16(left: &dyn Array, right: &dyn Array) -> DynComparator {
+    let left = left
+        .as_any()
+        .downcast_ref::<PrimitiveArray<f16>>()
+        .unwrap()
+        .clone();
+    let right = right
+        .as_any()
+        .downcast_ref::<PrimitiveArray<f16>>()
+        .unwrap()
+        .clone();
+    Box::new(move |i, j| total_cmp_f16(&left.value(i), &right.value(j)))
+}
+
 fn compare_string<O: Offset>(left: &dyn Array, right: &dyn Array) -> DynComparator {
     let left = left
         .as_any()
@@ -132,6 +156,20 @@ fn compare_binary<O: Offset>(left: &dyn Array, right: &dyn Array) -> DynComparat
     Box::new(move |i, j| left.value(i).cmp(right.value(j)))
 }
 
+fn compare_fixed_size_binary(left: &dyn Array, right: &dyn Array) -> DynComparator {
+    let left = left
+        .as_any()
+        .downcast_ref::<FixedSizeBinaryArray>()
+        .unwrap()
+        .clone();
+    let right = right
+        .as_any()
+        .downcast_ref::<FixedSizeBinaryArray>()
+        .unwrap()
+        .clone();
+    Box::new(move |i, j| left.value(i).cmp(right.value(j)))
+}
+
 fn compare_dict<K>(left: &DictionaryArray<K>, right: &DictionaryArray<K>) -> Result<DynComparator>
 where
     K: DictionaryKey,
@@ -149,6 +187,81 @@ where
     }))
 }
 
+/// Returns a [`DynComparator`] that also compares validities: null slots are equal to each
+/// other and smaller than any value. Used to compare the values of nested arrays.
+fn build_compare_nullable(left: &dyn Array, right: &dyn Array) -> Result<DynComparator> {
+    let comparator = build_compare(left, right)?;
+    Ok(
+        match (left.validity().cloned(), right.validity().cloned()) {
+            (None, None) => comparator,
+            (left, right) => Box::new(move |i, j| {
+                let left = left.as_ref().map(|x| x.get_bit(i)).unwrap_or(true);
+                let right = right.as_ref().map(|x| x.get_bit(j)).unwrap_or(true);
+                match (left, right) {
+                    (true, true) => (comparator)(i, j),
+                    (left, right) => left.cmp(&right),
+                }
+            }),
+        },
+    )
+}
+
+fn compare_struct(left: &dyn Array, right: &dyn Array) -> Result<DynComparator> {
+    let left = left.as_any().downcast_ref::<StructArray>().unwrap();
+    let right = right.as_any().downcast_ref::<StructArray>().unwrap();
+    let comparators = left
+        .values()
+        .iter()
+        .zip(right.values().iter())
+        .map(|(left, right)| build_compare_nullable(left.as_ref(), right.as_ref()))
+        .collect::<Result<Vec<_>>>()?;
+    Ok(Box::new(move |i, j| {
+        comparators
+            .iter()
+            .map(|comparator| (comparator)(i, j))
+            .find(|ordering| *ordering != Ordering::Equal)
+            .unwrap_or(Ordering::Equal)
+    }))
+}
+
+/// Compares the ranges `[start, end)` of `values` lexicographically, and then by length.
+fn compare_ranges(
+    left: (usize, usize),
+    right: (usize, usize),
+    comparator: &DynComparator,
+) -> Ordering {
+    (left.0..left.1)
+        .zip(right.0..right.1)
+        .map(|(i, j)| (comparator)(i, j))
+        .find(|ordering| *ordering != Ordering::Equal)
+        .unwrap_or_else(|| (left.1 - left.0).cmp(&(right.1 - right.0)))
+}
+
+fn compare_list<O: Offset>(left: &dyn Array, right: &dyn Array) -> Result<DynComparator> {
+    let left = left.as_any().downcast_ref::<ListArray<O>>().unwrap();
+    let right = right.as_any().downcast_ref::<ListArray<O>>().unwrap();
+    let comparator = build_compare_nullable(left.values().as_ref(), right.values().as_ref())?;
+    let left = left.offsets().clone();
+    let right = right.offsets().clone();
+    Ok(Box::new(move |i, j| {
+        compare_ranges(left.start_end(i), right.start_end(j), &comparator)
+    }))
+}
+
+fn compare_fixed_size_list(left: &dyn Array, right: &dyn Array) -> Result<DynComparator> {
+    let left = left.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
+    let right = right.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
+    let size = left.size();
+    let comparator = build_compare_nullable(left.values().as_ref(), right.values().as_ref())?;
+    Ok(Box::new(move |i, j| {
+        compare_ranges(
+            (i * size, (i + 1) * size),
+            (j * size, (j + 1) * size),
+            &comparator,
+        )
+    }))
+}
+
 macro_rules! dyn_dict {
     ($key:ty, $lhs:expr, $rhs:expr) => {{
         let lhs = $lhs.as_any().downcast_ref().unwrap();
@@ -174,6 +287,8 @@ macro_rules! dyn_dict {
 /// # Ok(())
 /// # }
 /// ```
+/// Structs are compared field by field, and lists lexicographically and then by length.
+/// Null values within them are smaller than any other value.
 /// # Error
 /// The arrays' [`DataType`] must be equal and the types must have a natural order.
 // This is a factory of comparisons.
@@ -207,17 +322,25 @@ pub fn build_compare(left: &dyn Array, right: &dyn Array) -> Result<DynComparato
         | (Timestamp(Millisecond, None), Timestamp(Millisecond, None))
         | (Timestamp(Microsecond, None), Timestamp(Microsecond, None))
         | (Timestamp(Nanosecond, None), Timestamp(Nanosecond, None))
+        | (Timestamp(_, Some(_)), Timestamp(_, Some(_)))
         | (Duration(Second), Duration(Second))
         | (Duration(Millisecond), Duration(Millisecond))
         | (Duration(Microsecond), Duration(Microsecond))
         | (Duration(Nanosecond), Duration(Nanosecond)) => compare_primitives::<i64>(left, right),
+        (Float16, Float16) => compare_f16(left, right),
         (Float32, Float32) => compare_f32(left, right),
         (Float64, Float64) => compare_f64(left, right),
         (Decimal(_, _), Decimal(_, _)) => compare_primitives::<i128>(left, right),
+        (Decimal256(_, _), Decimal256(_, _)) => compare_primitives::<i256>(left, right),
         (Utf8, Utf8) => compare_string::<i32>(left, right),
         (LargeUtf8, LargeUtf8) => compare_string::<i64>(left, right),
         (Binary, Binary) => compare_binary::<i32>(left, right),
         (LargeBinary, LargeBinary) => compare_binary::<i64>(left, right),
+        (FixedSizeBinary(_), FixedSizeBinary(_)) => compare_fixed_size_binary(left, right),
+        (Struct(_), Struct(_)) => compare_struct(left, right)?,
+        (List(_), List(_)) => compare_list::<i32>(left, right)?,
+        (LargeList(_), LargeList(_)) => compare_list::<i64>(left, right)?,
+        (FixedSizeList(_, _), FixedSizeList(_, _)) => compare_fixed_size_list(left, right)?,
         (Dictionary(key_type_lhs, ..), Dictionary(key_type_rhs, ..)) => {
             match (key_type_lhs, key_type_rhs) {
                 (IntegerType::UInt8, IntegerType::UInt8) => dyn_dict!(u8, left, right),
@@ -1,6 +1,7 @@
 use std::ops::Add;
 
-use crate::types::simd::{i128x8, NativeSimd};
+use crate::types::f16;
+use crate::types::simd::{f16x32, i128x8, NativeSimd};
 
 use super::{SimdOrd, Sum};
 
@@ -100,6 +101,69 @@ pub(super) use simd_ord_int;
 simd_add!(i128x8, i128, 8, add);
 simd_ord_int!(i128x8, i128);
 
+// unlike `f32::max`, `f16::max` returns `NaN` when `self` is `NaN`; every `f16` is exactly
+// representable as an `f32`, so these reduce via `f32` instead.
+#[inline]
+fn f16_max(a: f16, b: f16) -> f16 {
+    f16::from_f32(a.to_f32().max(b.to_f32()))
+}
+
+#[inline]
+fn f16_min(a: f16, b: f16) -> f16 {
+    f16::from_f32(a.to_f32().min(b.to_f32()))
+}
+
+// `f16` has no SIMD intrinsics, and so its lanes are reduced one by one in both channels.
+// Like the other floats, `NaN` is ignored unless all values are `NaN`.
+impl SimdOrd<f16> for f16x32 {
+    const MIN: f16 = f16::NAN;
+    const MAX: f16 = f16::NAN;
+
+    #[inline]
+    fn max_element(self) -> f16 {
+        self.0.iter().copied().fold(Self::MIN, f16_max)
+    }
+
+    #[inline]
+    fn min_element(self) -> f16 {
+        self.0.iter().copied().fold(Self::MAX, f16_min)
+    }
+
+    #[inline]
+    fn max_lane(self, x: Self) -> Self {
+        let mut result = Self::default();
+        result
+            .0
+            .iter_mut()
+            .zip(self.0.iter())
+            .zip(x.0.iter())
+            .for_each(|((a, b), c)| *a = f16_max(*b, *c));
+        result
+    }
+
+    #[inline]
+    fn min_lane(self, x: Self) -> Self {
+        let mut result = Self::default();
+        result
+            .0
+            .iter_mut()
+            .zip(self.0.iter())
+            .zip(x.0.iter())
+            .for_each(|((a, b), c)| *a = f16_min(*b, *c));
+        result
+    }
+
+    #[inline]
+    fn new_min() -> Self {
+        Self([Self::MAX; Self::LANES])
+    }
+
+    #[inline]
+    fn new_max() -> Self {
+        Self([Self::MIN; Self::LANES])
+    }
+}
+
 #[cfg(not(feature = "simd"))]
 mod native;
 #[cfg(not(feature = "simd"))]
@@ -7,7 +7,7 @@ use crate::datatypes::{DataType, PhysicalType, PrimitiveType};
 use crate::error::{Error, Result};
 use crate::scalar::*;
 use crate::types::simd::*;
-use crate::types::NativeType;
+use crate::types::{f16, NativeType};
 use crate::{
     array::{Array, PrimitiveArray},
     bitmap::Bitmap,
@@ -103,13 +103,38 @@ where
     }
 }
 
+/// Returns the sum of values in the array, accumulated in `f32` to not lose precision (or
+/// overflow) in the intermediate sums of half-precision floats.
+///
+/// Returns `None` if the array is empty or only contains null values.
+pub fn sum_f16(array: &PrimitiveArray<f16>) -> Option<f16> {
+    if array.null_count() == array.len() {
+        return None;
+    }
+
+    let sum: f32 = match array.validity() {
+        None => array.values().iter().map(|x| x.to_f32()).sum(),
+        Some(_) => array.iter().flatten().map(|x| x.to_f32()).sum(),
+    };
+    Some(f16::from_f32(sum))
+}
+
 /// Whether [`sum`] supports `data_type`
 pub fn can_sum(data_type: &DataType) -> bool {
     if let PhysicalType::Primitive(primitive) = data_type.to_physical_type() {
         use PrimitiveType::*;
         matches!(
             primitive,
-            Int8 | Int16 | Int64 | Int128 | UInt8 | UInt16 | UInt32 | UInt64 | Float32 | Float64
+            Int8 | Int16
+                | Int64
+                | Int128
+                | UInt8
+                | UInt16
+                | UInt32
+                | UInt64
+                | Float16
+                | Float32
+                | Float64
         )
     } else {
         false
@@ -146,6 +171,11 @@ macro_rules! with_match_primitive_type {(
 /// Errors iff the operation is not supported.
 pub fn sum(array: &dyn Array) -> Result<Box<dyn Scalar>> {
     Ok(match array.data_type().to_physical_type() {
+        PhysicalType::Primitive(PrimitiveType::Float16) => {
+            let data_type = array.data_type().clone();
+            let array = array.as_any().downcast_ref().unwrap();
+            Box::new(PrimitiveScalar::new(data_type, sum_f16(array)))
+        }
         PhysicalType::Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
             let data_type = array.data_type().clone();
             let array = array.as_any().downcast_ref().unwrap();
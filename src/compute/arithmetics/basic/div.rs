@@ -1,7 +1,7 @@
 //! Definition of basic div operations with primitive arrays
 use std::ops::Div;
 
-use num_traits::{CheckedDiv, NumCast};
+use num_traits::CheckedDiv;
 
 use crate::datatypes::PrimitiveType;
 use crate::{
@@ -108,13 +108,13 @@ where
 /// ```
 pub fn div_scalar<T>(lhs: &PrimitiveArray<T>, rhs: &T) -> PrimitiveArray<T>
 where
-    T: NativeArithmetics + Div<Output = T> + NumCast,
+    T: NativeArithmetics + Div<Output = T>,
 {
     let rhs = *rhs;
     match T::PRIMITIVE {
         PrimitiveType::UInt64 => {
             let lhs = lhs.as_any().downcast_ref::<PrimitiveArray<u64>>().unwrap();
-            let rhs = rhs.to_u64().unwrap();
+            let rhs: u64 = bytemuck::cast(rhs);
 
             let reduced_div = StrengthReducedU64::new(rhs);
             let r = unary(lhs, |a| a / reduced_div, lhs.data_type().clone());
@@ -126,7 +126,7 @@ where
         }
         PrimitiveType::UInt32 => {
             let lhs = lhs.as_any().downcast_ref::<PrimitiveArray<u32>>().unwrap();
-            let rhs = rhs.to_u32().unwrap();
+            let rhs: u32 = bytemuck::cast(rhs);
 
             let reduced_div = StrengthReducedU32::new(rhs);
             let r = unary(lhs, |a| a / reduced_div, lhs.data_type().clone());
@@ -138,7 +138,7 @@ where
         }
         PrimitiveType::UInt16 => {
             let lhs = lhs.as_any().downcast_ref::<PrimitiveArray<u16>>().unwrap();
-            let rhs = rhs.to_u16().unwrap();
+            let rhs: u16 = bytemuck::cast(rhs);
 
             let reduced_div = StrengthReducedU16::new(rhs);
 
@@ -151,7 +151,7 @@ where
         }
         PrimitiveType::UInt8 => {
             let lhs = lhs.as_any().downcast_ref::<PrimitiveArray<u8>>().unwrap();
-            let rhs = rhs.to_u8().unwrap();
+            let rhs: u8 = bytemuck::cast(rhs);
 
             let reduced_div = StrengthReducedU8::new(rhs);
             let r = unary(lhs, |a| a / reduced_div, lhs.data_type().clone());
@@ -191,7 +191,7 @@ where
 // Implementation of ArrayDiv trait for PrimitiveArrays with a scalar
 impl<T> ArrayDiv<T> for PrimitiveArray<T>
 where
-    T: NativeArithmetics + Div<Output = T> + NumCast,
+    T: NativeArithmetics + Div<Output = T>,
 {
     fn div(&self, rhs: &T) -> Self {
         div_scalar(self, rhs)
@@ -22,7 +22,10 @@ use std::ops::Neg;
 
 use num_traits::{CheckedNeg, WrappingNeg};
 
-use crate::{array::PrimitiveArray, types::NativeType};
+use crate::{
+    array::PrimitiveArray,
+    types::{f16, NativeType},
+};
 
 use super::super::arity::{unary, unary_checked};
 
@@ -39,6 +42,7 @@ impl NativeArithmetics for i8 {}
 impl NativeArithmetics for i16 {}
 impl NativeArithmetics for i32 {}
 impl NativeArithmetics for i64 {}
+impl NativeArithmetics for f16 {}
 impl NativeArithmetics for f32 {}
 impl NativeArithmetics for f64 {}
 
@@ -1,6 +1,6 @@
 use std::ops::Rem;
 
-use num_traits::{CheckedRem, NumCast};
+use num_traits::CheckedRem;
 
 use crate::datatypes::PrimitiveType;
 use crate::{
@@ -94,14 +94,14 @@ where
 /// ```
 pub fn rem_scalar<T>(lhs: &PrimitiveArray<T>, rhs: &T) -> PrimitiveArray<T>
 where
-    T: NativeArithmetics + Rem<Output = T> + NumCast,
+    T: NativeArithmetics + Rem<Output = T>,
 {
     let rhs = *rhs;
 
     match T::PRIMITIVE {
         PrimitiveType::UInt64 => {
             let lhs = lhs.as_any().downcast_ref::<PrimitiveArray<u64>>().unwrap();
-            let rhs = rhs.to_u64().unwrap();
+            let rhs: u64 = bytemuck::cast(rhs);
 
             let reduced_rem = StrengthReducedU64::new(rhs);
 
@@ -115,7 +115,7 @@ where
         }
         PrimitiveType::UInt32 => {
             let lhs = lhs.as_any().downcast_ref::<PrimitiveArray<u32>>().unwrap();
-            let rhs = rhs.to_u32().unwrap();
+            let rhs: u32 = bytemuck::cast(rhs);
 
             let reduced_rem = StrengthReducedU32::new(rhs);
 
@@ -129,7 +129,7 @@ where
         }
         PrimitiveType::UInt16 => {
             let lhs = lhs.as_any().downcast_ref::<PrimitiveArray<u16>>().unwrap();
-            let rhs = rhs.to_u16().unwrap();
+            let rhs: u16 = bytemuck::cast(rhs);
 
             let reduced_rem = StrengthReducedU16::new(rhs);
 
@@ -143,7 +143,7 @@ where
         }
         PrimitiveType::UInt8 => {
             let lhs = lhs.as_any().downcast_ref::<PrimitiveArray<u8>>().unwrap();
-            let rhs = rhs.to_u8().unwrap();
+            let rhs: u8 = bytemuck::cast(rhs);
 
             let reduced_rem = StrengthReducedU8::new(rhs);
 
@@ -184,7 +184,7 @@ where
 
 impl<T> ArrayRem<T> for PrimitiveArray<T>
 where
-    T: NativeArithmetics + Rem<Output = T> + NumCast,
+    T: NativeArithmetics + Rem<Output = T>,
 {
     fn rem(&self, rhs: &T) -> Self {
         rem_scalar(self, rhs)
@@ -3,16 +3,17 @@ use crate::{
     array::PrimitiveArray,
     compute::{
         arithmetics::{ArrayAdd, ArrayCheckedAdd, ArraySaturatingAdd},
-        arity::{binary, binary_checked},
+        arity::{binary, binary_checked, unary},
         utils::{check_same_len, combine_validities},
     },
 };
 use crate::{
     datatypes::DataType,
     error::{Error, Result},
+    scalar::{PrimitiveScalar, Scalar},
 };
 
-use super::{adjusted_precision_scale, get_parameters, max_value, number_digits};
+use super::{adaptive, get_parameters, max_value};
 
 /// Adds two decimal [`PrimitiveArray`] with the same precision and scale.
 /// # Error
@@ -53,6 +54,35 @@ pub fn add(lhs: &PrimitiveArray<i128>, rhs: &PrimitiveArray<i128>) -> PrimitiveA
     binary(lhs, rhs, lhs.data_type().clone(), op)
 }
 
+/// Adds a decimal [`PrimitiveArray`] and a [`PrimitiveScalar`] with the same precision and
+/// scale. A null scalar results in a null array.
+/// # Panic
+/// This function panics iff the precision and scale are different, or the added numbers result
+/// in a number larger than the possible number for the precision.
+pub fn add_scalar(lhs: &PrimitiveArray<i128>, rhs: &PrimitiveScalar<i128>) -> PrimitiveArray<i128> {
+    let (precision, _) = get_parameters(lhs.data_type(), rhs.data_type()).unwrap();
+
+    let rhs = if let Some(rhs) = *rhs.value() {
+        rhs
+    } else {
+        return PrimitiveArray::<i128>::new_null(lhs.data_type().clone(), lhs.len());
+    };
+
+    let max = max_value(precision);
+    let op = move |a: i128| {
+        let res = a + rhs;
+
+        assert!(
+            res.abs() <= max,
+            "Overflow in addition presented for precision {precision}"
+        );
+
+        res
+    };
+
+    unary(lhs, op, lhs.data_type().clone())
+}
+
 /// Saturated addition of two decimal primitive arrays with the same precision
 /// and scale. If the precision and scale is different, then an
 /// InvalidArgumentError is returned. If the result from the sum is larger than
@@ -80,18 +110,18 @@ pub fn saturating_add(
     let (precision, _) = get_parameters(lhs.data_type(), rhs.data_type()).unwrap();
 
     let max = max_value(precision);
-    let op = move |a, b| {
-        let res: i128 = a + b;
-
-        if res.abs() > max {
+    let op = move |a: i128, b: i128| match a.checked_add(b) {
+        Some(res) if res.abs() > max => {
             if res > 0 {
                 max
             } else {
                 -max
             }
-        } else {
-            res
         }
+        Some(res) => res,
+        // both terms have the sign of the overflowing sum
+        None if a < 0 => -max,
+        None => max,
     };
 
     binary(lhs, rhs, lhs.data_type().clone(), op)
@@ -180,64 +210,17 @@ impl ArraySaturatingAdd<PrimitiveArray<i128>> for PrimitiveArray<i128> {
 ///
 /// assert_eq!(result, expected);
 /// ```
+/// # Errors
+/// This function errors iff the arrays have different lengths or are not
+/// [`Decimal`](DataType::Decimal), or a result does not fit in a [`Decimal`](DataType::Decimal).
 pub fn adaptive_add(
     lhs: &PrimitiveArray<i128>,
     rhs: &PrimitiveArray<i128>,
 ) -> Result<PrimitiveArray<i128>> {
-    check_same_len(lhs, rhs)?;
-
-    let (lhs_p, lhs_s, rhs_p, rhs_s) =
-        if let (DataType::Decimal(lhs_p, lhs_s), DataType::Decimal(rhs_p, rhs_s)) =
-            (lhs.data_type(), rhs.data_type())
-        {
-            (*lhs_p, *lhs_s, *rhs_p, *rhs_s)
-        } else {
-            return Err(Error::InvalidArgumentError(
-                "Incorrect data type for the array".to_string(),
-            ));
-        };
-
-    // The resulting precision is mutable because it could change while
-    // looping through the iterator
-    let (mut res_p, res_s, diff) = adjusted_precision_scale(lhs_p, lhs_s, rhs_p, rhs_s);
-
-    let shift = 10i128.pow(diff as u32);
-    let mut max = max_value(res_p);
-
-    let values = lhs
-        .values()
-        .iter()
-        .zip(rhs.values().iter())
-        .map(|(l, r)| {
-            // Based on the array's scales one of the arguments in the sum has to be shifted
-            // to the left to match the final scale
-            let res = if lhs_s > rhs_s {
-                l + r * shift
-            } else {
-                l * shift + r
-            };
-
-            // The precision of the resulting array will change if one of the
-            // sums during the iteration produces a value bigger than the
-            // possible value for the initial precision
-
-            //  99.9999 -> 6, 4
-            //  00.0001 -> 6, 4
-            // -----------------
-            // 100.0000 -> 7, 4
-            if res.abs() > max {
-                res_p = number_digits(res);
-                max = max_value(res_p);
-            }
-            res
-        })
-        .collect::<Vec<_>>();
-
-    let validity = combine_validities(lhs.validity(), rhs.validity());
-
-    Ok(PrimitiveArray::<i128>::new(
-        DataType::Decimal(res_p, res_s),
-        values.into(),
-        validity,
-    ))
+    adaptive(
+        lhs,
+        rhs,
+        |l, r, _| l.checked_add(r),
+        |_, _| Err(Error::Overflow),
+    )
 }
@@ -0,0 +1,665 @@
+//! Defines the arithmetic kernels for [`PrimitiveArray`]s of [`i256`] representing
+//! [`Decimal256`](crate::datatypes::DataType::Decimal256).
+//!
+//! Each operation has the same variants and semantics as its counterpart for
+//! [`Decimal`](crate::datatypes::DataType::Decimal) in [`decimal`](super).
+use ethnum::I256;
+
+use crate::{
+    array::PrimitiveArray,
+    bitmap::MutableBitmap,
+    compute::{
+        arithmetics::{
+            ArrayAdd, ArrayCheckedAdd, ArrayCheckedDiv, ArrayCheckedMul, ArrayCheckedSub, ArrayDiv,
+            ArrayMul, ArraySaturatingAdd, ArraySaturatingMul, ArraySaturatingSub, ArraySub,
+        },
+        arity::{binary, binary_checked, unary},
+        utils::{check_same_len, combine_validities},
+    },
+    datatypes::DataType,
+    error::{Error, Result},
+    scalar::{PrimitiveScalar, Scalar},
+    types::i256,
+};
+
+use super::adjusted_precision_scale;
+
+/// The maximum precision of a [`Decimal256`](DataType::Decimal256)
+pub(crate) const MAX_PRECISION: usize = 76;
+
+#[inline]
+pub(super) fn ten_pow(exp: usize) -> I256 {
+    I256::new(10).pow(exp as u32)
+}
+
+/// Maximum value that can exist with a selected precision
+#[inline]
+pub(crate) fn max_value(precision: usize) -> I256 {
+    ten_pow(precision) - 1
+}
+
+// Calculates the number of digits in a I256 number
+fn number_digits(num: I256) -> usize {
+    let mut num = num.unsigned_abs();
+    let mut digits = 0;
+    while num != 0 {
+        num /= 10;
+        digits += 1;
+    }
+    digits
+}
+
+fn get_parameters(lhs: &DataType, rhs: &DataType) -> Result<(usize, usize)> {
+    if let (DataType::Decimal256(lhs_p, lhs_s), DataType::Decimal256(rhs_p, rhs_s)) =
+        (lhs.to_logical_type(), rhs.to_logical_type())
+    {
+        if lhs_p == rhs_p && lhs_s == rhs_s {
+            Ok((*lhs_p, *lhs_s))
+        } else {
+            Err(Error::InvalidArgumentError(
+                "Arrays must have the same precision and scale".to_string(),
+            ))
+        }
+    } else {
+        unreachable!()
+    }
+}
+
+fn get_adaptive_parameters(lhs: &DataType, rhs: &DataType) -> Result<(usize, usize, usize, usize)> {
+    if let (DataType::Decimal256(lhs_p, lhs_s), DataType::Decimal256(rhs_p, rhs_s)) =
+        (lhs.to_logical_type(), rhs.to_logical_type())
+    {
+        Ok((*lhs_p, *lhs_s, *rhs_p, *rhs_s))
+    } else {
+        Err(Error::InvalidArgumentError(
+            "Incorrect data type for the array".to_string(),
+        ))
+    }
+}
+
+#[inline]
+fn saturate(res: I256, max: I256) -> I256 {
+    if res > max {
+        max
+    } else if res < -max {
+        -max
+    } else {
+        res
+    }
+}
+
+/// Applies `op` to each value of `lhs` and the value of `rhs`, or returns a null array when
+/// `rhs` is null.
+fn binary_scalar<F: Fn(i256, i256) -> i256>(
+    lhs: &PrimitiveArray<i256>,
+    rhs: &PrimitiveScalar<i256>,
+    op: F,
+) -> PrimitiveArray<i256> {
+    match *rhs.value() {
+        Some(rhs) => unary(lhs, |a| op(a, rhs), lhs.data_type().clone()),
+        None => PrimitiveArray::new_null(lhs.data_type().clone(), lhs.len()),
+    }
+}
+
+fn add_op(precision: usize) -> impl Fn(i256, i256) -> i256 {
+    let max = max_value(precision);
+    move |a: i256, b: i256| {
+        let res = a.0 + b.0;
+
+        assert!(
+            res.abs() <= max,
+            "Overflow in addition presented for precision {precision}"
+        );
+
+        i256(res)
+    }
+}
+
+/// Adds two decimal [`PrimitiveArray`] with the same precision and scale.
+/// # Panic
+/// This function panics iff the precision and scale are different, or the added numbers result
+/// in a number larger than the possible number for the precision.
+/// # Examples
+/// ```
+/// use re_arrow2::compute::arithmetics::decimal::decimal256::add;
+/// use re_arrow2::array::PrimitiveArray;
+/// use re_arrow2::datatypes::DataType;
+/// use re_arrow2::types::i256;
+///
+/// let a = PrimitiveArray::from([Some(i256::from_words(0, 1)), None]).to(DataType::Decimal256(5, 2));
+/// let b = PrimitiveArray::from([Some(i256::from_words(0, 2)), None]).to(DataType::Decimal256(5, 2));
+///
+/// let result = add(&a, &b);
+/// let expected = PrimitiveArray::from([Some(i256::from_words(0, 3)), None]).to(DataType::Decimal256(5, 2));
+///
+/// assert_eq!(result, expected);
+/// ```
+pub fn add(lhs: &PrimitiveArray<i256>, rhs: &PrimitiveArray<i256>) -> PrimitiveArray<i256> {
+    let (precision, _) = get_parameters(lhs.data_type(), rhs.data_type()).unwrap();
+
+    binary(lhs, rhs, lhs.data_type().clone(), add_op(precision))
+}
+
+/// Adds a decimal [`PrimitiveArray`] and a [`PrimitiveScalar`] with the same precision and
+/// scale, as [`add`] does. A null scalar results in a null array.
+/// # Panic
+/// This function panics in the same cases as [`add`].
+pub fn add_scalar(lhs: &PrimitiveArray<i256>, rhs: &PrimitiveScalar<i256>) -> PrimitiveArray<i256> {
+    let (precision, _) = get_parameters(lhs.data_type(), rhs.data_type()).unwrap();
+
+    binary_scalar(lhs, rhs, add_op(precision))
+}
+
+/// Saturated addition of two decimal primitive arrays with the same precision and scale.
+/// If the result from the sum is larger than the possible number with the selected precision,
+/// then the resulted number is the maximum number for the selected precision.
+/// # Panic
+/// This function panics iff the precision and scale are different.
+pub fn saturating_add(
+    lhs: &PrimitiveArray<i256>,
+    rhs: &PrimitiveArray<i256>,
+) -> PrimitiveArray<i256> {
+    let (precision, _) = get_parameters(lhs.data_type(), rhs.data_type()).unwrap();
+
+    let max = max_value(precision);
+    let op = move |a: i256, b: i256| i256(saturate(a.0 + b.0, max));
+
+    binary(lhs, rhs, lhs.data_type().clone(), op)
+}
+
+/// Checked addition of two decimal primitive arrays with the same precision and scale.
+/// If the result from the sum is larger than the possible number with the selected precision
+/// (overflowing), then the validity for that index is changed to None.
+/// # Panic
+/// This function panics iff the precision and scale are different.
+pub fn checked_add(lhs: &PrimitiveArray<i256>, rhs: &PrimitiveArray<i256>) -> PrimitiveArray<i256> {
+    let (precision, _) = get_parameters(lhs.data_type(), rhs.data_type()).unwrap();
+
+    let max = max_value(precision);
+    let op = move |a: i256, b: i256| {
+        let res = a.0 + b.0;
+        (res.abs() <= max).then_some(i256(res))
+    };
+
+    binary_checked(lhs, rhs, lhs.data_type().clone(), op)
+}
+
+fn sub_op(precision: usize) -> impl Fn(i256, i256) -> i256 {
+    let max = max_value(precision);
+    move |a: i256, b: i256| {
+        let res = a.0 - b.0;
+
+        assert!(
+            res.abs() <= max,
+            "Overflow in subtract presented for precision {precision}"
+        );
+
+        i256(res)
+    }
+}
+
+/// Subtracts two decimal [`PrimitiveArray`] with the same precision and scale.
+/// # Panic
+/// This function panics iff the precision and scale are different, or the subtracted numbers
+/// result in a number larger than the possible number for the precision.
+pub fn sub(lhs: &PrimitiveArray<i256>, rhs: &PrimitiveArray<i256>) -> PrimitiveArray<i256> {
+    let (precision, _) = get_parameters(lhs.data_type(), rhs.data_type()).unwrap();
+
+    binary(lhs, rhs, lhs.data_type().clone(), sub_op(precision))
+}
+
+/// Subtracts a [`PrimitiveScalar`] from a decimal [`PrimitiveArray`] with the same precision and
+/// scale, as [`sub`] does. A null scalar results in a null array.
+/// # Panic
+/// This function panics in the same cases as [`sub`].
+pub fn sub_scalar(lhs: &PrimitiveArray<i256>, rhs: &PrimitiveScalar<i256>) -> PrimitiveArray<i256> {
+    let (precision, _) = get_parameters(lhs.data_type(), rhs.data_type()).unwrap();
+
+    binary_scalar(lhs, rhs, sub_op(precision))
+}
+
+/// Saturated subtraction of two decimal primitive arrays with the same precision and scale.
+/// If the result is larger than the possible number with the selected precision, then the
+/// resulted number is the maximum (or minimum) number for the selected precision.
+/// # Panic
+/// This function panics iff the precision and scale are different.
+pub fn saturating_sub(
+    lhs: &PrimitiveArray<i256>,
+    rhs: &PrimitiveArray<i256>,
+) -> PrimitiveArray<i256> {
+    let (precision, _) = get_parameters(lhs.data_type(), rhs.data_type()).unwrap();
+
+    let max = max_value(precision);
+    let op = move |a: i256, b: i256| i256(saturate(a.0 - b.0, max));
+
+    binary(lhs, rhs, lhs.data_type().clone(), op)
+}
+
+/// Checked subtraction of two decimal primitive arrays with the same precision and scale.
+/// If the result is larger than the possible number with the selected precision (overflowing),
+/// then the validity for that index is changed to None.
+/// # Panic
+/// This function panics iff the precision and scale are different.
+pub fn checked_sub(lhs: &PrimitiveArray<i256>, rhs: &PrimitiveArray<i256>) -> PrimitiveArray<i256> {
+    let (precision, _) = get_parameters(lhs.data_type(), rhs.data_type()).unwrap();
+
+    let max = max_value(precision);
+    let op = move |a: i256, b: i256| {
+        let res = a.0 - b.0;
+        (res.abs() <= max).then_some(i256(res))
+    };
+
+    binary_checked(lhs, rhs, lhs.data_type().clone(), op)
+}
+
+fn mul_op(precision: usize, scale: usize) -> impl Fn(i256, i256) -> i256 {
+    let scale = ten_pow(scale);
+    let max = max_value(precision);
+    move |a: i256, b: i256| {
+        let res =
+            a.0.checked_mul(b.0)
+                .expect("Mayor overflow for multiplication")
+                / scale;
+
+        assert!(
+            res.abs() <= max,
+            "Overflow in multiplication presented for precision {precision}"
+        );
+
+        i256(res)
+    }
+}
+
+/// Multiplies two decimal [`PrimitiveArray`] with the same precision and scale.
+/// # Panic
+/// This function panics iff the precision and scale are different, or the multiplied numbers
+/// result in a number larger than the possible number for the precision.
+/// # Examples
+/// ```
+/// use re_arrow2::compute::arithmetics::decimal::decimal256::mul;
+/// use re_arrow2::array::PrimitiveArray;
+/// use re_arrow2::datatypes::DataType;
+/// use re_arrow2::types::i256;
+///
+/// // 1.50 * 2.00
+/// let a = PrimitiveArray::from([Some(i256::from_words(0, 1_50))]).to(DataType::Decimal256(5, 2));
+/// let b = PrimitiveArray::from([Some(i256::from_words(0, 2_00))]).to(DataType::Decimal256(5, 2));
+///
+/// let result = mul(&a, &b);
+/// let expected = PrimitiveArray::from([Some(i256::from_words(0, 3_00))]).to(DataType::Decimal256(5, 2));
+///
+/// assert_eq!(result, expected);
+/// ```
+pub fn mul(lhs: &PrimitiveArray<i256>, rhs: &PrimitiveArray<i256>) -> PrimitiveArray<i256> {
+    let (precision, scale) = get_parameters(lhs.data_type(), rhs.data_type()).unwrap();
+
+    binary(lhs, rhs, lhs.data_type().clone(), mul_op(precision, scale))
+}
+
+/// Multiplies a decimal [`PrimitiveArray`] and a [`PrimitiveScalar`] with the same precision and
+/// scale, as [`mul`] does. A null scalar results in a null array.
+/// # Panic
+/// This function panics in the same cases as [`mul`].
+pub fn mul_scalar(lhs: &PrimitiveArray<i256>, rhs: &PrimitiveScalar<i256>) -> PrimitiveArray<i256> {
+    let (precision, scale) = get_parameters(lhs.data_type(), rhs.data_type()).unwrap();
+
+    binary_scalar(lhs, rhs, mul_op(precision, scale))
+}
+
+/// Saturated multiplication of two decimal primitive arrays with the same precision and scale.
+/// If the result is larger than the possible number with the selected precision, then the
+/// resulted number is the maximum (or minimum) number for the selected precision.
+/// # Panic
+/// This function panics iff the precision and scale are different.
+pub fn saturating_mul(
+    lhs: &PrimitiveArray<i256>,
+    rhs: &PrimitiveArray<i256>,
+) -> PrimitiveArray<i256> {
+    let (precision, scale) = get_parameters(lhs.data_type(), rhs.data_type()).unwrap();
+
+    let scale = ten_pow(scale);
+    let max = max_value(precision);
+    let op = move |a: i256, b: i256| match a.0.checked_mul(b.0) {
+        Some(res) => i256(saturate(res / scale, max)),
+        None if a.0.is_negative() != b.0.is_negative() => i256(-max),
+        None => i256(max),
+    };
+
+    binary(lhs, rhs, lhs.data_type().clone(), op)
+}
+
+/// Checked multiplication of two decimal primitive arrays with the same precision and scale.
+/// If the result is larger than the possible number with the selected precision (overflowing),
+/// then the validity for that index is changed to None.
+/// # Panic
+/// This function panics iff the precision and scale are different.
+pub fn checked_mul(lhs: &PrimitiveArray<i256>, rhs: &PrimitiveArray<i256>) -> PrimitiveArray<i256> {
+    let (precision, scale) = get_parameters(lhs.data_type(), rhs.data_type()).unwrap();
+
+    let scale = ten_pow(scale);
+    let max = max_value(precision);
+    let op = move |a: i256, b: i256| {
+        let res = a.0.checked_mul(b.0)? / scale;
+        (res.abs() <= max).then_some(i256(res))
+    };
+
+    binary_checked(lhs, rhs, lhs.data_type().clone(), op)
+}
+
+fn div_op(precision: usize, scale: usize) -> impl Fn(i256, i256) -> i256 {
+    let scale = ten_pow(scale);
+    let max = max_value(precision);
+    move |a: i256, b: i256| {
+        // the dividend is scaled up to maintain the scale after the division
+        let numeral = a.0.checked_mul(scale).expect("Mayor overflow for division");
+        let res = numeral.checked_div(b.0).expect("Found division by zero");
+
+        assert!(
+            res.abs() <= max,
+            "Overflow in division presented for precision {precision}"
+        );
+
+        i256(res)
+    }
+}
+
+/// Divides two decimal [`PrimitiveArray`] with the same precision and scale.
+/// # Panic
+/// This function panics iff the precision and scale are different, the divisor is zero, or the
+/// division results in a number larger than the possible number for the precision.
+pub fn div(lhs: &PrimitiveArray<i256>, rhs: &PrimitiveArray<i256>) -> PrimitiveArray<i256> {
+    let (precision, scale) = get_parameters(lhs.data_type(), rhs.data_type()).unwrap();
+
+    binary(lhs, rhs, lhs.data_type().clone(), div_op(precision, scale))
+}
+
+/// Divides a decimal [`PrimitiveArray`] by a [`PrimitiveScalar`] with the same precision and
+/// scale, as [`div`] does. A null scalar results in a null array.
+/// # Panic
+/// This function panics in the same cases as [`div`].
+pub fn div_scalar(lhs: &PrimitiveArray<i256>, rhs: &PrimitiveScalar<i256>) -> PrimitiveArray<i256> {
+    let (precision, scale) = get_parameters(lhs.data_type(), rhs.data_type()).unwrap();
+
+    binary_scalar(lhs, rhs, div_op(precision, scale))
+}
+
+/// Saturated division of two decimal primitive arrays with the same precision and scale.
+/// If the result is larger than the possible number with the selected precision, then the
+/// resulted number is the maximum (or minimum) number for the selected precision.
+/// A division by zero results in zero.
+/// # Panic
+/// This function panics iff the precision and scale are different.
+pub fn saturating_div(
+    lhs: &PrimitiveArray<i256>,
+    rhs: &PrimitiveArray<i256>,
+) -> PrimitiveArray<i256> {
+    let (precision, scale) = get_parameters(lhs.data_type(), rhs.data_type()).unwrap();
+
+    let scale = ten_pow(scale);
+    let max = max_value(precision);
+    let op = move |a: i256, b: i256| {
+        if b.0 == 0 {
+            return i256(I256::ZERO);
+        }
+        match a.0.checked_mul(scale) {
+            Some(numeral) => i256(saturate(numeral / b.0, max)),
+            None if a.0.is_negative() != b.0.is_negative() => i256(-max),
+            None => i256(max),
+        }
+    };
+
+    binary(lhs, rhs, lhs.data_type().clone(), op)
+}
+
+/// Checked division of two decimal primitive arrays with the same precision and scale.
+/// If the divisor is zero or the result is larger than the possible number with the selected
+/// precision (overflowing), then the validity for that index is changed to None.
+/// # Panic
+/// This function panics iff the precision and scale are different.
+pub fn checked_div(lhs: &PrimitiveArray<i256>, rhs: &PrimitiveArray<i256>) -> PrimitiveArray<i256> {
+    let (precision, scale) = get_parameters(lhs.data_type(), rhs.data_type()).unwrap();
+
+    let scale = ten_pow(scale);
+    let max = max_value(precision);
+    let op = move |a: i256, b: i256| {
+        let res = a.0.checked_mul(scale)?.checked_div(b.0)?;
+        (res.abs() <= max).then_some(i256(res))
+    };
+
+    binary_checked(lhs, rhs, lhs.data_type().clone(), op)
+}
+
+/// Applies `op` to the values of `lhs` and `rhs` rescaled to the largest of both scales, and
+/// adapts the precision of the result to fit its largest value.
+///
+/// `op` and `on_overflow` behave like in [`adaptive`](super::adaptive) for [`Decimal`](DataType::Decimal).
+pub(crate) fn adaptive<F, O>(
+    lhs: &PrimitiveArray<i256>,
+    rhs: &PrimitiveArray<i256>,
+    op: F,
+    on_overflow: O,
+) -> Result<PrimitiveArray<i256>>
+where
+    F: Fn(I256, I256, I256) -> Option<I256>,
+    O: Fn(I256, I256) -> Result<Option<I256>>,
+{
+    check_same_len(lhs, rhs)?;
+
+    let (lhs_p, lhs_s, rhs_p, rhs_s) = get_adaptive_parameters(lhs.data_type(), rhs.data_type())?;
+    let (res_p, res_s, diff) = adjusted_precision_scale(lhs_p, lhs_s, rhs_p, rhs_s);
+    let mut res_p = res_p.min(MAX_PRECISION);
+
+    let shift = ten_pow(diff);
+    let scale = ten_pow(res_s);
+    let mut max = max_value(res_p);
+
+    let validity = combine_validities(lhs.validity(), rhs.validity());
+    let mut result_validity = validity.clone().map(|x| x.make_mut());
+
+    let mut values = Vec::with_capacity(lhs.len());
+    for (i, (l, r)) in lhs.values().iter().zip(rhs.values().iter()).enumerate() {
+        // null slots may hold any value
+        if validity.as_ref().map_or(false, |x| !x.get_bit(i)) {
+            values.push(i256(I256::ZERO));
+            continue;
+        }
+        let (l, r) = (l.0, r.0);
+        // Based on the array's scales one of the arguments has to be shifted
+        // to the left to match the final scale
+        let res = if lhs_s > rhs_s {
+            r.checked_mul(shift).and_then(|r| op(l, r, scale))
+        } else {
+            l.checked_mul(shift).and_then(|l| op(l, r, scale))
+        }
+        .filter(|res| number_digits(*res) <= MAX_PRECISION);
+        let res = match res {
+            Some(res) => Some(res),
+            None => on_overflow(l, r)?,
+        };
+        match res {
+            Some(res) => {
+                // The precision of the result grows to fit the largest value
+                if res.abs() > max {
+                    res_p = number_digits(res);
+                    max = max_value(res_p);
+                }
+                values.push(i256(res));
+            }
+            None => {
+                values.push(i256(I256::ZERO));
+                result_validity
+                    .get_or_insert_with(|| MutableBitmap::from_len_set(lhs.len()))
+                    .set(i, false);
+            }
+        }
+    }
+
+    Ok(PrimitiveArray::<i256>::new(
+        DataType::Decimal256(res_p, res_s),
+        values.into(),
+        result_validity.map(|x| x.into()),
+    ))
+}
+
+/// Adaptive addition of two decimal primitive arrays with different precision and scale.
+/// The values are rescaled to the largest scale, and the precision of the result is the
+/// smallest that fits the result.
+/// # Errors
+/// This function errors iff the arrays have different lengths or are not
+/// [`Decimal256`](DataType::Decimal256), or a result does not fit in a [`Decimal256`](DataType::Decimal256).
+/// # Examples
+/// ```
+/// use re_arrow2::compute::arithmetics::decimal::decimal256::adaptive_add;
+/// use re_arrow2::array::PrimitiveArray;
+/// use re_arrow2::datatypes::DataType;
+/// use re_arrow2::types::i256;
+///
+/// let a = PrimitiveArray::from([Some(i256::from_words(0, 11111_11))]).to(DataType::Decimal256(7, 2));
+/// let b = PrimitiveArray::from([Some(i256::from_words(0, 11111_111))]).to(DataType::Decimal256(8, 3));
+/// let result = adaptive_add(&a, &b).unwrap();
+/// let expected = PrimitiveArray::from([Some(i256::from_words(0, 22222_221))]).to(DataType::Decimal256(8, 3));
+///
+/// assert_eq!(result, expected);
+/// ```
+pub fn adaptive_add(
+    lhs: &PrimitiveArray<i256>,
+    rhs: &PrimitiveArray<i256>,
+) -> Result<PrimitiveArray<i256>> {
+    adaptive(
+        lhs,
+        rhs,
+        |l, r, _| l.checked_add(r),
+        |_, _| Err(Error::Overflow),
+    )
+}
+
+/// Adaptive subtraction of two decimal primitive arrays with different precision and scale.
+/// See [`adaptive_add`] for the resulting precision and scale.
+/// # Errors
+/// This function errors iff the arrays have different lengths or are not
+/// [`Decimal256`](DataType::Decimal256), or a result does not fit in a [`Decimal256`](DataType::Decimal256).
+pub fn adaptive_sub(
+    lhs: &PrimitiveArray<i256>,
+    rhs: &PrimitiveArray<i256>,
+) -> Result<PrimitiveArray<i256>> {
+    adaptive(
+        lhs,
+        rhs,
+        |l, r, _| l.checked_sub(r),
+        |_, _| Err(Error::Overflow),
+    )
+}
+
+/// Adaptive multiplication of two decimal primitive arrays with different precision and scale.
+/// See [`adaptive_add`] for the resulting precision and scale.
+/// # Errors
+/// This function errors iff the arrays have different lengths or are not
+/// [`Decimal256`](DataType::Decimal256), or a result does not fit in a [`Decimal256`](DataType::Decimal256).
+pub fn adaptive_mul(
+    lhs: &PrimitiveArray<i256>,
+    rhs: &PrimitiveArray<i256>,
+) -> Result<PrimitiveArray<i256>> {
+    adaptive(
+        lhs,
+        rhs,
+        |l, r, scale| Some(l.checked_mul(r)? / scale),
+        |_, _| Err(Error::Overflow),
+    )
+}
+
+/// Adaptive division of two decimal primitive arrays with different precision and scale.
+/// See [`adaptive_add`] for the resulting precision and scale.
+/// # Errors
+/// This function errors iff the arrays have different lengths or are not
+/// [`Decimal256`](DataType::Decimal256), a divisor is zero, or a result does not fit in a
+/// [`Decimal256`](DataType::Decimal256).
+pub fn adaptive_div(
+    lhs: &PrimitiveArray<i256>,
+    rhs: &PrimitiveArray<i256>,
+) -> Result<PrimitiveArray<i256>> {
+    check_same_len(lhs, rhs)?;
+    let validity = combine_validities(lhs.validity(), rhs.validity());
+    let has_zero_divisor = rhs
+        .values()
+        .iter()
+        .enumerate()
+        .any(|(i, r)| r.0 == 0 && validity.as_ref().map_or(true, |x| x.get_bit(i)));
+    if has_zero_divisor {
+        return Err(Error::InvalidArgumentError(
+            "Found division by zero".to_string(),
+        ));
+    }
+    adaptive(
+        lhs,
+        rhs,
+        |l, r, scale| l.checked_mul(scale)?.checked_div(r),
+        |_, _| Err(Error::Overflow),
+    )
+}
+
+// Implementation of the arithmetic traits for PrimitiveArrays
+impl ArrayAdd<PrimitiveArray<i256>> for PrimitiveArray<i256> {
+    fn add(&self, rhs: &PrimitiveArray<i256>) -> Self {
+        add(self, rhs)
+    }
+}
+
+impl ArrayCheckedAdd<PrimitiveArray<i256>> for PrimitiveArray<i256> {
+    fn checked_add(&self, rhs: &PrimitiveArray<i256>) -> Self {
+        checked_add(self, rhs)
+    }
+}
+
+impl ArraySaturatingAdd<PrimitiveArray<i256>> for PrimitiveArray<i256> {
+    fn saturating_add(&self, rhs: &PrimitiveArray<i256>) -> Self {
+        saturating_add(self, rhs)
+    }
+}
+
+impl ArraySub<PrimitiveArray<i256>> for PrimitiveArray<i256> {
+    fn sub(&self, rhs: &PrimitiveArray<i256>) -> Self {
+        sub(self, rhs)
+    }
+}
+
+impl ArrayCheckedSub<PrimitiveArray<i256>> for PrimitiveArray<i256> {
+    fn checked_sub(&self, rhs: &PrimitiveArray<i256>) -> Self {
+        checked_sub(self, rhs)
+    }
+}
+
+impl ArraySaturatingSub<PrimitiveArray<i256>> for PrimitiveArray<i256> {
+    fn saturating_sub(&self, rhs: &PrimitiveArray<i256>) -> Self {
+        saturating_sub(self, rhs)
+    }
+}
+
+impl ArrayMul<PrimitiveArray<i256>> for PrimitiveArray<i256> {
+    fn mul(&self, rhs: &PrimitiveArray<i256>) -> Self {
+        mul(self, rhs)
+    }
+}
+
+impl ArrayCheckedMul<PrimitiveArray<i256>> for PrimitiveArray<i256> {
+    fn checked_mul(&self, rhs: &PrimitiveArray<i256>) -> Self {
+        checked_mul(self, rhs)
+    }
+}
+
+impl ArraySaturatingMul<PrimitiveArray<i256>> for PrimitiveArray<i256> {
+    fn saturating_mul(&self, rhs: &PrimitiveArray<i256>) -> Self {
+        saturating_mul(self, rhs)
+    }
+}
+
+impl ArrayDiv<PrimitiveArray<i256>> for PrimitiveArray<i256> {
+    fn div(&self, rhs: &PrimitiveArray<i256>) -> Self {
+        div(self, rhs)
+    }
+}
+
+impl ArrayCheckedDiv<PrimitiveArray<i256>> for PrimitiveArray<i256> {
+    fn checked_div(&self, rhs: &PrimitiveArray<i256>) -> Self {
+        checked_div(self, rhs)
+    }
+}
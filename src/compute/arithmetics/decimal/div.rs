@@ -13,7 +13,7 @@ use crate::{
     scalar::{PrimitiveScalar, Scalar},
 };
 
-use super::{adjusted_precision_scale, get_parameters, max_value, number_digits};
+use super::{adaptive, get_parameters, max_value};
 
 /// Divide two decimal primitive arrays with the same precision and scale. If
 /// the precision and scale is different, then an InvalidArgumentError is
@@ -139,10 +139,11 @@ pub fn saturating_div(
     let max = max_value(precision);
 
     let op = move |a: i128, b: i128| {
-        let numeral: i128 = a * scale;
-
-        match numeral.checked_div(b) {
-            Some(res) => match res {
+        if b == 0 {
+            return 0;
+        }
+        match a.checked_mul(scale) {
+            Some(numeral) => match numeral / b {
                 res if res.abs() > max => {
                     if res > 0 {
                         max
@@ -150,9 +151,10 @@ pub fn saturating_div(
                         -max
                     }
                 }
-                _ => res,
+                res => res,
             },
-            None => 0,
+            None if (a < 0) != (b < 0) => -max,
+            None => max,
         }
     };
 
@@ -217,8 +219,7 @@ impl ArrayCheckedDiv<PrimitiveArray<i128>> for PrimitiveArray<i128> {
 /// and scale. If the precision and scale is different, then the smallest scale
 /// and precision is adjusted to the largest precision and scale. If during the
 /// division one of the results is larger than the max possible value, the
-/// result precision is changed to the precision of the max value. The function
-/// panics when divided by zero.
+/// result precision is changed to the precision of the max value.
 ///
 /// ```nocode
 ///  1000.00   -> 7, 2
@@ -239,69 +240,30 @@ impl ArrayCheckedDiv<PrimitiveArray<i128>> for PrimitiveArray<i128> {
 ///
 /// assert_eq!(result, expected);
 /// ```
+/// # Errors
+/// This function errors iff the arrays have different lengths or are not
+/// [`Decimal`](DataType::Decimal), a divisor is zero, or a result does not fit in a
+/// [`Decimal`](DataType::Decimal).
 pub fn adaptive_div(
     lhs: &PrimitiveArray<i128>,
     rhs: &PrimitiveArray<i128>,
 ) -> Result<PrimitiveArray<i128>> {
     check_same_len(lhs, rhs)?;
-
-    let (lhs_p, lhs_s, rhs_p, rhs_s) =
-        if let (DataType::Decimal(lhs_p, lhs_s), DataType::Decimal(rhs_p, rhs_s)) =
-            (lhs.data_type(), rhs.data_type())
-        {
-            (*lhs_p, *lhs_s, *rhs_p, *rhs_s)
-        } else {
-            return Err(Error::InvalidArgumentError(
-                "Incorrect data type for the array".to_string(),
-            ));
-        };
-
-    // The resulting precision is mutable because it could change while
-    // looping through the iterator
-    let (mut res_p, res_s, diff) = adjusted_precision_scale(lhs_p, lhs_s, rhs_p, rhs_s);
-
-    let shift = 10i128.pow(diff as u32);
-    let shift_1 = 10i128.pow(res_s as u32);
-    let mut max = max_value(res_p);
-
-    let values = lhs
+    let validity = combine_validities(lhs.validity(), rhs.validity());
+    let has_zero_divisor = rhs
         .values()
         .iter()
-        .zip(rhs.values().iter())
-        .map(|(l, r)| {
-            let numeral: i128 = l * shift_1;
-
-            // Based on the array's scales one of the arguments in the sum has to be shifted
-            // to the left to match the final scale
-            let res = if lhs_s > rhs_s {
-                numeral.checked_div(r * shift)
-            } else {
-                (numeral * shift).checked_div(*r)
-            }
-            .expect("Found division by zero");
-
-            // The precision of the resulting array will change if one of the
-            // multiplications during the iteration produces a value bigger
-            // than the possible value for the initial precision
-
-            //  10.0000 -> 6, 4
-            //  00.1000 -> 6, 4
-            // -----------------
-            // 100.0000 -> 7, 4
-            if res.abs() > max {
-                res_p = number_digits(res);
-                max = max_value(res_p);
-            }
-
-            res
-        })
-        .collect::<Vec<_>>();
-
-    let validity = combine_validities(lhs.validity(), rhs.validity());
-
-    Ok(PrimitiveArray::<i128>::new(
-        DataType::Decimal(res_p, res_s),
-        values.into(),
-        validity,
-    ))
+        .enumerate()
+        .any(|(i, r)| *r == 0 && validity.as_ref().map_or(true, |x| x.get_bit(i)));
+    if has_zero_divisor {
+        return Err(Error::InvalidArgumentError(
+            "Found division by zero".to_string(),
+        ));
+    }
+    adaptive(
+        lhs,
+        rhs,
+        |l, r, scale| l.checked_mul(scale)?.checked_div(r),
+        |_, _| Err(Error::Overflow),
+    )
 }
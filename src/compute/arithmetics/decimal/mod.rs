@@ -2,22 +2,34 @@
 //! [`Decimal`](crate::datatypes::DataType::Decimal) type specifies the
 //! precision and scale parameters. These affect the arithmetic operations and
 //! need to be considered while doing operations with Decimal numbers.
+//!
+//! The kernels for [`Decimal256`](crate::datatypes::DataType::Decimal256) are in [`decimal256`],
+//! while [`round`], [`floor`], [`ceil`], [`trunc`] and [`rescale`] support both widths.
 
 mod add;
 pub use add::*;
+pub mod decimal256;
 mod div;
 pub use div::*;
 mod mul;
 pub use mul::*;
+mod round;
+pub use round::*;
 mod sub;
 pub use sub::*;
 
+use crate::array::PrimitiveArray;
+use crate::bitmap::MutableBitmap;
+use crate::compute::utils::{check_same_len, combine_validities};
 use crate::datatypes::DataType;
 use crate::error::{Error, Result};
 
+/// The largest precision of a [`DataType::Decimal`]
+pub(crate) const MAX_PRECISION: usize = 38;
+
 /// Maximum value that can exist with a selected precision
 #[inline]
-fn max_value(precision: usize) -> i128 {
+pub(crate) fn max_value(precision: usize) -> i128 {
     10i128.pow(precision as u32) - 1
 }
 
@@ -86,6 +98,92 @@ fn adjusted_precision_scale(
     (res_p, res_s, diff)
 }
 
+/// Applies `op` to the values of `lhs` and `rhs` rescaled to the largest of both scales, and
+/// adapts the precision of the result to fit its largest value.
+///
+/// `op` receives the rescaled values and the scale factor of the result, and returns `None` if
+/// the result overflows or is undefined (e.g. a division by zero). For those slots, and those
+/// whose rescaling or precision overflows, `on_overflow` receives the original values and
+/// returns either the value of the slot, `None` for a null slot, or an error.
+pub(crate) fn adaptive<F, O>(
+    lhs: &PrimitiveArray<i128>,
+    rhs: &PrimitiveArray<i128>,
+    op: F,
+    on_overflow: O,
+) -> Result<PrimitiveArray<i128>>
+where
+    F: Fn(i128, i128, i128) -> Option<i128>,
+    O: Fn(i128, i128) -> Result<Option<i128>>,
+{
+    check_same_len(lhs, rhs)?;
+
+    let (lhs_p, lhs_s, rhs_p, rhs_s) =
+        if let (DataType::Decimal(lhs_p, lhs_s), DataType::Decimal(rhs_p, rhs_s)) =
+            (lhs.data_type(), rhs.data_type())
+        {
+            (*lhs_p, *lhs_s, *rhs_p, *rhs_s)
+        } else {
+            return Err(Error::InvalidArgumentError(
+                "Incorrect data type for the array".to_string(),
+            ));
+        };
+
+    // The resulting precision is mutable because it could change while
+    // looping through the iterator
+    let (res_p, res_s, diff) = adjusted_precision_scale(lhs_p, lhs_s, rhs_p, rhs_s);
+    let mut res_p = res_p.min(MAX_PRECISION);
+
+    let shift = 10i128.pow(diff as u32);
+    let scale = 10i128.pow(res_s as u32);
+    let mut max = max_value(res_p);
+
+    let validity = combine_validities(lhs.validity(), rhs.validity());
+    let mut result_validity = validity.clone().map(|x| x.make_mut());
+
+    let mut values = Vec::with_capacity(lhs.len());
+    for (i, (&l, &r)) in lhs.values().iter().zip(rhs.values().iter()).enumerate() {
+        // null slots may hold any value
+        if validity.as_ref().map_or(false, |x| !x.get_bit(i)) {
+            values.push(0);
+            continue;
+        }
+        // Based on the array's scales one of the arguments has to be shifted
+        // to the left to match the final scale
+        let res = if lhs_s > rhs_s {
+            r.checked_mul(shift).and_then(|r| op(l, r, scale))
+        } else {
+            l.checked_mul(shift).and_then(|l| op(l, r, scale))
+        }
+        .filter(|res| number_digits(*res) <= MAX_PRECISION);
+        let res = match res {
+            Some(res) => Some(res),
+            None => on_overflow(l, r)?,
+        };
+        match res {
+            Some(res) => {
+                // The precision of the result grows to fit the largest value
+                if res.abs() > max {
+                    res_p = number_digits(res);
+                    max = max_value(res_p);
+                }
+                values.push(res);
+            }
+            None => {
+                values.push(0);
+                result_validity
+                    .get_or_insert_with(|| MutableBitmap::from_len_set(lhs.len()))
+                    .set(i, false);
+            }
+        }
+    }
+
+    Ok(PrimitiveArray::<i128>::new(
+        DataType::Decimal(res_p, res_s),
+        values.into(),
+        result_validity.map(|x| x.into()),
+    ))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
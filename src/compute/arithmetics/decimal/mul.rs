@@ -13,7 +13,7 @@ use crate::{
     scalar::{PrimitiveScalar, Scalar},
 };
 
-use super::{adjusted_precision_scale, get_parameters, max_value, number_digits};
+use super::{adaptive, get_parameters, max_value};
 
 /// Multiply two decimal primitive arrays with the same precision and scale. If
 /// the precision and scale is different, then an InvalidArgumentError is
@@ -157,6 +157,7 @@ pub fn saturating_mul(
                 _ => res,
             }
         }
+        None if (a < 0) != (b < 0) => -max,
         None => max,
     };
 
@@ -251,69 +252,17 @@ impl ArraySaturatingMul<PrimitiveArray<i128>> for PrimitiveArray<i128> {
 ///
 /// assert_eq!(result, expected);
 /// ```
+/// # Errors
+/// This function errors iff the arrays have different lengths or are not
+/// [`Decimal`](DataType::Decimal), or a result does not fit in a [`Decimal`](DataType::Decimal).
 pub fn adaptive_mul(
     lhs: &PrimitiveArray<i128>,
     rhs: &PrimitiveArray<i128>,
 ) -> Result<PrimitiveArray<i128>> {
-    check_same_len(lhs, rhs)?;
-
-    let (lhs_p, lhs_s, rhs_p, rhs_s) =
-        if let (DataType::Decimal(lhs_p, lhs_s), DataType::Decimal(rhs_p, rhs_s)) =
-            (lhs.data_type(), rhs.data_type())
-        {
-            (*lhs_p, *lhs_s, *rhs_p, *rhs_s)
-        } else {
-            return Err(Error::InvalidArgumentError(
-                "Incorrect data type for the array".to_string(),
-            ));
-        };
-
-    // The resulting precision is mutable because it could change while
-    // looping through the iterator
-    let (mut res_p, res_s, diff) = adjusted_precision_scale(lhs_p, lhs_s, rhs_p, rhs_s);
-
-    let shift = 10i128.pow(diff as u32);
-    let shift_1 = 10i128.pow(res_s as u32);
-    let mut max = max_value(res_p);
-
-    let values = lhs
-        .values()
-        .iter()
-        .zip(rhs.values().iter())
-        .map(|(l, r)| {
-            // Based on the array's scales one of the arguments in the sum has to be shifted
-            // to the left to match the final scale
-            let res = if lhs_s > rhs_s {
-                l.checked_mul(r * shift)
-            } else {
-                (l * shift).checked_mul(*r)
-            }
-            .expect("Mayor overflow for multiplication");
-
-            let res = res / shift_1;
-
-            // The precision of the resulting array will change if one of the
-            // multiplications during the iteration produces a value bigger
-            // than the possible value for the initial precision
-
-            //  10.0000 -> 6, 4
-            //  10.0000 -> 6, 4
-            // -----------------
-            // 100.0000 -> 7, 4
-            if res.abs() > max {
-                res_p = number_digits(res);
-                max = max_value(res_p);
-            }
-
-            res
-        })
-        .collect::<Vec<_>>();
-
-    let validity = combine_validities(lhs.validity(), rhs.validity());
-
-    Ok(PrimitiveArray::<i128>::new(
-        DataType::Decimal(res_p, res_s),
-        values.into(),
-        validity,
-    ))
+    adaptive(
+        lhs,
+        rhs,
+        |l, r, scale| Some(l.checked_mul(r)? / scale),
+        |_, _| Err(Error::Overflow),
+    )
 }
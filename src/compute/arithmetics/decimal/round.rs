@@ -0,0 +1,220 @@
+//! Defines the rounding and rescaling kernels for arrays of both
+//! [`Decimal`](crate::datatypes::DataType::Decimal) and
+//! [`Decimal256`](crate::datatypes::DataType::Decimal256).
+use ethnum::I256;
+
+use crate::{
+    array::{Array, PrimitiveArray},
+    datatypes::{DataType, PhysicalType, PrimitiveType},
+    error::{Error, Result},
+    types::{i256, NativeType},
+};
+
+use super::decimal256::{max_value, ten_pow, MAX_PRECISION as MAX_PRECISION_256};
+
+/// The maximum precision of a [`Decimal`](DataType::Decimal)
+const MAX_PRECISION_128: usize = 38;
+
+/// A [`NativeType`] backing a decimal, operated on as an [`I256`].
+trait DecimalNative: NativeType {
+    fn to_i256(self) -> I256;
+    fn from_i256(value: I256) -> Self;
+}
+
+impl DecimalNative for i128 {
+    #[inline]
+    fn to_i256(self) -> I256 {
+        I256::new(self)
+    }
+
+    #[inline]
+    fn from_i256(value: I256) -> Self {
+        // results are checked against the precision, that always fits in an i128
+        value.as_i128()
+    }
+}
+
+impl DecimalNative for i256 {
+    #[inline]
+    fn to_i256(self) -> I256 {
+        self.0
+    }
+
+    #[inline]
+    fn from_i256(value: I256) -> Self {
+        i256(value)
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Rounding {
+    HalfAwayFromZero,
+    Floor,
+    Ceil,
+    Trunc,
+}
+
+/// Divides `value` by the positive `divisor`, rounding according to `rounding`.
+#[inline]
+fn div_round(value: I256, divisor: I256, rounding: Rounding) -> I256 {
+    let quotient = value / divisor;
+    let remainder = value % divisor;
+    match rounding {
+        Rounding::HalfAwayFromZero if remainder.unsigned_abs() * 2 >= divisor.unsigned_abs() => {
+            quotient + value.signum()
+        }
+        Rounding::Floor if remainder < 0 => quotient - 1,
+        Rounding::Ceil if remainder > 0 => quotient + 1,
+        // `/` already rounds towards zero
+        _ => quotient,
+    }
+}
+
+/// Returns the precision and scale of a decimal `data_type`.
+fn parameters(data_type: &DataType) -> Result<(usize, usize)> {
+    match data_type.to_logical_type() {
+        DataType::Decimal(precision, scale) | DataType::Decimal256(precision, scale) => {
+            Ok((*precision, *scale))
+        }
+        other => Err(Error::InvalidArgumentError(format!(
+            "Decimal rounding requires a decimal array, got {other:?}"
+        ))),
+    }
+}
+
+fn map<T: DecimalNative, F: Fn(I256) -> Option<I256>>(
+    array: &dyn Array,
+    data_type: DataType,
+    op: F,
+) -> Result<Box<dyn Array>> {
+    let array = array.as_any().downcast_ref::<PrimitiveArray<T>>().unwrap();
+    let values = array
+        .iter()
+        .map(|x| match x {
+            Some(x) => op(x.to_i256()).map(T::from_i256).ok_or(Error::Overflow),
+            None => Ok(T::default()),
+        })
+        .collect::<Result<Vec<_>>>()?;
+    Ok(PrimitiveArray::new(data_type, values.into(), array.validity().cloned()).boxed())
+}
+
+/// Applies `op` to each valid value of the decimal `array`, returning an array of `data_type`.
+fn map_dyn<F: Fn(I256) -> Option<I256>>(
+    array: &dyn Array,
+    data_type: DataType,
+    op: F,
+) -> Result<Box<dyn Array>> {
+    match array.data_type().to_physical_type() {
+        PhysicalType::Primitive(PrimitiveType::Int128) => map::<i128, _>(array, data_type, op),
+        PhysicalType::Primitive(PrimitiveType::Int256) => map::<i256, _>(array, data_type, op),
+        _ => unreachable!(),
+    }
+}
+
+fn round_with(array: &dyn Array, decimals: usize, rounding: Rounding) -> Result<Box<dyn Array>> {
+    let (precision, scale) = parameters(array.data_type())?;
+    if decimals >= scale {
+        return Ok(array.to_boxed());
+    }
+    let divisor = ten_pow(scale - decimals);
+    let max = max_value(precision);
+    map_dyn(array, array.data_type().clone(), |x| {
+        let result = div_round(x, divisor, rounding).checked_mul(divisor)?;
+        (result.abs() <= max).then_some(result)
+    })
+}
+
+/// Rounds each value of a decimal array to `decimals` fractional digits, with ties rounded away
+/// from zero. The result has the same [`DataType`] as `array`, and `decimals` larger than its
+/// scale leave it unchanged.
+/// # Errors
+/// This function errors iff `array` is not a [`Decimal`](DataType::Decimal) or
+/// [`Decimal256`](DataType::Decimal256), or a rounded value does not fit in its precision.
+/// # Example
+/// ```
+/// use re_arrow2::array::Int128Array;
+/// use re_arrow2::compute::arithmetics::decimal::round;
+/// use re_arrow2::datatypes::DataType;
+///
+/// let array = Int128Array::from(&[Some(1_2345), Some(-1_2350), None]).to(DataType::Decimal(6, 4));
+/// let result = round(&array, 2).unwrap();
+/// let expected = Int128Array::from(&[Some(1_2300), Some(-1_2400), None]).to(DataType::Decimal(6, 4));
+/// assert_eq!(expected, result.as_ref());
+/// ```
+pub fn round(array: &dyn Array, decimals: usize) -> Result<Box<dyn Array>> {
+    round_with(array, decimals, Rounding::HalfAwayFromZero)
+}
+
+/// Rounds each value of a decimal array down to the nearest integer. The result has the same
+/// [`DataType`] as `array`.
+/// # Errors
+/// This function errors iff `array` is not a [`Decimal`](DataType::Decimal) or
+/// [`Decimal256`](DataType::Decimal256), or a rounded value does not fit in its precision.
+pub fn floor(array: &dyn Array) -> Result<Box<dyn Array>> {
+    round_with(array, 0, Rounding::Floor)
+}
+
+/// Rounds each value of a decimal array up to the nearest integer. The result has the same
+/// [`DataType`] as `array`.
+/// # Errors
+/// This function errors iff `array` is not a [`Decimal`](DataType::Decimal) or
+/// [`Decimal256`](DataType::Decimal256), or a rounded value does not fit in its precision.
+pub fn ceil(array: &dyn Array) -> Result<Box<dyn Array>> {
+    round_with(array, 0, Rounding::Ceil)
+}
+
+/// Rounds each value of a decimal array towards zero to the nearest integer. The result has the
+/// same [`DataType`] as `array`.
+/// # Errors
+/// This function errors iff `array` is not a [`Decimal`](DataType::Decimal) or
+/// [`Decimal256`](DataType::Decimal256).
+pub fn trunc(array: &dyn Array) -> Result<Box<dyn Array>> {
+    round_with(array, 0, Rounding::Trunc)
+}
+
+/// Changes the precision and scale of a decimal array, keeping its width
+/// ([`Decimal`](DataType::Decimal) or [`Decimal256`](DataType::Decimal256)). Values are rounded
+/// half away from zero when the scale decreases.
+/// # Errors
+/// This function errors iff `array` is not a decimal, the new precision and scale are not valid
+/// for its width, or a value does not fit in the new precision.
+/// # Example
+/// ```
+/// use re_arrow2::array::Int128Array;
+/// use re_arrow2::compute::arithmetics::decimal::rescale;
+/// use re_arrow2::datatypes::DataType;
+///
+/// let array = Int128Array::from(&[Some(1_25), Some(-3_00), None]).to(DataType::Decimal(5, 2));
+///
+/// let result = rescale(&array, 6, 3).unwrap();
+/// let expected = Int128Array::from(&[Some(1_250), Some(-3_000), None]).to(DataType::Decimal(6, 3));
+/// assert_eq!(expected, result.as_ref());
+///
+/// let result = rescale(&array, 2, 1).unwrap();
+/// let expected = Int128Array::from(&[Some(1_3), Some(-3_0), None]).to(DataType::Decimal(2, 1));
+/// assert_eq!(expected, result.as_ref());
+/// ```
+pub fn rescale(array: &dyn Array, precision: usize, scale: usize) -> Result<Box<dyn Array>> {
+    let (_, from_scale) = parameters(array.data_type())?;
+    let (data_type, max_precision) = match array.data_type().to_logical_type() {
+        DataType::Decimal(_, _) => (DataType::Decimal(precision, scale), MAX_PRECISION_128),
+        _ => (DataType::Decimal256(precision, scale), MAX_PRECISION_256),
+    };
+    if precision == 0 || precision > max_precision || scale > precision {
+        return Err(Error::InvalidArgumentError(format!(
+            "Invalid precision and scale for {data_type:?}"
+        )));
+    }
+
+    let max = max_value(precision);
+    let check = move |x: I256| (x.abs() <= max).then_some(x);
+    if scale >= from_scale {
+        let factor = ten_pow(scale - from_scale);
+        map_dyn(array, data_type, |x| check(x.checked_mul(factor)?))
+    } else {
+        let divisor = ten_pow(from_scale - scale);
+        map_dyn(array, data_type, |x| {
+            check(div_round(x, divisor, Rounding::HalfAwayFromZero))
+        })
+    }
+}
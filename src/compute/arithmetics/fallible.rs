@@ -0,0 +1,890 @@
+//! Fallible dynamic arithmetics: [`try_add`], [`try_sub`], [`try_mul`], [`try_div`], [`try_rem`]
+//! and [`try_neg`], that return an error instead of panicking, with an [`OverflowMode`].
+use num_traits::{AsPrimitive, Bounded};
+
+use crate::{
+    array::{Array, DictionaryArray, PrimitiveArray},
+    bitmap::{Bitmap, MutableBitmap},
+    compute::{
+        arity::unary,
+        utils::{check_same_len, combine_validities},
+    },
+    datatypes::{DataType, IntervalUnit, PhysicalType, PrimitiveType, TimeUnit},
+    error::{Error, Result},
+    temporal_conversions,
+    types::{f16, i256, NativeType},
+};
+
+#[cfg(feature = "compute_arithmetics_decimal")]
+use super::decimal;
+use super::{basic, time};
+
+/// How the fallible arithmetic kernels handle a result that does not fit in its type.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash)]
+pub enum OverflowMode {
+    /// Returns [`Error::Overflow`].
+    #[default]
+    Error,
+    /// Turns the result into a null.
+    Null,
+    /// Wraps around the boundary of the type (two's complement).
+    Wrap,
+    /// Saturates the result to the minimum or maximum value of the type.
+    Saturate,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Op {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Rem,
+}
+
+impl Op {
+    fn name(self) -> &'static str {
+        match self {
+            Op::Add => "Addition",
+            Op::Sub => "Subtraction",
+            Op::Mul => "Multiplication",
+            Op::Div => "Division",
+            Op::Rem => "Remainder",
+        }
+    }
+}
+
+/// A [`NativeType`] supporting the operations of [`Op`] under each [`OverflowMode`].
+/// Each function returns `None` when the operation overflows or divides by zero.
+trait Arithmetic: NativeType {
+    fn checked(op: Op, a: Self, b: Self) -> Option<Self>;
+    fn wrapping(op: Op, a: Self, b: Self) -> Option<Self>;
+    fn saturating(op: Op, a: Self, b: Self) -> Option<Self>;
+    fn is_zero(self) -> bool;
+}
+
+/// A [`NativeType`] supporting negation under each [`OverflowMode`].
+trait Negate: NativeType {
+    fn checked_neg(self) -> Option<Self>;
+    fn wrapping_neg(self) -> Self;
+    fn saturating_neg(self) -> Self;
+}
+
+macro_rules! impl_integer {
+    ($($ty:ty),*) => {$(
+        impl Arithmetic for $ty {
+            #[inline]
+            fn checked(op: Op, a: Self, b: Self) -> Option<Self> {
+                match op {
+                    Op::Add => a.checked_add(b),
+                    Op::Sub => a.checked_sub(b),
+                    Op::Mul => a.checked_mul(b),
+                    Op::Div => a.checked_div(b),
+                    Op::Rem => a.checked_rem(b),
+                }
+            }
+
+            #[inline]
+            fn wrapping(op: Op, a: Self, b: Self) -> Option<Self> {
+                match op {
+                    Op::Add => Some(a.wrapping_add(b)),
+                    Op::Sub => Some(a.wrapping_sub(b)),
+                    Op::Mul => Some(a.wrapping_mul(b)),
+                    Op::Div => (b != 0).then(|| a.wrapping_div(b)),
+                    Op::Rem => (b != 0).then(|| a.wrapping_rem(b)),
+                }
+            }
+
+            #[inline]
+            fn saturating(op: Op, a: Self, b: Self) -> Option<Self> {
+                match op {
+                    Op::Add => Some(a.saturating_add(b)),
+                    Op::Sub => Some(a.saturating_sub(b)),
+                    Op::Mul => Some(a.saturating_mul(b)),
+                    Op::Div => (b != 0).then(|| a.saturating_div(b)),
+                    // the only overflowing remainder, `MIN % -1`, is mathematically 0
+                    Op::Rem => (b != 0).then(|| a.wrapping_rem(b)),
+                }
+            }
+
+            #[inline]
+            fn is_zero(self) -> bool {
+                self == 0
+            }
+        }
+    )*};
+}
+
+impl_integer!(i8, i16, i32, i64, u8, u16, u32, u64);
+
+macro_rules! impl_signed {
+    ($($ty:ty),*) => {$(
+        impl Negate for $ty {
+            #[inline]
+            fn checked_neg(self) -> Option<Self> {
+                self.checked_neg()
+            }
+
+            #[inline]
+            fn wrapping_neg(self) -> Self {
+                self.wrapping_neg()
+            }
+
+            #[inline]
+            fn saturating_neg(self) -> Self {
+                self.saturating_neg()
+            }
+        }
+    )*};
+}
+
+impl_signed!(i8, i16, i32, i64);
+
+macro_rules! impl_float {
+    ($($ty:ty),*) => {$(
+        impl Arithmetic for $ty {
+            #[inline]
+            fn checked(op: Op, a: Self, b: Self) -> Option<Self> {
+                Some(match op {
+                    Op::Add => a + b,
+                    Op::Sub => a - b,
+                    Op::Mul => a * b,
+                    Op::Div => a / b,
+                    Op::Rem => a % b,
+                })
+            }
+
+            #[inline]
+            fn wrapping(op: Op, a: Self, b: Self) -> Option<Self> {
+                Self::checked(op, a, b)
+            }
+
+            #[inline]
+            fn saturating(op: Op, a: Self, b: Self) -> Option<Self> {
+                Self::checked(op, a, b)
+            }
+
+            #[inline]
+            fn is_zero(self) -> bool {
+                // floats divide by zero following IEEE 754
+                false
+            }
+        }
+
+        impl Negate for $ty {
+            #[inline]
+            fn checked_neg(self) -> Option<Self> {
+                Some(-self)
+            }
+
+            #[inline]
+            fn wrapping_neg(self) -> Self {
+                -self
+            }
+
+            #[inline]
+            fn saturating_neg(self) -> Self {
+                -self
+            }
+        }
+    )*};
+}
+
+impl_float!(f16, f32, f64);
+
+/// Applies `op` to each pair of valid values of `lhs` and `rhs` (converted by `rhs_as`)
+/// according to `mode`. A conversion returning `None` is an overflow.
+fn apply<T, D, F>(
+    lhs: &PrimitiveArray<T>,
+    rhs: &PrimitiveArray<D>,
+    data_type: DataType,
+    op: Op,
+    mode: OverflowMode,
+    rhs_as: F,
+) -> Result<PrimitiveArray<T>>
+where
+    T: Arithmetic,
+    D: NativeType,
+    F: Fn(D) -> Option<T>,
+{
+    let validity = combine_validities(lhs.validity(), rhs.validity());
+    let is_valid = |i: usize| validity.as_ref().map_or(true, |x| x.get_bit(i));
+
+    let mut values = Vec::with_capacity(lhs.len());
+    let mut result_validity = MutableBitmap::with_capacity(lhs.len());
+    for (i, (a, b)) in lhs.values_iter().zip(rhs.values_iter()).enumerate() {
+        if !is_valid(i) {
+            values.push(T::default());
+            result_validity.push(false);
+            continue;
+        }
+        let Some(b) = rhs_as(*b) else {
+            if mode == OverflowMode::Error {
+                return Err(Error::Overflow);
+            }
+            values.push(T::default());
+            result_validity.push(false);
+            continue;
+        };
+        let value = match mode {
+            OverflowMode::Error | OverflowMode::Null => T::checked(op, *a, b),
+            OverflowMode::Wrap => T::wrapping(op, *a, b),
+            OverflowMode::Saturate => T::saturating(op, *a, b),
+        };
+        match value {
+            Some(value) => {
+                values.push(value);
+                result_validity.push(true);
+            }
+            None if mode == OverflowMode::Error => {
+                return Err(if b.is_zero() {
+                    Error::InvalidArgumentError("Division by zero".to_string())
+                } else {
+                    Error::Overflow
+                });
+            }
+            None => {
+                values.push(T::default());
+                result_validity.push(false);
+            }
+        }
+    }
+    Ok(PrimitiveArray::new(
+        data_type,
+        values.into(),
+        result_validity.into(),
+    ))
+}
+
+fn primitive_dyn<T: Arithmetic>(
+    lhs: &dyn Array,
+    rhs: &dyn Array,
+    op: Op,
+    mode: OverflowMode,
+) -> Result<Box<dyn Array>> {
+    let lhs = lhs.as_any().downcast_ref::<PrimitiveArray<T>>().unwrap();
+    let rhs = rhs.as_any().downcast_ref::<PrimitiveArray<T>>().unwrap();
+    apply(lhs, rhs, lhs.data_type().clone(), op, mode, Some).map(|x| x.boxed())
+}
+
+/// Returns the number of ticks per day of a date, time, timestamp or duration `data_type`.
+fn ticks_per_day(data_type: &DataType) -> i64 {
+    let ticks_per_second = match data_type {
+        DataType::Date32 => return 1,
+        DataType::Date64 => 1_000,
+        DataType::Timestamp(time_unit, _)
+        | DataType::Time32(time_unit)
+        | DataType::Time64(time_unit)
+        | DataType::Duration(time_unit) => match time_unit {
+            TimeUnit::Second => 1,
+            TimeUnit::Millisecond => 1_000,
+            TimeUnit::Microsecond => 1_000_000,
+            TimeUnit::Nanosecond => 1_000_000_000,
+        },
+        _ => unreachable!(),
+    };
+    ticks_per_second * temporal_conversions::SECONDS_IN_DAY
+}
+
+/// Returns a function converting ticks of `from` to (truncated) ticks of `to`, where an
+/// overflow wraps or saturates according to `mode`, and is `None` otherwise.
+fn rescale<T>(from: &DataType, to: &DataType, mode: OverflowMode) -> impl Fn(i64) -> Option<T>
+where
+    T: NativeType + Bounded + TryFrom<i64>,
+    i64: AsPrimitive<T>,
+{
+    let (from, to) = (ticks_per_day(from), ticks_per_day(to));
+    // the number of ticks per day of one type is a multiple of the other's
+    let (mul, div) = if to >= from {
+        (to / from, 1)
+    } else {
+        (1, from / to)
+    };
+    move |x| match mode {
+        OverflowMode::Wrap => Some((x.wrapping_mul(mul) / div).as_()),
+        OverflowMode::Saturate => {
+            let x = x.saturating_mul(mul) / div;
+            Some(T::try_from(x).unwrap_or(if x < 0 {
+                T::min_value()
+            } else {
+                T::max_value()
+            }))
+        }
+        OverflowMode::Error | OverflowMode::Null => T::try_from(x.checked_mul(mul)? / div).ok(),
+    }
+}
+
+/// Adds or subtracts a duration to a time, scaling the duration to the time's unit.
+fn time_dyn<T>(
+    lhs: &dyn Array,
+    rhs: &dyn Array,
+    op: Op,
+    mode: OverflowMode,
+) -> Result<Box<dyn Array>>
+where
+    T: Arithmetic + Bounded + TryFrom<i64>,
+    i64: AsPrimitive<T>,
+{
+    let rhs_as = rescale(rhs.data_type(), lhs.data_type(), mode);
+    let lhs = lhs.as_any().downcast_ref::<PrimitiveArray<T>>().unwrap();
+    let rhs = rhs.as_any().downcast_ref::<PrimitiveArray<i64>>().unwrap();
+    apply(lhs, rhs, lhs.data_type().clone(), op, mode, rhs_as).map(|x| x.boxed())
+}
+
+/// Subtracts two timestamps without timezone, returning a duration in the unit of `lhs`.
+fn timestamps_dyn(lhs: &dyn Array, rhs: &dyn Array, mode: OverflowMode) -> Result<Box<dyn Array>> {
+    let DataType::Timestamp(lhs_unit, None) = lhs.data_type() else {
+        unreachable!()
+    };
+    let data_type = DataType::Duration(*lhs_unit);
+    let rhs_as = rescale(rhs.data_type(), lhs.data_type(), mode);
+    let lhs = lhs.as_any().downcast_ref::<PrimitiveArray<i64>>().unwrap();
+    let rhs = rhs.as_any().downcast_ref::<PrimitiveArray<i64>>().unwrap();
+    apply(lhs, rhs, data_type, Op::Sub, mode, rhs_as).map(|x| x.boxed())
+}
+
+/// The integer backing a decimal, to handle the overflows of the adaptive decimal kernels.
+#[cfg(feature = "compute_arithmetics_decimal")]
+trait DecimalValue: Copy + PartialEq {
+    const ZERO: Self;
+
+    /// Returns the largest value of the largest precision
+    fn max() -> Self;
+
+    fn neg(self) -> Self;
+
+    fn to_f64(self) -> f64;
+}
+
+#[cfg(feature = "compute_arithmetics_decimal")]
+impl DecimalValue for i128 {
+    const ZERO: Self = 0;
+
+    fn max() -> Self {
+        decimal::max_value(decimal::MAX_PRECISION)
+    }
+
+    fn neg(self) -> Self {
+        -self
+    }
+
+    fn to_f64(self) -> f64 {
+        self as f64
+    }
+}
+
+#[cfg(feature = "compute_arithmetics_decimal")]
+impl DecimalValue for ethnum::I256 {
+    const ZERO: Self = ethnum::I256::ZERO;
+
+    fn max() -> Self {
+        decimal::decimal256::max_value(decimal::decimal256::MAX_PRECISION)
+    }
+
+    fn neg(self) -> Self {
+        -self
+    }
+
+    fn to_f64(self) -> f64 {
+        self.as_f64()
+    }
+}
+
+/// Returns the handler of the adaptive decimal kernels for the values (of scales `lhs_scale`
+/// and `rhs_scale`) whose result overflows, following `mode`.
+#[cfg(feature = "compute_arithmetics_decimal")]
+fn decimal_overflow<T: DecimalValue>(
+    op: Op,
+    mode: OverflowMode,
+    lhs_scale: usize,
+    rhs_scale: usize,
+) -> impl Fn(T, T) -> Result<Option<T>> {
+    move |l, r| match mode {
+        OverflowMode::Error if op == Op::Div && r == T::ZERO => {
+            Err(Error::InvalidArgumentError("Division by zero".to_string()))
+        }
+        OverflowMode::Error | OverflowMode::Wrap => Err(Error::Overflow),
+        OverflowMode::Null => Ok(None),
+        // as for integers, a division by zero is null unless it errors
+        OverflowMode::Saturate if op == Op::Div && r == T::ZERO => Ok(None),
+        OverflowMode::Saturate => {
+            // the result overflows, so its sign is not affected by the rounding of floats
+            let l = l.to_f64() / 10f64.powi(lhs_scale as i32);
+            let r = r.to_f64() / 10f64.powi(rhs_scale as i32);
+            let result = match op {
+                Op::Add => l + r,
+                Op::Sub => l - r,
+                Op::Mul => l * r,
+                Op::Div | Op::Rem => l / r,
+            };
+            Ok(Some(if result < 0.0 {
+                T::max().neg()
+            } else {
+                T::max()
+            }))
+        }
+    }
+}
+
+#[cfg(feature = "compute_arithmetics_decimal")]
+fn decimal_scale(data_type: &DataType) -> usize {
+    match data_type.to_logical_type() {
+        DataType::Decimal(_, scale) | DataType::Decimal256(_, scale) => *scale,
+        _ => unreachable!(),
+    }
+}
+
+#[cfg(feature = "compute_arithmetics_decimal")]
+macro_rules! decimal_dyn {
+    ($ty:ty, $module:path, $lhs:expr, $rhs:expr, $op:expr, $mode:expr) => {{
+        use $module as kernels;
+        let lhs = $lhs.as_any().downcast_ref::<PrimitiveArray<$ty>>().unwrap();
+        let rhs = $rhs.as_any().downcast_ref::<PrimitiveArray<$ty>>().unwrap();
+        let (op, mode) = ($op, $mode);
+        if op == Op::Rem {
+            return Err(Error::NotYetImplemented(
+                "Remainder of decimals is not supported".to_string(),
+            ));
+        }
+        if op == Op::Div
+            && mode == OverflowMode::Error
+            && rhs.iter().any(|x| x == Some(&<$ty>::default()))
+        {
+            return Err(Error::InvalidArgumentError("Division by zero".to_string()));
+        }
+
+        if mode == OverflowMode::Wrap {
+            return Err(Error::InvalidArgumentError(
+                "Decimals do not support wrapping arithmetics".to_string(),
+            ));
+        }
+
+        // decimals of different precision or scale adapt the precision and scale of the result
+        if lhs.data_type() != rhs.data_type() {
+            let on_overflow = decimal_overflow(
+                op,
+                mode,
+                decimal_scale(lhs.data_type()),
+                decimal_scale(rhs.data_type()),
+            );
+            return match op {
+                Op::Add => kernels::adaptive(lhs, rhs, |l, r, _| l.checked_add(r), on_overflow),
+                Op::Sub => kernels::adaptive(lhs, rhs, |l, r, _| l.checked_sub(r), on_overflow),
+                Op::Mul => kernels::adaptive(
+                    lhs,
+                    rhs,
+                    |l, r, scale| Some(l.checked_mul(r)? / scale),
+                    on_overflow,
+                ),
+                Op::Div => kernels::adaptive(
+                    lhs,
+                    rhs,
+                    |l, r, scale| l.checked_mul(scale)?.checked_div(r),
+                    on_overflow,
+                ),
+                Op::Rem => unreachable!(),
+            }
+            .map(|x| x.boxed());
+        }
+
+        let result = match (op, mode) {
+            (_, OverflowMode::Wrap) => unreachable!(),
+            (Op::Add, OverflowMode::Saturate) => kernels::saturating_add(lhs, rhs),
+            (Op::Sub, OverflowMode::Saturate) => kernels::saturating_sub(lhs, rhs),
+            (Op::Mul, OverflowMode::Saturate) => kernels::saturating_mul(lhs, rhs),
+            (Op::Div, OverflowMode::Saturate) => {
+                // `saturating_div` returns zero for a division by zero, which is null here
+                let result = kernels::saturating_div(lhs, rhs);
+                let non_zero = rhs
+                    .values()
+                    .iter()
+                    .map(|x| *x != <$ty>::default())
+                    .collect::<Bitmap>();
+                let validity = combine_validities(result.validity(), Some(&non_zero));
+                result.with_validity(validity)
+            }
+            (Op::Add, _) => kernels::checked_add(lhs, rhs),
+            (Op::Sub, _) => kernels::checked_sub(lhs, rhs),
+            (Op::Mul, _) => kernels::checked_mul(lhs, rhs),
+            (Op::Div, _) => kernels::checked_div(lhs, rhs),
+            (Op::Rem, _) => unreachable!(),
+        };
+        if mode == OverflowMode::Error {
+            let null_count = combine_validities(lhs.validity(), rhs.validity())
+                .as_ref()
+                .map_or(0, Bitmap::unset_bits);
+            if result.null_count() > null_count {
+                return Err(Error::Overflow);
+            }
+        }
+        Ok(result.boxed())
+    }};
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    Signed,
+    Unsigned,
+    Float,
+}
+
+fn numeric_kind(data_type: &DataType) -> Option<(Kind, usize)> {
+    use DataType::*;
+    Some(match data_type {
+        Int8 => (Kind::Signed, 8),
+        Int16 => (Kind::Signed, 16),
+        Int32 => (Kind::Signed, 32),
+        Int64 => (Kind::Signed, 64),
+        UInt8 => (Kind::Unsigned, 8),
+        UInt16 => (Kind::Unsigned, 16),
+        UInt32 => (Kind::Unsigned, 32),
+        UInt64 => (Kind::Unsigned, 64),
+        Float16 => (Kind::Float, 16),
+        Float32 => (Kind::Float, 32),
+        Float64 => (Kind::Float, 64),
+        _ => return None,
+    })
+}
+
+fn numeric_type(kind: Kind, bits: usize) -> DataType {
+    use DataType::*;
+    match (kind, bits) {
+        (Kind::Signed, 8) => Int8,
+        (Kind::Signed, 16) => Int16,
+        (Kind::Signed, 32) => Int32,
+        (Kind::Signed, 64) => Int64,
+        (Kind::Unsigned, 8) => UInt8,
+        (Kind::Unsigned, 16) => UInt16,
+        (Kind::Unsigned, 32) => UInt32,
+        (Kind::Unsigned, 64) => UInt64,
+        (Kind::Float, 16) => Float16,
+        (Kind::Float, 32) => Float32,
+        (Kind::Float, 64) => Float64,
+        _ => unreachable!(),
+    }
+}
+
+/// Returns the numeric [`DataType`] that the fallible arithmetic kernels convert operands of
+/// numeric types `lhs` and `rhs` to, or `None` if either is not numeric or there is none:
+/// * integers of the same signedness coerce to the wider of the two
+/// * a signed and an unsigned integer coerce to the narrowest signed integer that holds both
+///   (e.g. `UInt8` and `Int8` to `Int16`). `UInt64` and a signed integer have none.
+/// * an integer and a float coerce to `Float32` if the float is `Float16` or `Float32` and the
+///   integer has at most 16 bits, and to `Float64` otherwise
+/// * floats coerce to the wider of the two
+pub fn coerce_types(lhs: &DataType, rhs: &DataType) -> Option<DataType> {
+    let (lhs_kind, lhs_bits) = numeric_kind(lhs)?;
+    let (rhs_kind, rhs_bits) = numeric_kind(rhs)?;
+    Some(match (lhs_kind, rhs_kind) {
+        (l, r) if l == r => numeric_type(l, lhs_bits.max(rhs_bits)),
+        (Kind::Float, _) | (_, Kind::Float) => {
+            let (float_bits, int_bits) = if lhs_kind == Kind::Float {
+                (lhs_bits, rhs_bits)
+            } else {
+                (rhs_bits, lhs_bits)
+            };
+            if float_bits <= 32 && int_bits <= 16 {
+                DataType::Float32
+            } else {
+                DataType::Float64
+            }
+        }
+        _ => {
+            let (signed_bits, unsigned_bits) = if lhs_kind == Kind::Signed {
+                (lhs_bits, rhs_bits)
+            } else {
+                (rhs_bits, lhs_bits)
+            };
+            let bits = signed_bits.max(unsigned_bits * 2);
+            if bits > 64 {
+                return None;
+            }
+            numeric_type(Kind::Signed, bits)
+        }
+    })
+}
+
+fn cast_numeric_to<O>(array: &dyn Array, data_type: &DataType) -> Box<dyn Array>
+where
+    O: NativeType,
+    i8: AsPrimitive<O>,
+    i16: AsPrimitive<O>,
+    i32: AsPrimitive<O>,
+    i64: AsPrimitive<O>,
+    u8: AsPrimitive<O>,
+    u16: AsPrimitive<O>,
+    u32: AsPrimitive<O>,
+    u64: AsPrimitive<O>,
+    f32: AsPrimitive<O>,
+    f64: AsPrimitive<O>,
+{
+    macro_rules! cast {
+        ($ty:ty) => {{
+            let array = array
+                .as_any()
+                .downcast_ref::<PrimitiveArray<$ty>>()
+                .unwrap();
+            unary(array, |x| x.as_(), data_type.clone()).boxed()
+        }};
+    }
+    use PrimitiveType::*;
+    match array.data_type().to_physical_type() {
+        PhysicalType::Primitive(Int8) => cast!(i8),
+        PhysicalType::Primitive(Int16) => cast!(i16),
+        PhysicalType::Primitive(Int32) => cast!(i32),
+        PhysicalType::Primitive(Int64) => cast!(i64),
+        PhysicalType::Primitive(UInt8) => cast!(u8),
+        PhysicalType::Primitive(UInt16) => cast!(u16),
+        PhysicalType::Primitive(UInt32) => cast!(u32),
+        PhysicalType::Primitive(UInt64) => cast!(u64),
+        PhysicalType::Primitive(Float16) => {
+            let array = array
+                .as_any()
+                .downcast_ref::<PrimitiveArray<f16>>()
+                .unwrap();
+            unary(array, |x| x.to_f32().as_(), data_type.clone()).boxed()
+        }
+        PhysicalType::Primitive(Float32) => cast!(f32),
+        PhysicalType::Primitive(Float64) => cast!(f64),
+        _ => unreachable!(),
+    }
+}
+
+/// Converts a numeric `array` to the numeric `data_type` returned by [`coerce_types`].
+fn cast_numeric(array: &dyn Array, data_type: &DataType) -> Box<dyn Array> {
+    if array.data_type() == data_type {
+        return array.to_boxed();
+    }
+    use DataType::*;
+    match data_type {
+        Int16 => cast_numeric_to::<i16>(array, data_type),
+        Int32 => cast_numeric_to::<i32>(array, data_type),
+        Int64 => cast_numeric_to::<i64>(array, data_type),
+        UInt16 => cast_numeric_to::<u16>(array, data_type),
+        UInt32 => cast_numeric_to::<u32>(array, data_type),
+        UInt64 => cast_numeric_to::<u64>(array, data_type),
+        Float32 => cast_numeric_to::<f32>(array, data_type),
+        Float64 => cast_numeric_to::<f64>(array, data_type),
+        _ => unreachable!(),
+    }
+}
+
+fn try_arith(
+    lhs: &dyn Array,
+    rhs: &dyn Array,
+    op: Op,
+    mode: OverflowMode,
+) -> Result<Box<dyn Array>> {
+    check_same_len(lhs, rhs)?;
+
+    let coerced;
+    let (lhs, rhs) = match coerce_types(lhs.data_type(), rhs.data_type()) {
+        Some(data_type) if lhs.data_type() != rhs.data_type() => {
+            coerced = (cast_numeric(lhs, &data_type), cast_numeric(rhs, &data_type));
+            (coerced.0.as_ref(), coerced.1.as_ref())
+        }
+        _ => (lhs, rhs),
+    };
+
+    use DataType::*;
+    let is_additive = matches!(op, Op::Add | Op::Sub);
+    match (lhs.data_type(), rhs.data_type()) {
+        (Int8, Int8) => primitive_dyn::<i8>(lhs, rhs, op, mode),
+        (Int16, Int16) => primitive_dyn::<i16>(lhs, rhs, op, mode),
+        (Int32, Int32) => primitive_dyn::<i32>(lhs, rhs, op, mode),
+        (Int64, Int64) => primitive_dyn::<i64>(lhs, rhs, op, mode),
+        (UInt8, UInt8) => primitive_dyn::<u8>(lhs, rhs, op, mode),
+        (UInt16, UInt16) => primitive_dyn::<u16>(lhs, rhs, op, mode),
+        (UInt32, UInt32) => primitive_dyn::<u32>(lhs, rhs, op, mode),
+        (UInt64, UInt64) => primitive_dyn::<u64>(lhs, rhs, op, mode),
+        (Float16, Float16) => primitive_dyn::<f16>(lhs, rhs, op, mode),
+        (Float32, Float32) => primitive_dyn::<f32>(lhs, rhs, op, mode),
+        (Float64, Float64) => primitive_dyn::<f64>(lhs, rhs, op, mode),
+        (Duration(l), Duration(r)) if l == r && is_additive => {
+            primitive_dyn::<i64>(lhs, rhs, op, mode)
+        }
+        #[cfg(feature = "compute_arithmetics_decimal")]
+        (Decimal(_, _), Decimal(_, _)) => decimal_dyn!(i128, decimal, lhs, rhs, op, mode),
+        #[cfg(feature = "compute_arithmetics_decimal")]
+        (Decimal256(_, _), Decimal256(_, _)) => {
+            decimal_dyn!(i256, decimal::decimal256, lhs, rhs, op, mode)
+        }
+        (Time32(TimeUnit::Second | TimeUnit::Millisecond) | Date32, Duration(_)) if is_additive => {
+            time_dyn::<i32>(lhs, rhs, op, mode)
+        }
+        (
+            Time64(TimeUnit::Microsecond | TimeUnit::Nanosecond) | Date64 | Timestamp(_, _),
+            Duration(_),
+        ) if is_additive => time_dyn::<i64>(lhs, rhs, op, mode),
+        (Timestamp(_, None), Timestamp(_, None)) if op == Op::Sub => timestamps_dyn(lhs, rhs, mode),
+        (Timestamp(_, _), Interval(IntervalUnit::MonthDayNano)) if op == Op::Add => {
+            let lhs = lhs.as_any().downcast_ref().unwrap();
+            let rhs = rhs.as_any().downcast_ref().unwrap();
+            time::add_interval(lhs, rhs).map(|x| x.boxed())
+        }
+        (l, r) => Err(Error::NotYetImplemented(format!(
+            "{} of {l:?} with {r:?} is not supported",
+            op.name()
+        ))),
+    }
+}
+
+/// Adds two [`Array`]s, returning an error instead of panicking.
+///
+/// Operands of different numeric types are first converted to their common type following
+/// [`coerce_types`]. Integer overflows are handled according to `mode`. Floats follow IEEE 754
+/// and never overflow. Decimals (of both widths) overflow past their precision, are added like
+/// [`adaptive_add`](super::decimal::adaptive_add) when their precision or scale differ (where
+/// they overflow past the largest precision), and do not support [`OverflowMode::Wrap`].
+/// Besides numbers, this supports the same temporal types as [`add`](super::add).
+/// # Errors
+/// This function errors iff the arrays have different lengths, the operation is not supported
+/// for the data types, or the operation overflows with [`OverflowMode::Error`].
+/// # Example
+/// ```
+/// use re_arrow2::array::{Int64Array, Int8Array};
+/// use re_arrow2::compute::arithmetics::{try_add, OverflowMode};
+///
+/// let a = Int8Array::from(&[Some(100), Some(1), None]);
+/// let b = Int8Array::from(&[Some(100), Some(2), Some(3)]);
+/// assert!(try_add(&a, &b, OverflowMode::Error).is_err());
+///
+/// let result = try_add(&a, &b, OverflowMode::Saturate).unwrap();
+/// assert_eq!(Int8Array::from(&[Some(127), Some(3), None]), result.as_ref());
+///
+/// // mixed types are coerced to their common type
+/// let b = Int64Array::from(&[Some(100), Some(2), Some(3)]);
+/// let result = try_add(&a, &b, OverflowMode::Error).unwrap();
+/// assert_eq!(Int64Array::from(&[Some(200), Some(3), None]), result.as_ref());
+/// ```
+pub fn try_add(lhs: &dyn Array, rhs: &dyn Array, mode: OverflowMode) -> Result<Box<dyn Array>> {
+    try_arith(lhs, rhs, Op::Add, mode)
+}
+
+/// Subtracts two [`Array`]s, returning an error instead of panicking.
+///
+/// See [`try_add`] for the coercion and overflow rules. Besides numbers, this supports the same
+/// temporal types as [`sub`](super::sub).
+/// # Errors
+/// This function errors iff the arrays have different lengths, the operation is not supported
+/// for the data types, or the operation overflows with [`OverflowMode::Error`].
+pub fn try_sub(lhs: &dyn Array, rhs: &dyn Array, mode: OverflowMode) -> Result<Box<dyn Array>> {
+    try_arith(lhs, rhs, Op::Sub, mode)
+}
+
+/// Multiplies two [`Array`]s, returning an error instead of panicking.
+///
+/// See [`try_add`] for the coercion and overflow rules.
+/// # Errors
+/// This function errors iff the arrays have different lengths, the operation is not supported
+/// for the data types, or the operation overflows with [`OverflowMode::Error`].
+pub fn try_mul(lhs: &dyn Array, rhs: &dyn Array, mode: OverflowMode) -> Result<Box<dyn Array>> {
+    try_arith(lhs, rhs, Op::Mul, mode)
+}
+
+/// Divides two [`Array`]s, returning an error instead of panicking.
+///
+/// See [`try_add`] for the coercion and overflow rules. An integer or decimal division by zero
+/// errors with [`OverflowMode::Error`] and is null with any other mode.
+/// # Errors
+/// This function errors iff the arrays have different lengths, the operation is not supported
+/// for the data types, or the operation overflows or divides by zero with [`OverflowMode::Error`].
+/// # Example
+/// ```
+/// use re_arrow2::array::Int32Array;
+/// use re_arrow2::compute::arithmetics::{try_div, OverflowMode};
+///
+/// let a = Int32Array::from_slice([6, 1, i32::MIN]);
+/// let b = Int32Array::from_slice([3, 0, -1]);
+/// assert!(try_div(&a, &b, OverflowMode::Error).is_err());
+///
+/// let result = try_div(&a, &b, OverflowMode::Null).unwrap();
+/// assert_eq!(Int32Array::from(&[Some(2), None, None]), result.as_ref());
+///
+/// let result = try_div(&a, &b, OverflowMode::Wrap).unwrap();
+/// assert_eq!(Int32Array::from(&[Some(2), None, Some(i32::MIN)]), result.as_ref());
+/// ```
+pub fn try_div(lhs: &dyn Array, rhs: &dyn Array, mode: OverflowMode) -> Result<Box<dyn Array>> {
+    try_arith(lhs, rhs, Op::Div, mode)
+}
+
+/// Computes the remainder of two [`Array`]s, returning an error instead of panicking.
+///
+/// See [`try_add`] for the coercion and overflow rules, and [`try_div`] for division by zero.
+/// Decimals are not supported.
+/// # Errors
+/// This function errors iff the arrays have different lengths, the operation is not supported
+/// for the data types, or the operation overflows or divides by zero with [`OverflowMode::Error`].
+pub fn try_rem(lhs: &dyn Array, rhs: &dyn Array, mode: OverflowMode) -> Result<Box<dyn Array>> {
+    try_arith(lhs, rhs, Op::Rem, mode)
+}
+
+fn neg_dyn<T: Negate>(array: &dyn Array, mode: OverflowMode) -> Result<Box<dyn Array>> {
+    let array = array.as_any().downcast_ref::<PrimitiveArray<T>>().unwrap();
+    let mut values = Vec::with_capacity(array.len());
+    let mut validity = MutableBitmap::with_capacity(array.len());
+    for value in array.iter() {
+        let value = match (value, mode) {
+            (None, _) => None,
+            (Some(value), OverflowMode::Error) => Some(value.checked_neg().ok_or(Error::Overflow)?),
+            (Some(value), OverflowMode::Null) => value.checked_neg(),
+            (Some(value), OverflowMode::Wrap) => Some(value.wrapping_neg()),
+            (Some(value), OverflowMode::Saturate) => Some(value.saturating_neg()),
+        };
+        values.push(value.unwrap_or_default());
+        validity.push(value.is_some());
+    }
+    Ok(PrimitiveArray::new(array.data_type().clone(), values.into(), validity.into()).boxed())
+}
+
+/// Negates an [`Array`], returning an error instead of panicking.
+///
+/// Supports signed integers, floats, decimals, durations and the other logical types backed by
+/// them, as well as dictionaries of them. Integer overflows (e.g. `-i32::MIN`) are handled
+/// according to `mode`.
+/// # Errors
+/// This function errors iff the operation is not supported for the data type, or the operation
+/// overflows with [`OverflowMode::Error`].
+pub fn try_neg(array: &dyn Array, mode: OverflowMode) -> Result<Box<dyn Array>> {
+    use PhysicalType::*;
+    match array.data_type().to_physical_type() {
+        Primitive(PrimitiveType::Int8) => neg_dyn::<i8>(array, mode),
+        Primitive(PrimitiveType::Int16) => neg_dyn::<i16>(array, mode),
+        Primitive(PrimitiveType::Int32) => neg_dyn::<i32>(array, mode),
+        Primitive(PrimitiveType::Int64) => neg_dyn::<i64>(array, mode),
+        Primitive(PrimitiveType::Float16) => neg_dyn::<f16>(array, mode),
+        Primitive(PrimitiveType::Float32) => neg_dyn::<f32>(array, mode),
+        Primitive(PrimitiveType::Float64) => neg_dyn::<f64>(array, mode),
+        // the negation of a decimal always fits in its precision
+        Primitive(PrimitiveType::Int128) => {
+            let array = array.as_any().downcast_ref().unwrap();
+            Ok(basic::wrapping_negate::<i128>(array).boxed())
+        }
+        Primitive(PrimitiveType::Int256) => {
+            let array = array
+                .as_any()
+                .downcast_ref::<PrimitiveArray<i256>>()
+                .unwrap();
+            Ok(unary(
+                array,
+                |x| i256(x.0.wrapping_neg()),
+                array.data_type().clone(),
+            )
+            .boxed())
+        }
+        Dictionary(key) => match_integer_type!(key, |$T| {
+            let array = array.as_any().downcast_ref::<DictionaryArray<$T>>().unwrap();
+
+            let values = try_neg(array.values().as_ref(), mode)?;
+
+            // safety - this operation only applies to values and thus preserves the dictionary's invariant
+            unsafe {
+                DictionaryArray::<$T>::try_new_unchecked(array.data_type().clone(), array.keys().clone(), values).map(|x| x.boxed())
+            }
+        }),
+        _ => Err(Error::NotYetImplemented(format!(
+            "Negation of {:?} is not supported",
+            array.data_type()
+        ))),
+    }
+}
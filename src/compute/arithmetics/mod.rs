@@ -11,18 +11,28 @@
 //! * overflowing: returns an extra [`Bitmap`] denoting whether the operation overflowed.
 //! * adaptive: for [`Decimal`](crate::datatypes::DataType::Decimal) only,
 //!   adjusts the precision and scale to make the resulting value fit.
+//!
+//! The dynamic kernels [`add`], [`sub`], etc. panic on unsupported types and on overflow.
+//! Their fallible counterparts [`try_add`], [`try_sub`], etc. return a [`Result`](crate::error::Result)
+//! instead, handle overflow according to an [`OverflowMode`], and coerce operands of different
+//! numeric types to a common type (see [`coerce_types`]).
 #[forbid(unsafe_code)]
 pub mod basic;
 #[cfg(feature = "compute_arithmetics_decimal")]
 pub mod decimal;
+mod fallible;
 pub mod time;
 
+pub use fallible::{
+    coerce_types, try_add, try_div, try_mul, try_neg, try_rem, try_sub, OverflowMode,
+};
+
 use crate::{
     array::{Array, DictionaryArray, PrimitiveArray},
     bitmap::Bitmap,
     datatypes::{DataType, IntervalUnit, TimeUnit},
     scalar::{PrimitiveScalar, Scalar},
-    types::NativeType,
+    types::{f16, NativeType},
 };
 
 fn binary_dyn<T: NativeType, F: Fn(&PrimitiveArray<T>, &PrimitiveArray<T>) -> PrimitiveArray<T>>(
@@ -53,6 +63,7 @@ macro_rules! arith {
             (UInt16, UInt16) => binary_dyn::<u16, _>(lhs, rhs, basic::$op),
             (UInt32, UInt32) => binary_dyn::<u32, _>(lhs, rhs, basic::$op),
             (UInt64, UInt64) => binary_dyn::<u64, _>(lhs, rhs, basic::$op),
+            (Float16, Float16) => binary_dyn::<f16, _>(lhs, rhs, basic::$op),
             (Float32, Float32) => binary_dyn::<f32, _>(lhs, rhs, basic::$op),
             (Float64, Float64) => binary_dyn::<f64, _>(lhs, rhs, basic::$op),
             $ (
@@ -61,6 +72,11 @@ macro_rules! arith {
                 let rhs = rhs.as_any().downcast_ref().unwrap();
                 Box::new(decimal::$op_decimal(lhs, rhs)) as Box<dyn Array>
             }
+            (Decimal256(_, _), Decimal256(_, _)) => {
+                let lhs = lhs.as_any().downcast_ref().unwrap();
+                let rhs = rhs.as_any().downcast_ref().unwrap();
+                Box::new(decimal::decimal256::$op_decimal(lhs, rhs)) as Box<dyn Array>
+            }
             )?
             $ (
             (Time32(TimeUnit::Second), Duration(_))
@@ -143,6 +159,7 @@ macro_rules! arith_scalar {
             (UInt16, UInt16) => binary_scalar_dyn::<u16, _>(lhs, rhs, basic::$op),
             (UInt32, UInt32) => binary_scalar_dyn::<u32, _>(lhs, rhs, basic::$op),
             (UInt64, UInt64) => binary_scalar_dyn::<u64, _>(lhs, rhs, basic::$op),
+            (Float16, Float16) => binary_scalar_dyn::<f16, _>(lhs, rhs, basic::$op),
             (Float32, Float32) => binary_scalar_dyn::<f32, _>(lhs, rhs, basic::$op),
             (Float64, Float64) => binary_scalar_dyn::<f64, _>(lhs, rhs, basic::$op),
             $ (
@@ -151,6 +168,11 @@ macro_rules! arith_scalar {
                 let rhs = rhs.as_any().downcast_ref().unwrap();
                 decimal::$op_decimal(lhs, rhs).boxed()
             }
+            (Decimal256(_, _), Decimal256(_, _)) => {
+                let lhs = lhs.as_any().downcast_ref().unwrap();
+                let rhs = rhs.as_any().downcast_ref().unwrap();
+                decimal::decimal256::$op_decimal(lhs, rhs).boxed()
+            }
             )?
             $ (
             (Time32(TimeUnit::Second), Duration(_))
@@ -203,6 +225,7 @@ pub fn add(lhs: &dyn Array, rhs: &dyn Array) -> Box<dyn Array> {
         lhs,
         rhs,
         add,
+        decimal = add,
         duration = add_duration,
         interval = add_interval
     )
@@ -219,6 +242,7 @@ pub fn add_scalar(lhs: &dyn Array, rhs: &dyn Scalar) -> Box<dyn Array> {
         lhs,
         rhs,
         add_scalar,
+        decimal = add_scalar,
         duration = add_duration_scalar,
         interval = add_interval_scalar
     )
@@ -239,8 +263,10 @@ pub fn can_add(lhs: &DataType, rhs: &DataType) -> bool {
             | (UInt64, UInt64)
             | (Float64, Float64)
             | (Float32, Float32)
+            | (Float16, Float16)
             | (Duration(_), Duration(_))
             | (Decimal(_, _), Decimal(_, _))
+            | (Decimal256(_, _), Decimal256(_, _))
             | (Date32, Duration(_))
             | (Date64, Duration(_))
             | (Time32(TimeUnit::Millisecond), Duration(_))
@@ -280,6 +306,7 @@ pub fn sub_scalar(lhs: &dyn Array, rhs: &dyn Scalar) -> Box<dyn Array> {
         lhs,
         rhs,
         sub_scalar,
+        decimal = sub_scalar,
         duration = sub_duration_scalar,
         timestamp = sub_timestamps_scalar
     )
@@ -300,8 +327,10 @@ pub fn can_sub(lhs: &DataType, rhs: &DataType) -> bool {
             | (UInt64, UInt64)
             | (Float64, Float64)
             | (Float32, Float32)
+            | (Float16, Float16)
             | (Duration(_), Duration(_))
             | (Decimal(_, _), Decimal(_, _))
+            | (Decimal256(_, _), Decimal256(_, _))
             | (Date32, Duration(_))
             | (Date64, Duration(_))
             | (Time32(TimeUnit::Millisecond), Duration(_))
@@ -345,7 +374,9 @@ pub fn can_mul(lhs: &DataType, rhs: &DataType) -> bool {
             | (UInt64, UInt64)
             | (Float64, Float64)
             | (Float32, Float32)
+            | (Float16, Float16)
             | (Decimal(_, _), Decimal(_, _))
+            | (Decimal256(_, _), Decimal256(_, _))
     )
 }
 
@@ -395,6 +426,7 @@ pub fn can_rem(lhs: &DataType, rhs: &DataType) -> bool {
             | (UInt64, UInt64)
             | (Float64, Float64)
             | (Float32, Float32)
+            | (Float16, Float16)
     )
 }
 
@@ -403,7 +435,7 @@ macro_rules! with_match_negatable {(
 ) => ({
     macro_rules! __with_ty__ {( $_ $T:ident ) => ( $($body)* )}
     use crate::datatypes::PrimitiveType::*;
-    use crate::types::{days_ms, months_days_ns, i256};
+    use crate::types::{days_ms, months_days_ns, f16, i256};
     match $key_type {
         Int8 => __with_ty__! { i8 },
         Int16 => __with_ty__! { i16 },
@@ -413,7 +445,8 @@ macro_rules! with_match_negatable {(
         Int256 => __with_ty__! { i256 },
         DaysMs => __with_ty__! { days_ms },
         MonthDayNano => __with_ty__! { months_days_ns },
-        UInt8 | UInt16 | UInt32 | UInt64 | Float16 => todo!(),
+        UInt8 | UInt16 | UInt32 | UInt64 => todo!(),
+        Float16 => __with_ty__! { f16 },
         Float32 => __with_ty__! { f32 },
         Float64 => __with_ty__! { f64 },
     }
@@ -463,6 +496,7 @@ pub fn can_neg(data_type: &DataType) -> bool {
             | Primitive(Int64)
             | Primitive(Float64)
             | Primitive(Float32)
+            | Primitive(Float16)
             | Primitive(DaysMs)
             | Primitive(MonthDayNano)
     )
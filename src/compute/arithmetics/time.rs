@@ -26,7 +26,7 @@ use crate::{
 /// Creates the scale required to add or subtract a Duration to a time array
 /// (Timestamp, Time, or Date). The resulting scale always multiplies the rhs
 /// number (Duration) so it can be added to the lhs number (time array).
-fn create_scale(lhs: &DataType, rhs: &DataType) -> Result<f64> {
+pub(super) fn create_scale(lhs: &DataType, rhs: &DataType) -> Result<f64> {
     // Matching on both data types from both numbers to calculate the correct
     // scale for the operation. The timestamp, Time and duration have a
     // Timeunit enum in its data type. This enum is used to describe the
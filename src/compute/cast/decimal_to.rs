@@ -1,7 +1,9 @@
+use ethnum::{AsI256, I256};
 use num_traits::{AsPrimitive, Float, NumCast};
 
 use crate::error::Result;
-use crate::types::NativeType;
+use crate::offset::Offset;
+use crate::types::{i256, NativeType};
 use crate::{array::*, datatypes::DataType};
 
 #[inline]
@@ -134,3 +136,304 @@ where
     let from = from.as_any().downcast_ref().unwrap();
     Ok(Box::new(decimal_to_integer::<T>(from)))
 }
+
+/// Returns the (unscaled) bounds of a decimal of `precision`, i.e. `-(10^precision - 1)` and
+/// `10^precision - 1`
+#[inline]
+pub(super) fn bounds_for_precision(precision: usize) -> (I256, I256) {
+    let max = I256::new(10)
+        .checked_pow(precision as u32)
+        .map(|x| x - 1)
+        .unwrap_or(I256::MAX);
+    (-max, max)
+}
+
+/// Rescales `x` from `from_scale` to `to_scale`, truncating the digits that do not fit.
+/// Returns `None` on overflow.
+#[inline]
+fn rescale(x: I256, from_scale: usize, to_scale: usize) -> Option<I256> {
+    if from_scale > to_scale {
+        let factor = I256::new(10).checked_pow((from_scale - to_scale) as u32)?;
+        Some(x / factor)
+    } else {
+        let factor = I256::new(10).checked_pow((to_scale - from_scale) as u32)?;
+        x.checked_mul(factor)
+    }
+}
+
+fn decimal_parameters(data_type: &DataType) -> (usize, usize) {
+    match data_type.to_logical_type() {
+        DataType::Decimal(p, s) | DataType::Decimal256(p, s) => (*p, *s),
+        _ => panic!("internal error: the array is not a decimal"),
+    }
+}
+
+/// Maps `from` through `op`, making values outside of `to_precision` a null.
+fn decimal_map<T: NativeType, O: NativeType, F: Fn(T) -> Option<I256>, G: Fn(I256) -> Option<O>>(
+    from: &PrimitiveArray<T>,
+    op: F,
+    out: G,
+    to_type: DataType,
+) -> PrimitiveArray<O> {
+    let (to_precision, _) = decimal_parameters(&to_type);
+    let (min, max) = bounds_for_precision(to_precision);
+    let values = from.iter().map(|x| {
+        x.and_then(|x| op(*x))
+            .filter(|x| *x >= min && *x <= max)
+            .and_then(&out)
+    });
+    PrimitiveArray::<O>::from_trusted_len_iter(values).to(to_type)
+}
+
+/// Returns a [`PrimitiveArray<i256>`] with the casted values. Values are `None` on overflow
+pub fn decimal256_to_decimal256(
+    from: &PrimitiveArray<i256>,
+    to_precision: usize,
+    to_scale: usize,
+) -> PrimitiveArray<i256> {
+    let (from_precision, from_scale) = decimal_parameters(from.data_type());
+    if to_scale == from_scale && to_precision >= from_precision {
+        // fast path
+        return from
+            .clone()
+            .to(DataType::Decimal256(to_precision, to_scale));
+    }
+    decimal_map(
+        from,
+        |x: i256| rescale(x.0, from_scale, to_scale),
+        |x| Some(i256(x)),
+        DataType::Decimal256(to_precision, to_scale),
+    )
+}
+
+pub(super) fn decimal256_to_decimal256_dyn(
+    from: &dyn Array,
+    to_precision: usize,
+    to_scale: usize,
+) -> Result<Box<dyn Array>> {
+    let from = from.as_any().downcast_ref().unwrap();
+    Ok(Box::new(decimal256_to_decimal256(
+        from,
+        to_precision,
+        to_scale,
+    )))
+}
+
+/// Returns a [`PrimitiveArray<i256>`] with the casted values. Values are `None` on overflow
+pub fn decimal_to_decimal256(
+    from: &PrimitiveArray<i128>,
+    to_precision: usize,
+    to_scale: usize,
+) -> PrimitiveArray<i256> {
+    let (_, from_scale) = decimal_parameters(from.data_type());
+    decimal_map(
+        from,
+        |x: i128| rescale(I256::from(x), from_scale, to_scale),
+        |x| Some(i256(x)),
+        DataType::Decimal256(to_precision, to_scale),
+    )
+}
+
+pub(super) fn decimal_to_decimal256_dyn(
+    from: &dyn Array,
+    to_precision: usize,
+    to_scale: usize,
+) -> Result<Box<dyn Array>> {
+    let from = from.as_any().downcast_ref().unwrap();
+    Ok(Box::new(decimal_to_decimal256(
+        from,
+        to_precision,
+        to_scale,
+    )))
+}
+
+/// Returns a [`PrimitiveArray<i128>`] with the casted values. Values are `None` on overflow
+pub fn decimal256_to_decimal(
+    from: &PrimitiveArray<i256>,
+    to_precision: usize,
+    to_scale: usize,
+) -> PrimitiveArray<i128> {
+    let (_, from_scale) = decimal_parameters(from.data_type());
+    decimal_map(
+        from,
+        |x: i256| rescale(x.0, from_scale, to_scale),
+        |x| i128::try_from(x).ok(),
+        DataType::Decimal(to_precision, to_scale),
+    )
+}
+
+pub(super) fn decimal256_to_decimal_dyn(
+    from: &dyn Array,
+    to_precision: usize,
+    to_scale: usize,
+) -> Result<Box<dyn Array>> {
+    let from = from.as_any().downcast_ref().unwrap();
+    Ok(Box::new(decimal256_to_decimal(
+        from,
+        to_precision,
+        to_scale,
+    )))
+}
+
+/// Returns a [`PrimitiveArray<T>`] with the casted values.
+pub fn decimal256_to_float<T>(from: &PrimitiveArray<i256>) -> PrimitiveArray<T>
+where
+    T: NativeType + Float,
+    f64: AsPrimitive<T>,
+{
+    let (_, from_scale) = decimal_parameters(from.data_type());
+
+    let div = 10_f64.powi(from_scale as i32);
+    let values = from
+        .values()
+        .iter()
+        .map(|x| (x.0.as_f64() / div).as_())
+        .collect();
+
+    PrimitiveArray::<T>::new(T::PRIMITIVE.into(), values, from.validity().cloned())
+}
+
+pub(super) fn decimal256_to_float_dyn<T>(from: &dyn Array) -> Result<Box<dyn Array>>
+where
+    T: NativeType + Float,
+    f64: AsPrimitive<T>,
+{
+    let from = from.as_any().downcast_ref().unwrap();
+    Ok(Box::new(decimal256_to_float::<T>(from)))
+}
+
+/// Returns a [`PrimitiveArray<T>`] with the casted values. Values are `None` on overflow
+pub fn decimal256_to_integer<T>(from: &PrimitiveArray<i256>) -> PrimitiveArray<T>
+where
+    T: NativeType + NumCast,
+{
+    let (_, from_scale) = decimal_parameters(from.data_type());
+
+    let factor = I256::new(10).pow(from_scale as u32);
+    let values = from.iter().map(|x| {
+        x.and_then(|x| i128::try_from(x.0 / factor).ok())
+            .and_then(T::from)
+    });
+
+    PrimitiveArray::from_trusted_len_iter(values)
+}
+
+pub(super) fn decimal256_to_integer_dyn<T>(from: &dyn Array) -> Result<Box<dyn Array>>
+where
+    T: NativeType + NumCast,
+{
+    let from = from.as_any().downcast_ref().unwrap();
+    Ok(Box::new(decimal256_to_integer::<T>(from)))
+}
+
+/// Returns a [`PrimitiveArray<i256>`] with the casted values. Values are `None` on overflow
+pub fn integer_to_decimal256<T: NativeType + AsPrimitive<i128>>(
+    from: &PrimitiveArray<T>,
+    to_precision: usize,
+    to_scale: usize,
+) -> PrimitiveArray<i256> {
+    decimal_map(
+        from,
+        |x: T| rescale(I256::from(x.as_()), 0, to_scale),
+        |x| Some(i256(x)),
+        DataType::Decimal256(to_precision, to_scale),
+    )
+}
+
+pub(super) fn integer_to_decimal256_dyn<T>(
+    from: &dyn Array,
+    precision: usize,
+    scale: usize,
+) -> Result<Box<dyn Array>>
+where
+    T: NativeType + AsPrimitive<i128>,
+{
+    let from = from.as_any().downcast_ref().unwrap();
+    Ok(Box::new(integer_to_decimal256::<T>(from, precision, scale)))
+}
+
+/// Returns a [`PrimitiveArray<i256>`] with the casted values. Values are `None` on overflow
+/// or when the value is not finite.
+pub fn float_to_decimal256<T>(
+    from: &PrimitiveArray<T>,
+    to_precision: usize,
+    to_scale: usize,
+) -> PrimitiveArray<i256>
+where
+    T: NativeType + Float + AsPrimitive<f64>,
+{
+    // 1.2 => 12
+    let multiplier = 10_f64.powi(to_scale as i32);
+    decimal_map(
+        from,
+        |x: T| {
+            let x = x.as_() * multiplier;
+            // values beyond 2^255 saturate, which the precision check turns into `None`
+            x.is_finite().then(|| x.as_i256())
+        },
+        |x| Some(i256(x)),
+        DataType::Decimal256(to_precision, to_scale),
+    )
+}
+
+pub(super) fn float_to_decimal256_dyn<T>(
+    from: &dyn Array,
+    precision: usize,
+    scale: usize,
+) -> Result<Box<dyn Array>>
+where
+    T: NativeType + Float + AsPrimitive<f64>,
+{
+    let from = from.as_any().downcast_ref().unwrap();
+    Ok(Box::new(float_to_decimal256::<T>(from, precision, scale)))
+}
+
+/// Formats an unscaled decimal whose absolute value is `digits` with `scale`, e.g. `(true, "5", 2)`
+/// to `-0.05`.
+fn format_decimal(negative: bool, digits: String, scale: usize) -> String {
+    let mut result = String::with_capacity(digits.len() + scale + 2);
+    if negative {
+        result.push('-');
+    }
+    if scale == 0 {
+        result.push_str(&digits);
+    } else if digits.len() > scale {
+        let (int, frac) = digits.split_at(digits.len() - scale);
+        result.push_str(int);
+        result.push('.');
+        result.push_str(frac);
+    } else {
+        result.push_str("0.");
+        result.extend(std::iter::repeat('0').take(scale - digits.len()));
+        result.push_str(&digits);
+    }
+    result
+}
+
+/// Returns a [`Utf8Array`] with the decimals of `from` formatted with their scale, e.g. `-1.50`.
+pub fn decimal_to_utf8<O: Offset>(from: &PrimitiveArray<i128>) -> Utf8Array<O> {
+    let (_, scale) = decimal_parameters(from.data_type());
+    let iter = from
+        .iter()
+        .map(|x| x.map(|x| format_decimal(*x < 0, x.unsigned_abs().to_string(), scale)));
+    Utf8Array::from_trusted_len_iter(iter)
+}
+
+pub(super) fn decimal_to_utf8_dyn<O: Offset>(from: &dyn Array) -> Result<Box<dyn Array>> {
+    let from = from.as_any().downcast_ref().unwrap();
+    Ok(Box::new(decimal_to_utf8::<O>(from)))
+}
+
+/// Returns a [`Utf8Array`] with the decimals of `from` formatted with their scale, e.g. `-1.50`.
+pub fn decimal256_to_utf8<O: Offset>(from: &PrimitiveArray<i256>) -> Utf8Array<O> {
+    let (_, scale) = decimal_parameters(from.data_type());
+    let iter = from
+        .iter()
+        .map(|x| x.map(|x| format_decimal(x.0 < 0, x.0.unsigned_abs().to_string(), scale)));
+    Utf8Array::from_trusted_len_iter(iter)
+}
+
+pub(super) fn decimal256_to_utf8_dyn<O: Offset>(from: &dyn Array) -> Result<Box<dyn Array>> {
+    let from = from.as_any().downcast_ref().unwrap();
+    Ok(Box::new(decimal256_to_utf8::<O>(from)))
+}
@@ -14,6 +14,8 @@ pub use dictionary_to::*;
 pub use primitive_to::*;
 pub use utf8_to::*;
 
+use std::sync::Arc;
+
 use crate::{
     array::*,
     datatypes::*,
@@ -22,7 +24,7 @@ use crate::{
 };
 
 /// options defining how Cast kernels behave
-#[derive(Clone, Copy, Debug, Default)]
+#[derive(Clone, Debug, Default)]
 pub struct CastOptions {
     /// default to false
     /// whether an overflowing cast should be converted to `None` (default), or be wrapped (i.e. `256i16 as u8 = 0` vectorized).
@@ -31,11 +33,28 @@ pub struct CastOptions {
     /// default to false
     /// whether to cast to an integer at the best-effort
     pub partial: bool,
+    /// default to false
+    /// whether a non-null value that can't be cast should error (pointing at its row) instead of
+    /// being converted to `None`. Only the outermost array is checked.
+    pub strict: bool,
+    /// default to `None` (RFC3339, e.g. `1996-12-19T16:39:57-02:00`)
+    /// the [`chrono::format::strftime`] format used to parse strings to timestamps.
+    pub timestamp_format: Option<Arc<str>>,
+    /// default to `None` (`%Y-%m-%d`)
+    /// the [`chrono::format::strftime`] format used to parse strings to dates.
+    pub date_format: Option<Arc<str>>,
+    /// default to `None` (`%H:%M:%S%.f`)
+    /// the [`chrono::format::strftime`] format used to parse strings to times.
+    pub time_format: Option<Arc<str>>,
+    /// default to `None`
+    /// the timezone in which strings without an offset are interpreted when cast to a timestamp
+    /// with timezone. When `None`, such strings are converted to `None`.
+    pub timezone: Option<Arc<str>>,
 }
 
 impl CastOptions {
     fn with_wrapped(&self, v: bool) -> Self {
-        let mut option = *self;
+        let mut option = self.clone();
         option.wrapped = v;
         option
     }
@@ -126,14 +145,33 @@ pub fn can_cast_types(from_type: &DataType, to_type: &DataType) -> bool {
             is_numeric(to_type)
                 || matches!(
                     to_type,
-                    LargeUtf8 | Binary | Date32 | Date64 | Timestamp(TimeUnit::Nanosecond, _)
+                    LargeUtf8
+                        | Binary
+                        | Date32
+                        | Date64
+                        | Time32(TimeUnit::Second | TimeUnit::Millisecond)
+                        | Time64(TimeUnit::Microsecond | TimeUnit::Nanosecond)
+                        | Timestamp(TimeUnit::Nanosecond, _)
+                        | Decimal(_, _)
+                        | Decimal256(_, _)
+                        | Duration(_)
+                        | Interval(IntervalUnit::MonthDayNano)
                 )
         }
         (LargeUtf8, to_type) => {
             is_numeric(to_type)
                 || matches!(
                     to_type,
-                    Utf8 | LargeBinary | Date32 | Date64 | Timestamp(TimeUnit::Nanosecond, _)
+                    Utf8 | LargeBinary
+                        | Date32
+                        | Date64
+                        | Time32(TimeUnit::Second | TimeUnit::Millisecond)
+                        | Time64(TimeUnit::Microsecond | TimeUnit::Nanosecond)
+                        | Timestamp(TimeUnit::Nanosecond, _)
+                        | Decimal(_, _)
+                        | Decimal256(_, _)
+                        | Duration(_)
+                        | Interval(IntervalUnit::MonthDayNano)
                 )
         }
 
@@ -151,8 +189,13 @@ pub fn can_cast_types(from_type: &DataType, to_type: &DataType) -> bool {
         (FixedSizeBinary(_), to_type) => matches!(to_type, Binary | LargeBinary),
         (Timestamp(_, _), Utf8) => true,
         (Timestamp(_, _), LargeUtf8) => true,
-        (_, Utf8) => is_numeric(from_type) || from_type == &Binary,
-        (_, LargeUtf8) => is_numeric(from_type) || from_type == &LargeBinary,
+        (_, Utf8) => {
+            is_numeric(from_type) || matches!(from_type, Binary | Decimal(_, _) | Decimal256(_, _))
+        }
+        (_, LargeUtf8) => {
+            is_numeric(from_type)
+                || matches!(from_type, LargeBinary | Decimal(_, _) | Decimal256(_, _))
+        }
 
         (_, Binary) => is_numeric(from_type),
         (_, LargeBinary) => is_numeric(from_type),
@@ -282,7 +325,27 @@ pub fn can_cast_types(from_type: &DataType, to_type: &DataType) -> bool {
             | Int64
             | Float32
             | Float64
-            | Decimal(_, _),
+            | Decimal(_, _)
+            | Decimal256(_, _),
+        ) => true,
+        (
+            Decimal256(_, _),
+            UInt8
+            | UInt16
+            | UInt32
+            | UInt64
+            | Int8
+            | Int16
+            | Int32
+            | Int64
+            | Float32
+            | Float64
+            | Decimal(_, _)
+            | Decimal256(_, _),
+        ) => true,
+        (
+            UInt8 | UInt16 | UInt32 | UInt64 | Int8 | Int16 | Int32 | Int64 | Float32 | Float64,
+            Decimal256(_, _),
         ) => true,
         // end numeric casts
 
@@ -314,6 +377,9 @@ pub fn can_cast_types(from_type: &DataType, to_type: &DataType) -> bool {
         (Timestamp(_, _), Date64) => true,
         (Int64, Duration(_)) => true,
         (Duration(_), Int64) => true,
+        (Duration(_), Duration(_)) => true,
+        (Duration(_), Interval(IntervalUnit::MonthDayNano)) => true,
+        (Interval(IntervalUnit::MonthDayNano), Duration(_)) => true,
         (Interval(_), Interval(IntervalUnit::MonthDayNano)) => true,
         (_, _) => false,
     }
@@ -426,6 +492,8 @@ fn cast_list_to_fixed_size_list<O: Offset>(
 /// * Utf8 to numeric: strings that can't be parsed to numbers return null, float strings
 ///   in integer casts return null
 /// * Numeric to boolean: 0 returns `false`, any other value returns `true`
+/// * Utf8 to Date, Time and Timestamp: strings are parsed with the formats of [`CastOptions`];
+///   strings that can't be parsed return null
 /// * List to List: the underlying data type is cast
 /// * Fixed Size List to List: the underlying data type is cast
 /// * List to Fixed Size List: the offsets are checked for valid order, then the
@@ -435,12 +503,50 @@ fn cast_list_to_fixed_size_list<O: Offset>(
 /// * Time32 and Time64: precision lost when going to higher interval
 /// * Timestamp and Date{32|64}: precision lost when going to higher interval
 /// * Temporal to/from backing primitive: zero-copy with data type change
+/// * Decimal and Decimal256 to numeric, decimal and utf8 and vice-versa: values that overflow
+///   the target's precision return null; decreasing the scale truncates
+/// * Duration to Duration: precision lost when going to a coarser unit
+/// * Duration and Interval(MonthDayNano): days are 24 hours; intervals with months return null
+/// * Utf8 to Duration and Interval(MonthDayNano): strings are parsed as ISO 8601 durations
+///   (e.g. `P1DT2H`); strings that can't be parsed return null
 /// Unsupported Casts
 /// * To or from `StructArray`
 /// * List to primitive
 /// * Utf8 to boolean
-/// * Interval and duration
+/// # Error
+/// When [`CastOptions::strict`] is set, this function errors on the first row whose value is
+/// not null but whose cast is.
 pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Result<Box<dyn Array>> {
+    if !options.strict {
+        return cast_impl(array, to_type, options);
+    }
+    let casted = cast_impl(
+        array,
+        to_type,
+        CastOptions {
+            strict: false,
+            ..options
+        },
+    )?;
+    if to_type == &DataType::Null || casted.null_count() == array.null_count() {
+        return Ok(casted);
+    }
+    if let Some(row) = (0..array.len()).find(|&i| array.is_valid(i) && casted.is_null(i)) {
+        let mut value = String::new();
+        get_display(array, "null")(&mut value, row).unwrap();
+        return Err(Error::InvalidArgumentError(format!(
+            "Casting value \"{value}\" at row {row} from {:?} to {to_type:?} failed",
+            array.data_type(),
+        )));
+    }
+    Ok(casted)
+}
+
+fn cast_impl(
+    array: &dyn Array,
+    to_type: &DataType,
+    options: CastOptions,
+) -> Result<Box<dyn Array>> {
     use DataType::*;
     let from_type = array.data_type();
 
@@ -577,8 +683,11 @@ pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Resu
             Int64 => utf8_to_primitive_dyn::<i32, i64>(array, to_type, options),
             Float32 => utf8_to_primitive_dyn::<i32, f32>(array, to_type, options),
             Float64 => utf8_to_primitive_dyn::<i32, f64>(array, to_type, options),
-            Date32 => utf8_to_date32_dyn::<i32>(array),
-            Date64 => utf8_to_date64_dyn::<i32>(array),
+            Date32 => utf8_to_date32_dyn::<i32>(array, options.date_format.as_deref()),
+            Date64 => utf8_to_date64_dyn::<i32>(array, options.date_format.as_deref()),
+            Time32(_) | Time64(_) => {
+                utf8_to_time_dyn::<i32>(array, to_type, options.time_format.as_deref())
+            }
             LargeUtf8 => Ok(Box::new(utf8_to_large_utf8(
                 array.as_any().downcast_ref().unwrap(),
             ))),
@@ -587,10 +696,19 @@ pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Resu
                 to_type.clone(),
             )
             .boxed()),
-            Timestamp(TimeUnit::Nanosecond, None) => utf8_to_naive_timestamp_ns_dyn::<i32>(array),
-            Timestamp(TimeUnit::Nanosecond, Some(tz)) => {
-                utf8_to_timestamp_ns_dyn::<i32>(array, tz.clone())
+            Timestamp(TimeUnit::Nanosecond, None) => {
+                utf8_to_naive_timestamp_ns_dyn::<i32>(array, options.timestamp_format.as_deref())
             }
+            Timestamp(TimeUnit::Nanosecond, Some(tz)) => utf8_to_timestamp_ns_dyn::<i32>(
+                array,
+                tz.clone(),
+                options.timestamp_format.as_deref(),
+                options.timezone.as_deref(),
+            ),
+            Decimal(p, s) => utf8_to_decimal_dyn::<i32>(array, *p, *s),
+            Decimal256(p, s) => utf8_to_decimal256_dyn::<i32>(array, *p, *s),
+            Duration(unit) => utf8_to_duration_dyn::<i32>(array, *unit),
+            Interval(IntervalUnit::MonthDayNano) => utf8_to_months_days_ns_dyn::<i32>(array),
             _ => Err(Error::NotYetImplemented(format!(
                 "Casting from {from_type:?} to {to_type:?} not supported",
             ))),
@@ -606,18 +724,30 @@ pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Resu
             Int64 => utf8_to_primitive_dyn::<i64, i64>(array, to_type, options),
             Float32 => utf8_to_primitive_dyn::<i64, f32>(array, to_type, options),
             Float64 => utf8_to_primitive_dyn::<i64, f64>(array, to_type, options),
-            Date32 => utf8_to_date32_dyn::<i64>(array),
-            Date64 => utf8_to_date64_dyn::<i64>(array),
+            Date32 => utf8_to_date32_dyn::<i64>(array, options.date_format.as_deref()),
+            Date64 => utf8_to_date64_dyn::<i64>(array, options.date_format.as_deref()),
+            Time32(_) | Time64(_) => {
+                utf8_to_time_dyn::<i64>(array, to_type, options.time_format.as_deref())
+            }
             Utf8 => utf8_large_to_utf8(array.as_any().downcast_ref().unwrap()).map(|x| x.boxed()),
             LargeBinary => Ok(utf8_to_binary::<i64>(
                 array.as_any().downcast_ref().unwrap(),
                 to_type.clone(),
             )
             .boxed()),
-            Timestamp(TimeUnit::Nanosecond, None) => utf8_to_naive_timestamp_ns_dyn::<i64>(array),
-            Timestamp(TimeUnit::Nanosecond, Some(tz)) => {
-                utf8_to_timestamp_ns_dyn::<i64>(array, tz.clone())
+            Timestamp(TimeUnit::Nanosecond, None) => {
+                utf8_to_naive_timestamp_ns_dyn::<i64>(array, options.timestamp_format.as_deref())
             }
+            Timestamp(TimeUnit::Nanosecond, Some(tz)) => utf8_to_timestamp_ns_dyn::<i64>(
+                array,
+                tz.clone(),
+                options.timestamp_format.as_deref(),
+                options.timezone.as_deref(),
+            ),
+            Decimal(p, s) => utf8_to_decimal_dyn::<i64>(array, *p, *s),
+            Decimal256(p, s) => utf8_to_decimal256_dyn::<i64>(array, *p, *s),
+            Duration(unit) => utf8_to_duration_dyn::<i64>(array, *unit),
+            Interval(IntervalUnit::MonthDayNano) => utf8_to_months_days_ns_dyn::<i64>(array),
             _ => Err(Error::NotYetImplemented(format!(
                 "Casting from {from_type:?} to {to_type:?} not supported",
             ))),
@@ -634,6 +764,8 @@ pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Resu
             Int64 => primitive_to_utf8_dyn::<i64, i32>(array),
             Float32 => primitive_to_utf8_dyn::<f32, i32>(array),
             Float64 => primitive_to_utf8_dyn::<f64, i32>(array),
+            Decimal(_, _) => decimal_to_utf8_dyn::<i32>(array),
+            Decimal256(_, _) => decimal256_to_utf8_dyn::<i32>(array),
             Binary => {
                 let array = array.as_any().downcast_ref::<BinaryArray<i32>>().unwrap();
 
@@ -669,6 +801,8 @@ pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Resu
             Int64 => primitive_to_utf8_dyn::<i64, i64>(array),
             Float32 => primitive_to_utf8_dyn::<f32, i64>(array),
             Float64 => primitive_to_utf8_dyn::<f64, i64>(array),
+            Decimal(_, _) => decimal_to_utf8_dyn::<i64>(array),
+            Decimal256(_, _) => decimal256_to_utf8_dyn::<i64>(array),
             Binary => binary_to_large_utf8(array.as_any().downcast_ref().unwrap(), to_type.clone())
                 .map(|x| x.boxed()),
             LargeBinary => {
@@ -915,6 +1049,33 @@ pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Resu
         (Decimal(_, _), Float32) => decimal_to_float_dyn::<f32>(array),
         (Decimal(_, _), Float64) => decimal_to_float_dyn::<f64>(array),
         (Decimal(_, _), Decimal(to_p, to_s)) => decimal_to_decimal_dyn(array, *to_p, *to_s),
+        (Decimal(_, _), Decimal256(to_p, to_s)) => decimal_to_decimal256_dyn(array, *to_p, *to_s),
+
+        (UInt8, Decimal256(p, s)) => integer_to_decimal256_dyn::<u8>(array, *p, *s),
+        (UInt16, Decimal256(p, s)) => integer_to_decimal256_dyn::<u16>(array, *p, *s),
+        (UInt32, Decimal256(p, s)) => integer_to_decimal256_dyn::<u32>(array, *p, *s),
+        (UInt64, Decimal256(p, s)) => integer_to_decimal256_dyn::<u64>(array, *p, *s),
+        (Int8, Decimal256(p, s)) => integer_to_decimal256_dyn::<i8>(array, *p, *s),
+        (Int16, Decimal256(p, s)) => integer_to_decimal256_dyn::<i16>(array, *p, *s),
+        (Int32, Decimal256(p, s)) => integer_to_decimal256_dyn::<i32>(array, *p, *s),
+        (Int64, Decimal256(p, s)) => integer_to_decimal256_dyn::<i64>(array, *p, *s),
+        (Float32, Decimal256(p, s)) => float_to_decimal256_dyn::<f32>(array, *p, *s),
+        (Float64, Decimal256(p, s)) => float_to_decimal256_dyn::<f64>(array, *p, *s),
+
+        (Decimal256(_, _), UInt8) => decimal256_to_integer_dyn::<u8>(array),
+        (Decimal256(_, _), UInt16) => decimal256_to_integer_dyn::<u16>(array),
+        (Decimal256(_, _), UInt32) => decimal256_to_integer_dyn::<u32>(array),
+        (Decimal256(_, _), UInt64) => decimal256_to_integer_dyn::<u64>(array),
+        (Decimal256(_, _), Int8) => decimal256_to_integer_dyn::<i8>(array),
+        (Decimal256(_, _), Int16) => decimal256_to_integer_dyn::<i16>(array),
+        (Decimal256(_, _), Int32) => decimal256_to_integer_dyn::<i32>(array),
+        (Decimal256(_, _), Int64) => decimal256_to_integer_dyn::<i64>(array),
+        (Decimal256(_, _), Float32) => decimal256_to_float_dyn::<f32>(array),
+        (Decimal256(_, _), Float64) => decimal256_to_float_dyn::<f64>(array),
+        (Decimal256(_, _), Decimal(to_p, to_s)) => decimal256_to_decimal_dyn(array, *to_p, *to_s),
+        (Decimal256(_, _), Decimal256(to_p, to_s)) => {
+            decimal256_to_decimal256_dyn(array, *to_p, *to_s)
+        }
         // end numeric casts
 
         // temporal casts
@@ -969,6 +1130,15 @@ pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Resu
 
         (Int64, Duration(_)) => primitive_to_same_primitive_dyn::<i64>(array, to_type),
         (Duration(_), Int64) => primitive_to_same_primitive_dyn::<i64>(array, to_type),
+        (Duration(from_unit), Duration(to_unit)) => {
+            primitive_dyn!(array, duration_to_duration, *from_unit, *to_unit)
+        }
+        (Duration(from_unit), Interval(IntervalUnit::MonthDayNano)) => {
+            primitive_dyn!(array, duration_to_months_days_ns, *from_unit)
+        }
+        (Interval(IntervalUnit::MonthDayNano), Duration(to_unit)) => {
+            primitive_dyn!(array, months_days_ns_to_duration, *to_unit)
+        }
 
         (Interval(IntervalUnit::DayTime), Interval(IntervalUnit::MonthDayNano)) => {
             primitive_dyn!(array, days_ms_to_months_days_ns)
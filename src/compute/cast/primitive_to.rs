@@ -584,6 +584,70 @@ pub fn months_to_months_days_ns(from: &PrimitiveArray<i32>) -> PrimitiveArray<mo
     )
 }
 
+/// Casts a [`PrimitiveArray`] of durations in `from_unit` to durations in `to_unit`.
+/// Converting to a coarser unit truncates; values are `None` on overflow.
+pub fn duration_to_duration(
+    from: &PrimitiveArray<i64>,
+    from_unit: TimeUnit,
+    to_unit: TimeUnit,
+) -> PrimitiveArray<i64> {
+    let from_size = time_unit_multiple(from_unit);
+    let to_size = time_unit_multiple(to_unit);
+    let to_type = DataType::Duration(to_unit);
+    if from_size >= to_size {
+        let divisor = from_size / to_size;
+        unary(from, |x| x / divisor, to_type)
+    } else {
+        let multiplier = to_size / from_size;
+        let iter = from
+            .iter()
+            .map(|x| x.and_then(|x| x.checked_mul(multiplier)));
+        PrimitiveArray::from_trusted_len_iter(iter).to(to_type)
+    }
+}
+
+/// Casts a [`PrimitiveArray`] of durations in `from_unit` to [`months_days_ns`] with zero months
+/// and days. Values are `None` on overflow.
+pub fn duration_to_months_days_ns(
+    from: &PrimitiveArray<i64>,
+    from_unit: TimeUnit,
+) -> PrimitiveArray<months_days_ns> {
+    let multiplier = NANOSECONDS / time_unit_multiple(from_unit);
+    let iter = from.iter().map(|x| {
+        x.and_then(|x| x.checked_mul(multiplier))
+            .map(|x| months_days_ns::new(0, 0, x))
+    });
+    PrimitiveArray::from_trusted_len_iter(iter).to(DataType::Interval(IntervalUnit::MonthDayNano))
+}
+
+/// Converts an interval to a duration in `to_unit`, counting days as 24 hours.
+/// Returns `None` when the interval has months (whose length is not fixed) or on overflow.
+#[inline]
+pub(super) fn months_days_ns_to_duration_scalar(
+    from: months_days_ns,
+    to_unit: TimeUnit,
+) -> Option<i64> {
+    if from.months() != 0 {
+        return None;
+    }
+    let nanos = (from.days() as i64)
+        .checked_mul(SECONDS_IN_DAY * NANOSECONDS)?
+        .checked_add(from.ns())?;
+    Some(nanos / (NANOSECONDS / time_unit_multiple(to_unit)))
+}
+
+/// Casts [`months_days_ns`]s to durations in `to_unit`, counting days as 24 hours. Values
+/// with months (whose length is not fixed) or that overflow are `None`.
+pub fn months_days_ns_to_duration(
+    from: &PrimitiveArray<months_days_ns>,
+    to_unit: TimeUnit,
+) -> PrimitiveArray<i64> {
+    let iter = from
+        .iter()
+        .map(|x| x.and_then(|x| months_days_ns_to_duration_scalar(*x, to_unit)));
+    PrimitiveArray::from_trusted_len_iter(iter).to(DataType::Duration(to_unit))
+}
+
 /// Casts f16 into f32
 pub fn f16_to_f32(from: &PrimitiveArray<f16>) -> PrimitiveArray<f32> {
     unary(from, |x| x.to_f32(), DataType::Float32)
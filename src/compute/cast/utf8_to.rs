@@ -1,22 +1,29 @@
 use std::sync::Arc;
 
-use chrono::Datelike;
+use chrono::format::{parse, Fixed, Item, Parsed, StrftimeItems};
+use chrono::{Datelike, TimeZone, Timelike};
+
+use ethnum::I256;
 
 use crate::{
     array::*,
-    datatypes::DataType,
-    error::Result,
+    datatypes::{DataType, IntervalUnit, TimeUnit},
+    error::{Error, Result},
     offset::Offset,
     temporal_conversions::{
+        parse_iso8601_duration, parse_offset,
         utf8_to_naive_timestamp_ns as utf8_to_naive_timestamp_ns_,
         utf8_to_timestamp_ns as utf8_to_timestamp_ns_, EPOCH_DAYS_FROM_CE,
     },
-    types::NativeType,
+    types::{i256, months_days_ns, NativeType},
 };
 
+use super::decimal_to::bounds_for_precision;
+use super::primitive_to::months_days_ns_to_duration_scalar;
 use super::CastOptions;
 
 const RFC3339: &str = "%Y-%m-%dT%H:%M:%S%.f%:z";
+const TIME_FORMAT: &str = "%H:%M:%S%.f";
 
 /// Casts a [`Utf8Array`] to a [`PrimitiveArray`], making any uncastable value a Null.
 pub fn utf8_to_primitive<O: Offset, T>(from: &Utf8Array<O>, to: &DataType) -> PrimitiveArray<T>
@@ -73,9 +80,31 @@ pub fn utf8_to_date32<O: Offset>(from: &Utf8Array<O>) -> PrimitiveArray<i32> {
     PrimitiveArray::<i32>::from_trusted_len_iter(iter).to(DataType::Date32)
 }
 
-pub(super) fn utf8_to_date32_dyn<O: Offset>(from: &dyn Array) -> Result<Box<dyn Array>> {
+/// Casts a [`Utf8Array`] to a Date32 primitive using the [`chrono::format::strftime`] format
+/// `fmt`, making any uncastable value a Null.
+pub fn utf8_to_date32_with_format<O: Offset>(
+    from: &Utf8Array<O>,
+    fmt: &str,
+) -> PrimitiveArray<i32> {
+    let iter = from.iter().map(|x| {
+        x.and_then(|x| {
+            chrono::NaiveDate::parse_from_str(x, fmt)
+                .ok()
+                .map(|x| x.num_days_from_ce() - EPOCH_DAYS_FROM_CE)
+        })
+    });
+    PrimitiveArray::<i32>::from_trusted_len_iter(iter).to(DataType::Date32)
+}
+
+pub(super) fn utf8_to_date32_dyn<O: Offset>(
+    from: &dyn Array,
+    fmt: Option<&str>,
+) -> Result<Box<dyn Array>> {
     let from = from.as_any().downcast_ref().unwrap();
-    Ok(Box::new(utf8_to_date32::<O>(from)))
+    Ok(match fmt {
+        Some(fmt) => Box::new(utf8_to_date32_with_format::<O>(from, fmt)),
+        None => Box::new(utf8_to_date32::<O>(from)),
+    })
 }
 
 /// Casts a [`Utf8Array`] to a Date64 primitive, making any uncastable value a Null.
@@ -90,9 +119,201 @@ pub fn utf8_to_date64<O: Offset>(from: &Utf8Array<O>) -> PrimitiveArray<i64> {
     PrimitiveArray::from_trusted_len_iter(iter).to(DataType::Date64)
 }
 
-pub(super) fn utf8_to_date64_dyn<O: Offset>(from: &dyn Array) -> Result<Box<dyn Array>> {
+/// Casts a [`Utf8Array`] to a Date64 primitive using the [`chrono::format::strftime`] format
+/// `fmt`, making any uncastable value a Null.
+pub fn utf8_to_date64_with_format<O: Offset>(
+    from: &Utf8Array<O>,
+    fmt: &str,
+) -> PrimitiveArray<i64> {
+    let iter = from.iter().map(|x| {
+        x.and_then(|x| {
+            chrono::NaiveDate::parse_from_str(x, fmt)
+                .ok()
+                .map(|x| (x.num_days_from_ce() - EPOCH_DAYS_FROM_CE) as i64 * 86400000)
+        })
+    });
+    PrimitiveArray::from_trusted_len_iter(iter).to(DataType::Date64)
+}
+
+pub(super) fn utf8_to_date64_dyn<O: Offset>(
+    from: &dyn Array,
+    fmt: Option<&str>,
+) -> Result<Box<dyn Array>> {
+    let from = from.as_any().downcast_ref().unwrap();
+    Ok(match fmt {
+        Some(fmt) => Box::new(utf8_to_date64_with_format::<O>(from, fmt)),
+        None => Box::new(utf8_to_date64::<O>(from)),
+    })
+}
+
+fn parse_time(value: &str, fmt: &str) -> Option<(i64, i64)> {
+    chrono::NaiveTime::parse_from_str(value, fmt)
+        .ok()
+        .map(|x| (x.num_seconds_from_midnight() as i64, x.nanosecond() as i64))
+}
+
+/// Casts a [`Utf8Array`] to a Time32 primitive of `unit` using the [`chrono::format::strftime`]
+/// format `fmt`, making any uncastable value a Null.
+/// # Panics
+/// iff `unit` is not `Second` nor `Millisecond`.
+pub fn utf8_to_time32<O: Offset>(
+    from: &Utf8Array<O>,
+    unit: TimeUnit,
+    fmt: &str,
+) -> PrimitiveArray<i32> {
+    let iter = from.iter().map(|x| {
+        x.and_then(|x| parse_time(x, fmt))
+            .map(|(seconds, nanos)| match unit {
+                TimeUnit::Second => seconds as i32,
+                TimeUnit::Millisecond => (seconds * 1_000 + nanos / 1_000_000) as i32,
+                _ => unreachable!("Time32 only supports second and millisecond units"),
+            })
+    });
+    PrimitiveArray::from_trusted_len_iter(iter).to(DataType::Time32(unit))
+}
+
+/// Casts a [`Utf8Array`] to a Time64 primitive of `unit` using the [`chrono::format::strftime`]
+/// format `fmt`, making any uncastable value a Null.
+/// # Panics
+/// iff `unit` is not `Microsecond` nor `Nanosecond`.
+pub fn utf8_to_time64<O: Offset>(
+    from: &Utf8Array<O>,
+    unit: TimeUnit,
+    fmt: &str,
+) -> PrimitiveArray<i64> {
+    let iter = from.iter().map(|x| {
+        x.and_then(|x| parse_time(x, fmt))
+            .map(|(seconds, nanos)| match unit {
+                TimeUnit::Microsecond => seconds * 1_000_000 + nanos / 1_000,
+                TimeUnit::Nanosecond => seconds * 1_000_000_000 + nanos,
+                _ => unreachable!("Time64 only supports microsecond and nanosecond units"),
+            })
+    });
+    PrimitiveArray::from_trusted_len_iter(iter).to(DataType::Time64(unit))
+}
+
+pub(super) fn utf8_to_time_dyn<O: Offset>(
+    from: &dyn Array,
+    to: &DataType,
+    fmt: Option<&str>,
+) -> Result<Box<dyn Array>> {
+    let from = from.as_any().downcast_ref().unwrap();
+    let fmt = fmt.unwrap_or(TIME_FORMAT);
+    match to {
+        DataType::Time32(unit) => Ok(Box::new(utf8_to_time32::<O>(from, *unit, fmt))),
+        DataType::Time64(unit) => Ok(Box::new(utf8_to_time64::<O>(from, *unit, fmt))),
+        _ => unreachable!(),
+    }
+}
+
+/// Parses a decimal string such as `-1.23` to its unscaled value in `scale`, truncating the
+/// digits beyond `scale`.
+fn parse_decimal(value: &str, scale: usize) -> Option<I256> {
+    let (negative, value) = match value.strip_prefix('-') {
+        Some(value) => (true, value),
+        None => (false, value.strip_prefix('+').unwrap_or(value)),
+    };
+    let (integer, fraction) = value.split_once('.').unwrap_or((value, ""));
+    if (integer.is_empty() && fraction.is_empty())
+        || !integer
+            .bytes()
+            .chain(fraction.bytes())
+            .all(|x| x.is_ascii_digit())
+    {
+        return None;
+    }
+    let fraction = &fraction[..fraction.len().min(scale)];
+
+    let mut digits = String::with_capacity(integer.len() + scale + 1);
+    digits.push('0');
+    digits.push_str(integer);
+    digits.push_str(fraction);
+    digits.extend(std::iter::repeat('0').take(scale - fraction.len()));
+    let value = I256::from_str_radix(&digits, 10).ok()?;
+    Some(if negative { -value } else { value })
+}
+
+/// Casts a [`Utf8Array`] to a [`PrimitiveArray<i128>`] of type `Decimal(precision, scale)`,
+/// truncating the digits beyond `scale`. Values that can't be parsed or that don't fit
+/// `precision` are `None`.
+pub fn utf8_to_decimal<O: Offset>(
+    from: &Utf8Array<O>,
+    precision: usize,
+    scale: usize,
+) -> PrimitiveArray<i128> {
+    let (min, max) = bounds_for_precision(precision);
+    let iter = from.iter().map(|x| {
+        x.and_then(|x| parse_decimal(x, scale))
+            .filter(|x| *x >= min && *x <= max)
+            .and_then(|x| i128::try_from(x).ok())
+    });
+    PrimitiveArray::from_trusted_len_iter(iter).to(DataType::Decimal(precision, scale))
+}
+
+pub(super) fn utf8_to_decimal_dyn<O: Offset>(
+    from: &dyn Array,
+    precision: usize,
+    scale: usize,
+) -> Result<Box<dyn Array>> {
+    let from = from.as_any().downcast_ref().unwrap();
+    Ok(Box::new(utf8_to_decimal::<O>(from, precision, scale)))
+}
+
+/// Casts a [`Utf8Array`] to a [`PrimitiveArray<i256>`] of type `Decimal256(precision, scale)`,
+/// truncating the digits beyond `scale`. Values that can't be parsed or that don't fit
+/// `precision` are `None`.
+pub fn utf8_to_decimal256<O: Offset>(
+    from: &Utf8Array<O>,
+    precision: usize,
+    scale: usize,
+) -> PrimitiveArray<i256> {
+    let (min, max) = bounds_for_precision(precision);
+    let iter = from.iter().map(|x| {
+        x.and_then(|x| parse_decimal(x, scale))
+            .filter(|x| *x >= min && *x <= max)
+            .map(i256)
+    });
+    PrimitiveArray::from_trusted_len_iter(iter).to(DataType::Decimal256(precision, scale))
+}
+
+pub(super) fn utf8_to_decimal256_dyn<O: Offset>(
+    from: &dyn Array,
+    precision: usize,
+    scale: usize,
+) -> Result<Box<dyn Array>> {
+    let from = from.as_any().downcast_ref().unwrap();
+    Ok(Box::new(utf8_to_decimal256::<O>(from, precision, scale)))
+}
+
+/// Casts a [`Utf8Array`] of ISO 8601 durations (e.g. `PT1H30M`) to durations in `unit`,
+/// counting days as 24 hours and weeks as 7 days. Values that can't be parsed, that have years
+/// or months, or that overflow are `None`.
+pub fn utf8_to_duration<O: Offset>(from: &Utf8Array<O>, unit: TimeUnit) -> PrimitiveArray<i64> {
+    let iter = from.iter().map(|x| {
+        x.and_then(parse_iso8601_duration)
+            .and_then(|x| months_days_ns_to_duration_scalar(x, unit))
+    });
+    PrimitiveArray::from_trusted_len_iter(iter).to(DataType::Duration(unit))
+}
+
+pub(super) fn utf8_to_duration_dyn<O: Offset>(
+    from: &dyn Array,
+    unit: TimeUnit,
+) -> Result<Box<dyn Array>> {
+    let from = from.as_any().downcast_ref().unwrap();
+    Ok(Box::new(utf8_to_duration::<O>(from, unit)))
+}
+
+/// Casts a [`Utf8Array`] of ISO 8601 durations (e.g. `P1Y2M3DT4H`) to [`months_days_ns`]
+/// intervals. Values that can't be parsed or that overflow are `None`.
+pub fn utf8_to_months_days_ns<O: Offset>(from: &Utf8Array<O>) -> PrimitiveArray<months_days_ns> {
+    let iter = from.iter().map(|x| x.and_then(parse_iso8601_duration));
+    PrimitiveArray::from_trusted_len_iter(iter).to(DataType::Interval(IntervalUnit::MonthDayNano))
+}
+
+pub(super) fn utf8_to_months_days_ns_dyn<O: Offset>(from: &dyn Array) -> Result<Box<dyn Array>> {
     let from = from.as_any().downcast_ref().unwrap();
-    Ok(Box::new(utf8_to_date64::<O>(from)))
+    Ok(Box::new(utf8_to_months_days_ns::<O>(from)))
 }
 
 pub(super) fn utf8_to_dictionary_dyn<O: Offset, K: DictionaryKey>(
@@ -117,9 +338,13 @@ pub fn utf8_to_dictionary<O: Offset, K: DictionaryKey>(
 
 pub(super) fn utf8_to_naive_timestamp_ns_dyn<O: Offset>(
     from: &dyn Array,
+    fmt: Option<&str>,
 ) -> Result<Box<dyn Array>> {
-    let from = from.as_any().downcast_ref().unwrap();
-    Ok(Box::new(utf8_to_naive_timestamp_ns::<O>(from)))
+    let from = from.as_any().downcast_ref::<Utf8Array<O>>().unwrap();
+    Ok(Box::new(utf8_to_naive_timestamp_ns_(
+        from,
+        fmt.unwrap_or(RFC3339),
+    )))
 }
 
 /// [`crate::temporal_conversions::utf8_to_timestamp_ns`] applied for RFC3339 formatting
@@ -130,11 +355,93 @@ pub fn utf8_to_naive_timestamp_ns<O: Offset>(from: &Utf8Array<O>) -> PrimitiveAr
 pub(super) fn utf8_to_timestamp_ns_dyn<O: Offset>(
     from: &dyn Array,
     timezone: Arc<String>,
+    fmt: Option<&str>,
+    default_timezone: Option<&str>,
 ) -> Result<Box<dyn Array>> {
-    let from = from.as_any().downcast_ref().unwrap();
-    utf8_to_timestamp_ns::<O>(from, timezone)
-        .map(Box::new)
-        .map(|x| x as Box<dyn Array>)
+    let from = from.as_any().downcast_ref::<Utf8Array<O>>().unwrap();
+    let fmt = fmt.unwrap_or(RFC3339);
+    let array = utf8_to_timestamp_ns_(from, fmt, timezone)?;
+    match default_timezone {
+        None => Ok(Box::new(array)),
+        Some(default_timezone) => {
+            if let Ok(tz) = parse_offset(default_timezone) {
+                Ok(Box::new(localize_naive_timestamps_ns(
+                    from, array, fmt, &tz,
+                )))
+            } else {
+                chrono_tz_localize_naive_timestamps_ns(from, array, fmt, default_timezone)
+                    .map(|x| Box::new(x) as Box<dyn Array>)
+            }
+        }
+    }
+}
+
+/// Returns the items of `fmt` without its timezone and offset specifiers, so that a naive
+/// timestamp must match the remaining items in full.
+fn naive_items(fmt: &str) -> Vec<Item<'_>> {
+    let permissive = StrftimeItems::new("%#z").next();
+    StrftimeItems::new(fmt)
+        .filter(|item| {
+            !matches!(
+                item,
+                Item::Fixed(
+                    Fixed::TimezoneName
+                        | Fixed::TimezoneOffsetColon
+                        | Fixed::TimezoneOffsetDoubleColon
+                        | Fixed::TimezoneOffsetTripleColon
+                        | Fixed::TimezoneOffsetColonZ
+                        | Fixed::TimezoneOffset
+                        | Fixed::TimezoneOffsetZ
+                )
+            ) && Some(item) != permissive.as_ref()
+        })
+        .collect()
+}
+
+/// Fills the null slots of `parsed` whose string in `from` is a naive timestamp, interpreting
+/// it as a local time in `tz`.
+fn localize_naive_timestamps_ns<O: Offset, T: TimeZone>(
+    from: &Utf8Array<O>,
+    parsed: PrimitiveArray<i64>,
+    fmt: &str,
+    tz: &T,
+) -> PrimitiveArray<i64> {
+    let data_type = parsed.data_type().clone();
+    let items = naive_items(fmt);
+    let iter = from.iter().zip(parsed.iter()).map(|(value, parsed)| {
+        parsed.copied().or_else(|| {
+            let mut naive = Parsed::new();
+            parse(&mut naive, value?, items.iter()).ok()?;
+            let naive = naive.to_naive_datetime_with_offset(0).ok()?;
+            tz.from_local_datetime(&naive)
+                .earliest()?
+                .timestamp_nanos_opt()
+        })
+    });
+    PrimitiveArray::from_trusted_len_iter(iter).to(data_type)
+}
+
+#[cfg(feature = "chrono-tz")]
+fn chrono_tz_localize_naive_timestamps_ns<O: Offset>(
+    from: &Utf8Array<O>,
+    parsed: PrimitiveArray<i64>,
+    fmt: &str,
+    timezone: &str,
+) -> Result<PrimitiveArray<i64>> {
+    let tz = crate::temporal_conversions::parse_offset_tz(timezone)?;
+    Ok(localize_naive_timestamps_ns(from, parsed, fmt, &tz))
+}
+
+#[cfg(not(feature = "chrono-tz"))]
+fn chrono_tz_localize_naive_timestamps_ns<O: Offset>(
+    _: &Utf8Array<O>,
+    _: PrimitiveArray<i64>,
+    _: &str,
+    timezone: &str,
+) -> Result<PrimitiveArray<i64>> {
+    Err(Error::InvalidArgumentError(format!(
+        "timezone \"{timezone}\" cannot be parsed (feature chrono-tz is not active)",
+    )))
 }
 
 /// [`crate::temporal_conversions::utf8_to_timestamp_ns`] applied for RFC3339 formatting
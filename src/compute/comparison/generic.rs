@@ -0,0 +1,203 @@
+//! Comparison of arrays without a dedicated kernel (e.g. nested and dictionary arrays),
+//! evaluated slot by slot.
+use std::cmp::Ordering;
+
+use crate::{
+    array::{ord::build_compare, *},
+    bitmap::Bitmap,
+    buffer::Buffer,
+    datatypes::{DataType, PhysicalType},
+    error::{Error, Result},
+    offset::Offset,
+};
+
+use super::super::utils::combine_validities;
+
+/// The comparison operators supported by [`compare`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum Operator {
+    Eq,
+    Neq,
+    EqAndValidity,
+    NeqAndValidity,
+    Lt,
+    LtEq,
+    Gt,
+    GtEq,
+}
+
+/// Returns whether the slot `i` of the left array is equal to the slot `j` of the right array.
+/// Null slots are equal to each other and different from any value.
+type DynEq = Box<dyn Fn(usize, usize) -> bool + Send + Sync>;
+
+fn with_validity(lhs: &dyn Array, rhs: &dyn Array, eq: DynEq) -> DynEq {
+    match (lhs.validity().cloned(), rhs.validity().cloned()) {
+        (None, None) => eq,
+        (lhs, rhs) => Box::new(move |i, j| {
+            let lhs = lhs.as_ref().map(|x| x.get_bit(i)).unwrap_or(true);
+            let rhs = rhs.as_ref().map(|x| x.get_bit(j)).unwrap_or(true);
+            match (lhs, rhs) {
+                (true, true) => eq(i, j),
+                (lhs, rhs) => lhs == rhs,
+            }
+        }),
+    }
+}
+
+macro_rules! dyn_eq {
+    ($ty:ty, $lhs:expr, $rhs:expr) => {{
+        let lhs = $lhs.as_any().downcast_ref::<$ty>().unwrap().clone();
+        let rhs = $rhs.as_any().downcast_ref::<$ty>().unwrap().clone();
+        Box::new(move |i, j| lhs.value(i) == rhs.value(j))
+    }};
+}
+
+fn eq_lists<O: Offset>(lhs: &dyn Array, rhs: &dyn Array) -> Result<DynEq> {
+    let lhs = lhs.as_any().downcast_ref::<ListArray<O>>().unwrap();
+    let rhs = rhs.as_any().downcast_ref::<ListArray<O>>().unwrap();
+    let values = build_eq(lhs.values().as_ref(), rhs.values().as_ref())?;
+    Ok(eq_ranges(
+        lhs.offsets().buffer().clone(),
+        rhs.offsets().buffer().clone(),
+        values,
+    ))
+}
+
+/// Compares lists whose elements on slot `i` are `values[offsets[i]..offsets[i + 1]]`.
+fn eq_ranges<O: Offset>(lhs: Buffer<O>, rhs: Buffer<O>, values: DynEq) -> DynEq {
+    Box::new(move |i, j| {
+        let (lhs_start, lhs_end) = (lhs[i].to_usize(), lhs[i + 1].to_usize());
+        let (rhs_start, rhs_end) = (rhs[j].to_usize(), rhs[j + 1].to_usize());
+        lhs_end - lhs_start == rhs_end - rhs_start
+            && (lhs_start..lhs_end)
+                .zip(rhs_start..rhs_end)
+                .all(|(i, j)| values(i, j))
+    })
+}
+
+/// Returns a [`DynEq`] between the slots of two arrays of the same data type.
+fn build_eq(lhs: &dyn Array, rhs: &dyn Array) -> Result<DynEq> {
+    use PhysicalType::*;
+    let eq: DynEq = match lhs.data_type().to_physical_type() {
+        Null => Box::new(|_, _| true),
+        Boolean => dyn_eq!(BooleanArray, lhs, rhs),
+        Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
+            dyn_eq!(PrimitiveArray<$T>, lhs, rhs)
+        }),
+        Utf8 => dyn_eq!(Utf8Array<i32>, lhs, rhs),
+        LargeUtf8 => dyn_eq!(Utf8Array<i64>, lhs, rhs),
+        Binary => dyn_eq!(BinaryArray<i32>, lhs, rhs),
+        LargeBinary => dyn_eq!(BinaryArray<i64>, lhs, rhs),
+        FixedSizeBinary => dyn_eq!(FixedSizeBinaryArray, lhs, rhs),
+        List => eq_lists::<i32>(lhs, rhs)?,
+        LargeList => eq_lists::<i64>(lhs, rhs)?,
+        FixedSizeList => {
+            let lhs = lhs.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
+            let rhs = rhs.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
+            let size = lhs.size();
+            let values = build_eq(lhs.values().as_ref(), rhs.values().as_ref())?;
+            Box::new(move |i, j| (0..size).all(|k| values(i * size + k, j * size + k)))
+        }
+        Struct => {
+            let lhs = lhs.as_any().downcast_ref::<StructArray>().unwrap();
+            let rhs = rhs.as_any().downcast_ref::<StructArray>().unwrap();
+            let fields = lhs
+                .values()
+                .iter()
+                .zip(rhs.values().iter())
+                .map(|(lhs, rhs)| build_eq(lhs.as_ref(), rhs.as_ref()))
+                .collect::<Result<Vec<_>>>()?;
+            Box::new(move |i, j| fields.iter().all(|eq| eq(i, j)))
+        }
+        Map => {
+            let lhs = lhs.as_any().downcast_ref::<MapArray>().unwrap();
+            let rhs = rhs.as_any().downcast_ref::<MapArray>().unwrap();
+            let values = build_eq(lhs.field().as_ref(), rhs.field().as_ref())?;
+            eq_ranges(
+                lhs.offsets().buffer().clone(),
+                rhs.offsets().buffer().clone(),
+                values,
+            )
+        }
+        Dictionary(key_type) => match_integer_type!(key_type, |$T| {
+            let lhs = lhs.as_any().downcast_ref::<DictionaryArray<$T>>().unwrap();
+            let rhs = rhs.as_any().downcast_ref::<DictionaryArray<$T>>().unwrap();
+            let values = build_eq(lhs.values().as_ref(), rhs.values().as_ref())?;
+            let lhs_keys = lhs.keys().values().clone();
+            let rhs_keys = rhs.keys().values().clone();
+            // safety: all dictionaries keys are guaranteed to be castable to usize
+            Box::new(move |i, j| unsafe { values(lhs_keys[i].as_usize(), rhs_keys[j].as_usize()) })
+        }),
+        Union => {
+            return Err(Error::NotYetImplemented(format!(
+                "Comparison between {:?} are not yet supported",
+                lhs.data_type()
+            )))
+        }
+    };
+    Ok(with_validity(lhs, rhs, eq))
+}
+
+/// Whether `data_type` is supported by [`compare`] with [`Operator::Eq`] and [`Operator::Neq`].
+pub(super) fn can_eq(data_type: &DataType) -> bool {
+    match data_type.to_logical_type() {
+        DataType::List(field)
+        | DataType::LargeList(field)
+        | DataType::FixedSizeList(field, _)
+        | DataType::Map(field, _) => can_eq(field.data_type()),
+        DataType::Struct(fields) => fields.iter().all(|field| can_eq(field.data_type())),
+        DataType::Dictionary(_, values, _) => can_eq(values.as_ref()),
+        DataType::Union(_, _, _) => false,
+        _ => true,
+    }
+}
+
+/// Compares the slots of `lhs` with the slots of `rhs` (or with its first slot when `broadcast`).
+/// # Errors
+/// This function errors iff the data type is not supported by `op`.
+pub(super) fn compare(
+    lhs: &dyn Array,
+    rhs: &dyn Array,
+    broadcast: bool,
+    op: Operator,
+) -> Result<BooleanArray> {
+    let rhs_index = |i: usize| if broadcast { 0 } else { i };
+    let validity = if broadcast {
+        lhs.validity().cloned()
+    } else {
+        combine_validities(lhs.validity(), rhs.validity())
+    };
+
+    let values = match op {
+        Operator::Eq | Operator::Neq => {
+            let eq = build_eq(lhs, rhs)?;
+            let is_eq = op == Operator::Eq;
+            (0..lhs.len())
+                .map(|i| eq(i, rhs_index(i)) == is_eq)
+                .collect::<Bitmap>()
+        }
+        Operator::EqAndValidity | Operator::NeqAndValidity => {
+            let eq = build_eq(lhs, rhs)?;
+            let is_eq = op == Operator::EqAndValidity;
+            let values = (0..lhs.len())
+                .map(|i| eq(i, rhs_index(i)) == is_eq)
+                .collect::<Bitmap>();
+            return Ok(BooleanArray::new(DataType::Boolean, values, None));
+        }
+        Operator::Lt | Operator::LtEq | Operator::Gt | Operator::GtEq => {
+            let cmp = build_compare(lhs, rhs)?;
+            let predicate = match op {
+                Operator::Lt => Ordering::is_lt,
+                Operator::LtEq => Ordering::is_le,
+                Operator::Gt => Ordering::is_gt,
+                _ => Ordering::is_ge,
+            };
+            // null slots are not compared: their keys or values may be undefined
+            let is_valid = |i: usize| validity.as_ref().map(|x| x.get_bit(i)).unwrap_or(true);
+            (0..lhs.len())
+                .map(|i| is_valid(i) && predicate(cmp(i, rhs_index(i))))
+                .collect::<Bitmap>()
+        }
+    };
+    Ok(BooleanArray::new(DataType::Boolean, values, validity))
+}
@@ -30,7 +30,7 @@
 //!
 //! let array1: &dyn Array = &PrimitiveArray::<f64>::from(&[Some(10.0), None, Some(20.0)]);
 //! let array2: &dyn Array = &PrimitiveArray::<f64>::from(&[Some(10.0), None, Some(10.0)]);
-//! let result = eq(array1, array2);
+//! let result = eq(array1, array2).unwrap();
 //! assert_eq!(result, BooleanArray::from([Some(true), None, Some(false)]));
 //! ```
 //!
@@ -45,7 +45,8 @@
 //! ```
 
 use crate::array::*;
-use crate::datatypes::{DataType, IntervalUnit};
+use crate::datatypes::{DataType, IntervalUnit, PrimitiveType};
+use crate::error::{Error, Result};
 use crate::scalar::*;
 
 pub mod binary;
@@ -53,7 +54,9 @@ pub mod boolean;
 pub mod primitive;
 pub mod utf8;
 
+mod generic;
 mod simd;
+use generic::Operator;
 pub use simd::{Simd8, Simd8Lanes, Simd8PartialEq, Simd8PartialOrd};
 
 use super::take::take_boolean;
@@ -77,13 +80,12 @@ macro_rules! match_eq_ord {(
         Int64 => __with_ty__! { i64 },
         Int128 => __with_ty__! { i128 },
         Int256 => __with_ty__! { i256 },
-        DaysMs => todo!(),
-        MonthDayNano => todo!(),
+        // ordering of these types is not supported by the kernels; see `is_ord_primitive`
+        DaysMs | MonthDayNano | Float16 => unreachable!(),
         UInt8 => __with_ty__! { u8 },
         UInt16 => __with_ty__! { u16 },
         UInt32 => __with_ty__! { u32 },
         UInt64 => __with_ty__! { u64 },
-        Float16 => todo!(),
         Float32 => __with_ty__! { f32 },
         Float64 => __with_ty__! { f64 },
     }
@@ -114,23 +116,48 @@ macro_rules! match_eq {(
     }
 })}
 
+/// Whether the kernels of `match_eq` support `primitive`.
+fn is_eq_primitive(_: PrimitiveType) -> bool {
+    true
+}
+
+/// Whether the kernels of `match_eq_ord` support `primitive`.
+fn is_ord_primitive(primitive: PrimitiveType) -> bool {
+    !matches!(
+        primitive,
+        PrimitiveType::DaysMs | PrimitiveType::MonthDayNano | PrimitiveType::Float16
+    )
+}
+
+fn check_data_types(lhs: &DataType, rhs: &DataType) -> Result<()> {
+    if lhs.to_logical_type() != rhs.to_logical_type() {
+        return Err(Error::InvalidArgumentError(format!(
+            "Comparison requires both sides to have the same logical type ({lhs:?} != {rhs:?})"
+        )));
+    }
+    Ok(())
+}
+
 macro_rules! compare {
-    ($lhs:expr, $rhs:expr, $op:tt, $p:tt) => {{
+    ($lhs:expr, $rhs:expr, $op:tt, $p:tt, $is_p:expr, $generic:expr) => {{
         let lhs = $lhs;
         let rhs = $rhs;
-        assert_eq!(
-            lhs.data_type().to_logical_type(),
-            rhs.data_type().to_logical_type()
-        );
+        check_data_types(lhs.data_type(), rhs.data_type())?;
+        if lhs.len() != rhs.len() {
+            return Err(Error::InvalidArgumentError(
+                "Comparison requires both arrays to have the same length".to_string(),
+            ));
+        }
 
         use crate::datatypes::PhysicalType::*;
-        match lhs.data_type().to_physical_type() {
+        Ok(match lhs.data_type().to_physical_type() {
+            Null => BooleanArray::new_null(DataType::Boolean, lhs.len()),
             Boolean => {
                 let lhs = lhs.as_any().downcast_ref().unwrap();
                 let rhs = rhs.as_any().downcast_ref().unwrap();
                 boolean::$op(lhs, rhs)
             }
-            Primitive(primitive) => $p!(primitive, |$T| {
+            Primitive(primitive) if $is_p(primitive) => $p!(primitive, |$T| {
                 let lhs = lhs.as_any().downcast_ref().unwrap();
                 let rhs = rhs.as_any().downcast_ref().unwrap();
                 primitive::$op::<$T>(lhs, rhs)
@@ -155,34 +182,38 @@ macro_rules! compare {
                 let rhs = rhs.as_any().downcast_ref().unwrap();
                 binary::$op::<i64>(lhs, rhs)
             }
-            _ => todo!(
-                "Comparison between {:?} are not yet supported",
-                lhs.data_type()
-            ),
-        }
+            _ => generic::compare(lhs, rhs, false, $generic)?,
+        })
     }};
 }
 
 /// `==` between two [`Array`]s.
 /// Use [`can_eq`] to check whether the operation is valid
-/// # Panic
-/// Panics iff either:
+/// # Errors
+/// This function errors iff either:
 /// * the arrays do not have have the same logical type
 /// * the arrays do not have the same length
 /// * the operation is not supported for the logical type
-pub fn eq(lhs: &dyn Array, rhs: &dyn Array) -> BooleanArray {
-    compare!(lhs, rhs, eq, match_eq)
+pub fn eq(lhs: &dyn Array, rhs: &dyn Array) -> Result<BooleanArray> {
+    compare!(lhs, rhs, eq, match_eq, is_eq_primitive, Operator::Eq)
 }
 
 /// `==` between two [`Array`]s and includes validities in comparison.
 /// Use [`can_eq`] to check whether the operation is valid
-/// # Panic
-/// Panics iff either:
+/// # Errors
+/// This function errors iff either:
 /// * the arrays do not have have the same logical type
 /// * the arrays do not have the same length
 /// * the operation is not supported for the logical type
-pub fn eq_and_validity(lhs: &dyn Array, rhs: &dyn Array) -> BooleanArray {
-    compare!(lhs, rhs, eq_and_validity, match_eq)
+pub fn eq_and_validity(lhs: &dyn Array, rhs: &dyn Array) -> Result<BooleanArray> {
+    compare!(
+        lhs,
+        rhs,
+        eq_and_validity,
+        match_eq,
+        is_eq_primitive,
+        Operator::EqAndValidity
+    )
 }
 
 /// Returns whether a [`DataType`] is comparable is supported by [`eq`].
@@ -192,24 +223,31 @@ pub fn can_eq(data_type: &DataType) -> bool {
 
 /// `!=` between two [`Array`]s.
 /// Use [`can_neq`] to check whether the operation is valid
-/// # Panic
-/// Panics iff either:
+/// # Errors
+/// This function errors iff either:
 /// * the arrays do not have have the same logical type
 /// * the arrays do not have the same length
 /// * the operation is not supported for the logical type
-pub fn neq(lhs: &dyn Array, rhs: &dyn Array) -> BooleanArray {
-    compare!(lhs, rhs, neq, match_eq)
+pub fn neq(lhs: &dyn Array, rhs: &dyn Array) -> Result<BooleanArray> {
+    compare!(lhs, rhs, neq, match_eq, is_eq_primitive, Operator::Neq)
 }
 
 /// `!=` between two [`Array`]s and includes validities in comparison.
 /// Use [`can_neq`] to check whether the operation is valid
-/// # Panic
-/// Panics iff either:
+/// # Errors
+/// This function errors iff either:
 /// * the arrays do not have have the same logical type
 /// * the arrays do not have the same length
 /// * the operation is not supported for the logical type
-pub fn neq_and_validity(lhs: &dyn Array, rhs: &dyn Array) -> BooleanArray {
-    compare!(lhs, rhs, neq_and_validity, match_eq)
+pub fn neq_and_validity(lhs: &dyn Array, rhs: &dyn Array) -> Result<BooleanArray> {
+    compare!(
+        lhs,
+        rhs,
+        neq_and_validity,
+        match_eq,
+        is_eq_primitive,
+        Operator::NeqAndValidity
+    )
 }
 
 /// Returns whether a [`DataType`] is comparable is supported by [`neq`].
@@ -219,13 +257,13 @@ pub fn can_neq(data_type: &DataType) -> bool {
 
 /// `<` between two [`Array`]s.
 /// Use [`can_lt`] to check whether the operation is valid
-/// # Panic
-/// Panics iff either:
+/// # Errors
+/// This function errors iff either:
 /// * the arrays do not have have the same logical type
 /// * the arrays do not have the same length
 /// * the operation is not supported for the logical type
-pub fn lt(lhs: &dyn Array, rhs: &dyn Array) -> BooleanArray {
-    compare!(lhs, rhs, lt, match_eq_ord)
+pub fn lt(lhs: &dyn Array, rhs: &dyn Array) -> Result<BooleanArray> {
+    compare!(lhs, rhs, lt, match_eq_ord, is_ord_primitive, Operator::Lt)
 }
 
 /// Returns whether a [`DataType`] is comparable is supported by [`lt`].
@@ -235,13 +273,20 @@ pub fn can_lt(data_type: &DataType) -> bool {
 
 /// `<=` between two [`Array`]s.
 /// Use [`can_lt_eq`] to check whether the operation is valid
-/// # Panic
-/// Panics iff either:
+/// # Errors
+/// This function errors iff either:
 /// * the arrays do not have have the same logical type
 /// * the arrays do not have the same length
 /// * the operation is not supported for the logical type
-pub fn lt_eq(lhs: &dyn Array, rhs: &dyn Array) -> BooleanArray {
-    compare!(lhs, rhs, lt_eq, match_eq_ord)
+pub fn lt_eq(lhs: &dyn Array, rhs: &dyn Array) -> Result<BooleanArray> {
+    compare!(
+        lhs,
+        rhs,
+        lt_eq,
+        match_eq_ord,
+        is_ord_primitive,
+        Operator::LtEq
+    )
 }
 
 /// Returns whether a [`DataType`] is comparable is supported by [`lt`].
@@ -251,13 +296,13 @@ pub fn can_lt_eq(data_type: &DataType) -> bool {
 
 /// `>` between two [`Array`]s.
 /// Use [`can_gt`] to check whether the operation is valid
-/// # Panic
-/// Panics iff either:
+/// # Errors
+/// This function errors iff either:
 /// * the arrays do not have have the same logical type
 /// * the arrays do not have the same length
 /// * the operation is not supported for the logical type
-pub fn gt(lhs: &dyn Array, rhs: &dyn Array) -> BooleanArray {
-    compare!(lhs, rhs, gt, match_eq_ord)
+pub fn gt(lhs: &dyn Array, rhs: &dyn Array) -> Result<BooleanArray> {
+    compare!(lhs, rhs, gt, match_eq_ord, is_ord_primitive, Operator::Gt)
 }
 
 /// Returns whether a [`DataType`] is comparable is supported by [`gt`].
@@ -267,13 +312,20 @@ pub fn can_gt(data_type: &DataType) -> bool {
 
 /// `>=` between two [`Array`]s.
 /// Use [`can_gt_eq`] to check whether the operation is valid
-/// # Panic
-/// Panics iff either:
+/// # Errors
+/// This function errors iff either:
 /// * the arrays do not have have the same logical type
 /// * the arrays do not have the same length
 /// * the operation is not supported for the logical type
-pub fn gt_eq(lhs: &dyn Array, rhs: &dyn Array) -> BooleanArray {
-    compare!(lhs, rhs, gt_eq, match_eq_ord)
+pub fn gt_eq(lhs: &dyn Array, rhs: &dyn Array) -> Result<BooleanArray> {
+    compare!(
+        lhs,
+        rhs,
+        gt_eq,
+        match_eq_ord,
+        is_ord_primitive,
+        Operator::GtEq
+    )
 }
 
 /// Returns whether a [`DataType`] is comparable is supported by [`gt_eq`].
@@ -282,26 +334,23 @@ pub fn can_gt_eq(data_type: &DataType) -> bool {
 }
 
 macro_rules! compare_scalar {
-    ($lhs:expr, $rhs:expr, $op:tt, $p:tt) => {{
+    ($lhs:expr, $rhs:expr, $op:tt, $p:tt, $is_p:expr, $generic:expr) => {{
         let lhs = $lhs;
         let rhs = $rhs;
-        assert_eq!(
-            lhs.data_type().to_logical_type(),
-            rhs.data_type().to_logical_type()
-        );
+        check_data_types(lhs.data_type(), rhs.data_type())?;
         if !rhs.is_valid() {
-            return BooleanArray::new_null(DataType::Boolean, lhs.len());
+            return Ok(BooleanArray::new_null(DataType::Boolean, lhs.len()));
         }
 
         use crate::datatypes::PhysicalType::*;
-        match lhs.data_type().to_physical_type() {
+        Ok(match lhs.data_type().to_physical_type() {
             Boolean => {
                 let lhs = lhs.as_any().downcast_ref().unwrap();
                 let rhs = rhs.as_any().downcast_ref::<BooleanScalar>().unwrap();
                 // validity checked above
                 boolean::$op(lhs, rhs.value().unwrap())
             }
-            Primitive(primitive) => $p!(primitive, |$T| {
+            Primitive(primitive) if $is_p(primitive) => $p!(primitive, |$T| {
                 let lhs = lhs.as_any().downcast_ref().unwrap();
                 let rhs = rhs.as_any().downcast_ref::<PrimitiveScalar<$T>>().unwrap();
                 primitive::$op::<$T>(lhs, rhs.value().unwrap())
@@ -329,34 +378,46 @@ macro_rules! compare_scalar {
             Dictionary(key_type) => {
                 match_integer_type!(key_type, |$T| {
                     let lhs = lhs.as_any().downcast_ref::<DictionaryArray<$T>>().unwrap();
-                    let values = $op(lhs.values().as_ref(), rhs);
+                    let rhs = rhs.as_any().downcast_ref::<DictionaryScalar<$T>>().unwrap();
+                    // validity checked above
+                    let values = $op(lhs.values().as_ref(), rhs.value().unwrap().as_ref())?;
 
                     take_boolean(&values, lhs.keys())
                 })
             }
-            _ => todo!("Comparisons of {:?} are not yet supported", lhs.data_type()),
-        }
+            _ => {
+                let rhs = to_array(rhs, 1)?;
+                generic::compare(lhs, rhs.as_ref(), true, $generic)?
+            }
+        })
     }};
 }
 
 /// `==` between an [`Array`] and a [`Scalar`].
 /// Use [`can_eq_scalar`] to check whether the operation is valid
-/// # Panic
-/// Panics iff either:
+/// # Errors
+/// This function errors iff either:
 /// * they do not have have the same logical type
 /// * the operation is not supported for the logical type
-pub fn eq_scalar(lhs: &dyn Array, rhs: &dyn Scalar) -> BooleanArray {
-    compare_scalar!(lhs, rhs, eq_scalar, match_eq)
+pub fn eq_scalar(lhs: &dyn Array, rhs: &dyn Scalar) -> Result<BooleanArray> {
+    compare_scalar!(lhs, rhs, eq_scalar, match_eq, is_eq_primitive, Operator::Eq)
 }
 
 /// `==` between an [`Array`] and a [`Scalar`] and includes validities in comparison.
 /// Use [`can_eq_scalar`] to check whether the operation is valid
-/// # Panic
-/// Panics iff either:
+/// # Errors
+/// This function errors iff either:
 /// * they do not have have the same logical type
 /// * the operation is not supported for the logical type
-pub fn eq_scalar_and_validity(lhs: &dyn Array, rhs: &dyn Scalar) -> BooleanArray {
-    compare_scalar!(lhs, rhs, eq_scalar_and_validity, match_eq)
+pub fn eq_scalar_and_validity(lhs: &dyn Array, rhs: &dyn Scalar) -> Result<BooleanArray> {
+    compare_scalar!(
+        lhs,
+        rhs,
+        eq_scalar_and_validity,
+        match_eq,
+        is_eq_primitive,
+        Operator::EqAndValidity
+    )
 }
 
 /// Returns whether a [`DataType`] is supported by [`eq_scalar`].
@@ -366,22 +427,36 @@ pub fn can_eq_scalar(data_type: &DataType) -> bool {
 
 /// `!=` between an [`Array`] and a [`Scalar`].
 /// Use [`can_neq_scalar`] to check whether the operation is valid
-/// # Panic
-/// Panics iff either:
+/// # Errors
+/// This function errors iff either:
 /// * they do not have have the same logical type
 /// * the operation is not supported for the logical type
-pub fn neq_scalar(lhs: &dyn Array, rhs: &dyn Scalar) -> BooleanArray {
-    compare_scalar!(lhs, rhs, neq_scalar, match_eq)
+pub fn neq_scalar(lhs: &dyn Array, rhs: &dyn Scalar) -> Result<BooleanArray> {
+    compare_scalar!(
+        lhs,
+        rhs,
+        neq_scalar,
+        match_eq,
+        is_eq_primitive,
+        Operator::Neq
+    )
 }
 
 /// `!=` between an [`Array`] and a [`Scalar`] and includes validities in comparison.
 /// Use [`can_neq_scalar`] to check whether the operation is valid
-/// # Panic
-/// Panics iff either:
+/// # Errors
+/// This function errors iff either:
 /// * they do not have have the same logical type
 /// * the operation is not supported for the logical type
-pub fn neq_scalar_and_validity(lhs: &dyn Array, rhs: &dyn Scalar) -> BooleanArray {
-    compare_scalar!(lhs, rhs, neq_scalar_and_validity, match_eq)
+pub fn neq_scalar_and_validity(lhs: &dyn Array, rhs: &dyn Scalar) -> Result<BooleanArray> {
+    compare_scalar!(
+        lhs,
+        rhs,
+        neq_scalar_and_validity,
+        match_eq,
+        is_eq_primitive,
+        Operator::NeqAndValidity
+    )
 }
 
 /// Returns whether a [`DataType`] is supported by [`neq_scalar`].
@@ -391,12 +466,19 @@ pub fn can_neq_scalar(data_type: &DataType) -> bool {
 
 /// `<` between an [`Array`] and a [`Scalar`].
 /// Use [`can_lt_scalar`] to check whether the operation is valid
-/// # Panic
-/// Panics iff either:
+/// # Errors
+/// This function errors iff either:
 /// * they do not have have the same logical type
 /// * the operation is not supported for the logical type
-pub fn lt_scalar(lhs: &dyn Array, rhs: &dyn Scalar) -> BooleanArray {
-    compare_scalar!(lhs, rhs, lt_scalar, match_eq_ord)
+pub fn lt_scalar(lhs: &dyn Array, rhs: &dyn Scalar) -> Result<BooleanArray> {
+    compare_scalar!(
+        lhs,
+        rhs,
+        lt_scalar,
+        match_eq_ord,
+        is_ord_primitive,
+        Operator::Lt
+    )
 }
 
 /// Returns whether a [`DataType`] is supported by [`lt_scalar`].
@@ -406,12 +488,19 @@ pub fn can_lt_scalar(data_type: &DataType) -> bool {
 
 /// `<=` between an [`Array`] and a [`Scalar`].
 /// Use [`can_lt_eq_scalar`] to check whether the operation is valid
-/// # Panic
-/// Panics iff either:
+/// # Errors
+/// This function errors iff either:
 /// * they do not have have the same logical type
 /// * the operation is not supported for the logical type
-pub fn lt_eq_scalar(lhs: &dyn Array, rhs: &dyn Scalar) -> BooleanArray {
-    compare_scalar!(lhs, rhs, lt_eq_scalar, match_eq_ord)
+pub fn lt_eq_scalar(lhs: &dyn Array, rhs: &dyn Scalar) -> Result<BooleanArray> {
+    compare_scalar!(
+        lhs,
+        rhs,
+        lt_eq_scalar,
+        match_eq_ord,
+        is_ord_primitive,
+        Operator::LtEq
+    )
 }
 
 /// Returns whether a [`DataType`] is supported by [`lt_eq_scalar`].
@@ -421,12 +510,19 @@ pub fn can_lt_eq_scalar(data_type: &DataType) -> bool {
 
 /// `>` between an [`Array`] and a [`Scalar`].
 /// Use [`can_gt_scalar`] to check whether the operation is valid
-/// # Panic
-/// Panics iff either:
+/// # Errors
+/// This function errors iff either:
 /// * they do not have have the same logical type
 /// * the operation is not supported for the logical type
-pub fn gt_scalar(lhs: &dyn Array, rhs: &dyn Scalar) -> BooleanArray {
-    compare_scalar!(lhs, rhs, gt_scalar, match_eq_ord)
+pub fn gt_scalar(lhs: &dyn Array, rhs: &dyn Scalar) -> Result<BooleanArray> {
+    compare_scalar!(
+        lhs,
+        rhs,
+        gt_scalar,
+        match_eq_ord,
+        is_ord_primitive,
+        Operator::Gt
+    )
 }
 
 /// Returns whether a [`DataType`] is supported by [`gt_scalar`].
@@ -436,12 +532,19 @@ pub fn can_gt_scalar(data_type: &DataType) -> bool {
 
 /// `>=` between an [`Array`] and a [`Scalar`].
 /// Use [`can_gt_eq_scalar`] to check whether the operation is valid
-/// # Panic
-/// Panics iff either:
+/// # Errors
+/// This function errors iff either:
 /// * they do not have have the same logical type
 /// * the operation is not supported for the logical type
-pub fn gt_eq_scalar(lhs: &dyn Array, rhs: &dyn Scalar) -> BooleanArray {
-    compare_scalar!(lhs, rhs, gt_eq_scalar, match_eq_ord)
+pub fn gt_eq_scalar(lhs: &dyn Array, rhs: &dyn Scalar) -> Result<BooleanArray> {
+    compare_scalar!(
+        lhs,
+        rhs,
+        gt_eq_scalar,
+        match_eq_ord,
+        is_ord_primitive,
+        Operator::GtEq
+    )
 }
 
 /// Returns whether a [`DataType`] is supported by [`gt_eq_scalar`].
@@ -449,6 +552,36 @@ pub fn can_gt_eq_scalar(data_type: &DataType) -> bool {
     can_partial_eq_and_ord_scalar(data_type)
 }
 
+/// Returns whether each value of `array` is within `low` and `high` (inclusive), i.e.
+/// `low <= array && array <= high`.
+/// Use [`can_between`] to check whether the operation is valid
+/// # Errors
+/// This function errors iff either:
+/// * the bounds do not have have the same logical type as `array`
+/// * the operation is not supported for the logical type
+/// # Example
+/// ```
+/// use re_arrow2::array::{BooleanArray, Int32Array};
+/// use re_arrow2::compute::comparison::between;
+/// use re_arrow2::scalar::PrimitiveScalar;
+///
+/// let array = Int32Array::from(&[Some(1), Some(2), None, Some(4)]);
+/// let low = PrimitiveScalar::from(Some(2i32));
+/// let high = PrimitiveScalar::from(Some(4i32));
+/// let result = between(&array, &low, &high).unwrap();
+/// assert_eq!(result, BooleanArray::from(&[Some(false), Some(true), None, Some(true)]));
+/// ```
+pub fn between(array: &dyn Array, low: &dyn Scalar, high: &dyn Scalar) -> Result<BooleanArray> {
+    let lower = gt_eq_scalar(array, low)?;
+    let upper = lt_eq_scalar(array, high)?;
+    Ok(super::boolean::and(&lower, &upper))
+}
+
+/// Returns whether a [`DataType`] is supported by [`between`].
+pub fn can_between(data_type: &DataType) -> bool {
+    can_partial_eq_and_ord_scalar(data_type)
+}
+
 // The list of operations currently supported.
 fn can_partial_eq_and_ord_scalar(data_type: &DataType) -> bool {
     if let DataType::Dictionary(_, values, _) = data_type.to_logical_type() {
@@ -459,9 +592,21 @@ fn can_partial_eq_and_ord_scalar(data_type: &DataType) -> bool {
 
 // The list of operations currently supported.
 fn can_partial_eq_and_ord(data_type: &DataType) -> bool {
+    // nested types and dictionaries are ordered by their values via `build_compare`
+    match data_type.to_logical_type() {
+        DataType::Dictionary(_, values, _) => return can_ord_values(values.as_ref()),
+        DataType::List(field) | DataType::LargeList(field) | DataType::FixedSizeList(field, _) => {
+            return can_ord_values(field.data_type())
+        }
+        DataType::Struct(fields) => {
+            return fields.iter().all(|field| can_ord_values(field.data_type()))
+        }
+        _ => {}
+    }
     matches!(
         data_type,
-        DataType::Boolean
+        DataType::Null
+            | DataType::Boolean
             | DataType::Int8
             | DataType::Int16
             | DataType::Int32
@@ -477,35 +622,32 @@ fn can_partial_eq_and_ord(data_type: &DataType) -> bool {
             | DataType::UInt16
             | DataType::UInt32
             | DataType::UInt64
+            | DataType::Float16
             | DataType::Float32
             | DataType::Float64
             | DataType::Utf8
             | DataType::LargeUtf8
             | DataType::Decimal(_, _)
+            | DataType::Decimal256(_, _)
             | DataType::Binary
             | DataType::LargeBinary
+            | DataType::FixedSizeBinary(_)
     )
 }
 
+// Whether `build_compare` supports `data_type`.
+fn can_ord_values(data_type: &DataType) -> bool {
+    data_type != &DataType::Null && can_partial_eq_and_ord(data_type)
+}
+
 // The list of operations currently supported.
 fn can_partial_eq(data_type: &DataType) -> bool {
-    can_partial_eq_and_ord(data_type)
-        || matches!(
-            data_type.to_logical_type(),
-            DataType::Float16
-                | DataType::Interval(IntervalUnit::DayTime)
-                | DataType::Interval(IntervalUnit::MonthDayNano)
-        )
+    generic::can_eq(data_type)
 }
 
 // The list of operations currently supported.
 fn can_partial_eq_scalar(data_type: &DataType) -> bool {
-    can_partial_eq_and_ord_scalar(data_type)
-        || matches!(
-            data_type.to_logical_type(),
-            DataType::Interval(IntervalUnit::DayTime)
-                | DataType::Interval(IntervalUnit::MonthDayNano)
-        )
+    generic::can_eq(data_type)
 }
 
 /// Utility for low level end users that implement their own comparison functions
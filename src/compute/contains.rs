@@ -1,12 +1,19 @@
-//! Declares the [`contains`] operator
+//! Declares the [`contains`] and set membership ([`is_in`], [`is_in_sql`]) operators
+
+use std::hash::Hash;
+
+use hashbrown::HashSet;
 
 use crate::{
-    array::{Array, BinaryArray, BooleanArray, ListArray, PrimitiveArray, Utf8Array},
-    bitmap::Bitmap,
-    datatypes::DataType,
+    array::{
+        Array, BinaryArray, BooleanArray, DictionaryArray, DictionaryKey, FixedSizeBinaryArray,
+        ListArray, PrimitiveArray, Utf8Array,
+    },
+    bitmap::{Bitmap, MutableBitmap},
+    datatypes::{DataType, PhysicalType, PrimitiveType},
     error::{Error, Result},
     offset::Offset,
-    types::NativeType,
+    types::{f16, NativeType},
 };
 
 use super::utils::combine_validities;
@@ -196,3 +203,213 @@ pub fn contains(list: &dyn Array, values: &dyn Array) -> Result<BooleanArray> {
         ))),
     }
 }
+
+/// The valid values of a set of values, unwrapping dictionaries.
+struct Set<'a> {
+    values: &'a dyn Array,
+    /// the valid slots of `values` in the set
+    slots: Vec<usize>,
+    /// whether the set contains a null
+    has_null: bool,
+}
+
+impl<'a> Set<'a> {
+    fn new(values: &'a dyn Array) -> Self {
+        if let DataType::Dictionary(key_type, _, _) = values.data_type().to_logical_type() {
+            return match_integer_type!(key_type, |$T| {
+                let values = values.as_any().downcast_ref::<DictionaryArray<$T>>().unwrap();
+                let dictionary = values.values().as_ref();
+                let keys = values.keys_iter().collect::<Vec<_>>();
+                let slots = keys
+                    .iter()
+                    .flatten()
+                    .copied()
+                    .filter(|key| dictionary.is_valid(*key))
+                    .collect::<Vec<_>>();
+                Self {
+                    values: dictionary,
+                    has_null: slots.len() < keys.len(),
+                    slots,
+                }
+            });
+        }
+        Self {
+            values,
+            slots: (0..values.len()).filter(|i| values.is_valid(*i)).collect(),
+            has_null: values.null_count() > 0,
+        }
+    }
+}
+
+/// Returns whether the value of each slot of `array` is in `set`, ignoring validity.
+fn membership_by<K, F, G>(length: usize, set: &[usize], set_key: F, key: G) -> Vec<bool>
+where
+    K: Hash + Eq,
+    F: Fn(usize) -> K,
+    G: Fn(usize) -> K,
+{
+    let set = set.iter().map(|i| set_key(*i)).collect::<HashSet<_>>();
+    (0..length).map(|i| set.contains(&key(i))).collect()
+}
+
+macro_rules! membership {
+    ($ty:ty, $array:expr, $set:expr, $value:ident => $key:expr) => {{
+        let array = $array.as_any().downcast_ref::<$ty>().unwrap();
+        let set_array = $set.values.as_any().downcast_ref::<$ty>().unwrap();
+        membership_by(
+            array.len(),
+            &$set.slots,
+            |i| {
+                let $value = set_array.value(i);
+                $key
+            },
+            |i| {
+                let $value = array.value(i);
+                $key
+            },
+        )
+    }};
+}
+
+/// Returns the bits of a float such that `0.0 == -0.0` and all `NaN`s are equal.
+#[inline]
+fn float_key(value: f64) -> u64 {
+    if value.is_nan() {
+        f64::NAN.to_bits()
+    } else {
+        (value + 0.0).to_bits()
+    }
+}
+
+fn membership(array: &dyn Array, set: &Set) -> Result<Vec<bool>> {
+    if array.data_type() != set.values.data_type() {
+        return Err(Error::InvalidArgumentError(format!(
+            "is_in requires values of the same logical type as the array, got {:?} and {:?}",
+            array.data_type(),
+            set.values.data_type()
+        )));
+    }
+    use PhysicalType::*;
+    Ok(match array.data_type().to_physical_type() {
+        Boolean => membership!(BooleanArray, array, set, x => x),
+        Primitive(PrimitiveType::Float16) => {
+            membership!(PrimitiveArray<f16>, array, set, x => float_key(x.to_f32() as f64))
+        }
+        Primitive(PrimitiveType::Float32) => {
+            membership!(PrimitiveArray<f32>, array, set, x => float_key(x as f64))
+        }
+        Primitive(PrimitiveType::Float64) => {
+            membership!(PrimitiveArray<f64>, array, set, x => float_key(x))
+        }
+        Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
+            membership!(PrimitiveArray<$T>, array, set, x => x.to_le_bytes())
+        }),
+        Utf8 => membership!(Utf8Array<i32>, array, set, x => x),
+        LargeUtf8 => membership!(Utf8Array<i64>, array, set, x => x),
+        Binary => membership!(BinaryArray<i32>, array, set, x => x),
+        LargeBinary => membership!(BinaryArray<i64>, array, set, x => x),
+        FixedSizeBinary => membership!(FixedSizeBinaryArray, array, set, x => x),
+        other => {
+            return Err(Error::NotYetImplemented(format!(
+                "is_in is not supported for arrays of physical type {other:?}"
+            )))
+        }
+    })
+}
+
+fn is_in_impl(array: &dyn Array, values: &dyn Array, sql_nulls: bool) -> Result<BooleanArray> {
+    let set = Set::new(values);
+
+    // dictionaries are tested on their values
+    let (is_in, slots): (Vec<bool>, Option<Vec<Option<usize>>>) =
+        if let DataType::Dictionary(key_type, _, _) = array.data_type().to_logical_type() {
+            match_integer_type!(key_type, |$T| {
+                let array = array.as_any().downcast_ref::<DictionaryArray<$T>>().unwrap();
+                let dictionary = array.values().as_ref();
+                let slots = array
+                    .keys_iter()
+                    .map(|key| key.filter(|key| dictionary.is_valid(*key)))
+                    .collect();
+                (membership(dictionary, &set)?, Some(slots))
+            })
+        } else {
+            let slots = array.validity().map(|validity| {
+                validity
+                    .iter()
+                    .enumerate()
+                    .map(|(i, v)| v.then_some(i))
+                    .collect()
+            });
+            (membership(array, &set)?, slots)
+        };
+
+    let length = array.len();
+    let mut values = MutableBitmap::with_capacity(length);
+    let mut validity = MutableBitmap::with_capacity(length);
+    for i in 0..length {
+        let slot = match &slots {
+            Some(slots) => slots[i],
+            None => Some(i),
+        };
+        let (value, is_valid) = match (slot, sql_nulls) {
+            (Some(slot), _) if is_in[slot] => (true, true),
+            // `x IN (..., NULL)` is null unless `x` is in the set
+            (Some(_), true) => (false, !set.has_null),
+            (Some(_), false) => (false, true),
+            (None, true) => (false, false),
+            (None, false) => (set.has_null, true),
+        };
+        values.push(value);
+        validity.push(is_valid);
+    }
+    Ok(BooleanArray::new(
+        DataType::Boolean,
+        values.into(),
+        validity.into(),
+    ))
+}
+
+/// Returns whether each value of `array` is one of `values`, backed by a hash set of `values`.
+///
+/// Nulls are treated as values: a null slot of `array` is in `values` iff `values` contains a
+/// null, and the result has no nulls. Use [`is_in_sql`] for SQL's semantics. Floats are equal
+/// when they compare equal (`0.0 == -0.0`), and `NaN`s are equal to each other.
+///
+/// Supported data types are booleans, primitives (including decimals), `[Large]Utf8`,
+/// `[Large]Binary` and `FixedSizeBinary`, and dictionaries of them (for both arguments).
+/// # Errors
+/// This function errors iff the (dictionary values') data types of `array` and `values` differ,
+/// or the data type is not supported.
+/// # Example
+/// ```
+/// use re_arrow2::array::{BooleanArray, Utf8Array};
+/// use re_arrow2::compute::contains::is_in;
+///
+/// let array = Utf8Array::<i32>::from([Some("a"), Some("b"), None]);
+/// let values = Utf8Array::<i32>::from_slice(["b", "c"]);
+/// let result = is_in(&array, &values).unwrap();
+/// assert_eq!(result, BooleanArray::from_slice([false, true, false]));
+/// ```
+pub fn is_in(array: &dyn Array, values: &dyn Array) -> Result<BooleanArray> {
+    is_in_impl(array, values, false)
+}
+
+/// Returns whether each value of `array` is one of `values`, with the semantics of SQL's
+/// `x IN (...)`: the result is null when `x` is null, or when `x` is not in `values` and `values`
+/// contains a null. See [`is_in`] for the supported data types.
+/// # Errors
+/// This function errors iff the (dictionary values') data types of `array` and `values` differ,
+/// or the data type is not supported.
+/// # Example
+/// ```
+/// use re_arrow2::array::{BooleanArray, Int32Array};
+/// use re_arrow2::compute::contains::is_in_sql;
+///
+/// let array = Int32Array::from(&[Some(1), Some(2), None]);
+/// let values = Int32Array::from(&[Some(1), None]);
+/// let result = is_in_sql(&array, &values).unwrap();
+/// assert_eq!(result, BooleanArray::from(&[Some(true), None, None]));
+/// ```
+pub fn is_in_sql(array: &dyn Array, values: &dyn Array) -> Result<BooleanArray> {
+    is_in_impl(array, values, true)
+}
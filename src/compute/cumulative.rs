@@ -0,0 +1,381 @@
+//! Contains cumulative (scan) operators: [`cumsum`], [`cumprod`], [`cummin`], [`cummax`] and
+//! [`cumcount`], as well as [`cumsum_decimal`] and [`cumprod_decimal`] for decimal arrays.
+//! Only [`DataType::Decimal`] is supported; sums and products of
+//! [`DataType::Decimal256`] arrays are not.
+//!
+//! All operators accept [`CumulativeOptions`] to scan in reverse and to reset the running
+//! aggregate at partition boundaries, e.g. the ranges returned by
+//! [`lexicographical_partition_ranges`](crate::compute::partition::lexicographical_partition_ranges).
+use std::ops::Range;
+
+use ethnum::I256;
+
+use crate::{
+    array::{Array, PrimitiveArray},
+    bitmap::MutableBitmap,
+    datatypes::DataType,
+    error::{Error, Result},
+    types::NativeType,
+};
+
+/// A [`NativeType`] that can be summed and multiplied by [`cumsum`] and [`cumprod`].
+pub trait CumulativeNative: NativeType {
+    /// Returns `self + rhs`, or `None` if it overflows.
+    fn add_checked(self, rhs: Self) -> Option<Self>;
+
+    /// Returns `self * rhs`, or `None` if it overflows.
+    fn mul_checked(self, rhs: Self) -> Option<Self>;
+}
+
+macro_rules! impl_cumulative_native_integer {
+    ($($type:ty),*) => {$(
+        impl CumulativeNative for $type {
+            #[inline]
+            fn add_checked(self, rhs: Self) -> Option<Self> {
+                self.checked_add(rhs)
+            }
+
+            #[inline]
+            fn mul_checked(self, rhs: Self) -> Option<Self> {
+                self.checked_mul(rhs)
+            }
+        }
+    )*};
+}
+
+impl_cumulative_native_integer!(i8, i16, i32, i64, i128, u8, u16, u32, u64);
+
+macro_rules! impl_cumulative_native_float {
+    ($($type:ty),*) => {$(
+        impl CumulativeNative for $type {
+            #[inline]
+            fn add_checked(self, rhs: Self) -> Option<Self> {
+                Some(self + rhs)
+            }
+
+            #[inline]
+            fn mul_checked(self, rhs: Self) -> Option<Self> {
+                Some(self * rhs)
+            }
+        }
+    )*};
+}
+
+impl_cumulative_native_float!(f32, f64);
+
+/// How null slots affect a cumulative operator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CumulativeNulls {
+    /// Null slots are null and are ignored by the running aggregate.
+    #[default]
+    Skip,
+    /// Null slots and all slots after them (within the same partition) are null.
+    Propagate,
+}
+
+/// Options of cumulative operators.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct CumulativeOptions<'a> {
+    /// Whether to scan from the last slot to the first slot
+    pub reverse: bool,
+    /// Consecutive ranges covering the array at whose start the running aggregate is reset.
+    /// `None` scans the whole array as a single partition.
+    pub partitions: Option<&'a [Range<usize>]>,
+    /// How null slots are handled
+    pub nulls: CumulativeNulls,
+}
+
+fn check_partitions(partitions: &[Range<usize>], length: usize) -> Result<()> {
+    let mut end = 0;
+    for range in partitions {
+        if range.start != end || range.end < range.start {
+            return Err(Error::InvalidArgumentError(
+                "Cumulative partitions must be consecutive ranges starting at 0".to_string(),
+            ));
+        }
+        end = range.end;
+    }
+    if end != length {
+        return Err(Error::InvalidArgumentError(format!(
+            "Cumulative partitions must cover the array's length ({length}), but end at {end}"
+        )));
+    }
+    Ok(())
+}
+
+/// Scans `array`, where `init` computes the running aggregate of the first non-null value
+/// of each partition and `step` updates it with the following non-null values.
+fn scan<T, O, I, S>(
+    array: &PrimitiveArray<T>,
+    options: &CumulativeOptions,
+    data_type: DataType,
+    init: I,
+    mut step: S,
+) -> Result<PrimitiveArray<O>>
+where
+    T: NativeType,
+    O: NativeType,
+    I: Fn(T) -> O,
+    S: FnMut(O, T) -> Result<O>,
+{
+    let length = array.len();
+    let whole = 0..length;
+    let partitions = match options.partitions {
+        Some(partitions) => {
+            check_partitions(partitions, length)?;
+            partitions
+        }
+        None => std::slice::from_ref(&whole),
+    };
+
+    let mut values = vec![O::default(); length];
+    let mut validity = MutableBitmap::from_len_set(length);
+    for range in partitions {
+        let indices: Box<dyn Iterator<Item = usize>> = if options.reverse {
+            Box::new(range.clone().rev())
+        } else {
+            Box::new(range.clone())
+        };
+
+        let mut acc: Option<O> = None;
+        let mut propagated = false;
+        for i in indices {
+            if propagated || array.is_null(i) {
+                propagated |= options.nulls == CumulativeNulls::Propagate;
+                validity.set(i, false);
+                continue;
+            }
+            let value = array.value(i);
+            let new = match acc {
+                Some(acc) => step(acc, value)?,
+                None => init(value),
+            };
+            acc = Some(new);
+            values[i] = new;
+        }
+    }
+    Ok(PrimitiveArray::new(
+        data_type,
+        values.into(),
+        validity.into(),
+    ))
+}
+
+/// Errors if `data_type` is a decimal, which `name` does not support.
+fn check_not_decimal(data_type: &DataType, name: &str) -> Result<()> {
+    if let DataType::Decimal(_, _) = data_type.to_logical_type() {
+        return Err(Error::InvalidArgumentError(format!(
+            "{name} does not support decimal arrays, use {name}_decimal instead"
+        )));
+    }
+    Ok(())
+}
+
+/// Returns the precision's largest absolute value and the scale's factor of the decimal
+/// `data_type`.
+fn decimal_bounds(data_type: &DataType, name: &str) -> Result<(i128, i128)> {
+    let (precision, scale) = match data_type.to_logical_type() {
+        DataType::Decimal(precision, scale) => (*precision, *scale),
+        other => {
+            return Err(Error::InvalidArgumentError(format!(
+                "{name} expects a decimal array, got {other:?}"
+            )))
+        }
+    };
+    let max = 10i128
+        .checked_pow(precision as u32)
+        .map(|x| x - 1)
+        .unwrap_or(i128::MAX);
+    Ok((max, 10i128.pow(scale as u32)))
+}
+
+/// Returns the cumulative sum of `array`, of the same data type as `array`.
+/// Use [`cumsum_decimal`] for decimal arrays.
+/// # Errors
+/// This function errors iff `array` is a decimal array, `options.partitions` do not cover
+/// the array, or a sum of integers overflows.
+/// # Example
+/// ```
+/// use re_arrow2::array::Int32Array;
+/// use re_arrow2::compute::cumulative::{cumsum, CumulativeOptions};
+///
+/// let array = Int32Array::from(&[Some(1), None, Some(3), Some(4)]);
+/// let partitions = [0..2, 2..4];
+/// let options = CumulativeOptions {
+///     partitions: Some(&partitions),
+///     ..Default::default()
+/// };
+/// let result = cumsum(&array, &options).unwrap();
+/// assert_eq!(result, Int32Array::from(&[Some(1), None, Some(3), Some(7)]));
+/// ```
+pub fn cumsum<T>(
+    array: &PrimitiveArray<T>,
+    options: &CumulativeOptions,
+) -> Result<PrimitiveArray<T>>
+where
+    T: CumulativeNative,
+{
+    check_not_decimal(array.data_type(), "cumsum")?;
+    scan(
+        array,
+        options,
+        array.data_type().clone(),
+        |x| x,
+        |acc, x| acc.add_checked(x).ok_or(Error::Overflow),
+    )
+}
+
+/// Returns the cumulative sum of a [`DataType::Decimal`] array, with the same precision
+/// and scale as `array`.
+/// # Errors
+/// This function errors iff `array` is not a decimal array, `options.partitions` do not cover
+/// the array, or a sum does not fit the precision.
+pub fn cumsum_decimal(
+    array: &PrimitiveArray<i128>,
+    options: &CumulativeOptions,
+) -> Result<PrimitiveArray<i128>> {
+    let (max, _) = decimal_bounds(array.data_type(), "cumsum_decimal")?;
+
+    scan(
+        array,
+        options,
+        array.data_type().clone(),
+        |x| x,
+        |acc, x| {
+            acc.checked_add(x)
+                .filter(|x| x.abs() <= max)
+                .ok_or(Error::Overflow)
+        },
+    )
+}
+
+/// Returns the cumulative product of `array`, of the same data type as `array`.
+/// Use [`cumprod_decimal`] for decimal arrays.
+/// # Errors
+/// This function errors iff `array` is a decimal array, `options.partitions` do not cover
+/// the array, or a product of integers overflows.
+pub fn cumprod<T>(
+    array: &PrimitiveArray<T>,
+    options: &CumulativeOptions,
+) -> Result<PrimitiveArray<T>>
+where
+    T: CumulativeNative,
+{
+    check_not_decimal(array.data_type(), "cumprod")?;
+    scan(
+        array,
+        options,
+        array.data_type().clone(),
+        |x| x,
+        |acc, x| acc.mul_checked(x).ok_or(Error::Overflow),
+    )
+}
+
+/// Returns the cumulative product of a [`DataType::Decimal`] array, with the same precision
+/// and scale as `array`.
+/// # Errors
+/// This function errors iff `array` is not a decimal array, `options.partitions` do not cover
+/// the array, or a product does not fit the precision.
+/// # Example
+/// ```
+/// use re_arrow2::array::PrimitiveArray;
+/// use re_arrow2::compute::cumulative::cumprod_decimal;
+/// use re_arrow2::datatypes::DataType;
+///
+/// // 1.50, 2.00, 0.50
+/// let array = PrimitiveArray::from_slice([150i128, 200, 50]).to(DataType::Decimal(5, 2));
+/// let result = cumprod_decimal(&array, &Default::default()).unwrap();
+/// let expected = PrimitiveArray::from_slice([150i128, 300, 150]).to(DataType::Decimal(5, 2));
+/// assert_eq!(result, expected);
+/// ```
+pub fn cumprod_decimal(
+    array: &PrimitiveArray<i128>,
+    options: &CumulativeOptions,
+) -> Result<PrimitiveArray<i128>> {
+    let (max, scale) = decimal_bounds(array.data_type(), "cumprod_decimal")?;
+    let scale = I256::from(scale);
+
+    scan(
+        array,
+        options,
+        array.data_type().clone(),
+        |x| x,
+        |acc, x| {
+            // the product of two `i128` always fits in an `I256`, and may fit the precision
+            // only once divided by the scale
+            let product = I256::from(acc) * I256::from(x) / scale;
+            i128::try_from(product)
+                .ok()
+                .filter(|x| x.abs() <= max)
+                .ok_or(Error::Overflow)
+        },
+    )
+}
+
+/// Returns the cumulative minimum of `array`, of the same data type as `array`.
+/// # Errors
+/// This function errors iff `options.partitions` do not cover the array.
+pub fn cummin<T>(
+    array: &PrimitiveArray<T>,
+    options: &CumulativeOptions,
+) -> Result<PrimitiveArray<T>>
+where
+    T: NativeType + PartialOrd,
+{
+    scan(
+        array,
+        options,
+        array.data_type().clone(),
+        |x| x,
+        |acc, x| Ok(if x < acc { x } else { acc }),
+    )
+}
+
+/// Returns the cumulative maximum of `array`, of the same data type as `array`.
+/// # Errors
+/// This function errors iff `options.partitions` do not cover the array.
+/// # Example
+/// ```
+/// use re_arrow2::array::Int32Array;
+/// use re_arrow2::compute::cumulative::{cummax, CumulativeNulls, CumulativeOptions};
+///
+/// let array = Int32Array::from(&[Some(1), Some(3), None, Some(4)]);
+/// let options = CumulativeOptions {
+///     reverse: true,
+///     nulls: CumulativeNulls::Propagate,
+///     ..Default::default()
+/// };
+/// let result = cummax(&array, &options).unwrap();
+/// assert_eq!(result, Int32Array::from(&[None, None, None, Some(4)]));
+/// ```
+pub fn cummax<T>(
+    array: &PrimitiveArray<T>,
+    options: &CumulativeOptions,
+) -> Result<PrimitiveArray<T>>
+where
+    T: NativeType + PartialOrd,
+{
+    scan(
+        array,
+        options,
+        array.data_type().clone(),
+        |x| x,
+        |acc, x| Ok(if x > acc { x } else { acc }),
+    )
+}
+
+/// Returns the cumulative number of non-null values of `array`.
+/// # Errors
+/// This function errors iff `options.partitions` do not cover the array.
+pub fn cumcount<T: NativeType>(
+    array: &PrimitiveArray<T>,
+    options: &CumulativeOptions,
+) -> Result<PrimitiveArray<u64>> {
+    scan(
+        array,
+        options,
+        DataType::UInt64,
+        |_| 1,
+        |acc, _| Ok(acc + 1),
+    )
+}
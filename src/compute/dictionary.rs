@@ -0,0 +1,252 @@
+//! Contains operators over [`DictionaryArray`]s that never decode them:
+//! [`unify_dictionaries`] makes many arrays share one values array and [`compact_dictionary`]
+//! drops unused values. To change the type of the keys, use
+//! `compute::cast::dictionary_to_dictionary_keys` (feature `compute_cast`).
+//!
+//! All operators accept sliced arrays.
+use std::hash::Hash;
+use std::sync::Arc;
+
+use crate::{
+    array::{
+        growable::make_growable, Array, BinaryArray, BooleanArray, DictionaryArray, DictionaryKey,
+        MutableArray, MutableBinaryArray, MutableBooleanArray, MutablePrimitiveArray,
+        MutableUtf8Array, PrimitiveArray, TryPush, Utf8Array, ValueMap,
+    },
+    datatypes::{DataType, PhysicalType, PrimitiveType},
+    error::{Error, Result},
+    offset::Offset,
+    types::NativeType,
+};
+
+/// The unified values and, per dictionary, the new key of each of its values
+/// (`None` for null values).
+type Unified<K> = (Box<dyn Array>, Vec<Vec<Option<K>>>);
+
+/// Unifies the values of `arrays` by inserting them in a [`ValueMap`] over `values`,
+/// using `push` to insert the value at an index.
+fn unify_hashed<K, M, A>(
+    arrays: &[&DictionaryArray<K>],
+    values: M,
+    push: impl Fn(&mut ValueMap<K, M>, &A, usize) -> Result<K>,
+) -> Result<Unified<K>>
+where
+    K: DictionaryKey,
+    M: MutableArray,
+    A: Array + 'static,
+{
+    let mut map = ValueMap::<K, M>::try_empty(values)?;
+    let mut remaps = Vec::with_capacity(arrays.len());
+    for array in arrays {
+        let values = array.values().as_any().downcast_ref::<A>().unwrap();
+        let mut remap = Vec::with_capacity(values.len());
+        for index in 0..values.len() {
+            let key = if values.is_valid(index) {
+                Some(push(&mut map, values, index)?)
+            } else {
+                None
+            };
+            remap.push(key);
+        }
+        remaps.push(remap);
+    }
+    Ok((map.into_values().as_box(), remaps))
+}
+
+fn unify_primitive<K, T>(arrays: &[&DictionaryArray<K>], data_type: &DataType) -> Result<Unified<K>>
+where
+    K: DictionaryKey,
+    T: NativeType + Eq + Hash,
+{
+    let values = MutablePrimitiveArray::<T>::new().to(data_type.clone());
+    unify_hashed(arrays, values, |map, values: &PrimitiveArray<T>, index| {
+        map.try_push_valid(values.value(index), |values, value| {
+            values.try_push(Some(value))
+        })
+    })
+}
+
+fn unify_utf8<K: DictionaryKey, O: Offset>(arrays: &[&DictionaryArray<K>]) -> Result<Unified<K>> {
+    let values = MutableUtf8Array::<O>::new();
+    unify_hashed(arrays, values, |map, values: &Utf8Array<O>, index| {
+        map.try_push_valid(values.value(index), |values, value| {
+            values.try_push(Some(value))
+        })
+    })
+}
+
+fn unify_binary<K: DictionaryKey, O: Offset>(arrays: &[&DictionaryArray<K>]) -> Result<Unified<K>> {
+    let values = MutableBinaryArray::<O>::new();
+    unify_hashed(arrays, values, |map, values: &BinaryArray<O>, index| {
+        map.try_push_valid(values.value(index), |values, value| {
+            values.try_push(Some(value))
+        })
+    })
+}
+
+fn unify_boolean<K: DictionaryKey>(arrays: &[&DictionaryArray<K>]) -> Result<Unified<K>> {
+    let values = MutableBooleanArray::new();
+    unify_hashed(arrays, values, |map, values: &BooleanArray, index| {
+        map.try_push_valid(values.value(index), |values, value| {
+            values.try_push(Some(value))
+        })
+    })
+}
+
+/// Unifies the values of `arrays` by concatenating them, for values that can't be hashed.
+fn unify_concatenated<K: DictionaryKey>(arrays: &[&DictionaryArray<K>]) -> Result<Unified<K>> {
+    let values = arrays
+        .iter()
+        .map(|array| array.values().as_ref())
+        .collect::<Vec<_>>();
+    let capacity = values.iter().map(|values| values.len()).sum();
+    let mut growable = make_growable(&values, false, capacity);
+
+    let mut offset = 0;
+    let mut remaps = Vec::with_capacity(arrays.len());
+    for (index, values) in values.iter().enumerate() {
+        growable.extend(index, 0, values.len());
+        let remap = (offset..offset + values.len())
+            .map(|key| K::try_from(key).map(Some).map_err(|_| Error::Overflow))
+            .collect::<Result<Vec<_>>>()?;
+        remaps.push(remap);
+        offset += values.len();
+    }
+    Ok((growable.as_box(), remaps))
+}
+
+/// Returns `array` with its keys mapped through `remap` and `values` as its values.
+fn remap_keys<K: DictionaryKey>(
+    array: &DictionaryArray<K>,
+    remap: &[Option<K>],
+    data_type: DataType,
+    values: Box<dyn Array>,
+) -> DictionaryArray<K> {
+    let keys = PrimitiveArray::<K>::from_trusted_len_iter(
+        array.keys_iter().map(|key| key.and_then(|key| remap[key])),
+    );
+    // Safety: `remap` only contains indices of `values`.
+    // `unwrap` is infallible because `data_type`'s values are of the type of `values`
+    unsafe { DictionaryArray::try_new_unchecked(data_type, keys, values) }.unwrap()
+}
+
+/// Returns `arrays` re-encoded to share one values array, so that they can be concatenated
+/// or compared by their keys.
+///
+/// Only the values referred to by a key are kept. They are deduplicated for boolean, integer
+/// (including temporal and decimal), utf8 and binary values, and rows whose value is null get a
+/// null key. The values of other types are
+/// concatenated. The resulting dictionaries are not ordered.
+/// # Example
+/// ```
+/// use re_arrow2::array::{DictionaryArray, Int32Array, Utf8Array};
+/// use re_arrow2::compute::dictionary::unify_dictionaries;
+///
+/// let a = DictionaryArray::try_from_keys(
+///     Int32Array::from_slice([0, 1, 0]),
+///     Utf8Array::<i32>::from_slice(["x", "y"]).boxed(),
+/// )
+/// .unwrap();
+/// let b = DictionaryArray::try_from_keys(
+///     Int32Array::from_slice([0, 1]),
+///     Utf8Array::<i32>::from_slice(["z", "x"]).boxed(),
+/// )
+/// .unwrap();
+///
+/// let unified = unify_dictionaries(&[&a, &b]).unwrap();
+/// let values = Utf8Array::<i32>::from_slice(["x", "y", "z"]);
+/// assert_eq!(unified[0].values().as_ref(), &values as &dyn re_arrow2::array::Array);
+/// assert_eq!(unified[0].keys(), &Int32Array::from_slice([0, 1, 0]));
+/// assert_eq!(unified[1].keys(), &Int32Array::from_slice([2, 0]));
+/// ```
+/// # Errors
+/// This function errors iff:
+/// * the values of `arrays` have different [`DataType`]s
+/// * the unified values do not fit in the key type `K`
+pub fn unify_dictionaries<K: DictionaryKey>(
+    arrays: &[&DictionaryArray<K>],
+) -> Result<Vec<DictionaryArray<K>>> {
+    let data_type = match arrays.first() {
+        Some(array) => array.values().data_type(),
+        None => return Ok(vec![]),
+    };
+    if let Some(array) = arrays
+        .iter()
+        .find(|array| array.values().data_type() != data_type)
+    {
+        return Err(Error::InvalidArgumentError(format!(
+            "Dictionaries with values of type {data_type:?} and {:?} can't be unified",
+            array.values().data_type()
+        )));
+    }
+
+    // only the values that a key refers to are unified
+    let compacted = arrays
+        .iter()
+        .map(|array| compact_dictionary(array))
+        .collect::<Vec<_>>();
+    let arrays = compacted.iter().collect::<Vec<_>>();
+    let arrays = arrays.as_slice();
+
+    use PrimitiveType::*;
+    let (values, remaps) = match data_type.to_physical_type() {
+        _ if matches!(data_type, DataType::Extension(..)) => unify_concatenated(arrays)?,
+        PhysicalType::Boolean => unify_boolean(arrays)?,
+        PhysicalType::Primitive(Int8) => unify_primitive::<K, i8>(arrays, data_type)?,
+        PhysicalType::Primitive(Int16) => unify_primitive::<K, i16>(arrays, data_type)?,
+        PhysicalType::Primitive(Int32) => unify_primitive::<K, i32>(arrays, data_type)?,
+        PhysicalType::Primitive(Int64) => unify_primitive::<K, i64>(arrays, data_type)?,
+        PhysicalType::Primitive(Int128) => unify_primitive::<K, i128>(arrays, data_type)?,
+        PhysicalType::Primitive(UInt8) => unify_primitive::<K, u8>(arrays, data_type)?,
+        PhysicalType::Primitive(UInt16) => unify_primitive::<K, u16>(arrays, data_type)?,
+        PhysicalType::Primitive(UInt32) => unify_primitive::<K, u32>(arrays, data_type)?,
+        PhysicalType::Primitive(UInt64) => unify_primitive::<K, u64>(arrays, data_type)?,
+        PhysicalType::Utf8 => unify_utf8::<K, i32>(arrays)?,
+        PhysicalType::LargeUtf8 => unify_utf8::<K, i64>(arrays)?,
+        PhysicalType::Binary => unify_binary::<K, i32>(arrays)?,
+        PhysicalType::LargeBinary => unify_binary::<K, i64>(arrays)?,
+        _ => unify_concatenated(arrays)?,
+    };
+
+    let data_type = DataType::Dictionary(K::KEY_TYPE, Arc::new(data_type.clone()), false);
+    Ok(arrays
+        .iter()
+        .zip(remaps)
+        .map(|(array, remap)| remap_keys(array, &remap, data_type.clone(), values.clone()))
+        .collect())
+}
+
+/// Returns `array` without the values that no key refers to, e.g. after slicing it.
+/// The used values keep their order, so an ordered dictionary remains ordered.
+pub fn compact_dictionary<K: DictionaryKey>(array: &DictionaryArray<K>) -> DictionaryArray<K> {
+    let values = array.values();
+    let mut used = vec![false; values.len()];
+    array.keys_iter().flatten().for_each(|key| used[key] = true);
+    if used.iter().all(|used| *used) {
+        return array.clone();
+    }
+    if !array.is_empty() && array.keys().null_count() == array.len() {
+        // null keys still index the values, so keep the single null value of `new_null`
+        return DictionaryArray::new_null(array.data_type().clone(), array.len());
+    }
+
+    let mut growable = make_growable(&[values.as_ref()], false, values.len());
+    let mut remap = vec![None; values.len()];
+    let mut length = 0;
+    let mut index = 0;
+    while index < used.len() {
+        if !used[index] {
+            index += 1;
+            continue;
+        }
+        let start = index;
+        while index < used.len() && used[index] {
+            // `unwrap` is infallible because `length <= index`, which is a key of type `K`
+            remap[index] = Some(K::try_from(length).ok().unwrap());
+            length += 1;
+            index += 1;
+        }
+        growable.extend(0, start, index - start);
+    }
+    remap_keys(array, &remap, array.data_type().clone(), growable.as_box())
+}
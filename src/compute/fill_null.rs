@@ -0,0 +1,306 @@
+//! Contains operators to replace null slots: [`fill_null`], [`fill_null_forward`],
+//! [`fill_null_backward`] and [`coalesce`].
+use crate::{
+    array::{
+        growable::make_growable, Array, BinaryArray, BooleanArray, MutableBinaryArray,
+        MutableUtf8Array, PrimitiveArray, Utf8Array,
+    },
+    bitmap::{utils::SlicesIterator, Bitmap},
+    datatypes::{DataType, PhysicalType},
+    error::{Error, Result},
+    offset::Offset,
+    scalar::{to_array, BinaryScalar, BooleanScalar, PrimitiveScalar, Scalar, Utf8Scalar},
+    types::NativeType,
+};
+
+/// Returns the validity of `array` if it has at least one null slot.
+fn nulls(array: &dyn Array) -> Option<&Bitmap> {
+    array
+        .validity()
+        .filter(|validity| validity.unset_bits() > 0)
+}
+
+/// Replaces every null slot of a [`PrimitiveArray`] by `value`.
+/// # Example
+/// ```
+/// use re_arrow2::array::Int32Array;
+/// use re_arrow2::compute::fill_null::fill_null_primitive;
+///
+/// let array = Int32Array::from(&[Some(1), None, Some(3)]);
+/// let result = fill_null_primitive(&array, 0);
+/// assert_eq!(result, Int32Array::from_slice([1, 0, 3]));
+/// ```
+pub fn fill_null_primitive<T: NativeType>(
+    array: &PrimitiveArray<T>,
+    value: T,
+) -> PrimitiveArray<T> {
+    let validity = match nulls(array) {
+        Some(validity) => validity,
+        None => return array.clone(),
+    };
+    let values = array
+        .values()
+        .iter()
+        .zip(validity.iter())
+        .map(|(x, is_valid)| if is_valid { *x } else { value })
+        .collect::<Vec<_>>();
+    PrimitiveArray::<T>::new(array.data_type().clone(), values.into(), None)
+}
+
+/// Replaces every null slot of a [`BooleanArray`] by `value`.
+pub fn fill_null_boolean(array: &BooleanArray, value: bool) -> BooleanArray {
+    let validity = match nulls(array) {
+        Some(validity) => validity,
+        None => return array.clone(),
+    };
+    let values = if value {
+        array.values() | &!validity
+    } else {
+        array.values() & validity
+    };
+    BooleanArray::new(array.data_type().clone(), values, None)
+}
+
+/// Replaces every null slot of a [`Utf8Array`] by `value`.
+pub fn fill_null_utf8<O: Offset>(array: &Utf8Array<O>, value: &str) -> Utf8Array<O> {
+    if nulls(array).is_none() {
+        return array.clone();
+    }
+    let iter = array.iter().map(|x| x.unwrap_or(value));
+    let filled: Utf8Array<O> = MutableUtf8Array::<O>::from_trusted_len_values_iter(iter).into();
+    // safety: `filled` is valid utf8
+    unsafe {
+        Utf8Array::<O>::new_unchecked(
+            array.data_type().clone(),
+            filled.offsets().clone(),
+            filled.values().clone(),
+            None,
+        )
+    }
+}
+
+/// Replaces every null slot of a [`BinaryArray`] by `value`.
+pub fn fill_null_binary<O: Offset>(array: &BinaryArray<O>, value: &[u8]) -> BinaryArray<O> {
+    if nulls(array).is_none() {
+        return array.clone();
+    }
+    let iter = array.iter().map(|x| x.unwrap_or(value));
+    let filled: BinaryArray<O> = MutableBinaryArray::<O>::from_trusted_len_values_iter(iter).into();
+    BinaryArray::<O>::new(
+        array.data_type().clone(),
+        filled.offsets().clone(),
+        filled.values().clone(),
+        None,
+    )
+}
+
+/// Replaces every null slot of an arbitrary array by `value` via a [`Growable`](crate::array::growable::Growable).
+fn fill_null_growable(array: &dyn Array, value: &dyn Scalar) -> Result<Box<dyn Array>> {
+    let validity = match nulls(array) {
+        Some(validity) => validity,
+        None => return Ok(array.to_boxed()),
+    };
+    let value = to_array(value, 1)?;
+    let mut growable = make_growable(&[array, value.as_ref()], false, array.len());
+
+    let mut end = 0;
+    for (start, len) in SlicesIterator::new(validity) {
+        (end..start).for_each(|_| growable.extend(1, 0, 1));
+        growable.extend(0, start, len);
+        end = start + len;
+    }
+    (end..array.len()).for_each(|_| growable.extend(1, 0, 1));
+    Ok(growable.as_box())
+}
+
+/// Replaces every null slot of `array` by `value`.
+/// When `value` is null, the array is returned unchanged.
+/// # Example
+/// ```
+/// use re_arrow2::array::Utf8Array;
+/// use re_arrow2::compute::fill_null::fill_null;
+/// use re_arrow2::scalar::Utf8Scalar;
+///
+/// let array = Utf8Array::<i32>::from([Some("a"), None, Some("c")]);
+/// let result = fill_null(&array, &Utf8Scalar::<i32>::new(Some("b"))).unwrap();
+/// assert_eq!(result.as_ref(), &Utf8Array::<i32>::from_slice(["a", "b", "c"]) as &dyn re_arrow2::array::Array);
+/// ```
+/// # Errors
+/// This function errors iff the data types of `array` and `value` are different.
+pub fn fill_null(array: &dyn Array, value: &dyn Scalar) -> Result<Box<dyn Array>> {
+    if array.data_type() != value.data_type() {
+        return Err(Error::InvalidArgumentError(format!(
+            "Fill null requires the array and the value to have the same datatypes ({:?} != {:?})",
+            array.data_type(),
+            value.data_type()
+        )));
+    }
+    if !value.is_valid() {
+        return Ok(array.to_boxed());
+    }
+
+    use PhysicalType::*;
+    Ok(match array.data_type().to_physical_type() {
+        Boolean => {
+            let value = value.as_any().downcast_ref::<BooleanScalar>().unwrap();
+            let array = array.as_any().downcast_ref().unwrap();
+            Box::new(fill_null_boolean(array, value.value().unwrap()))
+        }
+        Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
+            let value = value.as_any().downcast_ref::<PrimitiveScalar<$T>>().unwrap();
+            let array = array.as_any().downcast_ref().unwrap();
+            Box::new(fill_null_primitive::<$T>(array, value.value().unwrap()))
+        }),
+        Utf8 => {
+            let value = value.as_any().downcast_ref::<Utf8Scalar<i32>>().unwrap();
+            let array = array.as_any().downcast_ref().unwrap();
+            Box::new(fill_null_utf8::<i32>(array, value.value().unwrap()))
+        }
+        LargeUtf8 => {
+            let value = value.as_any().downcast_ref::<Utf8Scalar<i64>>().unwrap();
+            let array = array.as_any().downcast_ref().unwrap();
+            Box::new(fill_null_utf8::<i64>(array, value.value().unwrap()))
+        }
+        Binary => {
+            let value = value.as_any().downcast_ref::<BinaryScalar<i32>>().unwrap();
+            let array = array.as_any().downcast_ref().unwrap();
+            Box::new(fill_null_binary::<i32>(array, value.value().unwrap()))
+        }
+        LargeBinary => {
+            let value = value.as_any().downcast_ref::<BinaryScalar<i64>>().unwrap();
+            let array = array.as_any().downcast_ref().unwrap();
+            Box::new(fill_null_binary::<i64>(array, value.value().unwrap()))
+        }
+        _ => fill_null_growable(array, value)?,
+    })
+}
+
+/// Returns, for every slot, the slot whose value it takes after filling nulls from the
+/// previous (`forward`) or next valid slot, at most `limit` slots away.
+fn fill_indices(validity: &Bitmap, limit: Option<usize>, forward: bool) -> Vec<Option<usize>> {
+    let limit = limit.unwrap_or(usize::MAX);
+    let len = validity.len();
+
+    let mut indices = vec![None; len];
+    let mut last_valid = None;
+    let mut set = |i: usize| {
+        if validity.get_bit(i) {
+            last_valid = Some(i);
+            indices[i] = Some(i);
+        } else {
+            indices[i] = last_valid.filter(|last: &usize| last.abs_diff(i) <= limit);
+        }
+    };
+    if forward {
+        (0..len).for_each(&mut set);
+    } else {
+        (0..len).rev().for_each(&mut set);
+    }
+    indices
+}
+
+fn fill_null_direction(array: &dyn Array, limit: Option<usize>, forward: bool) -> Box<dyn Array> {
+    let validity = match nulls(array) {
+        Some(validity) => validity,
+        None => return array.to_boxed(),
+    };
+    let indices = fill_indices(validity, limit, forward);
+
+    let mut growable = make_growable(&[array], true, array.len());
+    indices.into_iter().for_each(|index| match index {
+        Some(index) => growable.extend(0, index, 1),
+        None => growable.extend_validity(1),
+    });
+    growable.as_box()
+}
+
+/// Replaces every null slot of `array` by the last valid value before it.
+/// When `limit` is set, at most `limit` consecutive null slots are filled after each valid value.
+/// Null slots before the first valid value remain null.
+/// # Example
+/// ```
+/// use re_arrow2::array::Int32Array;
+/// use re_arrow2::compute::fill_null::fill_null_forward;
+///
+/// let array = Int32Array::from(&[None, Some(1), None, None, Some(4), None]);
+/// let result = fill_null_forward(&array, Some(1));
+/// let expected = Int32Array::from(&[None, Some(1), Some(1), None, Some(4), Some(4)]);
+/// assert_eq!(result.as_ref(), &expected as &dyn re_arrow2::array::Array);
+/// ```
+pub fn fill_null_forward(array: &dyn Array, limit: Option<usize>) -> Box<dyn Array> {
+    fill_null_direction(array, limit, true)
+}
+
+/// Replaces every null slot of `array` by the first valid value after it.
+/// When `limit` is set, at most `limit` consecutive null slots are filled before each valid value.
+/// Null slots after the last valid value remain null.
+/// # Example
+/// ```
+/// use re_arrow2::array::Int32Array;
+/// use re_arrow2::compute::fill_null::fill_null_backward;
+///
+/// let array = Int32Array::from(&[None, Some(1), None, None, Some(4), None]);
+/// let result = fill_null_backward(&array, None);
+/// let expected = Int32Array::from(&[Some(1), Some(1), Some(4), Some(4), Some(4), None]);
+/// assert_eq!(result.as_ref(), &expected as &dyn re_arrow2::array::Array);
+/// ```
+pub fn fill_null_backward(array: &dyn Array, limit: Option<usize>) -> Box<dyn Array> {
+    fill_null_direction(array, limit, false)
+}
+
+/// Returns, for every slot, the value of the first array in `arrays` that is valid in that slot,
+/// or null if all arrays are null in that slot.
+/// # Example
+/// ```
+/// use re_arrow2::array::Int32Array;
+/// use re_arrow2::compute::fill_null::coalesce;
+///
+/// let a = Int32Array::from(&[Some(1), None, None]);
+/// let b = Int32Array::from(&[Some(10), Some(20), None]);
+/// let result = coalesce(&[&a, &b]).unwrap();
+/// let expected = Int32Array::from(&[Some(1), Some(20), None]);
+/// assert_eq!(result.as_ref(), &expected as &dyn re_arrow2::array::Array);
+/// ```
+/// # Errors
+/// This function errors iff `arrays` is empty or its arrays do not have the same
+/// data type and length.
+pub fn coalesce(arrays: &[&dyn Array]) -> Result<Box<dyn Array>> {
+    let first = arrays.first().ok_or_else(|| {
+        Error::InvalidArgumentError("Coalesce requires at least one array".to_string())
+    })?;
+    if arrays.iter().any(|x| x.data_type() != first.data_type()) {
+        return Err(Error::InvalidArgumentError(
+            "Coalesce requires all arrays to have the same datatype".to_string(),
+        ));
+    }
+    if arrays.iter().any(|x| x.len() != first.len()) {
+        return Err(Error::InvalidArgumentError(
+            "Coalesce requires all arrays to have the same length".to_string(),
+        ));
+    }
+    if first.data_type() == &DataType::Null || nulls(*first).is_none() {
+        return Ok(first.to_boxed());
+    }
+
+    // index of the first array that is valid on each slot
+    let choice = |i: usize| arrays.iter().position(|x| x.is_valid(i));
+
+    let mut growable = make_growable(arrays, true, first.len());
+    let mut extend = |choice: Option<usize>, start: usize, len: usize| match choice {
+        Some(index) => growable.extend(index, start, len),
+        None => growable.extend_validity(len),
+    };
+
+    let mut start = 0;
+    let mut current = choice(0);
+    for i in 1..first.len() {
+        let next = choice(i);
+        if next != current {
+            extend(current, start, i - start);
+            start = i;
+            current = next;
+        }
+    }
+    extend(current, start, first.len() - start);
+    Ok(growable.as_box())
+}
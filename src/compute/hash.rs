@@ -1,4 +1,8 @@
-//! Contains the [`hash`] and typed (e.g. [`hash_primitive`]) operators.
+//! Contains the [`hash`] and typed (e.g. [`hash_primitive`]) operators, and [`hash_columns`].
+//!
+//! [`hash`] and its typed variants are fast but are not guaranteed to be reproducible across
+//! platforms or versions. [`hash_columns`] is stable: it supports all data types and
+//! several [`HashAlgorithm`]s compatible with other systems (e.g. Apache Spark and Iceberg).
 // multiversion does not copy documentation, causing a false positive
 #![allow(missing_docs)]
 use ahash::RandomState;
@@ -12,11 +16,15 @@ macro_rules! new_state {
 }
 
 use crate::{
-    array::{Array, BinaryArray, BooleanArray, PrimitiveArray, Utf8Array},
+    array::{
+        Array, BinaryArray, BooleanArray, DictionaryArray, DictionaryKey, FixedSizeBinaryArray,
+        FixedSizeListArray, ListArray, MapArray, PrimitiveArray, StructArray, UnionArray,
+        Utf8Array,
+    },
     datatypes::{DataType, PhysicalType, PrimitiveType},
     error::{Error, Result},
-    offset::Offset,
-    types::NativeType,
+    offset::{Offset, OffsetsBuffer},
+    types::{days_ms, f16, i256, months_days_ns, NativeType},
 };
 
 use super::arity::unary;
@@ -95,15 +103,23 @@ macro_rules! with_match_primitive_type {(
 })}
 
 /// Returns the element-wise hash of an [`Array`]. Validity is preserved.
-/// Supported DataTypes:
-/// * Boolean types
-/// * All primitive types except `Float32` and `Float64`
-/// * `[Large]Utf8`;
-/// * `[Large]Binary`.
+///
+/// Booleans, integers, intervals, `[Large]Utf8` and `[Large]Binary` use the fast
+/// typed operators (e.g. [`hash_primitive`]); all other data types (e.g. floats, decimals and
+/// nested types) use [`HashAlgorithm::XxHash64`] (see [`hash_columns`]).
 /// # Errors
-/// This function errors whenever it does not support the specific `DataType`.
+/// This function does not error; it returns a [`Result`] for backward compatibility.
 pub fn hash(array: &dyn Array) -> Result<PrimitiveArray<u64>> {
     use PhysicalType::*;
+    if !has_typed_hash(array.data_type()) {
+        let hash = build_hash(array, HashAlgorithm::XxHash64);
+        let values = (0..array.len()).map(|i| hash(i, 0)).collect::<Vec<_>>();
+        return Ok(PrimitiveArray::new(
+            DataType::UInt64,
+            values.into(),
+            array.validity().cloned(),
+        ));
+    }
     Ok(match array.data_type().to_physical_type() {
         Boolean => hash_boolean(array.as_any().downcast_ref().unwrap()),
         Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
@@ -113,15 +129,13 @@ pub fn hash(array: &dyn Array) -> Result<PrimitiveArray<u64>> {
         LargeBinary => hash_binary::<i64>(array.as_any().downcast_ref().unwrap()),
         Utf8 => hash_utf8::<i32>(array.as_any().downcast_ref().unwrap()),
         LargeUtf8 => hash_utf8::<i64>(array.as_any().downcast_ref().unwrap()),
-        t => {
-            return Err(Error::NotYetImplemented(format!(
-                "Hash not implemented for type {t:?}"
-            )))
-        }
+        // `has_typed_hash` routed every other type to `build_hash` above
+        _ => unreachable!(),
     })
 }
 
 /// Checks if an array of type `datatype` can be used in [`hash`].
+/// All data types are supported.
 ///
 /// # Examples
 /// ```
@@ -131,10 +145,15 @@ pub fn hash(array: &dyn Array) -> Result<PrimitiveArray<u64>> {
 /// let data_type = DataType::Int8;
 /// assert_eq!(can_hash(&data_type), true);
 
-/// let data_type = DataType::Null;
-/// assert_eq!(can_hash(&data_type), false);
+/// let data_type = DataType::Float64;
+/// assert_eq!(can_hash(&data_type), true);
 /// ```
-pub fn can_hash(data_type: &DataType) -> bool {
+pub fn can_hash(_: &DataType) -> bool {
+    true
+}
+
+/// Whether `data_type` is hashed by a typed operator in [`hash`].
+fn has_typed_hash(data_type: &DataType) -> bool {
     matches!(
         data_type.to_physical_type(),
         PhysicalType::Boolean
@@ -155,3 +174,495 @@ pub fn can_hash(data_type: &DataType) -> bool {
             | PhysicalType::LargeUtf8
     )
 }
+
+const PRIME64_1: u64 = 0x9E3779B185EBCA87;
+const PRIME64_2: u64 = 0xC2B2AE3D27D4EB4F;
+const PRIME64_3: u64 = 0x165667B19E3779F9;
+const PRIME64_4: u64 = 0x85EBCA77C2B2AE63;
+const PRIME64_5: u64 = 0x27D4EB2F165667C5;
+
+#[inline]
+fn xxhash64_round(acc: u64, input: u64) -> u64 {
+    acc.wrapping_add(input.wrapping_mul(PRIME64_2))
+        .rotate_left(31)
+        .wrapping_mul(PRIME64_1)
+}
+
+#[inline]
+fn xxhash64_merge_round(acc: u64, value: u64) -> u64 {
+    (acc ^ xxhash64_round(0, value))
+        .wrapping_mul(PRIME64_1)
+        .wrapping_add(PRIME64_4)
+}
+
+#[inline]
+fn read_u64(bytes: &[u8]) -> u64 {
+    u64::from_le_bytes(bytes[..8].try_into().unwrap())
+}
+
+/// The xxHash64 of `bytes` with `seed`.
+fn xxhash64(bytes: &[u8], seed: u64) -> u64 {
+    let mut remaining = bytes;
+    let mut hash = if bytes.len() >= 32 {
+        let mut v = [
+            seed.wrapping_add(PRIME64_1).wrapping_add(PRIME64_2),
+            seed.wrapping_add(PRIME64_2),
+            seed,
+            seed.wrapping_sub(PRIME64_1),
+        ];
+        while remaining.len() >= 32 {
+            for (i, v) in v.iter_mut().enumerate() {
+                *v = xxhash64_round(*v, read_u64(&remaining[i * 8..]));
+            }
+            remaining = &remaining[32..];
+        }
+        let hash = v[0]
+            .rotate_left(1)
+            .wrapping_add(v[1].rotate_left(7))
+            .wrapping_add(v[2].rotate_left(12))
+            .wrapping_add(v[3].rotate_left(18));
+        v.iter()
+            .fold(hash, |hash, v| xxhash64_merge_round(hash, *v))
+    } else {
+        seed.wrapping_add(PRIME64_5)
+    };
+    hash = hash.wrapping_add(bytes.len() as u64);
+
+    while remaining.len() >= 8 {
+        hash ^= xxhash64_round(0, read_u64(remaining));
+        hash = hash
+            .rotate_left(27)
+            .wrapping_mul(PRIME64_1)
+            .wrapping_add(PRIME64_4);
+        remaining = &remaining[8..];
+    }
+    if remaining.len() >= 4 {
+        let value = u32::from_le_bytes(remaining[..4].try_into().unwrap()) as u64;
+        hash ^= value.wrapping_mul(PRIME64_1);
+        hash = hash
+            .rotate_left(23)
+            .wrapping_mul(PRIME64_2)
+            .wrapping_add(PRIME64_3);
+        remaining = &remaining[4..];
+    }
+    for byte in remaining {
+        hash ^= (*byte as u64).wrapping_mul(PRIME64_5);
+        hash = hash.rotate_left(11).wrapping_mul(PRIME64_1);
+    }
+
+    hash ^= hash >> 33;
+    hash = hash.wrapping_mul(PRIME64_2);
+    hash ^= hash >> 29;
+    hash = hash.wrapping_mul(PRIME64_3);
+    hash ^ (hash >> 32)
+}
+
+const MURMUR3_C1: u32 = 0xcc9e2d51;
+const MURMUR3_C2: u32 = 0x1b873593;
+
+#[inline]
+fn murmur3_mix_k1(k1: u32) -> u32 {
+    k1.wrapping_mul(MURMUR3_C1)
+        .rotate_left(15)
+        .wrapping_mul(MURMUR3_C2)
+}
+
+#[inline]
+fn murmur3_mix_h1(h1: u32, k1: u32) -> u32 {
+    (h1 ^ k1)
+        .rotate_left(13)
+        .wrapping_mul(5)
+        .wrapping_add(0xe6546b64)
+}
+
+/// The Murmur3 (x86, 32 bits) of `bytes` with `seed`. When `spark_tail`, the bytes after the
+/// last 4 byte block are each mixed as a sign-extended 32 bit integer, like Apache Spark.
+fn murmur3_32(bytes: &[u8], seed: u32, spark_tail: bool) -> u32 {
+    let mut chunks = bytes.chunks_exact(4);
+    let mut hash = chunks.by_ref().fold(seed, |hash, chunk| {
+        let k1 = u32::from_le_bytes(chunk.try_into().unwrap());
+        murmur3_mix_h1(hash, murmur3_mix_k1(k1))
+    });
+    let remainder = chunks.remainder();
+    if spark_tail {
+        hash = remainder.iter().fold(hash, |hash, byte| {
+            murmur3_mix_h1(hash, murmur3_mix_k1(*byte as i8 as i32 as u32))
+        });
+    } else if !remainder.is_empty() {
+        let k1 = remainder
+            .iter()
+            .enumerate()
+            .fold(0u32, |k1, (i, byte)| k1 ^ ((*byte as u32) << (8 * i)));
+        hash ^= murmur3_mix_k1(k1);
+    }
+
+    hash ^= bytes.len() as u32;
+    hash ^= hash >> 16;
+    hash = hash.wrapping_mul(0x85ebca6b);
+    hash ^= hash >> 13;
+    hash = hash.wrapping_mul(0xc2b2ae35);
+    hash ^ (hash >> 16)
+}
+
+/// The stable hash algorithms of [`hash_columns`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum HashAlgorithm {
+    /// [xxHash64](https://xxhash.com), with values encoded like Apache Spark's `xxhash64`
+    /// (e.g. integers of up to 32 bits and booleans as 32 bit integers).
+    #[default]
+    XxHash64,
+    /// Murmur3 (x86, 32 bits), with values encoded like Apache Spark's `hash`, used by Spark's
+    /// hash partitioning and bucketing (seed 42).
+    SparkMurmur3,
+    /// Murmur3 (x86, 32 bits), with values encoded like Apache Iceberg's bucket transform
+    /// (seed 0): integers as 64 bit integers and decimals as their unscaled big-endian bytes.
+    IcebergMurmur3,
+}
+
+impl HashAlgorithm {
+    #[inline]
+    fn hash(self, bytes: &[u8], seed: u64) -> u64 {
+        match self {
+            Self::XxHash64 => xxhash64(bytes, seed),
+            Self::SparkMurmur3 => murmur3_32(bytes, seed as u32, true) as u64,
+            Self::IcebergMurmur3 => murmur3_32(bytes, seed as u32, false) as u64,
+        }
+    }
+}
+
+/// Returns the hash of the slot `i` of an array seeded by `seed`.
+/// Null slots return `seed`.
+type DynHash = Box<dyn Fn(usize, u64) -> u64 + Send + Sync>;
+
+/// A native type with a stable little-endian byte representation to hash.
+trait HashBytes: NativeType {
+    /// Writes the bytes of `self` into `buffer` and returns their number. When `widen`,
+    /// integers and floats are encoded as 64 bit values.
+    fn hash_bytes(self, widen: bool, buffer: &mut [u8; 16]) -> usize;
+}
+
+#[inline]
+fn write_bytes<const N: usize>(bytes: [u8; N], buffer: &mut [u8; 16]) -> usize {
+    buffer[..N].copy_from_slice(&bytes);
+    N
+}
+
+macro_rules! hash_bytes_integer {
+    ($type:ty, $as:ty) => {
+        impl HashBytes for $type {
+            #[inline]
+            fn hash_bytes(self, widen: bool, buffer: &mut [u8; 16]) -> usize {
+                if widen {
+                    write_bytes((self as i64).to_le_bytes(), buffer)
+                } else {
+                    write_bytes((self as $as).to_le_bytes(), buffer)
+                }
+            }
+        }
+    };
+}
+
+// integers of up to 32 bits are hashed as 32 bit integers
+hash_bytes_integer!(i8, i32);
+hash_bytes_integer!(i16, i32);
+hash_bytes_integer!(i32, i32);
+hash_bytes_integer!(i64, i64);
+hash_bytes_integer!(u8, i32);
+hash_bytes_integer!(u16, i32);
+hash_bytes_integer!(u32, u32);
+hash_bytes_integer!(u64, u64);
+
+/// Normalizes `value` so that `0.0 == -0.0` and all `NaN`s hash equally
+#[inline]
+fn normalize_f64(value: f64) -> f64 {
+    if value.is_nan() {
+        f64::NAN
+    } else if value == 0.0 {
+        0.0
+    } else {
+        value
+    }
+}
+
+impl HashBytes for f32 {
+    #[inline]
+    fn hash_bytes(self, widen: bool, buffer: &mut [u8; 16]) -> usize {
+        if widen {
+            (self as f64).hash_bytes(widen, buffer)
+        } else {
+            write_bytes((normalize_f64(self as f64) as f32).to_le_bytes(), buffer)
+        }
+    }
+}
+
+impl HashBytes for f64 {
+    #[inline]
+    fn hash_bytes(self, _: bool, buffer: &mut [u8; 16]) -> usize {
+        write_bytes(normalize_f64(self).to_le_bytes(), buffer)
+    }
+}
+
+impl HashBytes for f16 {
+    #[inline]
+    fn hash_bytes(self, widen: bool, buffer: &mut [u8; 16]) -> usize {
+        self.to_f32().hash_bytes(widen, buffer)
+    }
+}
+
+impl HashBytes for days_ms {
+    #[inline]
+    fn hash_bytes(self, _: bool, buffer: &mut [u8; 16]) -> usize {
+        write_bytes(self.to_le_bytes(), buffer)
+    }
+}
+
+impl HashBytes for months_days_ns {
+    #[inline]
+    fn hash_bytes(self, _: bool, buffer: &mut [u8; 16]) -> usize {
+        write_bytes(self.to_le_bytes(), buffer)
+    }
+}
+
+/// Returns the minimal big-endian two's complement representation of the big-endian `bytes`
+/// (e.g. Java's `BigInteger::toByteArray`).
+fn minimal_be_bytes(bytes: &[u8]) -> &[u8] {
+    let sign = if bytes[0] & 0x80 != 0 { 0xFF } else { 0x00 };
+    // a leading byte is redundant when it only repeats the sign bit of the next byte
+    let start = (0..bytes.len() - 1)
+        .find(|&i| bytes[i] != sign || (bytes[i + 1] & 0x80) != (sign & 0x80))
+        .unwrap_or(bytes.len() - 1);
+    &bytes[start..]
+}
+
+macro_rules! with_match_hash_type {(
+    $key_type:expr, | $_:tt $T:ident | $($body:tt)*
+) => ({
+    macro_rules! __with_ty__ {( $_ $T:ident ) => ( $($body)* )}
+    use crate::datatypes::PrimitiveType::*;
+    match $key_type {
+        Int8 => __with_ty__! { i8 },
+        Int16 => __with_ty__! { i16 },
+        Int32 => __with_ty__! { i32 },
+        Int64 => __with_ty__! { i64 },
+        UInt8 => __with_ty__! { u8 },
+        UInt16 => __with_ty__! { u16 },
+        UInt32 => __with_ty__! { u32 },
+        UInt64 => __with_ty__! { u64 },
+        Float16 => __with_ty__! { f16 },
+        Float32 => __with_ty__! { f32 },
+        Float64 => __with_ty__! { f64 },
+        DaysMs => __with_ty__! { days_ms },
+        MonthDayNano => __with_ty__! { months_days_ns },
+        Int128 | Int256 => unreachable!(),
+    }
+})}
+
+fn hash_native<T: HashBytes>(array: &dyn Array, algorithm: HashAlgorithm) -> DynHash {
+    let array = array
+        .as_any()
+        .downcast_ref::<PrimitiveArray<T>>()
+        .unwrap()
+        .clone();
+    let widen = algorithm == HashAlgorithm::IcebergMurmur3;
+    Box::new(move |i, seed| {
+        let mut buffer = [0; 16];
+        let length = array.value(i).hash_bytes(widen, &mut buffer);
+        algorithm.hash(&buffer[..length], seed)
+    })
+}
+
+fn hash_decimal(array: &dyn Array, algorithm: HashAlgorithm) -> DynHash {
+    let is_long = algorithm != HashAlgorithm::IcebergMurmur3
+        && matches!(
+            array.data_type().to_logical_type(),
+            DataType::Decimal(precision, _) if *precision <= 18
+        );
+    let array = array
+        .as_any()
+        .downcast_ref::<PrimitiveArray<i128>>()
+        .unwrap()
+        .clone();
+    if is_long {
+        // Spark hashes decimals that fit in 64 bits as their unscaled 64 bit integer
+        Box::new(move |i, seed| algorithm.hash(&(array.value(i) as i64).to_le_bytes(), seed))
+    } else {
+        Box::new(move |i, seed| {
+            algorithm.hash(minimal_be_bytes(&array.value(i).to_be_bytes()), seed)
+        })
+    }
+}
+
+fn hash_decimal256(array: &dyn Array, algorithm: HashAlgorithm) -> DynHash {
+    let array = array
+        .as_any()
+        .downcast_ref::<PrimitiveArray<i256>>()
+        .unwrap()
+        .clone();
+    Box::new(move |i, seed| algorithm.hash(minimal_be_bytes(&array.value(i).to_be_bytes()), seed))
+}
+
+/// Hashes the values `[offsets[i], offsets[i + 1])` of slot `i` in order, each seeded by the
+/// hash of the previous ones.
+fn hash_ranges<O: Offset>(offsets: &OffsetsBuffer<O>, values: DynHash) -> DynHash {
+    let offsets = offsets.clone();
+    Box::new(move |i, seed| {
+        let (start, end) = offsets.start_end(i);
+        (start..end).fold(seed, |seed, j| values(j, seed))
+    })
+}
+
+macro_rules! hash_dyn {
+    ($ty:ty, $array:expr, $algorithm:expr, $value:ident => $bytes:expr) => {{
+        let array = $array.as_any().downcast_ref::<$ty>().unwrap().clone();
+        let algorithm = $algorithm;
+        Box::new(move |i, seed| {
+            let $value = array.value(i);
+            algorithm.hash($bytes, seed)
+        })
+    }};
+}
+
+/// Returns a [`DynHash`] of the slots of `array`.
+fn build_hash(array: &dyn Array, algorithm: HashAlgorithm) -> DynHash {
+    use PhysicalType::*;
+    let hash: DynHash = match array.data_type().to_physical_type() {
+        Null => return Box::new(|_, seed| seed),
+        // booleans are hashed as 32 bit integers
+        Boolean => hash_dyn!(BooleanArray, array, algorithm, x => &(x as i32).to_le_bytes()),
+        Primitive(PrimitiveType::Int128) => hash_decimal(array, algorithm),
+        Primitive(PrimitiveType::Int256) => hash_decimal256(array, algorithm),
+        Primitive(primitive) => {
+            with_match_hash_type!(primitive, |$T| hash_native::<$T>(array, algorithm))
+        }
+        Utf8 => hash_dyn!(Utf8Array<i32>, array, algorithm, x => x.as_bytes()),
+        LargeUtf8 => hash_dyn!(Utf8Array<i64>, array, algorithm, x => x.as_bytes()),
+        Binary => hash_dyn!(BinaryArray<i32>, array, algorithm, x => x),
+        LargeBinary => hash_dyn!(BinaryArray<i64>, array, algorithm, x => x),
+        FixedSizeBinary => hash_dyn!(FixedSizeBinaryArray, array, algorithm, x => x),
+        List => {
+            let array = array.as_any().downcast_ref::<ListArray<i32>>().unwrap();
+            hash_ranges(
+                array.offsets(),
+                build_hash(array.values().as_ref(), algorithm),
+            )
+        }
+        LargeList => {
+            let array = array.as_any().downcast_ref::<ListArray<i64>>().unwrap();
+            hash_ranges(
+                array.offsets(),
+                build_hash(array.values().as_ref(), algorithm),
+            )
+        }
+        // entries are hashed as structs: key then value
+        Map => {
+            let array = array.as_any().downcast_ref::<MapArray>().unwrap();
+            hash_ranges(
+                array.offsets(),
+                build_hash(array.field().as_ref(), algorithm),
+            )
+        }
+        FixedSizeList => {
+            let array = array.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
+            let size = array.size();
+            let values = build_hash(array.values().as_ref(), algorithm);
+            Box::new(move |i, seed| {
+                (i * size..(i + 1) * size).fold(seed, |seed, j| values(j, seed))
+            })
+        }
+        Struct => {
+            let array = array.as_any().downcast_ref::<StructArray>().unwrap();
+            let fields = array
+                .values()
+                .iter()
+                .map(|field| build_hash(field.as_ref(), algorithm))
+                .collect::<Vec<_>>();
+            Box::new(move |i, seed| fields.iter().fold(seed, |seed, field| field(i, seed)))
+        }
+        // the type id (as a 32 bit integer) followed by the value
+        Union => {
+            let array = array.as_any().downcast_ref::<UnionArray>().unwrap().clone();
+            let fields = array
+                .fields()
+                .iter()
+                .map(|field| build_hash(field.as_ref(), algorithm))
+                .collect::<Vec<_>>();
+            Box::new(move |i, seed| {
+                let type_id = array.types()[i] as i32;
+                let (field, offset) = array.index(i);
+                fields[field](offset, algorithm.hash(&type_id.to_le_bytes(), seed))
+            })
+        }
+        Dictionary(key_type) => match_integer_type!(key_type, |$T| {
+            let array = array.as_any().downcast_ref::<DictionaryArray<$T>>().unwrap();
+            let keys = array.keys().values().clone();
+            let values = build_hash(array.values().as_ref(), algorithm);
+            // safety: all dictionaries keys are guaranteed to be castable to usize
+            Box::new(move |i, seed| unsafe { values(keys[i].as_usize(), seed) })
+        }),
+    };
+    match array.validity().cloned() {
+        Some(validity) if validity.unset_bits() > 0 => Box::new(move |i, seed| {
+            if validity.get_bit(i) {
+                hash(i, seed)
+            } else {
+                seed
+            }
+        }),
+        _ => hash,
+    }
+}
+
+/// Returns the stable hash of every row of `columns` with `algorithm`, seeded by `seed`.
+///
+/// Rows are hashed column by column: the hash of the first column is seeded by `seed`, and the
+/// hash of each following column is seeded by the hash of the previous columns. Null values
+/// leave the hash unchanged. Nested values are hashed the same way: list elements in order,
+/// struct fields in order, and map entries as their key followed by their value. This matches
+/// Apache Spark's `xxhash64(...)` (with [`HashAlgorithm::XxHash64`] and seed 42) and
+/// `hash(...)` (with [`HashAlgorithm::SparkMurmur3`] and seed 42).
+///
+/// Murmur3 hashes are 32 bit: they are returned in the lower 32 bits, and only the lower
+/// 32 bits of `seed` are used. Floats are normalized so that `-0.0` and `0.0`, as well as all
+/// `NaN`s, have the same hash. The result is the same across runs and platforms.
+/// # Errors
+/// This function errors iff `columns` is empty or the columns have different lengths.
+/// # Example
+/// ```
+/// use re_arrow2::array::{Int32Array, PrimitiveArray, Utf8Array};
+/// use re_arrow2::compute::hash::{hash_columns, HashAlgorithm};
+///
+/// let a = Int32Array::from_slice([1, 2, 1]);
+/// let b = Utf8Array::<i32>::from_slice(["x", "y", "x"]);
+/// let hashes = hash_columns(&[&a, &b], HashAlgorithm::XxHash64, 42).unwrap();
+/// assert_eq!(hashes.value(0), hashes.value(2));
+/// assert_ne!(hashes.value(0), hashes.value(1));
+///
+/// // Iceberg's bucket of the string "iceberg"
+/// let array = Utf8Array::<i32>::from_slice(["iceberg"]);
+/// let hashes = hash_columns(&[&array], HashAlgorithm::IcebergMurmur3, 0).unwrap();
+/// assert_eq!(hashes.value(0) as u32 as i32, 1210000089);
+/// ```
+pub fn hash_columns(
+    columns: &[&dyn Array],
+    algorithm: HashAlgorithm,
+    seed: u64,
+) -> Result<PrimitiveArray<u64>> {
+    let length = columns.first().map(|x| x.len()).ok_or_else(|| {
+        Error::InvalidArgumentError("hash_columns requires at least one column".to_string())
+    })?;
+    if columns.iter().any(|x| x.len() != length) {
+        return Err(Error::InvalidArgumentError(
+            "hash_columns requires columns of the same length".to_string(),
+        ));
+    }
+
+    let mut hashes = vec![seed; length];
+    for column in columns {
+        let hash = build_hash(*column, algorithm);
+        hashes
+            .iter_mut()
+            .enumerate()
+            .for_each(|(i, x)| *x = hash(i, *x));
+    }
+    Ok(PrimitiveArray::from_vec(hashes))
+}
@@ -1,4 +1,4 @@
-//! Contains the operator [`if_then_else`].
+//! Contains the operators [`if_then_else`] and [`case_when`].
 use crate::array::{growable, Array, BooleanArray};
 use crate::bitmap::utils::SlicesIterator;
 use crate::error::{Error, Result};
@@ -73,3 +73,83 @@ pub fn if_then_else(
     };
     Ok(result)
 }
+
+/// Returns, for each row, the value of the first branch whose predicate is `true`, or the
+/// value of `otherwise` when no predicate is `true`.
+///
+/// Unlike [`if_then_else`], a null predicate is not `true` and thus falls through to the
+/// next branch.
+/// # Example
+/// ```rust
+/// # use re_arrow2::error::Result;
+/// use re_arrow2::compute::if_then_else::case_when;
+/// use re_arrow2::array::{Int32Array, BooleanArray};
+///
+/// # fn main() -> Result<()> {
+/// let a = Int32Array::from_slice(&[1, 2, 3]);
+/// let b = Int32Array::from_slice(&[4, 5, 6]);
+/// let otherwise = Int32Array::from(&[Some(7), None, Some(9)]);
+/// let branches = [
+///     (BooleanArray::from(&[Some(true), None, Some(false)]), &a as _),
+///     (BooleanArray::from_slice(&[true, false, true]), &b as _),
+/// ];
+/// let result = case_when(&branches, &otherwise)?;
+///
+/// let expected = Int32Array::from(&[Some(1), None, Some(6)]);
+///
+/// assert_eq!(expected, result.as_ref());
+/// # Ok(())
+/// # }
+/// ```
+/// # Errors
+/// This function errors iff the values of the branches and `otherwise` do not have the same
+/// datatype, or the predicates and values do not all have the same length.
+pub fn case_when(
+    branches: &[(BooleanArray, &dyn Array)],
+    otherwise: &dyn Array,
+) -> Result<Box<dyn Array>> {
+    let len = otherwise.len();
+    for (predicate, values) in branches {
+        if values.data_type() != otherwise.data_type() {
+            return Err(Error::InvalidArgumentError(format!(
+                "Case when requires the values to have the same datatypes ({:?} != {:?})",
+                values.data_type(),
+                otherwise.data_type()
+            )));
+        }
+        if (predicate.len() != len) | (values.len() != len) {
+            return Err(Error::InvalidArgumentError(format!(
+                "Case when requires all arguments to have the same length (predicate = {}, values = {}, otherwise = {})",
+                predicate.len(),
+                values.len(),
+                len
+            )));
+        }
+    }
+
+    let mut arrays = branches
+        .iter()
+        .map(|(_, values)| *values)
+        .collect::<Vec<_>>();
+    arrays.push(otherwise);
+    let mut growable = growable::make_growable(&arrays, false, len);
+
+    // the index of the array selected at each row, extended in runs of the same array
+    let selected = |row: usize| {
+        branches
+            .iter()
+            .position(|(predicate, _)| predicate.is_valid(row) && predicate.value(row))
+            .unwrap_or(branches.len())
+    };
+    let mut start = 0;
+    while start < len {
+        let index = selected(start);
+        let mut end = start + 1;
+        while end < len && selected(end) == index {
+            end += 1;
+        }
+        growable.extend(index, start, end - start);
+        start = end;
+    }
+    Ok(growable.as_box())
+}
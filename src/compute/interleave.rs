@@ -0,0 +1,68 @@
+//! Contains the operator [`interleave`].
+use crate::array::{growable::make_growable, Array};
+use crate::error::{Error, Result};
+
+/// Returns an array whose row `i` is the row `indices[i].1` of the array `indices[i].0` of
+/// `arrays`, gathering rows from many arrays in one pass.
+///
+/// Consecutive rows of the same array are copied together, like
+/// `merge_sort::take_arrays` does with its slices.
+/// # Example
+/// ```rust
+/// # use re_arrow2::error::Result;
+/// use re_arrow2::compute::interleave::interleave;
+/// use re_arrow2::array::Int32Array;
+///
+/// # fn main() -> Result<()> {
+/// let a = Int32Array::from_slice(&[1, 2, 3]);
+/// let b = Int32Array::from(&[None, Some(5)]);
+/// let result = interleave(&[&a, &b], &[(0, 1), (1, 0), (1, 1), (0, 0)])?;
+///
+/// let expected = Int32Array::from(&[Some(2), None, Some(5), Some(1)]);
+///
+/// assert_eq!(expected, result.as_ref());
+/// # Ok(())
+/// # }
+/// ```
+/// # Errors
+/// This function errors iff:
+/// * `arrays` is empty or its arrays do not have the same datatype
+/// * an index is out of bounds
+pub fn interleave(arrays: &[&dyn Array], indices: &[(usize, usize)]) -> Result<Box<dyn Array>> {
+    let data_type = match arrays.first() {
+        Some(array) => array.data_type(),
+        None => {
+            return Err(Error::InvalidArgumentError(
+                "Interleave requires at least one array".to_string(),
+            ))
+        }
+    };
+    if let Some(array) = arrays.iter().find(|array| array.data_type() != data_type) {
+        return Err(Error::InvalidArgumentError(format!(
+            "Interleave requires the arrays to have the same datatypes ({:?} != {:?})",
+            data_type,
+            array.data_type()
+        )));
+    }
+    if let Some((array, row)) = indices
+        .iter()
+        .find(|(array, row)| arrays.get(*array).map_or(true, |array| *row >= array.len()))
+    {
+        return Err(Error::InvalidArgumentError(format!(
+            "Interleave index ({array}, {row}) is out of bounds"
+        )));
+    }
+
+    let mut growable = make_growable(arrays, false, indices.len());
+    let mut start = 0;
+    while start < indices.len() {
+        let (array, row) = indices[start];
+        let mut len = 1;
+        while start + len < indices.len() && indices[start + len] == (array, row + len) {
+            len += 1;
+        }
+        growable.extend(array, row, len);
+        start += len;
+    }
+    Ok(growable.as_box())
+}
@@ -0,0 +1,224 @@
+//! Defines kernels to convert [`Utf8Array`]s of JSON to typed arrays ([`parse_json`] and
+//! [`infer_json_type`]) and typed arrays to JSON ([`to_json`]).
+//!
+//! Each row is (de)serialized independently, using the same rules as [`crate::io::json`].
+use json_deserializer::Value;
+use streaming_iterator::StreamingIterator;
+
+use crate::{
+    array::{Array, ListArray, MutableUtf8Array, StructArray, Utf8Array},
+    datatypes::{DataType, IntegerType, IntervalUnit},
+    error::{Error, Result},
+    io::json::{
+        read::{_deserialize, coerce_data_type, infer},
+        write::new_serializer,
+    },
+    offset::Offset,
+    temporal_conversions::parse_offset,
+};
+
+/// Returns whether [`parse_json`] supports `data_type`.
+pub fn can_parse_json(data_type: &DataType) -> bool {
+    use DataType::*;
+    match data_type {
+        Null
+        | Boolean
+        | Int8
+        | Int16
+        | Int32
+        | Int64
+        | UInt8
+        | UInt16
+        | UInt32
+        | UInt64
+        | Float32
+        | Float64
+        | Date32
+        | Date64
+        | Time32(_)
+        | Time64(_)
+        | Duration(_)
+        | Interval(IntervalUnit::YearMonth)
+        | Utf8
+        | LargeUtf8
+        | Binary
+        | LargeBinary
+        | Timestamp(_, None) => true,
+        Timestamp(_, Some(tz)) => parse_offset(tz).is_ok(),
+        List(field) | LargeList(field) => can_parse_json(field.data_type()),
+        Struct(fields) => fields.iter().all(|field| can_parse_json(field.data_type())),
+        _ => false,
+    }
+}
+
+/// Parses each row of `array` as JSON to an array of `data_type`.
+///
+/// Null rows are null. Rows that are not valid JSON, and values that do not match
+/// `data_type` (e.g. a string for [`DataType::Int32`]), are null unless `strict` is set.
+/// Nested values are checked too, so `{"a": "x"}` does not match a struct whose field `a` is
+/// [`DataType::Int64`].
+/// # Errors
+/// This function errors iff:
+/// * `data_type` is not supported (see [`can_parse_json`])
+/// * `strict` is set and a non-null row is not valid JSON or does not match `data_type`, at
+///   any level of nesting
+pub fn parse_json<O: Offset>(
+    array: &Utf8Array<O>,
+    data_type: DataType,
+    strict: bool,
+) -> Result<Box<dyn Array>> {
+    if !can_parse_json(&data_type) {
+        return Err(Error::NotYetImplemented(format!(
+            "Parsing JSON to {data_type:?} is not supported"
+        )));
+    }
+
+    let rows = array
+        .iter()
+        .enumerate()
+        .map(|(row, value)| match value {
+            Some(value) => match json_deserializer::parse(value.as_bytes()) {
+                Ok(value) => Ok(value),
+                Err(error) if strict => Err(Error::InvalidArgumentError(format!(
+                    "Row {row} is not valid JSON: {error}"
+                ))),
+                Err(_) => Ok(Value::Null),
+            },
+            None => Ok(Value::Null),
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let parsed = _deserialize(&rows, data_type.clone());
+
+    if strict {
+        let mismatch = rows
+            .iter()
+            .enumerate()
+            .find(|(row, value)| !is_match(value, parsed.as_ref(), *row));
+        if let Some((row, _)) = mismatch {
+            return Err(Error::InvalidArgumentError(format!(
+                "Row {row} (\"{}\") does not match {data_type:?}",
+                array.value(row),
+            )));
+        }
+    }
+    Ok(parsed)
+}
+
+/// Returns whether every non-null JSON value in `value`, including nested ones, was deserialized
+/// to a valid slot of `array` at `index`.
+fn is_match(value: &Value, array: &dyn Array, index: usize) -> bool {
+    if matches!(value, Value::Null) {
+        return true;
+    }
+    if array.is_null(index) {
+        return false;
+    }
+    match (value, array.data_type()) {
+        (Value::Object(object), DataType::Struct(fields)) => {
+            let array = array.as_any().downcast_ref::<StructArray>().unwrap();
+            fields.iter().zip(array.values()).all(|(field, values)| {
+                object
+                    .get(&field.name)
+                    .map_or(true, |value| is_match(value, values.as_ref(), index))
+            })
+        }
+        (Value::Array(items), DataType::List(_)) => is_list_match::<i32>(items, array, index),
+        (Value::Array(items), DataType::LargeList(_)) => is_list_match::<i64>(items, array, index),
+        _ => true,
+    }
+}
+
+fn is_list_match<O: Offset>(items: &[Value], array: &dyn Array, index: usize) -> bool {
+    let array = array.as_any().downcast_ref::<ListArray<O>>().unwrap();
+    let start = array.offsets().start_end(index).0;
+    items
+        .iter()
+        .enumerate()
+        .all(|(i, item)| is_match(item, array.values().as_ref(), start + i))
+}
+
+/// Infers the [`DataType`] of the JSON rows of `array`, coercing the types of the rows as
+/// [`crate::io::json::read::infer`] does for JSON arrays.
+///
+/// Null rows, rows that are not valid JSON and rows that are JSON `null` are ignored; the type
+/// is [`DataType::Null`] when no row remains.
+/// # Errors
+/// This function errors iff the type of a row can't be inferred.
+pub fn infer_json_type<O: Offset>(array: &Utf8Array<O>) -> Result<DataType> {
+    let data_types = array
+        .iter()
+        .flatten()
+        .filter_map(|value| json_deserializer::parse(value.as_bytes()).ok())
+        .map(|value| infer(&value))
+        .filter(|data_type| !matches!(data_type, Ok(DataType::Null)))
+        .collect::<Result<Vec<_>>>()?;
+    Ok(coerce_data_type(&data_types))
+}
+
+/// Returns whether [`to_json`] supports `data_type`.
+pub fn can_to_json(data_type: &DataType) -> bool {
+    use DataType::*;
+    match data_type.to_logical_type() {
+        Null
+        | Boolean
+        | Int8
+        | Int16
+        | Int32
+        | Int64
+        | UInt8
+        | UInt16
+        | UInt32
+        | UInt64
+        | Float32
+        | Float64
+        | Date32
+        | Date64
+        | Duration(_)
+        | Utf8
+        | LargeUtf8
+        | Timestamp(_, None) => true,
+        Timestamp(_, Some(tz)) => can_write_timezone(tz),
+        List(field) | LargeList(field) | FixedSizeList(field, _) => can_to_json(field.data_type()),
+        Struct(fields) => fields.iter().all(|field| can_to_json(field.data_type())),
+        Dictionary(IntegerType::UInt32, values, _) => values.as_ref() == &LargeUtf8,
+        _ => false,
+    }
+}
+
+#[cfg(feature = "chrono-tz")]
+fn can_write_timezone(timezone: &str) -> bool {
+    parse_offset(timezone).is_ok() || crate::temporal_conversions::parse_offset_tz(timezone).is_ok()
+}
+
+#[cfg(not(feature = "chrono-tz"))]
+fn can_write_timezone(timezone: &str) -> bool {
+    parse_offset(timezone).is_ok()
+}
+
+/// Serializes each row of `array` to JSON.
+///
+/// Null rows are null; nested nulls are written as JSON `null`.
+/// # Errors
+/// This function errors iff `array`'s type is not supported (see [`can_to_json`]).
+pub fn to_json(array: &dyn Array) -> Result<Utf8Array<i32>> {
+    if !can_to_json(array.data_type()) {
+        return Err(Error::NotYetImplemented(format!(
+            "Writing {:?} to JSON is not supported",
+            array.data_type()
+        )));
+    }
+
+    let mut serializer = new_serializer(array, 0, usize::MAX);
+    let mut result = MutableUtf8Array::<i32>::with_capacity(array.len());
+    for row in 0..array.len() {
+        // `unwrap` is infalible because the serializer yields one item per row
+        let value = serializer.next().unwrap();
+        if array.is_null(row) {
+            result.push::<&str>(None);
+        } else {
+            result.push(Some(std::str::from_utf8(value)?));
+        }
+    }
+    Ok(result.into())
+}
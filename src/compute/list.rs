@@ -0,0 +1,519 @@
+//! Contains operators over list-like arrays ([`ListArray`] and [`FixedSizeListArray`]),
+//! such as [`flatten`], [`explode`], [`list_get`], [`list_slice`], [`list_sum`] and [`list_sort`].
+//!
+//! All operators accept sliced arrays and treat null lists as having no elements.
+use std::sync::Arc;
+
+use crate::{
+    array::{growable::make_growable, Array, FixedSizeListArray, ListArray, PrimitiveArray},
+    bitmap::Bitmap,
+    datatypes::{DataType, Field, PhysicalType, PrimitiveType},
+    error::{Error, Result},
+    offset::{Offset, Offsets},
+    types::{f16, i256, NativeType},
+};
+
+use super::sort::{sort_to_indices, SortOptions};
+
+/// A list-like array decomposed into the ranges of each list over its values.
+struct Lists<'a> {
+    field: &'a Field,
+    values: &'a dyn Array,
+    /// `(start, end)` of each list over `values`
+    ranges: Vec<(usize, usize)>,
+    validity: Option<&'a Bitmap>,
+}
+
+impl<'a> Lists<'a> {
+    fn try_new(array: &'a dyn Array) -> Result<Self> {
+        let validity = array.validity();
+        match array.data_type().to_physical_type() {
+            PhysicalType::List => Ok(Self::from_list::<i32>(
+                array.as_any().downcast_ref().unwrap(),
+            )),
+            PhysicalType::LargeList => Ok(Self::from_list::<i64>(
+                array.as_any().downcast_ref().unwrap(),
+            )),
+            PhysicalType::FixedSizeList => {
+                let array = array.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
+                let size = array.size();
+                Ok(Self {
+                    field: FixedSizeListArray::get_child_and_size(array.data_type()).0,
+                    values: array.values().as_ref(),
+                    ranges: (0..array.len())
+                        .map(|i| (i * size, (i + 1) * size))
+                        .collect(),
+                    validity,
+                })
+            }
+            _ => Err(Error::InvalidArgumentError(format!(
+                "List operators require a list array, got an array of type {:?}",
+                array.data_type()
+            ))),
+        }
+    }
+
+    fn from_list<O: Offset>(array: &'a ListArray<O>) -> Self {
+        Self {
+            field: ListArray::<O>::get_child_field(array.data_type()),
+            values: array.values().as_ref(),
+            ranges: array
+                .offsets()
+                .buffer()
+                .windows(2)
+                .map(|w| (w[0].to_usize(), w[1].to_usize()))
+                .collect(),
+            validity: array.validity(),
+        }
+    }
+
+    #[inline]
+    fn is_valid(&self, i: usize) -> bool {
+        self.validity.map(|x| x.get_bit(i)).unwrap_or(true)
+    }
+
+    /// The `(row, (start, end))` of every valid list
+    fn valid_ranges(&self) -> impl Iterator<Item = (usize, (usize, usize))> + '_ {
+        self.ranges
+            .iter()
+            .copied()
+            .enumerate()
+            .filter(|(row, _)| self.is_valid(*row))
+    }
+}
+
+/// Returns the slots `ranges` of `values` concatenated.
+fn gather_ranges(
+    values: &dyn Array,
+    ranges: impl Iterator<Item = (usize, usize)>,
+    capacity: usize,
+) -> Box<dyn Array> {
+    let mut growable = make_growable(&[values], false, capacity);
+    ranges.for_each(|(start, end)| growable.extend(0, start, end - start));
+    growable.as_box()
+}
+
+/// Returns a new list-like array with the same type as `array` (or a [`DataType::List`]
+/// when `array` is a [`FixedSizeListArray`] and `fixed` is `false`) whose lists have `lengths`.
+fn new_list(
+    array: &dyn Array,
+    field: &Field,
+    lengths: Vec<usize>,
+    values: Box<dyn Array>,
+    fixed: bool,
+) -> Result<Box<dyn Array>> {
+    let validity = array.validity().cloned();
+    Ok(match array.data_type().to_physical_type() {
+        PhysicalType::LargeList => Box::new(ListArray::<i64>::try_new(
+            array.data_type().clone(),
+            Offsets::try_from_lengths(lengths.into_iter())?.into(),
+            values,
+            validity,
+        )?),
+        PhysicalType::FixedSizeList if fixed => Box::new(FixedSizeListArray::try_new(
+            array.data_type().clone(),
+            values,
+            validity,
+        )?),
+        PhysicalType::FixedSizeList => Box::new(ListArray::<i32>::try_new(
+            DataType::List(Arc::new(field.clone())),
+            Offsets::try_from_lengths(lengths.into_iter())?.into(),
+            values,
+            validity,
+        )?),
+        _ => Box::new(ListArray::<i32>::try_new(
+            array.data_type().clone(),
+            Offsets::try_from_lengths(lengths.into_iter())?.into(),
+            values,
+            validity,
+        )?),
+    })
+}
+
+/// Returns the elements of all valid lists of `array` concatenated into a single array.
+/// # Example
+/// ```
+/// use re_arrow2::array::{Int32Array, ListArray, MutableListArray, MutablePrimitiveArray, TryExtend};
+/// use re_arrow2::compute::list::flatten;
+///
+/// let mut array = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+/// array.try_extend(vec![Some(vec![Some(1), Some(2)]), None, Some(vec![None])]).unwrap();
+/// let array: ListArray<i32> = array.into();
+///
+/// let result = flatten(&array).unwrap();
+/// assert_eq!(result.as_ref(), &Int32Array::from(&[Some(1), Some(2), None]) as &dyn re_arrow2::array::Array);
+/// ```
+/// # Errors
+/// This function errors iff `array` is not a list-like array.
+pub fn flatten(array: &dyn Array) -> Result<Box<dyn Array>> {
+    let lists = Lists::try_new(array)?;
+    let capacity = lists.valid_ranges().map(|(_, (s, e))| e - s).sum();
+    Ok(gather_ranges(
+        lists.values,
+        lists.valid_ranges().map(|(_, range)| range),
+        capacity,
+    ))
+}
+
+/// Returns the elements of all valid lists of `array` concatenated into a single array
+/// (as in [`flatten`]), together with the index of the row of `array` that each element belongs to.
+/// The indices can be used with [`take`](crate::compute::take::take) to repeat the other
+/// columns of a [`Chunk`](crate::chunk::Chunk) per element.
+///
+/// Null and empty lists produce no elements.
+/// # Example
+/// ```
+/// use re_arrow2::array::{Int32Array, ListArray, MutableListArray, MutablePrimitiveArray, TryExtend, UInt64Array};
+/// use re_arrow2::compute::list::explode;
+///
+/// let mut array = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+/// array.try_extend(vec![Some(vec![Some(1), Some(2)]), None, Some(vec![Some(3)])]).unwrap();
+/// let array: ListArray<i32> = array.into();
+///
+/// let (indices, values) = explode(&array).unwrap();
+/// assert_eq!(indices, UInt64Array::from_slice([0, 0, 2]));
+/// assert_eq!(values.as_ref(), &Int32Array::from_slice([1, 2, 3]) as &dyn re_arrow2::array::Array);
+/// ```
+/// # Errors
+/// This function errors iff `array` is not a list-like array.
+pub fn explode(array: &dyn Array) -> Result<(PrimitiveArray<u64>, Box<dyn Array>)> {
+    let lists = Lists::try_new(array)?;
+    let indices = lists
+        .valid_ranges()
+        .flat_map(|(row, (start, end))| std::iter::repeat(row as u64).take(end - start))
+        .collect::<Vec<_>>();
+    let values = gather_ranges(
+        lists.values,
+        lists.valid_ranges().map(|(_, range)| range),
+        indices.len(),
+    );
+    Ok((PrimitiveArray::from_vec(indices), values))
+}
+
+/// Returns the number of elements of each list of `array`, as `Int32` for
+/// [`DataType::List`] and [`DataType::FixedSizeList`] and as `Int64` for [`DataType::LargeList`].
+/// Null lists have a null length.
+/// # Errors
+/// This function errors iff `array` is not a list-like array.
+pub fn list_value_length(array: &dyn Array) -> Result<Box<dyn Array>> {
+    let lists = Lists::try_new(array)?;
+    let validity = lists.validity.cloned();
+    let lengths = lists.ranges.iter().map(|(start, end)| end - start);
+    Ok(match array.data_type().to_physical_type() {
+        PhysicalType::LargeList => Box::new(PrimitiveArray::<i64>::new(
+            DataType::Int64,
+            lengths.map(|x| x as i64).collect::<Vec<_>>().into(),
+            validity,
+        )),
+        _ => Box::new(PrimitiveArray::<i32>::new(
+            DataType::Int32,
+            lengths.map(|x| x as i32).collect::<Vec<_>>().into(),
+            validity,
+        )),
+    })
+}
+
+/// Resolves a possibly negative `index` into a list of `length` elements.
+#[inline]
+fn resolve_index(index: i64, length: usize) -> Option<usize> {
+    let index = if index < 0 {
+        length as i64 + index
+    } else {
+        index
+    };
+    (index >= 0 && (index as usize) < length).then_some(index as usize)
+}
+
+/// Returns the element at position `index` of each list of `array`.
+/// A negative `index` counts from the end of each list (i.e. `-1` is the last element).
+/// The result is null for null lists and for lists where `index` is out of bounds.
+/// # Example
+/// ```
+/// use re_arrow2::array::{Int32Array, ListArray, MutableListArray, MutablePrimitiveArray, TryExtend};
+/// use re_arrow2::compute::list::list_get;
+///
+/// let mut array = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+/// array.try_extend(vec![Some(vec![Some(1), Some(2)]), None, Some(vec![Some(3)])]).unwrap();
+/// let array: ListArray<i32> = array.into();
+///
+/// let result = list_get(&array, -1).unwrap();
+/// assert_eq!(result.as_ref(), &Int32Array::from(&[Some(2), None, Some(3)]) as &dyn re_arrow2::array::Array);
+/// ```
+/// # Errors
+/// This function errors iff `array` is not a list-like array.
+pub fn list_get(array: &dyn Array, index: i64) -> Result<Box<dyn Array>> {
+    let lists = Lists::try_new(array)?;
+
+    let mut growable = make_growable(&[lists.values], true, lists.ranges.len());
+    for (row, (start, end)) in lists.ranges.iter().enumerate() {
+        match resolve_index(index, end - start).filter(|_| lists.is_valid(row)) {
+            Some(index) => growable.extend(0, start + index, 1),
+            None => growable.extend_validity(1),
+        }
+    }
+    Ok(growable.as_box())
+}
+
+/// Returns a list array whose lists contain at most `length` elements of each list of
+/// `array` starting at position `start` (or all elements until the end of each list
+/// when `length` is `None`).
+/// A negative `start` counts from the end of each list.
+///
+/// The result has the same type as `array`, except for [`FixedSizeListArray`]s, that
+/// are returned as a [`DataType::List`].
+/// # Example
+/// ```
+/// use re_arrow2::array::{ListArray, MutableListArray, MutablePrimitiveArray, TryExtend};
+/// use re_arrow2::compute::list::list_slice;
+///
+/// let mut array = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+/// array.try_extend(vec![Some(vec![Some(1), Some(2), Some(3)]), None, Some(vec![Some(4)])]).unwrap();
+/// let array: ListArray<i32> = array.into();
+///
+/// let result = list_slice(&array, 1, Some(1)).unwrap();
+///
+/// let mut expected = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+/// expected.try_extend(vec![Some(vec![Some(2)]), None, Some(vec![])]).unwrap();
+/// let expected: ListArray<i32> = expected.into();
+/// assert_eq!(result.as_ref(), &expected as &dyn re_arrow2::array::Array);
+/// ```
+/// # Errors
+/// This function errors iff `array` is not a list-like array or the resulting offsets overflow.
+pub fn list_slice(array: &dyn Array, start: i64, length: Option<usize>) -> Result<Box<dyn Array>> {
+    let lists = Lists::try_new(array)?;
+
+    let ranges = lists
+        .ranges
+        .iter()
+        .enumerate()
+        .map(|(row, (list_start, list_end))| {
+            if !lists.is_valid(row) {
+                return (0, 0);
+            }
+            let list_length = list_end - list_start;
+            let offset = if start < 0 {
+                list_length.saturating_sub(start.unsigned_abs() as usize)
+            } else {
+                (start as usize).min(list_length)
+            };
+            let length = length.unwrap_or(usize::MAX).min(list_length - offset);
+            (list_start + offset, list_start + offset + length)
+        })
+        .collect::<Vec<_>>();
+
+    let lengths = ranges.iter().map(|(s, e)| e - s).collect::<Vec<_>>();
+    let values = gather_ranges(lists.values, ranges.into_iter(), lengths.iter().sum());
+    new_list(array, lists.field, lengths, values, false)
+}
+
+/// Returns a list array of the same type as `array` whose lists are sorted according
+/// to `options`.
+/// # Errors
+/// This function errors iff `array` is not a list-like array or its values cannot be sorted
+/// (see [`can_sort`](crate::compute::sort::can_sort)).
+pub fn list_sort(array: &dyn Array, options: &SortOptions) -> Result<Box<dyn Array>> {
+    let lists = Lists::try_new(array)?;
+
+    let capacity = lists.ranges.iter().map(|(s, e)| e - s).sum();
+    let mut growable = make_growable(&[lists.values], false, capacity);
+    for (row, (start, end)) in lists.ranges.iter().enumerate() {
+        if lists.is_valid(row) {
+            let list = lists.values.sliced(*start, end - start);
+            let indices = sort_to_indices::<u64>(list.as_ref(), options, None)?;
+            indices
+                .values()
+                .iter()
+                .for_each(|index| growable.extend(0, start + *index as usize, 1));
+        } else {
+            growable.extend(0, *start, end - start);
+        }
+    }
+    let lengths = lists.ranges.iter().map(|(s, e)| e - s).collect();
+    new_list(array, lists.field, lengths, growable.as_box(), true)
+}
+
+/// A numeric type whose lists can be reduced by [`list_sum`], [`list_min`] and [`list_max`].
+trait ListNative: NativeType + PartialOrd {
+    /// Returns `self + other`, or `None` if it overflows.
+    fn checked_sum(self, other: Self) -> Option<Self>;
+
+    /// Returns the minimum of `self` and `other`. As in [`crate::compute::aggregate`], `NaN`
+    /// is ignored unless both are `NaN`.
+    fn min_ignore_nan(self, other: Self) -> Self;
+
+    /// Returns the maximum of `self` and `other`. As in [`crate::compute::aggregate`], `NaN`
+    /// is ignored unless both are `NaN`.
+    fn max_ignore_nan(self, other: Self) -> Self;
+}
+
+macro_rules! impl_list_native_integer {
+    ($($type:ty),*) => {
+        $(
+            impl ListNative for $type {
+                #[inline]
+                fn checked_sum(self, other: Self) -> Option<Self> {
+                    self.checked_add(other)
+                }
+
+                #[inline]
+                fn min_ignore_nan(self, other: Self) -> Self {
+                    self.min(other)
+                }
+
+                #[inline]
+                fn max_ignore_nan(self, other: Self) -> Self {
+                    self.max(other)
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_list_native_float {
+    ($($type:ty),*) => {
+        $(
+            impl ListNative for $type {
+                #[inline]
+                fn checked_sum(self, other: Self) -> Option<Self> {
+                    Some(self + other)
+                }
+
+                #[inline]
+                fn min_ignore_nan(self, other: Self) -> Self {
+                    self.min(other)
+                }
+
+                #[inline]
+                fn max_ignore_nan(self, other: Self) -> Self {
+                    self.max(other)
+                }
+            }
+        )*
+    };
+}
+
+impl_list_native_integer!(i8, i16, i32, i64, i128, u8, u16, u32, u64);
+impl_list_native_float!(f32, f64);
+
+impl ListNative for f16 {
+    #[inline]
+    fn checked_sum(self, other: Self) -> Option<Self> {
+        Some(self + other)
+    }
+
+    // unlike `f32::min`, `f16::min` returns `NaN` when `self` is `NaN`
+    #[inline]
+    fn min_ignore_nan(self, other: Self) -> Self {
+        f16::from_f32(self.to_f32().min(other.to_f32()))
+    }
+
+    #[inline]
+    fn max_ignore_nan(self, other: Self) -> Self {
+        f16::from_f32(self.to_f32().max(other.to_f32()))
+    }
+}
+
+impl ListNative for i256 {
+    #[inline]
+    fn checked_sum(self, other: Self) -> Option<Self> {
+        self.0.checked_add(other.0).map(i256)
+    }
+
+    #[inline]
+    fn min_ignore_nan(self, other: Self) -> Self {
+        self.min(other)
+    }
+
+    #[inline]
+    fn max_ignore_nan(self, other: Self) -> Self {
+        self.max(other)
+    }
+}
+
+/// Reduces the valid values of each list with `op`, which returns `None` on overflow.
+fn reduce<T, F>(lists: &Lists, op: F) -> Result<Box<dyn Array>>
+where
+    T: NativeType,
+    F: Fn(T, T) -> Option<T>,
+{
+    let values = lists
+        .values
+        .as_any()
+        .downcast_ref::<PrimitiveArray<T>>()
+        .unwrap();
+    let reduced = lists
+        .ranges
+        .iter()
+        .enumerate()
+        .map(|(row, (start, end))| {
+            if !lists.is_valid(row) {
+                return Ok(None);
+            }
+            (*start..*end)
+                .filter(|i| values.is_valid(*i))
+                .map(|i| values.value(i))
+                .try_fold(None, |acc, x| match acc {
+                    None => Ok(Some(x)),
+                    Some(acc) => op(acc, x).map(Some).ok_or(Error::Overflow),
+                })
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let array = PrimitiveArray::<T>::from_trusted_len_iter(reduced.into_iter());
+    Ok(array.to(values.data_type().clone()).boxed())
+}
+
+/// Returns the [`PrimitiveType`] of the values of `lists`, if it is a [`ListNative`] type.
+fn list_native_type(lists: &Lists, name: &str) -> Result<PrimitiveType> {
+    match lists.values.data_type().to_physical_type() {
+        PhysicalType::Primitive(primitive)
+            if !matches!(
+                primitive,
+                PrimitiveType::DaysMs | PrimitiveType::MonthDayNano
+            ) =>
+        {
+            Ok(primitive)
+        }
+        other => Err(Error::InvalidArgumentError(format!(
+            "`{name}` does not support lists of {other:?}"
+        ))),
+    }
+}
+
+/// Returns the sum of the valid elements of each list of `array`.
+/// The result is null for null and empty lists, and for lists with only null elements.
+/// # Errors
+/// This function errors iff `array` is not a list-like array of numeric values or the sum of
+/// a list of integers overflows.
+pub fn list_sum(array: &dyn Array) -> Result<Box<dyn Array>> {
+    let lists = Lists::try_new(array)?;
+    let primitive = list_native_type(&lists, "list_sum")?;
+    crate::with_match_primitive_without_interval_type!(primitive, |$T| {
+        reduce::<$T, _>(&lists, |acc, x| acc.checked_sum(x))
+    })
+}
+
+/// Returns the minimum of the valid elements of each list of `array`.
+/// The result is null for null and empty lists, and for lists with only null elements.
+/// `NaN` is ignored unless all the valid elements of a list are `NaN`.
+/// # Errors
+/// This function errors iff `array` is not a list-like array of numeric values.
+pub fn list_min(array: &dyn Array) -> Result<Box<dyn Array>> {
+    let lists = Lists::try_new(array)?;
+    let primitive = list_native_type(&lists, "list_min")?;
+    crate::with_match_primitive_without_interval_type!(primitive, |$T| {
+        reduce::<$T, _>(&lists, |acc, x| Some(acc.min_ignore_nan(x)))
+    })
+}
+
+/// Returns the maximum of the valid elements of each list of `array`.
+/// The result is null for null and empty lists, and for lists with only null elements.
+/// `NaN` is ignored unless all the valid elements of a list are `NaN`.
+/// # Errors
+/// This function errors iff `array` is not a list-like array of numeric values.
+pub fn list_max(array: &dyn Array) -> Result<Box<dyn Array>> {
+    let lists = Lists::try_new(array)?;
+    let primitive = list_native_type(&lists, "list_max")?;
+    crate::with_match_primitive_without_interval_type!(primitive, |$T| {
+        reduce::<$T, _>(&lists, |acc, x| Some(acc.max_ignore_nan(x)))
+    })
+}
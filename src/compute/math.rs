@@ -0,0 +1,710 @@
+//! Defines elementwise math kernels (e.g. [`abs`], [`sqrt`], [`round`] and [`clip`]) for arrays
+//! of integers, floats (including [`f16`]) and decimals.
+//!
+//! All kernels propagate nulls. Floats follow IEEE 754: values outside the domain of a function
+//! (e.g. the square root of a negative number) return `NaN` instead of erroring, and `NaN`
+//! returns `NaN`. The kernels returning floats ([`sqrt`], [`exp`], [`sin`], ...) keep the type
+//! of float arrays and return [`DataType::Float64`] for integer and decimal arrays.
+use num_traits::AsPrimitive;
+
+use crate::{
+    array::{Array, BooleanArray, PrimitiveArray},
+    bitmap::{Bitmap, MutableBitmap},
+    compute::{
+        arithmetics::decimal,
+        arity::{binary, unary},
+        utils::check_same_len,
+    },
+    datatypes::DataType,
+    error::{Error, Result},
+    scalar::{PrimitiveScalar, Scalar},
+    types::{f16, i256, NativeType},
+};
+
+/// Returns whether the unary kernels of this module ([`abs`], [`sign`], [`sqrt`], [`round`],
+/// [`clip`], [`is_nan`], ...) support arrays of `data_type`.
+pub fn can_math(data_type: &DataType) -> bool {
+    use DataType::*;
+    matches!(
+        data_type.to_logical_type(),
+        Int8 | Int16
+            | Int32
+            | Int64
+            | UInt8
+            | UInt16
+            | UInt32
+            | UInt64
+            | Float16
+            | Float32
+            | Float64
+            | Decimal(_, _)
+            | Decimal256(_, _)
+    )
+}
+
+/// Returns whether [`atan2`] supports arrays of `lhs` and `rhs`.
+pub fn can_atan2(lhs: &DataType, rhs: &DataType) -> bool {
+    can_math(lhs) && lhs.to_logical_type() == rhs.to_logical_type()
+}
+
+#[inline]
+fn downcast<T: NativeType>(array: &dyn Array) -> &PrimitiveArray<T> {
+    array.as_any().downcast_ref().unwrap()
+}
+
+fn unsupported(name: &str, data_type: &DataType) -> Error {
+    Error::InvalidArgumentError(format!(
+        "The math kernel \"{name}\" does not support arrays of type {data_type:?}"
+    ))
+}
+
+/// Converts an integer or decimal array to an array of `f64`.
+fn to_float64(array: &dyn Array, name: &str) -> Result<PrimitiveArray<f64>> {
+    macro_rules! cast {
+        ($ty:ty) => {
+            unary(downcast::<$ty>(array), |x| x.as_(), DataType::Float64)
+        };
+    }
+
+    Ok(match array.data_type().to_logical_type() {
+        DataType::Int8 => cast!(i8),
+        DataType::Int16 => cast!(i16),
+        DataType::Int32 => cast!(i32),
+        DataType::Int64 => cast!(i64),
+        DataType::UInt8 => cast!(u8),
+        DataType::UInt16 => cast!(u16),
+        DataType::UInt32 => cast!(u32),
+        DataType::UInt64 => cast!(u64),
+        DataType::Decimal(_, scale) => {
+            let factor = 10f64.powi(*scale as i32);
+            unary(
+                downcast::<i128>(array),
+                |x| x as f64 / factor,
+                DataType::Float64,
+            )
+        }
+        DataType::Decimal256(_, scale) => {
+            let factor = 10f64.powi(*scale as i32);
+            unary(
+                downcast::<i256>(array),
+                |x| x.0.as_f64() / factor,
+                DataType::Float64,
+            )
+        }
+        other => return Err(unsupported(name, other)),
+    })
+}
+
+fn float_unary(
+    array: &dyn Array,
+    name: &str,
+    op32: fn(f32) -> f32,
+    op64: fn(f64) -> f64,
+) -> Result<Box<dyn Array>> {
+    let data_type = array.data_type().clone();
+    Ok(match data_type.to_logical_type() {
+        DataType::Float16 => unary(
+            downcast::<f16>(array),
+            |x| f16::from_f32(op32(x.to_f32())),
+            data_type,
+        )
+        .boxed(),
+        DataType::Float32 => unary(downcast::<f32>(array), op32, data_type).boxed(),
+        DataType::Float64 => unary(downcast::<f64>(array), op64, data_type).boxed(),
+        _ => unary(&to_float64(array, name)?, op64, DataType::Float64).boxed(),
+    })
+}
+
+macro_rules! float_function {
+    ($(#[$meta:meta])* $name:ident) => {
+        $(#[$meta])*
+        /// # Errors
+        /// This function errors iff [`can_math`] is false for the data type of `array`.
+        pub fn $name(array: &dyn Array) -> Result<Box<dyn Array>> {
+            float_unary(array, stringify!($name), f32::$name, f64::$name)
+        }
+    };
+}
+
+float_function!(
+    /// Returns the square root of each value of `array`, `NaN` for negative values.
+    sqrt
+);
+float_function!(
+    /// Returns the cube root of each value of `array`.
+    cbrt
+);
+float_function!(
+    /// Returns `e` to the power of each value of `array`.
+    exp
+);
+float_function!(
+    /// Returns the natural logarithm of each value of `array`, `-inf` for zero and `NaN` for
+    /// negative values.
+    ln
+);
+float_function!(
+    /// Returns the base 2 logarithm of each value of `array`, `-inf` for zero and `NaN` for
+    /// negative values.
+    log2
+);
+float_function!(
+    /// Returns the base 10 logarithm of each value of `array`, `-inf` for zero and `NaN` for
+    /// negative values.
+    log10
+);
+float_function!(
+    /// Returns the sine of each value of `array`, in radians.
+    sin
+);
+float_function!(
+    /// Returns the cosine of each value of `array`, in radians.
+    cos
+);
+float_function!(
+    /// Returns the tangent of each value of `array`, in radians.
+    tan
+);
+float_function!(
+    /// Returns the arcsine of each value of `array` in radians, `NaN` outside of `[-1, 1]`.
+    asin
+);
+float_function!(
+    /// Returns the arccosine of each value of `array` in radians, `NaN` outside of `[-1, 1]`.
+    acos
+);
+float_function!(
+    /// Returns the arctangent of each value of `array`, in radians.
+    atan
+);
+
+/// Returns the four quadrant arctangent of each pair of values of `y` and `x`, in radians.
+/// # Errors
+/// This function errors iff [`can_atan2`] is false for the data types of `y` and `x`, or the
+/// arrays have different lengths.
+/// # Example
+/// ```
+/// use re_arrow2::array::Float64Array;
+/// use re_arrow2::compute::math::atan2;
+///
+/// let y = Float64Array::from(&[Some(1.0), Some(0.0), None]);
+/// let x = Float64Array::from(&[Some(0.0), Some(-1.0), Some(1.0)]);
+/// let result = atan2(&y, &x).unwrap();
+/// let expected = Float64Array::from(&[
+///     Some(std::f64::consts::FRAC_PI_2),
+///     Some(std::f64::consts::PI),
+///     None,
+/// ]);
+/// assert_eq!(expected, result.as_ref());
+/// ```
+pub fn atan2(y: &dyn Array, x: &dyn Array) -> Result<Box<dyn Array>> {
+    if !can_atan2(y.data_type(), x.data_type()) {
+        return Err(Error::InvalidArgumentError(format!(
+            "The math kernel \"atan2\" does not support arrays of types {:?} and {:?}",
+            y.data_type(),
+            x.data_type()
+        )));
+    }
+    check_same_len(y, x)?;
+
+    let data_type = y.data_type().clone();
+    Ok(match data_type.to_logical_type() {
+        DataType::Float16 => binary(downcast::<f16>(y), downcast::<f16>(x), data_type, |y, x| {
+            f16::from_f32(y.to_f32().atan2(x.to_f32()))
+        })
+        .boxed(),
+        DataType::Float32 => binary(
+            downcast::<f32>(y),
+            downcast::<f32>(x),
+            data_type,
+            f32::atan2,
+        )
+        .boxed(),
+        DataType::Float64 => binary(
+            downcast::<f64>(y),
+            downcast::<f64>(x),
+            data_type,
+            f64::atan2,
+        )
+        .boxed(),
+        _ => binary(
+            &to_float64(y, "atan2")?,
+            &to_float64(x, "atan2")?,
+            DataType::Float64,
+            f64::atan2,
+        )
+        .boxed(),
+    })
+}
+
+/// Returns the absolute value of each value of `array`, with the same [`DataType`].
+/// # Errors
+/// This function errors iff [`can_math`] is false for the data type of `array`, or
+/// a (non-null) value of a signed integer array is its minimum, whose absolute value overflows.
+/// # Example
+/// ```
+/// use re_arrow2::array::Int32Array;
+/// use re_arrow2::compute::math::abs;
+///
+/// let array = Int32Array::from(&[Some(-1), Some(2), None]);
+/// let result = abs(&array).unwrap();
+/// assert_eq!(Int32Array::from(&[Some(1), Some(2), None]), result.as_ref());
+///
+/// assert!(abs(&Int32Array::from_slice([i32::MIN])).is_err());
+/// ```
+pub fn abs(array: &dyn Array) -> Result<Box<dyn Array>> {
+    let data_type = array.data_type().clone();
+
+    macro_rules! signed {
+        ($ty:ty) => {{
+            let array = downcast::<$ty>(array);
+            if array.iter().flatten().any(|x| *x == <$ty>::MIN) {
+                return Err(Error::Overflow);
+            }
+            unary(array, |x| x.wrapping_abs(), data_type).boxed()
+        }};
+    }
+
+    Ok(match data_type.to_logical_type() {
+        DataType::Int8 => signed!(i8),
+        DataType::Int16 => signed!(i16),
+        DataType::Int32 => signed!(i32),
+        DataType::Int64 => signed!(i64),
+        DataType::UInt8 | DataType::UInt16 | DataType::UInt32 | DataType::UInt64 => {
+            array.to_boxed()
+        }
+        DataType::Float16 => unary(
+            downcast::<f16>(array),
+            |x| f16::from_bits(x.to_bits() & 0x7fff),
+            data_type,
+        )
+        .boxed(),
+        DataType::Float32 => unary(downcast::<f32>(array), f32::abs, data_type).boxed(),
+        DataType::Float64 => unary(downcast::<f64>(array), f64::abs, data_type).boxed(),
+        // decimals are bounded by their precision and so their absolute value never overflows
+        DataType::Decimal(_, _) => {
+            unary(downcast::<i128>(array), |x| x.wrapping_abs(), data_type).boxed()
+        }
+        DataType::Decimal256(_, _) => unary(
+            downcast::<i256>(array),
+            |x| i256(x.0.wrapping_abs()),
+            data_type,
+        )
+        .boxed(),
+        other => return Err(unsupported("abs", other)),
+    })
+}
+
+/// Returns the sign of each value of `array`: `-1`, `0` or `1` with the same [`DataType`] for
+/// integers and floats, and as [`DataType::Int8`] for decimals. Zeros (including `-0.0`) and
+/// `NaN` are returned unchanged.
+/// # Errors
+/// This function errors iff [`can_math`] is false for the data type of `array`.
+pub fn sign(array: &dyn Array) -> Result<Box<dyn Array>> {
+    let data_type = array.data_type().clone();
+
+    macro_rules! signed {
+        ($ty:ty) => {
+            unary(downcast::<$ty>(array), |x| x.signum(), data_type).boxed()
+        };
+    }
+    macro_rules! unsigned {
+        ($ty:ty) => {
+            unary(downcast::<$ty>(array), |x| <$ty>::from(x != 0), data_type).boxed()
+        };
+    }
+    macro_rules! float {
+        ($ty:ty) => {
+            unary(
+                downcast::<$ty>(array),
+                |x| {
+                    if x == 0.0 || x.is_nan() {
+                        x
+                    } else {
+                        x.signum()
+                    }
+                },
+                data_type,
+            )
+            .boxed()
+        };
+    }
+
+    Ok(match data_type.to_logical_type() {
+        DataType::Int8 => signed!(i8),
+        DataType::Int16 => signed!(i16),
+        DataType::Int32 => signed!(i32),
+        DataType::Int64 => signed!(i64),
+        DataType::UInt8 => unsigned!(u8),
+        DataType::UInt16 => unsigned!(u16),
+        DataType::UInt32 => unsigned!(u32),
+        DataType::UInt64 => unsigned!(u64),
+        DataType::Float16 => unary(
+            downcast::<f16>(array),
+            |x| {
+                if x.to_f32() == 0.0 || x.is_nan() {
+                    x
+                } else {
+                    x.signum()
+                }
+            },
+            data_type,
+        )
+        .boxed(),
+        DataType::Float32 => float!(f32),
+        DataType::Float64 => float!(f64),
+        DataType::Decimal(_, _) => unary(
+            downcast::<i128>(array),
+            |x| x.signum() as i8,
+            DataType::Int8,
+        )
+        .boxed(),
+        DataType::Decimal256(_, _) => unary(
+            downcast::<i256>(array),
+            |x| match x.0.is_negative() {
+                true => -1,
+                false => i8::from(x.0 != ethnum::I256::ZERO),
+            },
+            DataType::Int8,
+        )
+        .boxed(),
+        other => return Err(unsupported("sign", other)),
+    })
+}
+
+/// Rounds `x` to `ndigits` decimal digits, with ties rounded away from zero.
+fn round_float(x: f64, ndigits: i32) -> f64 {
+    if !x.is_finite() {
+        return x;
+    }
+    if ndigits >= 0 {
+        let factor = 10f64.powi(ndigits);
+        let rounded = (x * factor).round() / factor;
+        // `x * factor` only overflows when `x` has no digits to round
+        if rounded.is_finite() {
+            rounded
+        } else {
+            x
+        }
+    } else {
+        let factor = 10f64.powi(-ndigits);
+        if factor.is_finite() {
+            (x / factor).round() * factor
+        } else {
+            0.0
+        }
+    }
+}
+
+/// Rounds `x` to a multiple of `10^-ndigits` for a negative `ndigits`, with ties rounded away
+/// from zero, returning `None` if the result overflows.
+fn round_integer(x: i128, ndigits: i32) -> Option<i128> {
+    let Some(factor) = 10i128.checked_pow(ndigits.unsigned_abs()) else {
+        // `x` is smaller than `factor`
+        return Some(0);
+    };
+    let quotient = x / factor;
+    let remainder = x % factor;
+    let quotient = if remainder.abs() * 2 >= factor {
+        quotient + x.signum()
+    } else {
+        quotient
+    };
+    quotient.checked_mul(factor)
+}
+
+/// Rounds each value of `array` to `ndigits` decimal digits, with ties rounded away from zero.
+/// A negative `ndigits` rounds to the left of the decimal point (e.g. to tens for `-1`). The
+/// result has the same [`DataType`] as `array`.
+///
+/// Decimals are rounded by [`decimal::round`].
+/// # Errors
+/// This function errors iff:
+/// * [`can_math`] is false for the data type of `array`
+/// * `ndigits` is negative and `array` is a decimal
+/// * a rounded (non-null) value overflows its type or precision
+/// # Example
+/// ```
+/// use re_arrow2::array::{Float64Array, Int32Array};
+/// use re_arrow2::compute::math::round;
+///
+/// let array = Float64Array::from(&[Some(1.25), Some(-2.5), None]);
+/// let result = round(&array, 1).unwrap();
+/// assert_eq!(Float64Array::from(&[Some(1.3), Some(-2.5), None]), result.as_ref());
+///
+/// let array = Int32Array::from(&[Some(149), Some(-150), None]);
+/// let result = round(&array, -2).unwrap();
+/// assert_eq!(Int32Array::from(&[Some(100), Some(-200), None]), result.as_ref());
+/// ```
+pub fn round(array: &dyn Array, ndigits: i32) -> Result<Box<dyn Array>> {
+    let data_type = array.data_type().clone();
+
+    macro_rules! integer {
+        ($ty:ty) => {{
+            let array = downcast::<$ty>(array);
+            if ndigits >= 0 {
+                return Ok(array.clone().boxed());
+            }
+            let op = |x: $ty| {
+                round_integer(i128::from(x), ndigits).and_then(|x| <$ty>::try_from(x).ok())
+            };
+            if array.iter().flatten().any(|x| op(*x).is_none()) {
+                return Err(Error::Overflow);
+            }
+            unary(array, |x| op(x).unwrap_or_default(), data_type).boxed()
+        }};
+    }
+
+    Ok(match data_type.to_logical_type() {
+        DataType::Int8 => integer!(i8),
+        DataType::Int16 => integer!(i16),
+        DataType::Int32 => integer!(i32),
+        DataType::Int64 => integer!(i64),
+        DataType::UInt8 => integer!(u8),
+        DataType::UInt16 => integer!(u16),
+        DataType::UInt32 => integer!(u32),
+        DataType::UInt64 => integer!(u64),
+        DataType::Float16 => unary(
+            downcast::<f16>(array),
+            |x| f16::from_f64(round_float(x.to_f64(), ndigits)),
+            data_type,
+        )
+        .boxed(),
+        DataType::Float32 => unary(
+            downcast::<f32>(array),
+            |x| round_float(x as f64, ndigits) as f32,
+            data_type,
+        )
+        .boxed(),
+        DataType::Float64 => unary(
+            downcast::<f64>(array),
+            |x| round_float(x, ndigits),
+            data_type,
+        )
+        .boxed(),
+        DataType::Decimal(_, _) | DataType::Decimal256(_, _) => {
+            let ndigits = usize::try_from(ndigits).map_err(|_| {
+                Error::InvalidArgumentError(
+                    "Decimals can only be rounded to a non-negative number of digits".to_string(),
+                )
+            })?;
+            return decimal::round(array, ndigits);
+        }
+        other => return Err(unsupported("round", other)),
+    })
+}
+
+fn round_integral(
+    array: &dyn Array,
+    name: &str,
+    op32: fn(f32) -> f32,
+    op64: fn(f64) -> f64,
+    op_decimal: fn(&dyn Array) -> Result<Box<dyn Array>>,
+) -> Result<Box<dyn Array>> {
+    let data_type = array.data_type().clone();
+    Ok(match data_type.to_logical_type() {
+        DataType::Int8
+        | DataType::Int16
+        | DataType::Int32
+        | DataType::Int64
+        | DataType::UInt8
+        | DataType::UInt16
+        | DataType::UInt32
+        | DataType::UInt64 => array.to_boxed(),
+        DataType::Float16 => unary(
+            downcast::<f16>(array),
+            |x| f16::from_f32(op32(x.to_f32())),
+            data_type,
+        )
+        .boxed(),
+        DataType::Float32 => unary(downcast::<f32>(array), op32, data_type).boxed(),
+        DataType::Float64 => unary(downcast::<f64>(array), op64, data_type).boxed(),
+        DataType::Decimal(_, _) | DataType::Decimal256(_, _) => return op_decimal(array),
+        other => return Err(unsupported(name, other)),
+    })
+}
+
+/// Rounds each value of `array` down to the nearest integer. The result has the same
+/// [`DataType`] as `array`, and integer arrays are returned unchanged.
+/// # Errors
+/// This function errors iff [`can_math`] is false for the data type of `array`, or a decimal
+/// value does not fit in its precision after rounding.
+pub fn floor(array: &dyn Array) -> Result<Box<dyn Array>> {
+    round_integral(array, "floor", f32::floor, f64::floor, decimal::floor)
+}
+
+/// Rounds each value of `array` up to the nearest integer. The result has the same
+/// [`DataType`] as `array`, and integer arrays are returned unchanged.
+/// # Errors
+/// This function errors iff [`can_math`] is false for the data type of `array`, or a decimal
+/// value does not fit in its precision after rounding.
+pub fn ceil(array: &dyn Array) -> Result<Box<dyn Array>> {
+    round_integral(array, "ceil", f32::ceil, f64::ceil, decimal::ceil)
+}
+
+/// Rounds each value of `array` towards zero to the nearest integer. The result has the same
+/// [`DataType`] as `array`, and integer arrays are returned unchanged.
+/// # Errors
+/// This function errors iff [`can_math`] is false for the data type of `array`.
+pub fn trunc(array: &dyn Array) -> Result<Box<dyn Array>> {
+    round_integral(array, "trunc", f32::trunc, f64::trunc, decimal::trunc)
+}
+
+/// Limits each value of `array` to the closed interval `[min, max]`, where a `None` bound is
+/// unbounded. `NaN` is returned unchanged.
+/// # Example
+/// ```
+/// use re_arrow2::array::Int32Array;
+/// use re_arrow2::compute::math::clip_primitive;
+///
+/// let array = Int32Array::from(&[Some(-5), Some(3), Some(10), None]);
+/// let result = clip_primitive(&array, Some(0), Some(5));
+/// assert_eq!(result, Int32Array::from(&[Some(0), Some(3), Some(5), None]));
+/// ```
+pub fn clip_primitive<T: NativeType + PartialOrd>(
+    array: &PrimitiveArray<T>,
+    min: Option<T>,
+    max: Option<T>,
+) -> PrimitiveArray<T> {
+    unary(
+        array,
+        |x| {
+            let x = match min {
+                Some(min) if x < min => min,
+                _ => x,
+            };
+            match max {
+                Some(max) if x > max => max,
+                _ => x,
+            }
+        },
+        array.data_type().clone(),
+    )
+}
+
+/// Limits each value of `array` to the closed interval `[min, max]`, where a null bound is
+/// unbounded. See [`clip_primitive`].
+/// # Errors
+/// This function errors iff [`can_math`] is false for the data type of `array`, the bounds do not
+/// have the data type of `array`, or `min` is larger than `max`.
+pub fn clip(array: &dyn Array, min: &dyn Scalar, max: &dyn Scalar) -> Result<Box<dyn Array>> {
+    if !can_math(array.data_type()) {
+        return Err(unsupported("clip", array.data_type()));
+    }
+    if min.data_type() != array.data_type() || max.data_type() != array.data_type() {
+        return Err(Error::InvalidArgumentError(format!(
+            "The bounds of \"clip\" must be of type {:?}",
+            array.data_type()
+        )));
+    }
+
+    macro_rules! clip {
+        ($ty:ty) => {{
+            let bound = |scalar: &dyn Scalar| {
+                *scalar
+                    .as_any()
+                    .downcast_ref::<PrimitiveScalar<$ty>>()
+                    .unwrap()
+                    .value()
+            };
+            let (min, max) = (bound(min), bound(max));
+            if let (Some(min), Some(max)) = (min, max) {
+                if min > max {
+                    return Err(Error::InvalidArgumentError(
+                        "The lower bound of \"clip\" must not be larger than its upper bound"
+                            .to_string(),
+                    ));
+                }
+            }
+            clip_primitive(downcast::<$ty>(array), min, max).boxed()
+        }};
+    }
+
+    Ok(match array.data_type().to_logical_type() {
+        DataType::Int8 => clip!(i8),
+        DataType::Int16 => clip!(i16),
+        DataType::Int32 => clip!(i32),
+        DataType::Int64 => clip!(i64),
+        DataType::UInt8 => clip!(u8),
+        DataType::UInt16 => clip!(u16),
+        DataType::UInt32 => clip!(u32),
+        DataType::UInt64 => clip!(u64),
+        DataType::Float16 => clip!(f16),
+        DataType::Float32 => clip!(f32),
+        DataType::Float64 => clip!(f64),
+        DataType::Decimal(_, _) => clip!(i128),
+        DataType::Decimal256(_, _) => clip!(i256),
+        _ => unreachable!(),
+    })
+}
+
+fn float_predicate(
+    array: &dyn Array,
+    name: &str,
+    op16: fn(f16) -> bool,
+    op32: fn(f32) -> bool,
+    op64: fn(f64) -> bool,
+    integer: bool,
+) -> Result<BooleanArray> {
+    macro_rules! float {
+        ($ty:ty, $op:expr) => {
+            Bitmap::from_trusted_len_iter(downcast::<$ty>(array).values().iter().map(|x| $op(*x)))
+        };
+    }
+
+    let values = match array.data_type().to_logical_type() {
+        DataType::Float16 => float!(f16, op16),
+        DataType::Float32 => float!(f32, op32),
+        DataType::Float64 => float!(f64, op64),
+        data_type if can_math(data_type) => {
+            if integer {
+                MutableBitmap::from_len_set(array.len()).into()
+            } else {
+                Bitmap::new_zeroed(array.len())
+            }
+        }
+        other => return Err(unsupported(name, other)),
+    };
+    Ok(BooleanArray::new(
+        DataType::Boolean,
+        values,
+        array.validity().cloned(),
+    ))
+}
+
+macro_rules! float_predicate {
+    ($(#[$meta:meta])* $name:ident, $integer:expr) => {
+        $(#[$meta])*
+        /// # Errors
+        /// This function errors iff [`can_math`] is false for the data type of `array`.
+        pub fn $name(array: &dyn Array) -> Result<BooleanArray> {
+            float_predicate(
+                array,
+                stringify!($name),
+                f16::$name,
+                f32::$name,
+                f64::$name,
+                $integer,
+            )
+        }
+    };
+}
+
+float_predicate!(
+    /// Returns whether each value of `array` is `NaN`, which is always false for integers and
+    /// decimals.
+    is_nan,
+    false
+);
+float_predicate!(
+    /// Returns whether each value of `array` is neither infinite nor `NaN`, which is always true
+    /// for integers and decimals.
+    is_finite,
+    true
+);
+float_predicate!(
+    /// Returns whether each value of `array` is positive or negative infinity, which is always
+    /// false for integers and decimals.
+    is_infinite,
+    false
+);
@@ -40,6 +40,15 @@ pub mod concatenate;
 #[cfg(feature = "compute_contains")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_contains")))]
 pub mod contains;
+#[cfg(feature = "compute_cumulative")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_cumulative")))]
+pub mod cumulative;
+#[cfg(feature = "compute_dictionary")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_dictionary")))]
+pub mod dictionary;
+#[cfg(feature = "compute_fill_null")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_fill_null")))]
+pub mod fill_null;
 #[cfg(feature = "compute_filter")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_filter")))]
 pub mod filter;
@@ -49,6 +58,12 @@ pub mod hash;
 #[cfg(feature = "compute_if_then_else")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_if_then_else")))]
 pub mod if_then_else;
+#[cfg(feature = "compute_interleave")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_interleave")))]
+pub mod interleave;
+#[cfg(feature = "compute_json")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_json")))]
+pub mod json;
 #[cfg(feature = "compute_length")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_length")))]
 pub mod length;
@@ -58,15 +73,27 @@ pub mod like;
 #[cfg(feature = "compute_limit")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_limit")))]
 pub mod limit;
+#[cfg(feature = "compute_list")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_list")))]
+pub mod list;
+#[cfg(feature = "compute_math")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_math")))]
+pub mod math;
 #[cfg(feature = "compute_merge_sort")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_merge_sort")))]
 pub mod merge_sort;
+#[cfg(feature = "compute_nested")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_nested")))]
+pub mod nested;
 #[cfg(feature = "compute_nullif")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_nullif")))]
 pub mod nullif;
 #[cfg(feature = "compute_partition")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_partition")))]
 pub mod partition;
+#[cfg(feature = "compute_rank")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_rank")))]
+pub mod rank;
 #[cfg(feature = "compute_regex_match")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_regex_match")))]
 pub mod regex_match;
@@ -82,6 +109,9 @@ pub mod take;
 #[cfg(feature = "compute_temporal")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_temporal")))]
 pub mod temporal;
+#[cfg(feature = "compute_unique")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_unique")))]
+pub mod unique;
 #[cfg(feature = "compute_utf8")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_utf8")))]
 pub mod utf8;
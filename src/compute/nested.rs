@@ -0,0 +1,236 @@
+//! Contains operators over nested arrays: [`StructArray`]s ([`struct_field`],
+//! [`struct_flatten`] and [`struct_from_columns`]), [`MapArray`]s ([`map_keys`], [`map_values`]
+//! and [`map_get`]) and [`UnionArray`]s ([`union_variant`] and [`union_to_struct`]).
+//!
+//! All operators accept sliced arrays. Values extracted from a container are null wherever
+//! the container is null.
+use std::sync::Arc;
+
+use crate::{
+    array::{growable::make_growable, Array, ListArray, MapArray, StructArray, UnionArray},
+    bitmap::Bitmap,
+    compute::{comparison::eq_scalar, utils::combine_validities},
+    datatypes::{DataType, Field},
+    error::{Error, Result},
+    scalar::Scalar,
+};
+
+fn as_struct(array: &dyn Array) -> Result<&StructArray> {
+    array.as_any().downcast_ref().ok_or_else(|| {
+        Error::InvalidArgumentError(format!(
+            "Expected a struct array, got {:?}",
+            array.data_type()
+        ))
+    })
+}
+
+fn as_map(array: &dyn Array) -> Result<&MapArray> {
+    array.as_any().downcast_ref().ok_or_else(|| {
+        Error::InvalidArgumentError(format!("Expected a map array, got {:?}", array.data_type()))
+    })
+}
+
+fn as_union(array: &dyn Array) -> Result<&UnionArray> {
+    array.as_any().downcast_ref().ok_or_else(|| {
+        Error::InvalidArgumentError(format!(
+            "Expected a union array, got {:?}",
+            array.data_type()
+        ))
+    })
+}
+
+/// Returns `child` with the validity of its parent applied to it.
+fn with_parent_validity(child: &dyn Array, parent: Option<&Bitmap>) -> Box<dyn Array> {
+    if parent.is_none() {
+        return child.to_boxed();
+    }
+    child.with_validity(combine_validities(child.validity(), parent))
+}
+
+/// Returns the field `name` of the [`StructArray`] `array`, null wherever `array` is null.
+/// # Example
+/// ```
+/// use std::sync::Arc;
+/// use re_arrow2::array::{Array, Int32Array, StructArray};
+/// use re_arrow2::compute::nested::struct_field;
+/// use re_arrow2::datatypes::{DataType, Field};
+///
+/// let array = StructArray::try_new(
+///     DataType::Struct(Arc::new(vec![Field::new("a", DataType::Int32, true)])),
+///     vec![Int32Array::from_slice([1, 2]).boxed()],
+///     Some([true, false].into()),
+/// )
+/// .unwrap();
+///
+/// let result = struct_field(&array, "a").unwrap();
+/// assert_eq!(Int32Array::from(&[Some(1), None]), result.as_ref());
+/// ```
+/// # Errors
+/// This function errors iff `array` is not a [`StructArray`] or it has no field `name`.
+pub fn struct_field(array: &dyn Array, name: &str) -> Result<Box<dyn Array>> {
+    let array = as_struct(array)?;
+    let index = array
+        .fields()
+        .iter()
+        .position(|field| field.name == name)
+        .ok_or_else(|| {
+            Error::InvalidArgumentError(format!("The struct has no field \"{name}\""))
+        })?;
+    Ok(with_parent_validity(
+        array.values()[index].as_ref(),
+        array.validity(),
+    ))
+}
+
+/// Returns the fields of the [`StructArray`] `array` (in the order of its [`Field`]s), each
+/// null wherever `array` is null.
+/// # Errors
+/// This function errors iff `array` is not a [`StructArray`].
+pub fn struct_flatten(array: &dyn Array) -> Result<Vec<Box<dyn Array>>> {
+    let array = as_struct(array)?;
+    Ok(array
+        .values()
+        .iter()
+        .map(|child| with_parent_validity(child.as_ref(), array.validity()))
+        .collect())
+}
+
+/// Returns a [`StructArray`] whose fields are `columns`, named `names`, with `validity`.
+/// All fields are nullable.
+/// # Errors
+/// This function errors iff:
+/// * `names` and `columns` have different lengths
+/// * `columns` is empty or the columns have different lengths
+/// * `validity` is set and its length is different from the length of the columns
+pub fn struct_from_columns(
+    names: &[&str],
+    columns: Vec<Box<dyn Array>>,
+    validity: Option<Bitmap>,
+) -> Result<StructArray> {
+    if names.len() != columns.len() {
+        return Err(Error::InvalidArgumentError(format!(
+            "A struct with {} names can't have {} columns",
+            names.len(),
+            columns.len()
+        )));
+    }
+    let fields = names
+        .iter()
+        .zip(columns.iter())
+        .map(|(name, column)| Field::new(*name, column.data_type().clone(), true))
+        .collect();
+    StructArray::try_new(DataType::Struct(Arc::new(fields)), columns, validity)
+}
+
+/// Returns a [`ListArray`] with the field at `index` of the entries of each map.
+fn map_entries_field(array: &dyn Array, index: usize) -> Result<Box<dyn Array>> {
+    let array = as_map(array)?;
+    let entries = as_struct(array.field().as_ref())?;
+    let field = entries.fields()[index].clone();
+    let values = entries.values()[index].clone();
+    Ok(ListArray::<i32>::try_new(
+        DataType::List(Arc::new(field)),
+        array.offsets().clone(),
+        values,
+        array.validity().cloned(),
+    )?
+    .boxed())
+}
+
+/// Returns a [`ListArray`] with the keys of each map of the [`MapArray`] `array`.
+/// Null maps are null lists.
+/// # Errors
+/// This function errors iff `array` is not a [`MapArray`].
+pub fn map_keys(array: &dyn Array) -> Result<Box<dyn Array>> {
+    map_entries_field(array, 0)
+}
+
+/// Returns a [`ListArray`] with the values of each map of the [`MapArray`] `array`.
+/// Null maps are null lists.
+/// # Errors
+/// This function errors iff `array` is not a [`MapArray`].
+pub fn map_values(array: &dyn Array) -> Result<Box<dyn Array>> {
+    map_entries_field(array, 1)
+}
+
+/// Returns the value of the first entry whose key equals `key` in each map of the
+/// [`MapArray`] `array`. The result is null for null maps and for maps without `key`.
+/// # Errors
+/// This function errors iff `array` is not a [`MapArray`] or the keys can't be compared
+/// to `key` (see [`crate::compute::comparison::can_eq_scalar`]).
+pub fn map_get(array: &dyn Array, key: &dyn Scalar) -> Result<Box<dyn Array>> {
+    let array = as_map(array)?;
+    let entries = as_struct(array.field().as_ref())?;
+    let (keys, values) = (entries.values()[0].as_ref(), entries.values()[1].as_ref());
+    let matches = eq_scalar(keys, key)?;
+
+    let mut growable = make_growable(&[values], true, array.len());
+    for row in 0..array.len() {
+        let (start, end) = array.offsets().start_end(row);
+        let found = array
+            .is_valid(row)
+            .then(|| (start..end).find(|&i| matches.is_valid(i) && matches.value(i)))
+            .flatten();
+        match found {
+            Some(index) => growable.extend(0, index, 1),
+            None => growable.extend_validity(1),
+        }
+    }
+    Ok(growable.as_box())
+}
+
+/// Returns the index of the field of `data_type` (a [`DataType::Union`]) with `type_id`.
+fn union_field_index(data_type: &DataType, type_id: i8) -> Result<usize> {
+    let index = match data_type.to_logical_type() {
+        DataType::Union(fields, Some(ids), _) => ids
+            .iter()
+            .position(|id| *id == type_id as i32)
+            .filter(|index| *index < fields.len()),
+        DataType::Union(fields, None, _) => usize::try_from(type_id)
+            .ok()
+            .filter(|id| *id < fields.len()),
+        _ => unreachable!(),
+    };
+    index.ok_or_else(|| {
+        Error::InvalidArgumentError(format!("The union has no variant with type id {type_id}"))
+    })
+}
+
+/// Returns the values of `array` at the field `index`, null in the slots of other fields.
+fn union_field(array: &UnionArray, index: usize) -> Box<dyn Array> {
+    let field = array.fields()[index].as_ref();
+    let mut growable = make_growable(&[field], true, array.len());
+    for row in 0..array.len() {
+        match array.index(row) {
+            (field_index, slot) if field_index == index => growable.extend(0, slot, 1),
+            _ => growable.extend_validity(1),
+        }
+    }
+    growable.as_box()
+}
+
+/// Returns the values of the variant `type_id` of the [`UnionArray`] `array`, null in the
+/// slots of the other variants. The result has the type of the variant's field.
+/// # Errors
+/// This function errors iff `array` is not a [`UnionArray`] or it has no variant `type_id`.
+pub fn union_variant(array: &dyn Array, type_id: i8) -> Result<Box<dyn Array>> {
+    let array = as_union(array)?;
+    let index = union_field_index(array.data_type(), type_id)?;
+    Ok(union_field(array, index))
+}
+
+/// Returns a [`StructArray`] with one nullable field per variant of the [`UnionArray`] `array`,
+/// where each row has the value of its variant set and the other fields null.
+/// # Errors
+/// This function errors iff `array` is not a [`UnionArray`].
+pub fn union_to_struct(array: &dyn Array) -> Result<StructArray> {
+    let array = as_union(array)?;
+    let fields = UnionArray::get_fields(array.data_type())
+        .iter()
+        .map(|field| Field::new(field.name.clone(), field.data_type().clone(), true))
+        .collect();
+    let values = (0..array.fields().len())
+        .map(|index| union_field(array, index))
+        .collect();
+    StructArray::try_new(DataType::Struct(Arc::new(fields)), values, None)
+}
@@ -15,10 +15,15 @@
 // specific language governing permissions and limitations
 // under the License.
 
-//! Defines partition kernel for [`crate::array::Array`]
+//! Defines partition kernels for [`crate::array::Array`]: [`lexicographical_partition_ranges`]
+//! over sorted columns and [`hash_partition`] of a [`Chunk`].
 
 use crate::array::ord::DynComparator;
+use crate::array::{Array, PrimitiveArray};
+use crate::chunk::Chunk;
+use crate::compute::hash::{hash_columns, HashAlgorithm};
 use crate::compute::sort::{build_compare, SortColumn};
+use crate::compute::take::take;
 use crate::error::{Error, Result};
 use std::cmp::Ordering;
 use std::iter::Iterator;
@@ -39,6 +44,76 @@ pub fn lexicographical_partition_ranges(
     LexicographicalPartitionIterator::try_new(columns)
 }
 
+/// The seed of the hash used by [`hash_partition`], same as Apache Spark's.
+pub const HASH_PARTITION_SEED: u64 = 42;
+
+/// Partitions the rows of `chunk` into `n` chunks by the hash of its `key_columns`.
+///
+/// The row of each key is assigned to the chunk `hash % n`, where `hash` is computed by
+/// [`hash_columns`] with [`HashAlgorithm::XxHash64`] and [`HASH_PARTITION_SEED`], so that the
+/// same key always lands in the same chunk across runs and platforms. Rows keep their relative
+/// order within each chunk.
+/// # Errors
+/// This function errors iff `n` is zero, `key_columns` is empty or a key column is out of
+/// bounds.
+/// # Example
+/// ```
+/// use re_arrow2::array::{Array, Int32Array, Utf8Array};
+/// use re_arrow2::chunk::Chunk;
+/// use re_arrow2::compute::partition::hash_partition;
+///
+/// let keys = Int32Array::from_slice([1, 2, 1, 3]);
+/// let values = Utf8Array::<i32>::from_slice(["a", "b", "c", "d"]);
+/// let chunk = Chunk::new(vec![keys.boxed(), values.boxed()]);
+///
+/// let partitions = hash_partition(&chunk, &[0], 2).unwrap();
+/// assert_eq!(partitions.len(), 2);
+/// assert_eq!(partitions.iter().map(|x| x.len()).sum::<usize>(), 4);
+/// ```
+pub fn hash_partition<A: AsRef<dyn Array>>(
+    chunk: &Chunk<A>,
+    key_columns: &[usize],
+    n: usize,
+) -> Result<Vec<Chunk<Box<dyn Array>>>> {
+    if n == 0 {
+        return Err(Error::InvalidArgumentError(
+            "hash_partition requires a positive number of partitions".to_string(),
+        ));
+    }
+    let columns = chunk.arrays();
+    let keys = key_columns
+        .iter()
+        .map(|&i| {
+            columns.get(i).map(|x| x.as_ref()).ok_or_else(|| {
+                Error::InvalidArgumentError(format!(
+                    "hash_partition key column {i} is out of bounds of a chunk with {} columns",
+                    columns.len()
+                ))
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let hashes = hash_columns(&keys, HashAlgorithm::XxHash64, HASH_PARTITION_SEED)?;
+
+    let mut indices = vec![vec![]; n];
+    hashes
+        .values()
+        .iter()
+        .enumerate()
+        .for_each(|(row, hash)| indices[(hash % n as u64) as usize].push(row as u64));
+
+    indices
+        .into_iter()
+        .map(|indices| {
+            let indices = PrimitiveArray::from_vec(indices);
+            let arrays = columns
+                .iter()
+                .map(|array| take(array.as_ref(), &indices))
+                .collect::<Result<Vec<_>>>()?;
+            Chunk::try_new(arrays)
+        })
+        .collect()
+}
+
 struct LexicographicalPartitionIterator {
     comparator: DynComparator,
     num_rows: usize,
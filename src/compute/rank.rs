@@ -0,0 +1,236 @@
+//! Contains ranking window functions evaluated per partition, such as [`row_number`], [`rank`],
+//! [`dense_rank`], [`percent_rank`] and [`ntile`].
+//!
+//! Rows are partitioned by the values of `partition_by` and ordered within each partition by
+//! `order_by`, as in SQL's `OVER (PARTITION BY ... ORDER BY ...)`. Rows whose `order_by` values
+//! are equal are peers. All functions return arrays aligned with the input rows.
+use std::ops::Range;
+
+use crate::{
+    array::{ord::DynComparator, Array, PrimitiveArray},
+    error::{Error, Result},
+};
+
+use super::sort::{build_compare, lexsort_to_indices, SortColumn};
+
+/// The rows of `partition_by` and `order_by` in window order.
+struct Windows {
+    /// the row of each position
+    rows: Vec<usize>,
+    /// the positions of each partition
+    partitions: Vec<Range<usize>>,
+    /// whether the position is a peer of the previous position of its partition
+    is_peer: Vec<bool>,
+}
+
+fn build_comparators(columns: &[SortColumn]) -> Result<Vec<DynComparator>> {
+    columns
+        .iter()
+        .map(|column| build_compare(column.values, column.options.unwrap_or_default()))
+        .collect()
+}
+
+fn windows(partition_by: &[&dyn Array], order_by: &[SortColumn]) -> Result<Windows> {
+    let partition_by = partition_by
+        .iter()
+        .map(|values| SortColumn {
+            values: *values,
+            options: None,
+        })
+        .collect::<Vec<_>>();
+    let columns = partition_by
+        .iter()
+        .chain(order_by.iter())
+        .cloned()
+        .collect::<Vec<_>>();
+    if columns.is_empty() {
+        return Err(Error::InvalidArgumentError(
+            "Ranking requires at least one partition or order by column".to_string(),
+        ));
+    }
+
+    let rows = lexsort_to_indices::<u64>(&columns, None)?
+        .values()
+        .iter()
+        .map(|row| *row as usize)
+        .collect::<Vec<_>>();
+
+    let partition_cmp = build_comparators(&partition_by)?;
+    let order_cmp = build_comparators(order_by)?;
+    let is_equal = |comparators: &[DynComparator], i: usize, j: usize| {
+        comparators.iter().all(|cmp| cmp(i, j).is_eq())
+    };
+
+    let mut partitions = vec![];
+    let mut is_peer = Vec::with_capacity(rows.len());
+    let mut start = 0;
+    for position in 0..rows.len() {
+        if position > 0 && !is_equal(&partition_cmp, rows[position - 1], rows[position]) {
+            partitions.push(start..position);
+            start = position;
+        }
+        is_peer.push(position > start && is_equal(&order_cmp, rows[position - 1], rows[position]));
+    }
+    if !rows.is_empty() {
+        partitions.push(start..rows.len());
+    }
+
+    Ok(Windows {
+        rows,
+        partitions,
+        is_peer,
+    })
+}
+
+/// Evaluates `f(partition, position, rank)` on every position of every partition, where `rank`
+/// is the one-based position of the first peer of `position` within its partition,
+/// and collects the results in row order.
+fn evaluate<T, F>(windows: &Windows, mut f: F) -> Vec<T>
+where
+    T: Default + Clone,
+    F: FnMut(&Range<usize>, usize, usize) -> T,
+{
+    let mut result = vec![T::default(); windows.rows.len()];
+    for partition in &windows.partitions {
+        let mut rank = 1;
+        for position in partition.clone() {
+            if !windows.is_peer[position] {
+                rank = position - partition.start + 1;
+            }
+            result[windows.rows[position]] = f(partition, position, rank);
+        }
+    }
+    result
+}
+
+/// Returns the one-based number of each row within its partition.
+/// The numbering of peers is arbitrary.
+/// # Errors
+/// This function errors iff the columns are empty, have different lengths or cannot be sorted.
+/// # Example
+/// ```
+/// use re_arrow2::array::{UInt64Array, Utf8Array, Int32Array};
+/// use re_arrow2::compute::rank::row_number;
+/// use re_arrow2::compute::sort::SortColumn;
+///
+/// let partition = Utf8Array::<i32>::from_slice(["a", "b", "a", "a"]);
+/// let order = Int32Array::from_slice([3, 1, 1, 2]);
+/// let order_by = [SortColumn { values: &order, options: None }];
+/// let result = row_number(&[&partition], &order_by).unwrap();
+/// assert_eq!(result, UInt64Array::from_slice([3, 1, 1, 2]));
+/// ```
+pub fn row_number(
+    partition_by: &[&dyn Array],
+    order_by: &[SortColumn],
+) -> Result<PrimitiveArray<u64>> {
+    let windows = windows(partition_by, order_by)?;
+    let values = evaluate(&windows, |partition, position, _| {
+        (position - partition.start + 1) as u64
+    });
+    Ok(PrimitiveArray::from_vec(values))
+}
+
+/// Returns the one-based rank of each row within its partition, with gaps: peers have the same
+/// rank, and the following row's rank is its [`row_number`].
+/// # Errors
+/// This function errors iff the columns are empty, have different lengths or cannot be sorted.
+/// # Example
+/// ```
+/// use re_arrow2::array::{UInt64Array, Int32Array};
+/// use re_arrow2::compute::rank::rank;
+/// use re_arrow2::compute::sort::SortColumn;
+///
+/// let order = Int32Array::from_slice([10, 20, 10, 30]);
+/// let order_by = [SortColumn { values: &order, options: None }];
+/// let result = rank(&[], &order_by).unwrap();
+/// assert_eq!(result, UInt64Array::from_slice([1, 3, 1, 4]));
+/// ```
+pub fn rank(partition_by: &[&dyn Array], order_by: &[SortColumn]) -> Result<PrimitiveArray<u64>> {
+    let windows = windows(partition_by, order_by)?;
+    let values = evaluate(&windows, |_, _, rank| rank as u64);
+    Ok(PrimitiveArray::from_vec(values))
+}
+
+/// Returns the one-based rank of each row within its partition, without gaps: peers have the
+/// same rank, and the following row's rank is one more.
+/// # Errors
+/// This function errors iff the columns are empty, have different lengths or cannot be sorted.
+pub fn dense_rank(
+    partition_by: &[&dyn Array],
+    order_by: &[SortColumn],
+) -> Result<PrimitiveArray<u64>> {
+    let windows = windows(partition_by, order_by)?;
+    let mut dense_rank = 0;
+    let values = evaluate(&windows, |partition, position, _| {
+        if position == partition.start {
+            dense_rank = 1;
+        } else if !windows.is_peer[position] {
+            dense_rank += 1;
+        }
+        dense_rank
+    });
+    Ok(PrimitiveArray::from_vec(values))
+}
+
+/// Returns the relative rank of each row within its partition, `(rank - 1) / (rows - 1)`,
+/// where `rows` is the number of rows of the partition. Partitions of one row have a relative
+/// rank of zero.
+/// # Errors
+/// This function errors iff the columns are empty, have different lengths or cannot be sorted.
+pub fn percent_rank(
+    partition_by: &[&dyn Array],
+    order_by: &[SortColumn],
+) -> Result<PrimitiveArray<f64>> {
+    let windows = windows(partition_by, order_by)?;
+    let values = evaluate(&windows, |partition, _, rank| {
+        if partition.len() > 1 {
+            (rank - 1) as f64 / (partition.len() - 1) as f64
+        } else {
+            0.0
+        }
+    });
+    Ok(PrimitiveArray::from_vec(values))
+}
+
+/// Distributes the rows of each partition, in order, into `n` buckets numbered from one whose
+/// sizes differ by at most one (the first buckets are the larger ones), and returns the bucket
+/// of each row.
+/// # Errors
+/// This function errors iff `n` is zero, or the columns are empty, have different lengths or
+/// cannot be sorted.
+/// # Example
+/// ```
+/// use re_arrow2::array::{UInt64Array, Int32Array};
+/// use re_arrow2::compute::rank::ntile;
+/// use re_arrow2::compute::sort::SortColumn;
+///
+/// let order = Int32Array::from_slice([5, 4, 3, 2, 1]);
+/// let order_by = [SortColumn { values: &order, options: None }];
+/// let result = ntile(&[], &order_by, 2).unwrap();
+/// assert_eq!(result, UInt64Array::from_slice([2, 2, 1, 1, 1]));
+/// ```
+pub fn ntile(
+    partition_by: &[&dyn Array],
+    order_by: &[SortColumn],
+    n: usize,
+) -> Result<PrimitiveArray<u64>> {
+    if n == 0 {
+        return Err(Error::InvalidArgumentError(
+            "ntile requires a positive number of buckets".to_string(),
+        ));
+    }
+    let windows = windows(partition_by, order_by)?;
+    let values = evaluate(&windows, |partition, position, _| {
+        let position = position - partition.start;
+        let size = partition.len() / n;
+        let larger = partition.len() % n;
+        // the first `larger` buckets have `size + 1` rows
+        let bucket = if position < larger * (size + 1) {
+            position / (size + 1)
+        } else {
+            larger + (position - larger * (size + 1)) / size
+        };
+        (bucket + 1) as u64
+    });
+    Ok(PrimitiveArray::from_vec(values))
+}
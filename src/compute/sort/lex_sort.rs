@@ -181,9 +181,7 @@ pub fn lexsort_to_indices_impl<I: Index>(
         })
         .collect::<Result<Vec<DynComparator>>>()?;
 
-    let lex_comparator = |a_idx: &I, b_idx: &I| -> Ordering {
-        let a_idx = a_idx.to_usize();
-        let b_idx = b_idx.to_usize();
+    let lex_comparator: DynComparator = Box::new(move |a_idx: usize, b_idx: usize| {
         for comparator in comparators.iter() {
             match comparator(a_idx, b_idx) {
                 Ordering::Equal => continue,
@@ -192,25 +190,36 @@ pub fn lexsort_to_indices_impl<I: Index>(
         }
 
         Ordering::Equal
-    };
+    });
+
+    Ok(sort_indices_by(row_count, limit, lex_comparator))
+}
+
+/// Returns the (at most `limit`) indices of `0..row_count` sorted according to `comparator`.
+pub(super) fn sort_indices_by<I: Index>(
+    row_count: usize,
+    limit: Option<usize>,
+    comparator: DynComparator,
+) -> PrimitiveArray<I> {
+    let comparator = |a: &I, b: &I| comparator(a.to_usize(), b.to_usize());
 
     let mut values = I::range(0, row_count).unwrap().collect::<Vec<_>>();
 
     if let Some(limit) = limit {
         let limit = limit.min(row_count);
         let before = if limit < row_count {
-            let (before, _, _) = values.select_nth_unstable_by(limit, lex_comparator);
+            let (before, _, _) = values.select_nth_unstable_by(limit, comparator);
             before
         } else {
             &mut values[..]
         };
-        before.sort_unstable_by(lex_comparator);
+        before.sort_unstable_by(comparator);
         values.truncate(limit);
         values.shrink_to_fit();
     } else {
-        values.sort_unstable_by(lex_comparator);
+        values.sort_unstable_by(comparator);
     }
 
     let data_type = I::PRIMITIVE.into();
-    Ok(PrimitiveArray::<I>::new(data_type, values.into(), None))
+    PrimitiveArray::<I>::new(data_type, values.into(), None)
 }
@@ -1,23 +1,26 @@
 //! Contains operators to sort individual and slices of [`Array`]s.
-use std::cmp::Ordering;
-
 use crate::array::ord;
+use crate::array::*;
 use crate::compute::take;
 use crate::datatypes::*;
 use crate::error::{Error, Result};
 use crate::offset::Offset;
-use crate::{array::*, types::Index};
+use crate::types::{f16, i256, Index};
 
 mod binary;
 mod boolean;
 mod common;
 mod lex_sort;
 mod primitive;
+mod search_sorted;
+mod top_k;
 mod utf8;
 
 pub mod row;
 pub(crate) use lex_sort::build_compare;
 pub use lex_sort::{lexsort, lexsort_to_indices, lexsort_to_indices_impl, SortColumn};
+pub use search_sorted::{search_sorted, SearchSide};
+pub use top_k::{top_k, top_k_to_indices, TopK};
 
 macro_rules! dyn_sort {
     ($ty:ty, $array:expr, $cmp:expr, $options:expr, $limit:expr) => {{
@@ -54,12 +57,15 @@ pub fn sort(
         DataType::Int64
         | DataType::Date64
         | DataType::Time64(_)
-        | DataType::Timestamp(_, None)
+        | DataType::Timestamp(_, _)
         | DataType::Duration(_) => dyn_sort!(i64, values, ord::total_cmp, options, limit),
+        DataType::Decimal(_, _) => dyn_sort!(i128, values, ord::total_cmp, options, limit),
+        DataType::Decimal256(_, _) => dyn_sort!(i256, values, ord::total_cmp, options, limit),
         DataType::UInt8 => dyn_sort!(u8, values, ord::total_cmp, options, limit),
         DataType::UInt16 => dyn_sort!(u16, values, ord::total_cmp, options, limit),
         DataType::UInt32 => dyn_sort!(u32, values, ord::total_cmp, options, limit),
         DataType::UInt64 => dyn_sort!(u64, values, ord::total_cmp, options, limit),
+        DataType::Float16 => dyn_sort!(f16, values, ord::total_cmp_f16, options, limit),
         DataType::Float32 => dyn_sort!(f32, values, ord::total_cmp_f32, options, limit),
         DataType::Float64 => dyn_sort!(f64, values, ord::total_cmp_f64, options, limit),
         _ => {
@@ -120,14 +126,21 @@ pub fn sort_to_indices<I: Index>(
         DataType::Int64
         | DataType::Date64
         | DataType::Time64(_)
-        | DataType::Timestamp(_, None)
+        | DataType::Timestamp(_, _)
         | DataType::Duration(_) => {
             dyn_sort_indices!(I, i64, values, ord::total_cmp, options, limit)
         }
+        DataType::Decimal(_, _) => {
+            dyn_sort_indices!(I, i128, values, ord::total_cmp, options, limit)
+        }
+        DataType::Decimal256(_, _) => {
+            dyn_sort_indices!(I, i256, values, ord::total_cmp, options, limit)
+        }
         DataType::UInt8 => dyn_sort_indices!(I, u8, values, ord::total_cmp, options, limit),
         DataType::UInt16 => dyn_sort_indices!(I, u16, values, ord::total_cmp, options, limit),
         DataType::UInt32 => dyn_sort_indices!(I, u32, values, ord::total_cmp, options, limit),
         DataType::UInt64 => dyn_sort_indices!(I, u64, values, ord::total_cmp, options, limit),
+        DataType::Float16 => dyn_sort_indices!(I, f16, values, ord::total_cmp_f16, options, limit),
         DataType::Float32 => dyn_sort_indices!(I, f32, values, ord::total_cmp_f32, options, limit),
         DataType::Float64 => dyn_sort_indices!(I, f64, values, ord::total_cmp_f64, options, limit),
         DataType::Utf8 => Ok(utf8::indices_sorted_unstable_by::<I, i32>(
@@ -150,61 +163,23 @@ pub fn sort_to_indices<I: Index>(
             options,
             limit,
         )),
-        DataType::List(field) => {
-            let (v, n) = partition_validity(values);
-            match &field.data_type {
-                DataType::Int8 => Ok(sort_list::<I, i32>(values, v, n, options, limit)),
-                DataType::Int16 => Ok(sort_list::<I, i32>(values, v, n, options, limit)),
-                DataType::Int32 => Ok(sort_list::<I, i32>(values, v, n, options, limit)),
-                DataType::Int64 => Ok(sort_list::<I, i32>(values, v, n, options, limit)),
-                DataType::UInt8 => Ok(sort_list::<I, i32>(values, v, n, options, limit)),
-                DataType::UInt16 => Ok(sort_list::<I, i32>(values, v, n, options, limit)),
-                DataType::UInt32 => Ok(sort_list::<I, i32>(values, v, n, options, limit)),
-                DataType::UInt64 => Ok(sort_list::<I, i32>(values, v, n, options, limit)),
-                t => Err(Error::NotYetImplemented(format!(
-                    "Sort not supported for list type {t:?}"
-                ))),
+        DataType::Dictionary(key_type, value_type, _)
+            if matches!(value_type.as_ref(), DataType::Utf8 | DataType::LargeUtf8) =>
+        {
+            match value_type.as_ref() {
+                DataType::Utf8 => Ok(sort_dict::<I, i32>(values, key_type, options, limit)),
+                _ => Ok(sort_dict::<I, i64>(values, key_type, options, limit)),
             }
         }
-        DataType::LargeList(field) => {
-            let (v, n) = partition_validity(values);
-            match field.data_type() {
-                DataType::Int8 => Ok(sort_list::<I, i64>(values, v, n, options, limit)),
-                DataType::Int16 => Ok(sort_list::<I, i64>(values, v, n, options, limit)),
-                DataType::Int32 => Ok(sort_list::<I, i64>(values, v, n, options, limit)),
-                DataType::Int64 => Ok(sort_list::<I, i64>(values, v, n, options, limit)),
-                DataType::UInt8 => Ok(sort_list::<I, i64>(values, v, n, options, limit)),
-                DataType::UInt16 => Ok(sort_list::<I, i64>(values, v, n, options, limit)),
-                DataType::UInt32 => Ok(sort_list::<I, i64>(values, v, n, options, limit)),
-                DataType::UInt64 => Ok(sort_list::<I, i64>(values, v, n, options, limit)),
-                t => Err(Error::NotYetImplemented(format!(
-                    "Sort not supported for list type {t:?}"
-                ))),
-            }
-        }
-        DataType::FixedSizeList(field, _) => {
-            let (v, n) = partition_validity(values);
-            match field.data_type() {
-                DataType::Int8 => Ok(sort_list::<I, i32>(values, v, n, options, limit)),
-                DataType::Int16 => Ok(sort_list::<I, i32>(values, v, n, options, limit)),
-                DataType::Int32 => Ok(sort_list::<I, i32>(values, v, n, options, limit)),
-                DataType::Int64 => Ok(sort_list::<I, i32>(values, v, n, options, limit)),
-                DataType::UInt8 => Ok(sort_list::<I, i32>(values, v, n, options, limit)),
-                DataType::UInt16 => Ok(sort_list::<I, i32>(values, v, n, options, limit)),
-                DataType::UInt32 => Ok(sort_list::<I, i32>(values, v, n, options, limit)),
-                DataType::UInt64 => Ok(sort_list::<I, i32>(values, v, n, options, limit)),
-                t => Err(Error::NotYetImplemented(format!(
-                    "Sort not supported for list type {t:?}"
-                ))),
-            }
+        DataType::FixedSizeBinary(_)
+        | DataType::Struct(_)
+        | DataType::List(_)
+        | DataType::LargeList(_)
+        | DataType::FixedSizeList(_, _)
+        | DataType::Dictionary(_, _, _) => {
+            let comparator = build_compare(values, *options)?;
+            Ok(lex_sort::sort_indices_by(values.len(), limit, comparator))
         }
-        DataType::Dictionary(key_type, value_type, _) => match value_type.as_ref() {
-            DataType::Utf8 => Ok(sort_dict::<I, i32>(values, key_type, options, limit)),
-            DataType::LargeUtf8 => Ok(sort_dict::<I, i64>(values, key_type, options, limit)),
-            t => Err(Error::NotYetImplemented(format!(
-                "Sort not supported for dictionary type with keys {t:?}"
-            ))),
-        },
         t => Err(Error::NotYetImplemented(format!(
             "Sort not supported for data type {t:?}"
         ))),
@@ -251,38 +226,44 @@ pub fn can_sort(data_type: &DataType) -> bool {
         | DataType::Int64
         | DataType::Date64
         | DataType::Time64(_)
-        | DataType::Timestamp(_, None)
+        | DataType::Timestamp(_, _)
         | DataType::Duration(_)
         | DataType::UInt8
         | DataType::UInt16
         | DataType::UInt32
         | DataType::UInt64
+        | DataType::Float16
         | DataType::Float32
         | DataType::Float64
+        | DataType::Decimal(_, _)
+        | DataType::Decimal256(_, _)
         | DataType::Utf8
         | DataType::LargeUtf8
         | DataType::Binary
-        | DataType::LargeBinary => true,
+        | DataType::LargeBinary
+        | DataType::FixedSizeBinary(_) => true,
         DataType::List(field) | DataType::LargeList(field) | DataType::FixedSizeList(field, _) => {
-            matches!(
-                field.data_type(),
-                DataType::Int8
-                    | DataType::Int16
-                    | DataType::Int32
-                    | DataType::Int64
-                    | DataType::UInt8
-                    | DataType::UInt16
-                    | DataType::UInt32
-                    | DataType::UInt64
-            )
-        }
-        DataType::Dictionary(_, value_type, _) => {
-            matches!(*value_type.as_ref(), DataType::Utf8 | DataType::LargeUtf8)
+            can_sort_values(field.data_type())
         }
+        DataType::Struct(fields) => fields
+            .iter()
+            .all(|field| can_sort_values(field.data_type())),
+        DataType::Dictionary(_, value_type, _) => can_sort_values(value_type.as_ref()),
         _ => false,
     }
 }
 
+/// Whether the values of nested arrays and dictionaries of type `data_type` can be compared
+/// via [`ord::build_compare`].
+fn can_sort_values(data_type: &DataType) -> bool {
+    can_sort(data_type)
+        && !matches!(
+            data_type,
+            DataType::Interval(IntervalUnit::DayTime)
+                | DataType::Interval(IntervalUnit::MonthDayNano)
+        )
+}
+
 /// Options that define how sort kernels should behave
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub struct SortOptions {
@@ -301,69 +282,3 @@ impl Default for SortOptions {
         }
     }
 }
-
-fn sort_list<I, O>(
-    values: &dyn Array,
-    value_indices: Vec<I>,
-    null_indices: Vec<I>,
-    options: &SortOptions,
-    limit: Option<usize>,
-) -> PrimitiveArray<I>
-where
-    I: Index,
-    O: Offset,
-{
-    let mut valids: Vec<(I, Box<dyn Array>)> = values
-        .as_any()
-        .downcast_ref::<FixedSizeListArray>()
-        .map_or_else(
-            || {
-                let values = values.as_any().downcast_ref::<ListArray<O>>().unwrap();
-                value_indices
-                    .iter()
-                    .copied()
-                    .map(|index| (index, values.value(index.to_usize())))
-                    .collect()
-            },
-            |values| {
-                value_indices
-                    .iter()
-                    .copied()
-                    .map(|index| (index, values.value(index.to_usize())))
-                    .collect()
-            },
-        );
-
-    if !options.descending {
-        valids.sort_by(|a, b| cmp_array(a.1.as_ref(), b.1.as_ref()))
-    } else {
-        valids.sort_by(|a, b| cmp_array(b.1.as_ref(), a.1.as_ref()))
-    }
-
-    let values = valids.iter().map(|tuple| tuple.0);
-
-    let mut values = if options.nulls_first {
-        null_indices.into_iter().chain(values).collect::<Vec<I>>()
-    } else {
-        values.chain(null_indices).collect::<Vec<I>>()
-    };
-
-    values.truncate(limit.unwrap_or(values.len()));
-
-    let data_type = I::PRIMITIVE.into();
-    PrimitiveArray::<I>::new(data_type, values.into(), None)
-}
-
-/// Compare two `Array`s based on the ordering defined in [ord](crate::array::ord).
-fn cmp_array(a: &dyn Array, b: &dyn Array) -> Ordering {
-    let cmp_op = ord::build_compare(a, b).unwrap();
-    let length = a.len().min(b.len());
-
-    for i in 0..length {
-        let result = cmp_op(i, i);
-        if result != Ordering::Equal {
-            return result;
-        }
-    }
-    a.len().cmp(&b.len())
-}
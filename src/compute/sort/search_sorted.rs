@@ -0,0 +1,101 @@
+//! Contains [`search_sorted`], which finds the insertion points of values in a sorted array.
+use std::cmp::Ordering;
+
+use crate::{
+    array::{ord, Array, PrimitiveArray},
+    error::Result,
+};
+
+use super::SortOptions;
+
+/// Which insertion point [`search_sorted`] returns when a value is already in the sorted array.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash)]
+pub enum SearchSide {
+    /// The index of the first slot equal to the value, i.e. the lowest insertion point.
+    #[default]
+    Left,
+    /// The index after the last slot equal to the value, i.e. the highest insertion point.
+    Right,
+}
+
+/// Returns the first index in `0..length` for which `pred` is false, assuming that `pred` is
+/// true for a (possibly empty) prefix of `0..length` and false for the remaining indices.
+#[inline]
+fn partition_point<F: Fn(usize) -> bool>(length: usize, pred: F) -> usize {
+    let mut low = 0;
+    let mut high = length;
+    while low < high {
+        let mid = low + (high - low) / 2;
+        if pred(mid) {
+            low = mid + 1;
+        } else {
+            high = mid;
+        }
+    }
+    low
+}
+
+/// Returns, for each value of `needles`, the index at which it would be inserted in `sorted` to
+/// keep it sorted according to `options`.
+///
+/// `sorted` must be sorted according to `options` (e.g. by [`sort`](super::sort) with the same
+/// options): nulls are placed first or last according to `nulls_first`, and a null needle is
+/// inserted among the nulls of `sorted`. Floats follow IEEE 754 totalOrder. `side` decides
+/// whether the first or last insertion point is returned when a needle is in `sorted`.
+///
+/// Each search is a binary search over the comparator of [`ord::build_compare`], and so this
+/// supports all data types with a natural order, including nested types and dictionaries.
+/// # Errors
+/// This function errors iff `sorted` and `needles` have different data types, or the data type
+/// has no natural order.
+/// # Example
+/// ```
+/// use re_arrow2::array::Int32Array;
+/// use re_arrow2::compute::sort::{search_sorted, SearchSide, SortOptions};
+///
+/// let sorted = Int32Array::from(&[None, Some(1), Some(3), Some(3), Some(5)]);
+/// let needles = Int32Array::from(&[Some(3), Some(0), Some(6), None]);
+///
+/// let result = search_sorted(&sorted, &needles, SearchSide::Left, SortOptions::default()).unwrap();
+/// assert_eq!(result.values().as_slice(), &[2, 1, 5, 0]);
+///
+/// let result = search_sorted(&sorted, &needles, SearchSide::Right, SortOptions::default()).unwrap();
+/// assert_eq!(result.values().as_slice(), &[4, 1, 5, 1]);
+/// ```
+pub fn search_sorted(
+    sorted: &dyn Array,
+    needles: &dyn Array,
+    side: SearchSide,
+    options: SortOptions,
+) -> Result<PrimitiveArray<u64>> {
+    let comparator = ord::build_compare(sorted, needles)?;
+
+    // the order of the slot `i` of `sorted` relative to the slot `j` of `needles`
+    let cmp = |i: usize, j: usize| match (sorted.is_valid(i), needles.is_valid(j)) {
+        (true, true) => {
+            let ordering = comparator(i, j);
+            if options.descending {
+                ordering.reverse()
+            } else {
+                ordering
+            }
+        }
+        (false, false) => Ordering::Equal,
+        (false, true) if options.nulls_first => Ordering::Less,
+        (false, true) => Ordering::Greater,
+        (true, false) if options.nulls_first => Ordering::Greater,
+        (true, false) => Ordering::Less,
+    };
+
+    let length = sorted.len();
+    let indices = (0..needles.len())
+        .map(|j| {
+            let index = match side {
+                SearchSide::Left => partition_point(length, |i| cmp(i, j) == Ordering::Less),
+                SearchSide::Right => partition_point(length, |i| cmp(i, j) != Ordering::Greater),
+            };
+            index as u64
+        })
+        .collect::<Vec<_>>();
+    Ok(PrimitiveArray::from_vec(indices))
+}
@@ -0,0 +1,224 @@
+//! Contains [`top_k`], which sorts the first `k` rows of columns without sorting all of them,
+//! and [`TopK`], which keeps the top `k` rows of a stream of [`Chunk`]s.
+use std::cmp::Ordering;
+
+use crate::{
+    array::{growable::make_growable, Array, PrimitiveArray},
+    chunk::Chunk,
+    error::{Error, Result},
+    types::Index,
+};
+
+use super::row::{RowConverter, Rows, SortField};
+use super::SortColumn;
+
+/// Keeps the `k` smallest `candidates` according to `cmp`, sorted.
+fn select<T, F: FnMut(&T, &T) -> Ordering>(candidates: &mut Vec<T>, k: usize, mut cmp: F) {
+    if k == 0 {
+        candidates.clear();
+        return;
+    }
+    if candidates.len() > k {
+        candidates.select_nth_unstable_by(k - 1, &mut cmp);
+        candidates.truncate(k);
+    }
+    candidates.sort_unstable_by(cmp);
+}
+
+fn convert(converter: &mut RowConverter, columns: &[&dyn Array]) -> Result<Rows> {
+    let columns = columns.iter().map(|x| x.to_boxed()).collect::<Vec<_>>();
+    converter.convert_columns(&columns)
+}
+
+/// Returns the indices of the first `k` rows of `columns` sorted lexicographically.
+///
+/// Unlike [`lexsort_to_indices`](super::lexsort_to_indices) with a limit, this selects the
+/// `k` rows in linear time over their [row format](super::row) and only sorts those.
+/// Rows that compare equal keep their relative order.
+/// # Errors
+/// This function errors iff `columns` is empty, the columns have different lengths, or a data
+/// type is not supported by the [row format](super::row).
+/// # Example
+/// ```
+/// use re_arrow2::array::{Int32Array, Utf8Array};
+/// use re_arrow2::compute::sort::{top_k_to_indices, SortColumn, SortOptions};
+///
+/// let a = Int32Array::from_slice([3, 1, 3, 2]);
+/// let b = Utf8Array::<i32>::from_slice(["a", "b", "c", "d"]);
+/// let columns = [
+///     SortColumn { values: &a, options: Some(SortOptions { descending: true, nulls_first: false }) },
+///     SortColumn { values: &b, options: None },
+/// ];
+/// let indices = top_k_to_indices::<u32>(&columns, 3).unwrap();
+/// assert_eq!(indices.values().as_slice(), &[0, 2, 3]);
+/// ```
+pub fn top_k_to_indices<I: Index>(columns: &[SortColumn], k: usize) -> Result<PrimitiveArray<I>> {
+    let length = columns.first().map(|x| x.values.len()).ok_or_else(|| {
+        Error::InvalidArgumentError("top_k requires at least one column".to_string())
+    })?;
+    if columns.iter().any(|x| x.values.len() != length) {
+        return Err(Error::InvalidArgumentError(
+            "top_k requires columns of the same length".to_string(),
+        ));
+    }
+
+    let fields = columns
+        .iter()
+        .map(|x| {
+            SortField::new_with_options(x.values.data_type().clone(), x.options.unwrap_or_default())
+        })
+        .collect();
+    let values = columns.iter().map(|x| x.values).collect::<Vec<_>>();
+    let rows = convert(&mut RowConverter::new(fields), &values)?;
+
+    let mut indices = (0..length).collect::<Vec<_>>();
+    select(&mut indices, k, |a, b| {
+        rows.row(*a).cmp(&rows.row(*b)).then(a.cmp(b))
+    });
+    Ok(PrimitiveArray::from_trusted_len_values_iter(
+        indices.into_iter().map(I::from_usize).map(Option::unwrap),
+    ))
+}
+
+/// Returns the first `k` rows of `columns` sorted lexicographically (see [`top_k_to_indices`]).
+/// # Errors
+/// This function errors iff `columns` is empty, the columns have different lengths, or a data
+/// type is not supported by the [row format](super::row).
+pub fn top_k(columns: &[SortColumn], k: usize) -> Result<Vec<Box<dyn Array>>> {
+    let indices = top_k_to_indices::<u64>(columns, k)?;
+    columns
+        .iter()
+        .map(|x| crate::compute::take::take(x.values, &indices))
+        .collect()
+}
+
+/// Keeps the top `k` rows of a stream of [`Chunk`]s, according to some of their columns.
+///
+/// Rows that compare equal keep the order in which they were pushed.
+/// # Example
+/// ```
+/// use re_arrow2::array::{Array, Int32Array, Utf8Array};
+/// use re_arrow2::chunk::Chunk;
+/// use re_arrow2::compute::sort::{row::SortField, TopK};
+/// use re_arrow2::datatypes::DataType;
+///
+/// // the 2 smallest rows by the first column
+/// let mut top = TopK::new(vec![(0, SortField::new(DataType::Int32))], 2);
+/// for (keys, values) in [([5, 1], ["a", "b"]), ([3, 0], ["c", "d"])] {
+///     let keys = Int32Array::from_slice(keys).boxed();
+///     let values = Utf8Array::<i32>::from_slice(values).boxed();
+///     top.push(&Chunk::new(vec![keys, values])).unwrap();
+/// }
+/// let top = top.finish().unwrap();
+/// assert_eq!(Int32Array::from_slice([0, 1]), top.arrays()[0].as_ref());
+/// ```
+#[derive(Debug)]
+pub struct TopK {
+    /// the index of each key column in the chunks
+    keys: Vec<usize>,
+    converter: RowConverter,
+    k: usize,
+    /// the current top rows and the rows of their keys
+    top: Option<(Chunk<Box<dyn Array>>, Rows)>,
+}
+
+impl TopK {
+    /// Returns a new [`TopK`] of the `k` first rows sorted by `keys`: the index of each key
+    /// column in the chunks and its [`SortField`], in order of precedence.
+    pub fn new(keys: Vec<(usize, SortField)>, k: usize) -> Self {
+        let (keys, fields) = keys.into_iter().unzip();
+        Self {
+            keys,
+            converter: RowConverter::new(fields),
+            k,
+            top: None,
+        }
+    }
+
+    fn key_rows<A: AsRef<dyn Array>>(&mut self, chunk: &Chunk<A>) -> Result<Rows> {
+        let arrays = chunk.arrays();
+        let columns = self
+            .keys
+            .iter()
+            .map(|&i| {
+                arrays.get(i).map(|x| x.as_ref()).ok_or_else(|| {
+                    Error::InvalidArgumentError(format!(
+                        "TopK key column {i} is out of bounds of a chunk with {} columns",
+                        arrays.len()
+                    ))
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        convert(&mut self.converter, &columns)
+    }
+
+    /// Merges the rows of `chunk` into the top `k` rows.
+    /// # Errors
+    /// This function errors iff a key column is out of bounds, or the chunk's columns do not
+    /// match the [`SortField`]s or the previous chunks.
+    pub fn push<A: AsRef<dyn Array>>(&mut self, chunk: &Chunk<A>) -> Result<()> {
+        let rows = self.key_rows(chunk)?;
+        let arrays = chunk
+            .arrays()
+            .iter()
+            .map(|x| x.as_ref())
+            .collect::<Vec<_>>();
+
+        // candidates are `(source, row)`, where the source `0` is the current top
+        let mut candidates = (0..chunk.len()).map(|i| (1, i)).collect::<Vec<_>>();
+        if let Some((top, _)) = &self.top {
+            if top.arrays().len() != arrays.len()
+                || top
+                    .arrays()
+                    .iter()
+                    .zip(arrays.iter())
+                    .any(|(a, b)| a.data_type() != b.data_type())
+            {
+                return Err(Error::InvalidArgumentError(
+                    "TopK requires chunks with the same data types".to_string(),
+                ));
+            }
+            candidates.extend((0..top.len()).map(|i| (0, i)));
+        }
+        let top_rows = self.top.as_ref().map(|(_, rows)| rows);
+        let row = |(source, i): &(usize, usize)| match source {
+            0 => top_rows.unwrap().row(*i),
+            _ => rows.row(*i),
+        };
+        select(&mut candidates, self.k, |a, b| {
+            row(a).cmp(&row(b)).then(a.cmp(b))
+        });
+
+        let columns = (0..arrays.len())
+            .map(|column| {
+                let mut sources = vec![];
+                if let Some((top, _)) = &self.top {
+                    sources.push(top.arrays()[column].as_ref());
+                } else {
+                    // keeps the source `1` at index 1
+                    sources.push(arrays[column]);
+                }
+                sources.push(arrays[column]);
+                let mut growable = make_growable(&sources, true, candidates.len());
+                candidates
+                    .iter()
+                    .for_each(|(source, i)| growable.extend(*source, *i, 1));
+                growable.as_box()
+            })
+            .collect::<Vec<_>>();
+        let top = Chunk::try_new(columns)?;
+        let rows = self.key_rows(&top)?;
+        self.top = Some((top, rows));
+        Ok(())
+    }
+
+    /// Returns the current top rows, or `None` if no chunk was pushed.
+    pub fn top(&self) -> Option<&Chunk<Box<dyn Array>>> {
+        self.top.as_ref().map(|(top, _)| top)
+    }
+
+    /// Returns the top rows, or `None` if no chunk was pushed.
+    pub fn finish(self) -> Option<Chunk<Box<dyn Array>>> {
+        self.top.map(|(top, _)| top)
+    }
+}
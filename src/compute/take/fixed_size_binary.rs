@@ -0,0 +1,35 @@
+use crate::array::{Array, FixedSizeBinaryArray, PrimitiveArray};
+use crate::bitmap::Bitmap;
+
+use super::Index;
+
+/// `take` implementation for [`FixedSizeBinaryArray`]s
+pub fn take<O: Index>(
+    values: &FixedSizeBinaryArray,
+    indices: &PrimitiveArray<O>,
+) -> FixedSizeBinaryArray {
+    let size = values.size();
+    let indices_validity = indices.validity();
+
+    let mut buffer = Vec::<u8>::with_capacity(indices.len() * size);
+    indices.values().iter().enumerate().for_each(|(i, index)| {
+        if indices_validity.map(|x| x.get_bit(i)).unwrap_or(true) {
+            buffer.extend_from_slice(values.value(index.to_usize()));
+        } else {
+            buffer.extend(std::iter::repeat(0).take(size));
+        }
+    });
+
+    let validity = if values.null_count() > 0 {
+        let validity = indices.iter().map(|index| {
+            index
+                .map(|index| values.is_valid(index.to_usize()))
+                .unwrap_or(false)
+        });
+        Some(Bitmap::from_trusted_len_iter(validity))
+    } else {
+        indices.validity().cloned()
+    };
+
+    FixedSizeBinaryArray::new(values.data_type().clone(), buffer.into(), validity)
+}
@@ -27,6 +27,7 @@ use crate::{
 mod binary;
 mod boolean;
 mod dict;
+mod fixed_size_binary;
 mod fixed_size_list;
 mod generic_binary;
 mod list;
@@ -95,6 +96,10 @@ pub fn take<O: Index>(values: &dyn Array, indices: &PrimitiveArray<O>) -> Result
             let array = values.as_any().downcast_ref().unwrap();
             Ok(Box::new(fixed_size_list::take::<O>(array, indices)))
         }
+        FixedSizeBinary => {
+            let array = values.as_any().downcast_ref().unwrap();
+            Ok(Box::new(fixed_size_binary::take::<O>(array, indices)))
+        }
         t => unimplemented!("Take not supported for data type {:?}", t),
     }
 }
@@ -133,10 +138,12 @@ pub fn can_take(data_type: &DataType) -> bool {
             | DataType::Float32
             | DataType::Float64
             | DataType::Decimal(_, _)
+            | DataType::Decimal256(_, _)
             | DataType::Utf8
             | DataType::LargeUtf8
             | DataType::Binary
             | DataType::LargeBinary
+            | DataType::FixedSizeBinary(_)
             | DataType::Struct(_)
             | DataType::List(_)
             | DataType::LargeList(_)
@@ -409,3 +409,390 @@ fn can_time(data_type: &DataType) -> bool {
             | DataType::Timestamp(_, _)
     )
 }
+
+/// The unit to which [`date_trunc`] truncates timestamps.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TruncUnit {
+    /// Truncates to the start of the second
+    Second,
+    /// Truncates to the start of the minute
+    Minute,
+    /// Truncates to the start of the hour
+    Hour,
+    /// Truncates to midnight
+    Day,
+    /// Truncates to midnight of the Monday of the week (ISO 8601)
+    Week,
+    /// Truncates to midnight of the first day of the month
+    Month,
+    /// Truncates to midnight of the first day of the year
+    Year,
+}
+
+// Macro to dispatch `$body` over the timezone of a timestamp: a `FixedOffset` for
+// timestamps without timezone (i.e. UTC) and offsets, or a `chrono_tz::Tz`.
+macro_rules! with_timezone {
+    ($timezone:expr, | $tz:ident | $body:expr) => {{
+        match $timezone {
+            None => {
+                let $tz = chrono::FixedOffset::east_opt(0).unwrap();
+                $body
+            }
+            Some(timezone_str) => match parse_offset(timezone_str) {
+                Ok($tz) => $body,
+                Err(_) => {
+                    #[cfg(feature = "chrono-tz")]
+                    {
+                        let $tz = parse_offset_tz(timezone_str)?;
+                        $body
+                    }
+                    #[cfg(not(feature = "chrono-tz"))]
+                    {
+                        Err(Error::InvalidArgumentError(format!(
+                            "timezone \"{}\" cannot be parsed (feature chrono-tz is not active)",
+                            timezone_str
+                        )))
+                    }
+                }
+            },
+        }
+    }};
+}
+
+fn datetime_to_timestamp<T: chrono::TimeZone>(
+    datetime: chrono::DateTime<T>,
+    time_unit: TimeUnit,
+) -> Result<i64> {
+    match time_unit {
+        TimeUnit::Second => Some(datetime.timestamp()),
+        TimeUnit::Millisecond => Some(datetime.timestamp_millis()),
+        TimeUnit::Microsecond => Some(datetime.timestamp_micros()),
+        TimeUnit::Nanosecond => datetime.timestamp_nanos_opt(),
+    }
+    .ok_or_else(|| Error::Overflow)
+}
+
+fn truncate_naive(datetime: chrono::NaiveDateTime, unit: TruncUnit) -> chrono::NaiveDateTime {
+    let date = datetime.date();
+    let time = datetime.time();
+    let (date, time) = match unit {
+        TruncUnit::Second => (date, time.with_nanosecond(0).unwrap()),
+        TruncUnit::Minute => (
+            date,
+            chrono::NaiveTime::from_hms_opt(time.hour(), time.minute(), 0).unwrap(),
+        ),
+        TruncUnit::Hour => (
+            date,
+            chrono::NaiveTime::from_hms_opt(time.hour(), 0, 0).unwrap(),
+        ),
+        TruncUnit::Day => (date, chrono::NaiveTime::MIN),
+        TruncUnit::Week => {
+            let days = date.weekday().num_days_from_monday() as i64;
+            (date - chrono::Duration::days(days), chrono::NaiveTime::MIN)
+        }
+        TruncUnit::Month => (date.with_day(1).unwrap(), chrono::NaiveTime::MIN),
+        TruncUnit::Year => (date.with_ordinal(1).unwrap(), chrono::NaiveTime::MIN),
+    };
+    date.and_time(time)
+}
+
+fn date_trunc_impl<T: chrono::TimeZone>(
+    array: &PrimitiveArray<i64>,
+    time_unit: TimeUnit,
+    timezone: T,
+    unit: TruncUnit,
+) -> Result<PrimitiveArray<i64>> {
+    use chrono::{LocalResult, Offset};
+    let op = |x| {
+        let datetime = timestamp_to_datetime_opt(x, time_unit, &timezone).ok_or(Error::Overflow)?;
+        let local = truncate_naive(datetime.naive_local(), unit);
+        let truncated = match timezone.from_local_datetime(&local) {
+            LocalResult::Single(truncated) => truncated,
+            // the local time is ambiguous (e.g. DST fall-back): prefer the offset of the original instant
+            LocalResult::Ambiguous(earliest, latest) => {
+                if latest.offset().fix() == datetime.offset().fix() {
+                    latest
+                } else {
+                    earliest
+                }
+            }
+            // the local time does not exist (e.g. DST gap): keep the offset of the original instant
+            LocalResult::None => timezone.from_utc_datetime(&(local - datetime.offset().fix())),
+        };
+        datetime_to_timestamp(truncated, time_unit)
+    };
+    // null slots may hold any value, so they are not truncated
+    let values = array
+        .iter()
+        .map(|x| x.map_or(Ok(0), |x| op(*x)))
+        .collect::<Result<Vec<_>>>()?;
+    Ok(PrimitiveArray::new(
+        array.data_type().clone(),
+        values.into(),
+        array.validity().cloned(),
+    ))
+}
+
+/// Truncates each timestamp of `array` to the start of its `unit` (e.g. the start of its day).
+///
+/// Truncation happens in the local time of the timestamp's timezone (UTC when it has none),
+/// and the result keeps the data type of `array`. Timezones other than offsets
+/// (e.g. `"Europe/Lisbon"`) require the feature `chrono-tz`.
+/// # Example
+/// ```
+/// use re_arrow2::array::PrimitiveArray;
+/// use std::sync::Arc;
+/// use re_arrow2::compute::temporal::{date_trunc, TruncUnit};
+/// use re_arrow2::datatypes::{DataType, TimeUnit};
+///
+/// let data_type = DataType::Timestamp(TimeUnit::Second, Some(Arc::new("+01:00".to_string())));
+/// // 2021-01-01T23:30:00+01:00 and 2021-01-02T00:30:00+01:00
+/// let array = PrimitiveArray::<i64>::from([Some(1609540200), Some(1609543800), None]).to(data_type);
+/// let result = date_trunc(&array, TruncUnit::Day).unwrap();
+/// // 2021-01-01T00:00:00+01:00 and 2021-01-02T00:00:00+01:00
+/// let expected = PrimitiveArray::<i64>::from([Some(1609455600), Some(1609542000), None]);
+/// assert_eq!(result, expected.to(array.data_type().clone()));
+/// ```
+/// # Errors
+/// This function errors if `array` is not a timestamp, its timezone cannot be parsed,
+/// or a (non-null) timestamp or its truncation cannot be represented as a datetime.
+pub fn date_trunc(array: &dyn Array, unit: TruncUnit) -> Result<PrimitiveArray<i64>> {
+    match array.data_type().to_logical_type() {
+        DataType::Timestamp(time_unit, timezone) => {
+            let array = array.as_any().downcast_ref().unwrap();
+            with_timezone!(timezone, |tz| date_trunc_impl(array, *time_unit, tz, unit))
+        }
+        dt => Err(Error::NotYetImplemented(format!(
+            "\"date_trunc\" does not support type {dt:?}"
+        ))),
+    }
+}
+
+/// Assigns each timestamp of `array` to the start of its bin, where bins have a fixed
+/// width `stride` and are aligned with `origin`.
+///
+/// `stride` and `origin` are expressed in the time unit of `array` (`origin` being a timestamp).
+/// Since bins have a fixed width, they are computed on the instants and do not depend on
+/// the timezone. The result keeps the data type of `array`.
+/// # Example
+/// ```
+/// use re_arrow2::array::PrimitiveArray;
+/// use re_arrow2::compute::temporal::date_bin;
+/// use re_arrow2::datatypes::{DataType, TimeUnit};
+///
+/// let data_type = DataType::Timestamp(TimeUnit::Second, None);
+/// let array = PrimitiveArray::<i64>::from_slice([0, 899, 900, 1000, -1]).to(data_type);
+/// // bins of 15 minutes
+/// let result = date_bin(&array, 900, 0).unwrap();
+/// assert_eq!(result.values().as_slice(), &[0, 0, 900, 900, -900]);
+/// ```
+/// # Errors
+/// This function errors if `array` is not a timestamp, `stride` is not positive,
+/// or a bin's start cannot be represented as an `i64`.
+pub fn date_bin(array: &dyn Array, stride: i64, origin: i64) -> Result<PrimitiveArray<i64>> {
+    if !matches!(
+        array.data_type().to_logical_type(),
+        DataType::Timestamp(_, _)
+    ) {
+        return Err(Error::NotYetImplemented(format!(
+            "\"date_bin\" does not support type {:?}",
+            array.data_type()
+        )));
+    }
+    if stride <= 0 {
+        return Err(Error::InvalidArgumentError(
+            "date_bin requires a positive stride".to_string(),
+        ));
+    }
+    let array = array
+        .as_any()
+        .downcast_ref::<PrimitiveArray<i64>>()
+        .unwrap();
+    let (stride, origin) = (stride as i128, origin as i128);
+    let op = |x: i64| {
+        let bin = origin + (x as i128 - origin).div_euclid(stride) * stride;
+        i64::try_from(bin).map_err(|_| Error::Overflow)
+    };
+    // null slots may hold any value, so they are not binned
+    let values = array
+        .iter()
+        .map(|x| x.map_or(Ok(0), |x| op(*x)))
+        .collect::<Result<Vec<_>>>()?;
+    Ok(PrimitiveArray::new(
+        array.data_type().clone(),
+        values.into(),
+        array.validity().cloned(),
+    ))
+}
+
+fn format_to<D: std::fmt::Display>(buffer: &mut String, value: D) -> Result<()> {
+    use std::fmt::Write;
+    buffer.clear();
+    write!(buffer, "{value}")
+        .map_err(|_| Error::InvalidArgumentError("format is not valid for this type".to_string()))
+}
+
+fn strftime_impl<I: NativeType, D: std::fmt::Display, F: Fn(I) -> Option<D>>(
+    array: &PrimitiveArray<I>,
+    op: F,
+) -> Result<Utf8Array<i32>> {
+    let mut values = MutableUtf8ValuesArray::<i32>::with_capacity(array.len());
+    let mut buffer = String::new();
+    for value in array.iter() {
+        // null slots may hold any value, so they are not formatted
+        let Some(value) = value else {
+            values.push("");
+            continue;
+        };
+        let value = op(*value).ok_or_else(|| {
+            Error::InvalidArgumentError(format!(
+                "{value:?} is out of range for {:?}",
+                array.data_type()
+            ))
+        })?;
+        format_to(&mut buffer, value)?;
+        values.push(&buffer);
+    }
+    let values: Utf8Array<i32> = values.into();
+    Ok(values.with_validity(array.validity().cloned()))
+}
+
+fn strftime_timestamp<T>(
+    array: &PrimitiveArray<i64>,
+    time_unit: TimeUnit,
+    timezone: T,
+    items: &[chrono::format::Item],
+) -> Result<Utf8Array<i32>>
+where
+    T: chrono::TimeZone,
+    T::Offset: std::fmt::Display,
+{
+    strftime_impl(array, |x| {
+        timestamp_to_datetime_opt(x, time_unit, &timezone)
+            .map(|x| x.format_with_items(items.iter()))
+    })
+}
+
+/// Formats each value of a temporal array as a string according to `format`, using
+/// [`chrono`'s specifiers](https://docs.rs/chrono/latest/chrono/format/strftime/index.html).
+///
+/// Timestamps are formatted in the local time of their timezone (UTC when they have none).
+/// # Example
+/// ```
+/// use re_arrow2::array::{PrimitiveArray, Utf8Array};
+/// use std::sync::Arc;
+/// use re_arrow2::compute::temporal::strftime;
+/// use re_arrow2::datatypes::{DataType, TimeUnit};
+///
+/// let data_type = DataType::Timestamp(TimeUnit::Millisecond, Some(Arc::new("-02:00".to_string())));
+/// let array = PrimitiveArray::<i64>::from([Some(1_000), None]).to(data_type);
+/// let result = strftime(&array, "%Y-%m-%d %H:%M:%S%.3f %z").unwrap();
+/// assert_eq!(result, Utf8Array::<i32>::from([Some("1969-12-31 22:00:01.000 -0200"), None]));
+/// ```
+/// # Errors
+/// This function errors if `array` is not a date, time or timestamp, if a timezone cannot
+/// be parsed, if a (non-null) value is out of range for its type, or if `format` is invalid
+/// or requires fields that the type does not have (e.g. `%Y` for times).
+pub fn strftime(array: &dyn Array, format: &str) -> Result<Utf8Array<i32>> {
+    let items = chrono::format::StrftimeItems::new(format).collect::<Vec<_>>();
+    if items.contains(&chrono::format::Item::Error) {
+        return Err(Error::InvalidArgumentError(format!(
+            "\"{format}\" is not a valid format"
+        )));
+    }
+    let items = items.as_slice();
+    match array.data_type().to_logical_type() {
+        DataType::Timestamp(time_unit, timezone) => {
+            let array = array.as_any().downcast_ref().unwrap();
+            with_timezone!(timezone, |tz| strftime_timestamp(
+                array, *time_unit, tz, items
+            ))
+        }
+        DataType::Date32 => {
+            let array = array
+                .as_any()
+                .downcast_ref::<PrimitiveArray<i32>>()
+                .unwrap();
+            strftime_impl(array, |x| {
+                date32_to_date_opt(x).map(|x| x.format_with_items(items.iter()))
+            })
+        }
+        DataType::Date64 => {
+            let array = array
+                .as_any()
+                .downcast_ref::<PrimitiveArray<i64>>()
+                .unwrap();
+            strftime_impl(array, |x| {
+                date64_to_date_opt(x).map(|x| x.format_with_items(items.iter()))
+            })
+        }
+        DataType::Time32(time_unit) => {
+            let array = array
+                .as_any()
+                .downcast_ref::<PrimitiveArray<i32>>()
+                .unwrap();
+            let func = match time_unit {
+                TimeUnit::Second => time32s_to_time_opt,
+                _ => time32ms_to_time_opt,
+            };
+            strftime_impl(array, |x| {
+                func(x).map(|x| x.format_with_items(items.iter()))
+            })
+        }
+        DataType::Time64(time_unit) => {
+            let array = array
+                .as_any()
+                .downcast_ref::<PrimitiveArray<i64>>()
+                .unwrap();
+            let func = match time_unit {
+                TimeUnit::Microsecond => time64us_to_time_opt,
+                _ => time64ns_to_time_opt,
+            };
+            strftime_impl(array, |x| {
+                func(x).map(|x| x.format_with_items(items.iter()))
+            })
+        }
+        dt => Err(Error::NotYetImplemented(format!(
+            "\"strftime\" does not support type {dt:?}"
+        ))),
+    }
+}
+
+/// Changes the timezone of a timestamp array to `timezone`, keeping the instants it represents.
+///
+/// Since timestamps are stored as instants since the UNIX epoch in UTC, this only changes the
+/// data type; timestamps without timezone are interpreted as UTC.
+/// Passing `None` returns timestamps without timezone.
+/// # Example
+/// ```
+/// use re_arrow2::array::PrimitiveArray;
+/// use std::sync::Arc;
+/// use re_arrow2::compute::temporal::{convert_timezone, hour};
+/// use re_arrow2::datatypes::{DataType, TimeUnit};
+///
+/// let data_type = DataType::Timestamp(TimeUnit::Second, Some(Arc::new("+01:00".to_string())));
+/// let array = PrimitiveArray::<i64>::from_slice([0]).to(data_type);
+/// let result = convert_timezone(&array, Some(Arc::new("-03:00".to_string()))).unwrap();
+/// assert_eq!(result.values(), array.values());
+/// assert_eq!(hour(&result).unwrap().value(0), 21);
+/// ```
+/// # Errors
+/// This function errors if `array` is not a timestamp or `timezone` cannot be parsed.
+pub fn convert_timezone(
+    array: &dyn Array,
+    timezone: Option<std::sync::Arc<String>>,
+) -> Result<PrimitiveArray<i64>> {
+    match array.data_type().to_logical_type() {
+        DataType::Timestamp(time_unit, _) => {
+            with_timezone!(&timezone, |_tz| Ok::<_, Error>(()))?;
+            let array = array
+                .as_any()
+                .downcast_ref::<PrimitiveArray<i64>>()
+                .unwrap();
+            Ok(array.clone().to(DataType::Timestamp(*time_unit, timezone)))
+        }
+        dt => Err(Error::NotYetImplemented(format!(
+            "\"convert_timezone\" does not support type {dt:?}"
+        ))),
+    }
+}
@@ -0,0 +1,376 @@
+//! Contains the [`unique`], [`value_counts`] and [`dictionary_encode`] operators.
+//!
+//! All operators group the slots of an array by value: two slots belong to the same group
+//! when they are both null or when their values are equal. Floats are compared by their bit
+//! representation, so that `0.0` and `-0.0` are not grouped together, except that all `NaN`s
+//! (e.g. `f64::NAN` and `-f64::NAN`) are.
+use std::hash::{BuildHasherDefault, Hash, Hasher};
+use std::sync::Arc;
+
+use hashbrown::{hash_map::RawEntryMut, HashMap};
+
+use crate::{
+    array::{
+        growable::make_growable, Array, BinaryArray, BooleanArray, DictionaryArray, DictionaryKey,
+        FixedSizeBinaryArray, FixedSizeListArray, ListArray, MapArray, PassthroughHasher,
+        PrimitiveArray, StructArray, Utf8Array,
+    },
+    bitmap::Bitmap,
+    datatypes::{DataType, Field, PhysicalType},
+    error::{Error, Result},
+    offset::{Offset, OffsetsBuffer},
+    types::{f16, NativeType, PrimitiveType},
+};
+
+use super::hash::hash;
+
+/// Returns whether the values at two slots of an array are equal.
+type DynEq = Box<dyn Fn(usize, usize) -> bool + Send + Sync>;
+
+/// The hash assigned to null slots
+const NULL_HASH: u64 = 0;
+
+/// Returns the hash of every slot of `array`, consistent with [`build_is_equal`].
+fn hash_slots(array: &dyn Array) -> Result<Vec<u64>> {
+    let mut hashes = hash(array)?.values().to_vec();
+    if let Some(validity) = array.validity() {
+        hashes
+            .iter_mut()
+            .zip(validity.iter())
+            .filter(|(_, is_valid)| !is_valid)
+            .for_each(|(hash, _)| *hash = NULL_HASH);
+    }
+    Ok(hashes)
+}
+
+fn eq_offsets<O: Offset>(offsets: &OffsetsBuffer<O>, values: &dyn Array) -> Result<DynEq> {
+    let offsets = offsets.clone();
+    let values = build_is_equal(values)?;
+    Ok(Box::new(move |i, j| {
+        let (lhs_start, lhs_end) = offsets.start_end(i);
+        let (rhs_start, rhs_end) = offsets.start_end(j);
+        lhs_end - lhs_start == rhs_end - rhs_start
+            && (lhs_start..lhs_end)
+                .zip(rhs_start..rhs_end)
+                .all(|(lhs, rhs)| values(lhs, rhs))
+    }))
+}
+
+fn eq_native<T: NativeType>(array: &dyn Array) -> DynEq {
+    let array = array
+        .as_any()
+        .downcast_ref::<PrimitiveArray<T>>()
+        .unwrap()
+        .clone();
+    Box::new(move |i, j| {
+        array.value(i).to_le_bytes().as_ref() == array.value(j).to_le_bytes().as_ref()
+    })
+}
+
+fn eq_float<T: NativeType>(array: &dyn Array, is_nan: fn(T) -> bool) -> DynEq {
+    let array = array
+        .as_any()
+        .downcast_ref::<PrimitiveArray<T>>()
+        .unwrap()
+        .clone();
+    Box::new(move |i, j| {
+        let (lhs, rhs) = (array.value(i), array.value(j));
+        (is_nan(lhs) && is_nan(rhs)) || lhs.to_le_bytes().as_ref() == rhs.to_le_bytes().as_ref()
+    })
+}
+
+/// Returns a function that returns whether the slots `i` and `j` of `array` are equal.
+/// Two null slots are equal.
+fn build_is_equal(array: &dyn Array) -> Result<DynEq> {
+    use PhysicalType::*;
+    let values: DynEq = match array.data_type().to_physical_type() {
+        Null => Box::new(|_, _| true),
+        Boolean => {
+            let array = array
+                .as_any()
+                .downcast_ref::<BooleanArray>()
+                .unwrap()
+                .clone();
+            Box::new(move |i, j| array.value(i) == array.value(j))
+        }
+        Primitive(PrimitiveType::Float16) => eq_float::<f16>(array, |x| x.is_nan()),
+        Primitive(PrimitiveType::Float32) => eq_float::<f32>(array, |x| x.is_nan()),
+        Primitive(PrimitiveType::Float64) => eq_float::<f64>(array, |x| x.is_nan()),
+        Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
+            eq_native::<$T>(array)
+        }),
+        Binary => {
+            let array = array
+                .as_any()
+                .downcast_ref::<BinaryArray<i32>>()
+                .unwrap()
+                .clone();
+            Box::new(move |i, j| array.value(i) == array.value(j))
+        }
+        LargeBinary => {
+            let array = array
+                .as_any()
+                .downcast_ref::<BinaryArray<i64>>()
+                .unwrap()
+                .clone();
+            Box::new(move |i, j| array.value(i) == array.value(j))
+        }
+        Utf8 => {
+            let array = array
+                .as_any()
+                .downcast_ref::<Utf8Array<i32>>()
+                .unwrap()
+                .clone();
+            Box::new(move |i, j| array.value(i) == array.value(j))
+        }
+        LargeUtf8 => {
+            let array = array
+                .as_any()
+                .downcast_ref::<Utf8Array<i64>>()
+                .unwrap()
+                .clone();
+            Box::new(move |i, j| array.value(i) == array.value(j))
+        }
+        FixedSizeBinary => {
+            let array = array
+                .as_any()
+                .downcast_ref::<FixedSizeBinaryArray>()
+                .unwrap()
+                .clone();
+            Box::new(move |i, j| array.value(i) == array.value(j))
+        }
+        List => {
+            let array = array.as_any().downcast_ref::<ListArray<i32>>().unwrap();
+            eq_offsets(array.offsets(), array.values().as_ref())?
+        }
+        LargeList => {
+            let array = array.as_any().downcast_ref::<ListArray<i64>>().unwrap();
+            eq_offsets(array.offsets(), array.values().as_ref())?
+        }
+        Map => {
+            let array = array.as_any().downcast_ref::<MapArray>().unwrap();
+            eq_offsets(array.offsets(), array.field().as_ref())?
+        }
+        FixedSizeList => {
+            let array = array.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
+            let size = array.size();
+            let values = build_is_equal(array.values().as_ref())?;
+            Box::new(move |i, j| (0..size).all(|k| values(i * size + k, j * size + k)))
+        }
+        Struct => {
+            let array = array.as_any().downcast_ref::<StructArray>().unwrap();
+            let fields = array
+                .values()
+                .iter()
+                .map(|x| build_is_equal(x.as_ref()))
+                .collect::<Result<Vec<_>>>()?;
+            Box::new(move |i, j| fields.iter().all(|eq| eq(i, j)))
+        }
+        Dictionary(key_type) => match_integer_type!(key_type, |$T| {
+            let array = array.as_any().downcast_ref::<DictionaryArray<$T>>().unwrap();
+            let keys = array.keys().values().clone();
+            let values = build_is_equal(array.values().as_ref())?;
+            // safety: all dictionaries keys are guaranteed to be castable to usize
+            Box::new(move |i, j| unsafe { values(keys[i].as_usize(), keys[j].as_usize()) })
+        }),
+        other => {
+            return Err(Error::NotYetImplemented(format!(
+                "Grouping by value is not supported for arrays of physical type {other:?}"
+            )))
+        }
+    };
+    Ok(match array.validity().cloned() {
+        Some(validity) if validity.unset_bits() > 0 => {
+            Box::new(
+                move |i, j| match (validity.get_bit(i), validity.get_bit(j)) {
+                    (true, true) => values(i, j),
+                    (false, false) => true,
+                    _ => false,
+                },
+            )
+        }
+        _ => values,
+    })
+}
+
+#[derive(Clone, Copy)]
+struct Group {
+    hash: u64,
+    id: usize,
+}
+
+impl Hash for Group {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.hash.hash(state)
+    }
+}
+
+/// The result of grouping the slots of an array by value.
+struct Groups {
+    /// the first slot of each group, in order of appearance
+    first: Vec<usize>,
+    /// the group of each slot
+    ids: Vec<usize>,
+}
+
+fn group(array: &dyn Array) -> Result<Groups> {
+    let hashes = hash_slots(array)?;
+    let is_equal = build_is_equal(array)?;
+
+    let mut map = HashMap::<Group, (), BuildHasherDefault<PassthroughHasher>>::default();
+    let mut first = vec![];
+    let ids = hashes
+        .into_iter()
+        .enumerate()
+        .map(|(index, hash)| {
+            match map
+                .raw_entry_mut()
+                .from_hash(hash, |group| is_equal(first[group.id], index))
+            {
+                RawEntryMut::Occupied(entry) => entry.key().id,
+                RawEntryMut::Vacant(entry) => {
+                    let id = first.len();
+                    // NB: don't use .insert() here!
+                    entry.insert_hashed_nocheck(hash, Group { hash, id }, ());
+                    first.push(index);
+                    id
+                }
+            }
+        })
+        .collect();
+    Ok(Groups { first, ids })
+}
+
+/// Returns a new array with the values of `array` at `slots`
+fn gather(array: &dyn Array, slots: &[usize]) -> Box<dyn Array> {
+    let mut growable = make_growable(&[array], false, slots.len());
+    slots.iter().for_each(|slot| growable.extend(0, *slot, 1));
+    growable.as_box()
+}
+
+/// Returns the distinct values of `array`, in order of first appearance.
+/// If `array` contains nulls, the result contains a single null.
+/// # Example
+/// ```
+/// use re_arrow2::array::Int32Array;
+/// use re_arrow2::compute::unique::unique;
+///
+/// let array = Int32Array::from(&[Some(1), None, Some(3), Some(1), None]);
+/// let result = unique(&array).unwrap();
+/// assert_eq!(result.as_ref(), &Int32Array::from(&[Some(1), None, Some(3)]) as &dyn re_arrow2::array::Array);
+/// ```
+/// # Errors
+/// This function errors if the array's [`DataType`] is not supported (see [`can_unique`]).
+pub fn unique(array: &dyn Array) -> Result<Box<dyn Array>> {
+    let groups = group(array)?;
+    Ok(gather(array, &groups.first))
+}
+
+/// Returns the distinct values of `array` together with the number of times each appears,
+/// as a [`StructArray`] with the fields `values` and `counts` (of type `UInt64`).
+/// Values are in order of first appearance; nulls are counted as a single value.
+/// # Example
+/// ```
+/// use re_arrow2::array::{Int32Array, UInt64Array};
+/// use re_arrow2::compute::unique::value_counts;
+///
+/// let array = Int32Array::from(&[Some(1), None, Some(3), Some(1), None, Some(1)]);
+/// let result = value_counts(&array).unwrap();
+/// assert_eq!(result.values()[0].as_ref(), &Int32Array::from(&[Some(1), None, Some(3)]) as &dyn re_arrow2::array::Array);
+/// assert_eq!(result.values()[1].as_ref(), &UInt64Array::from_slice([3, 2, 1]) as &dyn re_arrow2::array::Array);
+/// ```
+/// # Errors
+/// This function errors if the array's [`DataType`] is not supported (see [`can_unique`]).
+pub fn value_counts(array: &dyn Array) -> Result<StructArray> {
+    let groups = group(array)?;
+
+    let mut counts = vec![0u64; groups.first.len()];
+    groups.ids.iter().for_each(|id| counts[*id] += 1);
+
+    let values = gather(array, &groups.first);
+    let counts = PrimitiveArray::<u64>::from_vec(counts).boxed();
+
+    let data_type = DataType::Struct(Arc::new(vec![
+        Field::new("values", array.data_type().clone(), true),
+        Field::new("counts", DataType::UInt64, false),
+    ]));
+    StructArray::try_new(data_type, vec![values, counts], None)
+}
+
+/// Encodes `array` as a [`DictionaryArray`] whose values are the distinct values of `array`,
+/// in order of first appearance. Null slots are encoded as null keys.
+/// # Example
+/// ```
+/// use re_arrow2::array::{Int32Array, Utf8Array};
+/// use re_arrow2::compute::unique::dictionary_encode;
+///
+/// let array = Utf8Array::<i32>::from([Some("a"), None, Some("b"), Some("a")]);
+/// let result = dictionary_encode::<i32>(&array).unwrap();
+/// assert_eq!(result.keys(), &Int32Array::from(&[Some(0), None, Some(1), Some(0)]));
+/// assert_eq!(result.values().as_ref(), &Utf8Array::<i32>::from_slice(["a", "b"]) as &dyn re_arrow2::array::Array);
+/// ```
+/// # Errors
+/// This function errors if the array's [`DataType`] is not supported (see [`can_unique`])
+/// or if the number of distinct values does not fit in `K`.
+pub fn dictionary_encode<K: DictionaryKey>(array: &dyn Array) -> Result<DictionaryArray<K>> {
+    let groups = group(array)?;
+
+    // nulls are all in the same group; remove it from the values
+    let null_id = groups.first.iter().position(|slot| array.is_null(*slot));
+    let first = groups
+        .first
+        .iter()
+        .enumerate()
+        .filter(|(id, _)| Some(*id) != null_id)
+        .map(|(_, slot)| *slot)
+        .collect::<Vec<_>>();
+
+    let keys = groups
+        .ids
+        .iter()
+        .map(|id| match null_id {
+            Some(null_id) if *id == null_id => Ok(K::default()),
+            Some(null_id) if *id > null_id => K::try_from(*id - 1).map_err(|_| Error::Overflow),
+            _ => K::try_from(*id).map_err(|_| Error::Overflow),
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let validity = null_id
+        .map(|null_id| Bitmap::from_trusted_len_iter(groups.ids.iter().map(|id| *id != null_id)));
+    let keys = PrimitiveArray::<K>::new(K::PRIMITIVE.into(), keys.into(), validity);
+
+    let values = gather(array, &first);
+    let data_type = DataType::Dictionary(K::KEY_TYPE, Arc::new(array.data_type().clone()), false);
+    DictionaryArray::try_new(data_type, keys, values)
+}
+
+/// Checks if an array of type `data_type` can be used in [`unique`], [`value_counts`] and
+/// [`dictionary_encode`].
+///
+/// # Examples
+/// ```
+/// use std::sync::Arc;
+/// use re_arrow2::compute::unique::can_unique;
+/// use re_arrow2::datatypes::{DataType, Field, UnionMode};
+///
+/// let data_type = DataType::Float64;
+/// assert_eq!(can_unique(&data_type), true);
+///
+/// let data_type = DataType::List(Arc::new(Field::new("item", DataType::Utf8, true)));
+/// assert_eq!(can_unique(&data_type), true);
+///
+/// let data_type = DataType::Union(Arc::new(vec![]), None, UnionMode::Dense);
+/// assert_eq!(can_unique(&data_type), false);
+/// ```
+pub fn can_unique(data_type: &DataType) -> bool {
+    use DataType::*;
+    match data_type.to_logical_type() {
+        Union(..) => false,
+        List(field) | LargeList(field) | FixedSizeList(field, _) | Map(field, _) => {
+            can_unique(field.data_type())
+        }
+        Struct(fields) => fields.iter().all(|field| can_unique(field.data_type())),
+        Dictionary(_, values, _) => can_unique(values),
+        _ => true,
+    }
+}
@@ -1,12 +1,18 @@
 //! Defines common maps to a [`Utf8Array`]
 
 use crate::{
-    array::{Array, Utf8Array},
-    datatypes::DataType,
+    array::{
+        growable::make_growable, Array, BooleanArray, DictionaryArray, DictionaryKey, ListArray,
+        MutableUtf8ValuesArray, PrimitiveArray, Utf8Array,
+    },
+    bitmap::Bitmap,
+    datatypes::{DataType, PhysicalType},
     error::{Error, Result},
-    offset::Offset,
+    offset::{Offset, Offsets},
 };
 
+use super::utils::combine_validities;
+
 /// utf8_apply will apply `Fn(&str) -> String` to every value in Utf8Array.
 pub fn utf8_apply<O: Offset, F: Fn(&str) -> String>(f: F, array: &Utf8Array<O>) -> Utf8Array<O> {
     let iter = array.values_iter().map(f);
@@ -98,3 +104,697 @@ pub fn lower(array: &dyn Array) -> Result<Box<dyn Array>> {
 pub fn can_lower(data_type: &DataType) -> bool {
     matches!(data_type, DataType::LargeUtf8 | DataType::Utf8)
 }
+
+/// Applies `op` to every value of a [`Utf8Array`], without allocating intermediate [`String`]s.
+fn utf8_map<O: Offset, F: Fn(&str) -> &str>(array: &Utf8Array<O>, op: F) -> Utf8Array<O> {
+    let iter = array.values_iter().map(op);
+
+    let new = Utf8Array::<O>::from_trusted_len_values_iter(iter);
+    new.with_validity(array.validity().cloned())
+}
+
+/// Returns `array` with its data type replaced by `data_type` (e.g. an extension type).
+fn with_data_type<O: Offset>(array: Utf8Array<O>, data_type: DataType) -> Utf8Array<O> {
+    let (_, offsets, values, validity) = array.into_inner();
+    // safety: `array` is valid utf8
+    unsafe { Utf8Array::<O>::new_unchecked(data_type, offsets, values, validity) }
+}
+
+/// Applies a predicate to every value of a [`Utf8Array`].
+fn utf8_predicate<O: Offset, F: Fn(&str) -> bool>(array: &Utf8Array<O>, op: F) -> BooleanArray {
+    let values = Bitmap::from_trusted_len_iter(array.values_iter().map(op));
+    BooleanArray::new(DataType::Boolean, values, array.validity().cloned())
+}
+
+/// Applies a predicate to every pair of values of two [`Utf8Array`]s.
+fn utf8_binary_predicate<O: Offset, F: Fn(&str, &str) -> bool>(
+    lhs: &Utf8Array<O>,
+    rhs: &Utf8Array<O>,
+    op: F,
+) -> Result<BooleanArray> {
+    if lhs.len() != rhs.len() {
+        return Err(Error::InvalidArgumentError(
+            "Arrays must have the same length".to_string(),
+        ));
+    }
+    let values = lhs
+        .values_iter()
+        .zip(rhs.values_iter())
+        .map(|(lhs, rhs)| op(lhs, rhs));
+    let values = Bitmap::from_trusted_len_iter(values);
+    let validity = combine_validities(lhs.validity(), rhs.validity());
+    Ok(BooleanArray::new(DataType::Boolean, values, validity))
+}
+
+/// Applies an operation returning an integer to every value of a [`Utf8Array`].
+/// The result is `Int32` for [`Utf8Array<i32>`] and `Int64` for [`Utf8Array<i64>`].
+fn utf8_to_offset<O: Offset, F: Fn(&str) -> usize>(
+    array: &Utf8Array<O>,
+    op: F,
+) -> PrimitiveArray<O> {
+    let values = array
+        .values_iter()
+        .map(|x| O::from_usize(op(x)).unwrap())
+        .collect::<Vec<_>>();
+
+    let data_type = if O::IS_LARGE {
+        DataType::Int64
+    } else {
+        DataType::Int32
+    };
+    PrimitiveArray::<O>::new(data_type, values.into(), array.validity().cloned())
+}
+
+/// Dispatches `$body` over a `[Large]Utf8` array downcasted to `$array`.
+macro_rules! with_utf8 {
+    ($array:expr, | $name:ident | $body:expr) => {{
+        match $array.data_type().to_physical_type() {
+            PhysicalType::Utf8 => {
+                let $name = $array.as_any().downcast_ref::<Utf8Array<i32>>().unwrap();
+                Box::new($body) as Box<dyn Array>
+            }
+            PhysicalType::LargeUtf8 => {
+                let $name = $array.as_any().downcast_ref::<Utf8Array<i64>>().unwrap();
+                Box::new($body) as Box<dyn Array>
+            }
+            _ => unreachable!(),
+        }
+    }};
+}
+
+/// Applies `op` to a `[Large]Utf8` array or to a dictionary-encoded `[Large]Utf8` array.
+///
+/// For dictionaries, `op` is applied to the values only. When `op` returns strings of the same
+/// type, the result is dictionary-encoded with the same keys; otherwise it is decoded.
+fn unary_dyn<F>(array: &dyn Array, name: &str, op: F) -> Result<Box<dyn Array>>
+where
+    F: Fn(&dyn Array) -> Result<Box<dyn Array>>,
+{
+    match array.data_type().to_physical_type() {
+        PhysicalType::Utf8 | PhysicalType::LargeUtf8 => op(array),
+        PhysicalType::Dictionary(key_type) if is_utf8_dictionary(array.data_type()) => {
+            match_integer_type!(key_type, |$T| {
+                let array = array
+                    .as_any()
+                    .downcast_ref::<DictionaryArray<$T>>()
+                    .unwrap();
+                let values = op(array.values().as_ref())?;
+                if values.data_type() == array.values().data_type() {
+                    let data_type = array.data_type().clone();
+                    return Ok(DictionaryArray::<$T>::try_new(data_type, array.keys().clone(), values)?.boxed());
+                }
+
+                Ok(decode(array.keys(), values.as_ref()))
+            })
+        }
+        _ => Err(Error::InvalidArgumentError(format!(
+            "{name} does not support type {:?}",
+            array.data_type()
+        ))),
+    }
+}
+
+/// Returns the rows of `values` at `keys`, i.e. a decoded dictionary.
+fn decode<K: DictionaryKey>(keys: &PrimitiveArray<K>, values: &dyn Array) -> Box<dyn Array> {
+    let mut growable = make_growable(&[values], true, keys.len());
+    keys.iter().for_each(|key| match key {
+        // safety: all dictionaries keys are guaranteed to be castable to usize
+        Some(key) => growable.extend(0, unsafe { key.as_usize() }, 1),
+        None => growable.extend_validity(1),
+    });
+    growable.as_box()
+}
+
+/// Returns `array` decoded to `[Large]Utf8` if it is a dictionary-encoded `[Large]Utf8` array,
+/// and `array` otherwise.
+fn decode_utf8_dictionary(array: &dyn Array) -> Box<dyn Array> {
+    match array.data_type().to_physical_type() {
+        PhysicalType::Dictionary(key_type) if is_utf8_dictionary(array.data_type()) => {
+            match_integer_type!(key_type, |$T| {
+                let array = array
+                    .as_any()
+                    .downcast_ref::<DictionaryArray<$T>>()
+                    .unwrap();
+                decode(array.keys(), array.values().as_ref())
+            })
+        }
+        _ => array.to_boxed(),
+    }
+}
+
+fn is_utf8_dictionary(data_type: &DataType) -> bool {
+    matches!(
+        data_type.to_logical_type(),
+        DataType::Dictionary(_, values, _) if matches!(values.as_ref(), DataType::Utf8 | DataType::LargeUtf8)
+    )
+}
+
+/// Checks if an array of type `data_type` can be used in the unary string operators of this
+/// module (e.g. [`trim`], [`reverse`], [`starts_with_scalar`] or [`split`]).
+///
+/// # Examples
+/// ```
+/// use std::sync::Arc;
+/// use re_arrow2::compute::utf8::can_utf8_unary;
+/// use re_arrow2::datatypes::{DataType, IntegerType};
+///
+/// let data_type = DataType::Utf8;
+/// assert_eq!(can_utf8_unary(&data_type), true);
+///
+/// let data_type = DataType::Dictionary(IntegerType::Int32, Arc::new(DataType::LargeUtf8), false);
+/// assert_eq!(can_utf8_unary(&data_type), true);
+///
+/// let data_type = DataType::Binary;
+/// assert_eq!(can_utf8_unary(&data_type), false);
+/// ```
+pub fn can_utf8_unary(data_type: &DataType) -> bool {
+    matches!(
+        data_type.to_physical_type(),
+        PhysicalType::Utf8 | PhysicalType::LargeUtf8
+    ) || is_utf8_dictionary(data_type)
+}
+
+/// Returns a [`Utf8Array`] with leading and trailing whitespace removed from each value.
+pub fn utf8_trim<O: Offset>(array: &Utf8Array<O>) -> Utf8Array<O> {
+    utf8_map(array, str::trim)
+}
+
+/// Returns a [`Utf8Array`] with leading whitespace removed from each value.
+pub fn utf8_ltrim<O: Offset>(array: &Utf8Array<O>) -> Utf8Array<O> {
+    utf8_map(array, str::trim_start)
+}
+
+/// Returns a [`Utf8Array`] with trailing whitespace removed from each value.
+pub fn utf8_rtrim<O: Offset>(array: &Utf8Array<O>) -> Utf8Array<O> {
+    utf8_map(array, str::trim_end)
+}
+
+/// Returns a new `Array` where leading and trailing whitespace is removed from each element.
+/// # Example
+/// ```
+/// use re_arrow2::array::Utf8Array;
+/// use re_arrow2::compute::utf8::trim;
+///
+/// let array = Utf8Array::<i32>::from([Some(" a "), None, Some("b\n")]);
+/// let result = trim(&array).unwrap();
+/// assert_eq!(result.as_ref(), &Utf8Array::<i32>::from([Some("a"), None, Some("b")]) as &dyn re_arrow2::array::Array);
+/// ```
+/// # Errors
+/// This function errors when the array is not a (dictionary-encoded) \[Large\]String array.
+pub fn trim(array: &dyn Array) -> Result<Box<dyn Array>> {
+    unary_dyn(array, "trim", |array| {
+        Ok(with_utf8!(array, |a| utf8_trim(a)))
+    })
+}
+
+/// Returns a new `Array` where leading whitespace is removed from each element.
+/// # Errors
+/// This function errors when the array is not a (dictionary-encoded) \[Large\]String array.
+pub fn ltrim(array: &dyn Array) -> Result<Box<dyn Array>> {
+    unary_dyn(array, "ltrim", |array| {
+        Ok(with_utf8!(array, |a| utf8_ltrim(a)))
+    })
+}
+
+/// Returns a new `Array` where trailing whitespace is removed from each element.
+/// # Errors
+/// This function errors when the array is not a (dictionary-encoded) \[Large\]String array.
+pub fn rtrim(array: &dyn Array) -> Result<Box<dyn Array>> {
+    unary_dyn(array, "rtrim", |array| {
+        Ok(with_utf8!(array, |a| utf8_rtrim(a)))
+    })
+}
+
+/// Returns a [`ListArray`] whose lists contain the substrings of each value of `array`
+/// separated by `pattern`.
+/// # Errors
+/// This function errors when `pattern` is empty or the offsets of the result overflow.
+pub fn utf8_split<O: Offset>(array: &Utf8Array<O>, pattern: &str) -> Result<ListArray<O>> {
+    if pattern.is_empty() {
+        return Err(Error::InvalidArgumentError(
+            "split requires a non-empty pattern".to_string(),
+        ));
+    }
+    let mut values = MutableUtf8ValuesArray::<O>::with_capacity(array.len());
+    let mut offsets = Offsets::<O>::with_capacity(array.len());
+    for value in array.iter() {
+        match value {
+            Some(value) => {
+                let length = values.len();
+                value.split(pattern).for_each(|x| values.push(x));
+                offsets.try_push_usize(values.len() - length)?;
+            }
+            None => offsets.extend_constant(1),
+        }
+    }
+    let values = with_data_type(values.into(), array.data_type().clone());
+    ListArray::<O>::try_new(
+        ListArray::<O>::default_datatype(array.data_type().clone()),
+        offsets.into(),
+        values.boxed(),
+        array.validity().cloned(),
+    )
+}
+
+/// Returns a new `Array` of lists with the substrings of each element separated by `pattern`.
+/// `[Large]Utf8` arrays return `[Large]List` arrays of `[Large]Utf8`.
+/// # Example
+/// ```
+/// use re_arrow2::array::{ListArray, MutableListArray, MutableUtf8Array, TryExtend, Utf8Array};
+/// use re_arrow2::compute::utf8::split;
+///
+/// let array = Utf8Array::<i32>::from([Some("a,b"), None, Some("c")]);
+/// let result = split(&array, ",").unwrap();
+///
+/// let mut expected = MutableListArray::<i32, MutableUtf8Array<i32>>::new();
+/// expected.try_extend(vec![Some(vec![Some("a"), Some("b")]), None, Some(vec![Some("c")])]).unwrap();
+/// let expected: ListArray<i32> = expected.into();
+/// assert_eq!(result.as_ref(), &expected as &dyn re_arrow2::array::Array);
+/// ```
+/// # Errors
+/// This function errors when the array is not a (dictionary-encoded) \[Large\]String array
+/// or `pattern` is empty.
+pub fn split(array: &dyn Array, pattern: &str) -> Result<Box<dyn Array>> {
+    unary_dyn(array, "split", |array| {
+        Ok(with_utf8!(array, |a| utf8_split(a, pattern)?))
+    })
+}
+
+/// Returns a [`Utf8Array`] where all occurrences of `from` are replaced by `to`.
+pub fn utf8_replace<O: Offset>(array: &Utf8Array<O>, from: &str, to: &str) -> Utf8Array<O> {
+    utf8_apply(|x| x.replace(from, to), array)
+}
+
+/// Returns a new `Array` where all occurrences of `from` are replaced by `to` in each element.
+/// # Errors
+/// This function errors when the array is not a (dictionary-encoded) \[Large\]String array.
+pub fn replace(array: &dyn Array, from: &str, to: &str) -> Result<Box<dyn Array>> {
+    unary_dyn(array, "replace", |array| {
+        Ok(with_utf8!(array, |a| utf8_replace(a, from, to)))
+    })
+}
+
+/// Returns a [`Utf8Array`] where all matches of `regex` are replaced by `to`.
+/// `to` may reference capture groups (e.g. `$1`), as in [`regex::Regex::replace_all`].
+/// # Errors
+/// This function errors when `regex` is not a valid regular expression.
+#[cfg(feature = "regex")]
+#[cfg_attr(docsrs, doc(cfg(feature = "regex")))]
+pub fn utf8_regex_replace<O: Offset>(
+    array: &Utf8Array<O>,
+    regex: &str,
+    to: &str,
+) -> Result<Utf8Array<O>> {
+    let regex = regex::Regex::new(regex).map_err(|e| {
+        Error::InvalidArgumentError(format!("Unable to build regex from pattern: {e:?}"))
+    })?;
+    Ok(utf8_apply(|x| regex.replace_all(x, to).into_owned(), array))
+}
+
+/// Returns a new `Array` where all matches of `regex` are replaced by `to` in each element.
+/// # Example
+/// ```
+/// use re_arrow2::array::Utf8Array;
+/// use re_arrow2::compute::utf8::regex_replace;
+///
+/// let array = Utf8Array::<i32>::from([Some("a1b22"), None]);
+/// let result = regex_replace(&array, r"\d+", "#").unwrap();
+/// assert_eq!(result.as_ref(), &Utf8Array::<i32>::from([Some("a#b#"), None]) as &dyn re_arrow2::array::Array);
+/// ```
+/// # Errors
+/// This function errors when the array is not a (dictionary-encoded) \[Large\]String array
+/// or `regex` is not a valid regular expression.
+#[cfg(feature = "regex")]
+#[cfg_attr(docsrs, doc(cfg(feature = "regex")))]
+pub fn regex_replace(array: &dyn Array, regex: &str, to: &str) -> Result<Box<dyn Array>> {
+    unary_dyn(array, "regex_replace", |array| {
+        Ok(with_utf8!(array, |a| utf8_regex_replace(a, regex, to)?))
+    })
+}
+
+/// Returns a [`Utf8Array`] with the values of `arrays` concatenated row by row, optionally
+/// separated by `separator`.
+fn utf8_concat_impl<O: Offset>(
+    arrays: &[&Utf8Array<O>],
+    separator: Option<&str>,
+) -> Result<Utf8Array<O>> {
+    let first = arrays.first().ok_or_else(|| {
+        Error::InvalidArgumentError("concat requires at least one array".to_string())
+    })?;
+    if arrays.iter().any(|x| x.len() != first.len()) {
+        return Err(Error::InvalidArgumentError(
+            "concat requires all arrays to have the same length".to_string(),
+        ));
+    }
+
+    let mut values = MutableUtf8ValuesArray::<O>::with_capacity(first.len());
+    let mut buffer = String::new();
+    for row in 0..first.len() {
+        buffer.clear();
+        let iter = arrays
+            .iter()
+            .filter(|x| x.is_valid(row))
+            .map(|x| x.value(row));
+        match separator {
+            Some(separator) => iter.enumerate().for_each(|(i, x)| {
+                if i > 0 {
+                    buffer.push_str(separator);
+                }
+                buffer.push_str(x)
+            }),
+            None => iter.for_each(|x| buffer.push_str(x)),
+        }
+        values.push(&buffer);
+    }
+    let validity = match separator {
+        Some(_) => None,
+        None => arrays.iter().fold(None, |acc, x| {
+            combine_validities(acc.as_ref(), x.validity())
+        }),
+    };
+    let values = with_data_type(values.into(), first.data_type().clone());
+    Ok(values.with_validity(validity))
+}
+
+/// Returns a [`Utf8Array`] with the values of `arrays` concatenated row by row.
+/// A row is null if any of the arrays is null in that row.
+/// # Errors
+/// This function errors when `arrays` is empty or the arrays have different lengths.
+pub fn utf8_concat<O: Offset>(arrays: &[&Utf8Array<O>]) -> Result<Utf8Array<O>> {
+    utf8_concat_impl(arrays, None)
+}
+
+/// Returns a [`Utf8Array`] with the values of `arrays` concatenated row by row and separated
+/// by `separator`. Null values are skipped, so that the result has no nulls.
+/// # Errors
+/// This function errors when `arrays` is empty or the arrays have different lengths.
+pub fn utf8_concat_ws<O: Offset>(
+    arrays: &[&Utf8Array<O>],
+    separator: &str,
+) -> Result<Utf8Array<O>> {
+    utf8_concat_impl(arrays, Some(separator))
+}
+
+fn concat_dyn(arrays: &[&dyn Array], separator: Option<&str>) -> Result<Box<dyn Array>> {
+    let arrays = arrays
+        .iter()
+        .map(|x| decode_utf8_dictionary(*x))
+        .collect::<Vec<_>>();
+    let data_type = arrays.first().map(|x| x.data_type()).ok_or_else(|| {
+        Error::InvalidArgumentError("concat requires at least one array".to_string())
+    })?;
+    if arrays.iter().any(|x| x.data_type() != data_type) {
+        return Err(Error::InvalidArgumentError(
+            "concat requires all arrays to have the same datatype".to_string(),
+        ));
+    }
+    Ok(match data_type.to_physical_type() {
+        PhysicalType::Utf8 => {
+            let arrays = arrays
+                .iter()
+                .map(|x| x.as_any().downcast_ref::<Utf8Array<i32>>().unwrap())
+                .collect::<Vec<_>>();
+            utf8_concat_impl(&arrays, separator)?.boxed()
+        }
+        PhysicalType::LargeUtf8 => {
+            let arrays = arrays
+                .iter()
+                .map(|x| x.as_any().downcast_ref::<Utf8Array<i64>>().unwrap())
+                .collect::<Vec<_>>();
+            utf8_concat_impl(&arrays, separator)?.boxed()
+        }
+        _ => {
+            return Err(Error::InvalidArgumentError(format!(
+                "concat does not support type {data_type:?}"
+            )))
+        }
+    })
+}
+
+/// Returns a new `Array` with the elements of `arrays` concatenated row by row.
+/// A row is null if any of the arrays is null in that row.
+/// # Example
+/// ```
+/// use re_arrow2::array::Utf8Array;
+/// use re_arrow2::compute::utf8::concat;
+///
+/// let a = Utf8Array::<i32>::from([Some("a"), Some("b"), None]);
+/// let b = Utf8Array::<i32>::from([Some("1"), Some("2"), Some("3")]);
+/// let result = concat(&[&a, &b]).unwrap();
+/// assert_eq!(result.as_ref(), &Utf8Array::<i32>::from([Some("a1"), Some("b2"), None]) as &dyn re_arrow2::array::Array);
+/// ```
+/// # Errors
+/// This function errors when `arrays` is empty, or its arrays are not (dictionary-encoded)
+/// \[Large\]String arrays of the same type and length.
+pub fn concat(arrays: &[&dyn Array]) -> Result<Box<dyn Array>> {
+    concat_dyn(arrays, None)
+}
+
+/// Returns a new `Array` with the elements of `arrays` concatenated row by row and separated
+/// by `separator`. Null elements are skipped, so that the result has no nulls.
+/// # Errors
+/// This function errors when `arrays` is empty, or its arrays are not (dictionary-encoded)
+/// \[Large\]String arrays of the same type and length.
+pub fn concat_ws(arrays: &[&dyn Array], separator: &str) -> Result<Box<dyn Array>> {
+    concat_dyn(arrays, Some(separator))
+}
+
+fn pad(value: &str, length: usize, fill: char, left: bool) -> String {
+    let chars = value.chars().count();
+    if chars >= length {
+        return value.chars().take(length).collect();
+    }
+    let padding = std::iter::repeat(fill).take(length - chars);
+    if left {
+        padding.chain(value.chars()).collect()
+    } else {
+        value.chars().chain(padding).collect()
+    }
+}
+
+/// Returns a [`Utf8Array`] whose values are left-padded with `fill` to `length` characters.
+/// Values longer than `length` are truncated to `length` characters.
+pub fn utf8_lpad<O: Offset>(array: &Utf8Array<O>, length: usize, fill: char) -> Utf8Array<O> {
+    utf8_apply(|x| pad(x, length, fill, true), array)
+}
+
+/// Returns a [`Utf8Array`] whose values are right-padded with `fill` to `length` characters.
+/// Values longer than `length` are truncated to `length` characters.
+pub fn utf8_rpad<O: Offset>(array: &Utf8Array<O>, length: usize, fill: char) -> Utf8Array<O> {
+    utf8_apply(|x| pad(x, length, fill, false), array)
+}
+
+/// Returns a new `Array` whose elements are left-padded with `fill` to `length` characters.
+/// Elements longer than `length` are truncated to `length` characters.
+/// # Example
+/// ```
+/// use re_arrow2::array::Utf8Array;
+/// use re_arrow2::compute::utf8::lpad;
+///
+/// let array = Utf8Array::<i32>::from([Some("7"), Some("12345"), None]);
+/// let result = lpad(&array, 3, '0').unwrap();
+/// assert_eq!(result.as_ref(), &Utf8Array::<i32>::from([Some("007"), Some("123"), None]) as &dyn re_arrow2::array::Array);
+/// ```
+/// # Errors
+/// This function errors when the array is not a (dictionary-encoded) \[Large\]String array.
+pub fn lpad(array: &dyn Array, length: usize, fill: char) -> Result<Box<dyn Array>> {
+    unary_dyn(array, "lpad", |array| {
+        Ok(with_utf8!(array, |a| utf8_lpad(a, length, fill)))
+    })
+}
+
+/// Returns a new `Array` whose elements are right-padded with `fill` to `length` characters.
+/// Elements longer than `length` are truncated to `length` characters.
+/// # Errors
+/// This function errors when the array is not a (dictionary-encoded) \[Large\]String array.
+pub fn rpad(array: &dyn Array, length: usize, fill: char) -> Result<Box<dyn Array>> {
+    unary_dyn(array, "rpad", |array| {
+        Ok(with_utf8!(array, |a| utf8_rpad(a, length, fill)))
+    })
+}
+
+/// Returns whether each value of `lhs` starts with `rhs`.
+pub fn utf8_starts_with_scalar<O: Offset>(lhs: &Utf8Array<O>, rhs: &str) -> BooleanArray {
+    utf8_predicate(lhs, |x| x.starts_with(rhs))
+}
+
+/// Returns whether each value of `lhs` ends with `rhs`.
+pub fn utf8_ends_with_scalar<O: Offset>(lhs: &Utf8Array<O>, rhs: &str) -> BooleanArray {
+    utf8_predicate(lhs, |x| x.ends_with(rhs))
+}
+
+/// Returns whether each value of `lhs` contains `rhs`.
+pub fn utf8_contains_scalar<O: Offset>(lhs: &Utf8Array<O>, rhs: &str) -> BooleanArray {
+    utf8_predicate(lhs, |x| x.contains(rhs))
+}
+
+/// Returns whether each value of `lhs` starts with the value of `rhs` in the same row.
+/// # Errors
+/// This function errors when the arrays have different lengths.
+pub fn utf8_starts_with<O: Offset>(lhs: &Utf8Array<O>, rhs: &Utf8Array<O>) -> Result<BooleanArray> {
+    utf8_binary_predicate(lhs, rhs, |lhs, rhs| lhs.starts_with(rhs))
+}
+
+/// Returns whether each value of `lhs` ends with the value of `rhs` in the same row.
+/// # Errors
+/// This function errors when the arrays have different lengths.
+pub fn utf8_ends_with<O: Offset>(lhs: &Utf8Array<O>, rhs: &Utf8Array<O>) -> Result<BooleanArray> {
+    utf8_binary_predicate(lhs, rhs, |lhs, rhs| lhs.ends_with(rhs))
+}
+
+/// Returns whether each value of `lhs` contains the value of `rhs` in the same row.
+/// # Errors
+/// This function errors when the arrays have different lengths.
+pub fn utf8_contains<O: Offset>(lhs: &Utf8Array<O>, rhs: &Utf8Array<O>) -> Result<BooleanArray> {
+    utf8_binary_predicate(lhs, rhs, |lhs, rhs| lhs.contains(rhs))
+}
+
+fn predicate_scalar_dyn<F>(lhs: &dyn Array, name: &str, op: F) -> Result<BooleanArray>
+where
+    F: Fn(&str) -> bool + Copy,
+{
+    let result = unary_dyn(lhs, name, |array| {
+        Ok(with_utf8!(array, |a| utf8_predicate(a, op)))
+    })?;
+    Ok(result
+        .as_any()
+        .downcast_ref::<BooleanArray>()
+        .unwrap()
+        .clone())
+}
+
+fn predicate_dyn<F>(lhs: &dyn Array, rhs: &dyn Array, name: &str, op: F) -> Result<BooleanArray>
+where
+    F: Fn(&str, &str) -> bool,
+{
+    let (lhs, rhs) = (decode_utf8_dictionary(lhs), decode_utf8_dictionary(rhs));
+    match (lhs.data_type(), rhs.data_type()) {
+        (DataType::Utf8, DataType::Utf8) => utf8_binary_predicate::<i32, _>(
+            lhs.as_any().downcast_ref().unwrap(),
+            rhs.as_any().downcast_ref().unwrap(),
+            op,
+        ),
+        (DataType::LargeUtf8, DataType::LargeUtf8) => utf8_binary_predicate::<i64, _>(
+            lhs.as_any().downcast_ref().unwrap(),
+            rhs.as_any().downcast_ref().unwrap(),
+            op,
+        ),
+        (lhs, rhs) => Err(Error::InvalidArgumentError(format!(
+            "{name} does not support types {lhs:?} and {rhs:?}"
+        ))),
+    }
+}
+
+/// Returns whether each element of `lhs` starts with `rhs`.
+/// # Example
+/// ```
+/// use re_arrow2::array::{BooleanArray, Utf8Array};
+/// use re_arrow2::compute::utf8::starts_with_scalar;
+///
+/// let array = Utf8Array::<i32>::from([Some("arrow"), Some("parquet"), None]);
+/// let result = starts_with_scalar(&array, "ar").unwrap();
+/// assert_eq!(result, BooleanArray::from([Some(true), Some(false), None]));
+/// ```
+/// # Errors
+/// This function errors when the array is not a (dictionary-encoded) \[Large\]String array.
+pub fn starts_with_scalar(lhs: &dyn Array, rhs: &str) -> Result<BooleanArray> {
+    predicate_scalar_dyn(lhs, "starts_with", |x| x.starts_with(rhs))
+}
+
+/// Returns whether each element of `lhs` ends with `rhs`.
+/// # Errors
+/// This function errors when the array is not a (dictionary-encoded) \[Large\]String array.
+pub fn ends_with_scalar(lhs: &dyn Array, rhs: &str) -> Result<BooleanArray> {
+    predicate_scalar_dyn(lhs, "ends_with", |x| x.ends_with(rhs))
+}
+
+/// Returns whether each element of `lhs` contains `rhs`.
+/// # Errors
+/// This function errors when the array is not a (dictionary-encoded) \[Large\]String array.
+pub fn contains_scalar(lhs: &dyn Array, rhs: &str) -> Result<BooleanArray> {
+    predicate_scalar_dyn(lhs, "contains", |x| x.contains(rhs))
+}
+
+/// Returns whether each element of `lhs` starts with the element of `rhs` in the same row.
+/// # Errors
+/// This function errors when the arrays are not (dictionary-encoded) \[Large\]String arrays of
+/// the same type and length.
+pub fn starts_with(lhs: &dyn Array, rhs: &dyn Array) -> Result<BooleanArray> {
+    predicate_dyn(lhs, rhs, "starts_with", |lhs, rhs| lhs.starts_with(rhs))
+}
+
+/// Returns whether each element of `lhs` ends with the element of `rhs` in the same row.
+/// # Errors
+/// This function errors when the arrays are not (dictionary-encoded) \[Large\]String arrays of
+/// the same type and length.
+pub fn ends_with(lhs: &dyn Array, rhs: &dyn Array) -> Result<BooleanArray> {
+    predicate_dyn(lhs, rhs, "ends_with", |lhs, rhs| lhs.ends_with(rhs))
+}
+
+/// Returns whether each element of `lhs` contains the element of `rhs` in the same row.
+/// # Errors
+/// This function errors when the arrays are not (dictionary-encoded) \[Large\]String arrays of
+/// the same type and length.
+pub fn contains(lhs: &dyn Array, rhs: &dyn Array) -> Result<BooleanArray> {
+    predicate_dyn(lhs, rhs, "contains", |lhs, rhs| lhs.contains(rhs))
+}
+
+/// Returns a [`Utf8Array`] whose values have their characters in reverse order.
+pub fn utf8_reverse<O: Offset>(array: &Utf8Array<O>) -> Utf8Array<O> {
+    utf8_apply(|x| x.chars().rev().collect(), array)
+}
+
+/// Returns a new `Array` whose elements have their characters in reverse order.
+/// # Errors
+/// This function errors when the array is not a (dictionary-encoded) \[Large\]String array.
+pub fn reverse(array: &dyn Array) -> Result<Box<dyn Array>> {
+    unary_dyn(array, "reverse", |array| {
+        Ok(with_utf8!(array, |a| utf8_reverse(a)))
+    })
+}
+
+/// Returns the number of characters (not bytes) of each value of a [`Utf8Array`].
+pub fn utf8_char_length<O: Offset>(array: &Utf8Array<O>) -> PrimitiveArray<O> {
+    utf8_to_offset(array, |x| x.chars().count())
+}
+
+/// Returns an array of integers with the number of characters (not bytes) on each element
+/// of the array: `Int32` for `Utf8` and `Int64` for `LargeUtf8`.
+/// See [`length`](crate::compute::length::length) for the number of bytes.
+/// # Example
+/// ```
+/// use re_arrow2::array::{Int32Array, Utf8Array};
+/// use re_arrow2::compute::utf8::char_length;
+///
+/// let array = Utf8Array::<i32>::from([Some("héllo"), None]);
+/// let result = char_length(&array).unwrap();
+/// assert_eq!(result.as_ref(), &Int32Array::from([Some(5), None]) as &dyn re_arrow2::array::Array);
+/// ```
+/// # Errors
+/// This function errors when the array is not a (dictionary-encoded) \[Large\]String array.
+pub fn char_length(array: &dyn Array) -> Result<Box<dyn Array>> {
+    unary_dyn(array, "char_length", |array| {
+        Ok(with_utf8!(array, |a| utf8_char_length(a)))
+    })
+}
+
+/// Returns the 1-based position, in characters, of the first occurrence of `substring` in
+/// each value of a [`Utf8Array`], or `0` when `substring` is not found.
+pub fn utf8_strpos<O: Offset>(array: &Utf8Array<O>, substring: &str) -> PrimitiveArray<O> {
+    utf8_to_offset(array, |x| {
+        x.find(substring)
+            .map(|byte| x[..byte].chars().count() + 1)
+            .unwrap_or(0)
+    })
+}
+
+/// Returns the 1-based position, in characters, of the first occurrence of `substring` in
+/// each element of the array, or `0` when `substring` is not found.
+/// The result is `Int32` for `Utf8` and `Int64` for `LargeUtf8`.
+/// # Errors
+/// This function errors when the array is not a (dictionary-encoded) \[Large\]String array.
+pub fn strpos(array: &dyn Array, substring: &str) -> Result<Box<dyn Array>> {
+    unary_dyn(array, "strpos", |array| {
+        Ok(with_utf8!(array, |a| utf8_strpos(a, substring)))
+    })
+}
@@ -15,14 +15,19 @@
 // specific language governing permissions and limitations
 // under the License.
 
-//! Defines windowing functions, like `shift`ing
+//! Defines windowing functions, like `shift`ing and rolling aggregations
+//! (e.g. [`rolling_mean`]).
+
+use std::collections::VecDeque;
 
 use crate::compute::concatenate::concatenate;
-use num_traits::{abs, clamp};
+use num_traits::{abs, clamp, AsPrimitive};
 
 use crate::{
-    array::{new_null_array, Array},
+    array::{new_null_array, Array, PrimitiveArray},
+    datatypes::DataType,
     error::{Error, Result},
+    types::NativeType,
 };
 
 /// Shifts array by defined number of items (to left or right)
@@ -63,3 +68,531 @@ pub fn shift(array: &dyn Array, offset: i64) -> Result<Box<dyn Array>> {
         concatenate(&[slice.as_ref(), null_array.as_ref()])
     }
 }
+
+/// The window over which a rolling aggregation is computed.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RollingWindow<'a> {
+    /// A window of a fixed number of rows: the window of row `i` is `[i + 1 - size, i]`.
+    Fixed(usize),
+    /// A window of a fixed duration over a sorted timestamp column: the window of row `i`
+    /// contains all rows `j` with `timestamps[i] - duration < timestamps[j] <= timestamps[i]`.
+    ///
+    /// `timestamps` must have the same length as the aggregated array, no nulls, and be sorted in
+    /// ascending order. `duration` is expressed in the same unit as `timestamps`.
+    Time {
+        /// The timestamps of each row (e.g. a `Timestamp`, `Date64` or `Int64` array)
+        timestamps: &'a PrimitiveArray<i64>,
+        /// The (positive) duration of the window
+        duration: i64,
+    },
+}
+
+/// Options of rolling aggregations such as [`rolling_sum`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RollingOptions<'a> {
+    /// The window of each row
+    pub window: RollingWindow<'a>,
+    /// The minimum number of non-null values a window must contain for its result to be non-null
+    pub min_periods: usize,
+    /// Whether windows are centered on their row instead of ending at it.
+    ///
+    /// A centered fixed window of row `i` is `[i - size / 2, i + (size - 1) / 2]`; a centered time
+    /// window is shifted forward by half its duration.
+    pub center: bool,
+}
+
+impl<'a> RollingOptions<'a> {
+    /// Returns trailing windows of `size` rows whose results require `size` non-null values.
+    pub fn fixed(size: usize) -> Self {
+        Self {
+            window: RollingWindow::Fixed(size),
+            min_periods: size,
+            center: false,
+        }
+    }
+
+    /// Returns trailing windows of `duration` over `timestamps` whose results require
+    /// one non-null value.
+    pub fn time(timestamps: &'a PrimitiveArray<i64>, duration: i64) -> Self {
+        Self {
+            window: RollingWindow::Time {
+                timestamps,
+                duration,
+            },
+            min_periods: 1,
+            center: false,
+        }
+    }
+}
+
+/// Returns the `[start, end)` bounds of the window of each of the `length` rows.
+/// Both `start` and `end` are non-decreasing.
+fn window_bounds(length: usize, options: &RollingOptions) -> Result<Vec<(usize, usize)>> {
+    match options.window {
+        RollingWindow::Fixed(size) => {
+            if size == 0 {
+                return Err(Error::InvalidArgumentError(
+                    "The size of a rolling window must be positive".to_string(),
+                ));
+            }
+            let offset = if options.center { (size - 1) / 2 } else { 0 };
+            Ok((0..length)
+                .map(|i| {
+                    let end = (i + 1 + offset).min(length);
+                    ((i + 1 + offset).saturating_sub(size), end)
+                })
+                .collect())
+        }
+        RollingWindow::Time {
+            timestamps,
+            duration,
+        } => {
+            if duration <= 0 {
+                return Err(Error::InvalidArgumentError(
+                    "The duration of a rolling window must be positive".to_string(),
+                ));
+            }
+            if timestamps.len() != length {
+                return Err(Error::InvalidArgumentError(format!(
+                    "Rolling windows require timestamps of the same length as the array ({}), but got {}",
+                    length,
+                    timestamps.len()
+                )));
+            }
+            if timestamps.null_count() > 0 {
+                return Err(Error::InvalidArgumentError(
+                    "Rolling windows require timestamps without nulls".to_string(),
+                ));
+            }
+            let timestamps = timestamps.values();
+            if timestamps.windows(2).any(|x| x[0] > x[1]) {
+                return Err(Error::InvalidArgumentError(
+                    "Rolling windows require timestamps sorted in ascending order".to_string(),
+                ));
+            }
+            let shift = if options.center { duration / 2 } else { 0 };
+
+            let (mut start, mut end) = (0, 0);
+            Ok(timestamps
+                .iter()
+                .map(|&timestamp| {
+                    // i128 so that the bounds do not overflow near i64::MIN and i64::MAX
+                    let upper = timestamp as i128 + shift as i128;
+                    let lower = upper - duration as i128;
+                    while end < length && timestamps[end] as i128 <= upper {
+                        end += 1;
+                    }
+                    while start < end && timestamps[start] as i128 <= lower {
+                        start += 1;
+                    }
+                    (start, end)
+                })
+                .collect())
+        }
+    }
+}
+
+/// The state of a rolling aggregation, updated as values enter and leave the window.
+trait RollingState<T> {
+    type Output: NativeType;
+
+    /// Adds the `value` of row `index` to the window
+    fn push(&mut self, index: usize, value: T) -> Result<()>;
+
+    /// Removes the `value` of row `index` from the window. Rows leave the window in the same
+    /// order they entered it.
+    fn pop(&mut self, index: usize, value: T) -> Result<()>;
+
+    /// Returns the result of the window, which contains `count` non-null values
+    fn get(&self, count: usize) -> Option<Self::Output>;
+}
+
+fn rolling<T: NativeType, S: RollingState<T>>(
+    array: &PrimitiveArray<T>,
+    options: &RollingOptions,
+    mut state: S,
+    data_type: DataType,
+) -> Result<PrimitiveArray<S::Output>> {
+    let bounds = window_bounds(array.len(), options)?;
+
+    let (mut start, mut end, mut count) = (0, 0, 0);
+    let result = bounds
+        .into_iter()
+        .map(|(new_start, new_end)| {
+            // remove before adding so that the state never holds more than one window
+            while start < new_start.min(end) {
+                if array.is_valid(start) {
+                    state.pop(start, array.value(start))?;
+                    count -= 1;
+                }
+                start += 1;
+            }
+            start = start.max(new_start);
+            end = end.max(start);
+            while end < new_end {
+                if array.is_valid(end) {
+                    state.push(end, array.value(end))?;
+                    count += 1;
+                }
+                end += 1;
+            }
+            Ok(if count >= options.min_periods {
+                state.get(count)
+            } else {
+                None
+            })
+        })
+        .collect::<Result<PrimitiveArray<S::Output>>>()?;
+    Ok(result.to(data_type))
+}
+
+/// A [`NativeType`] that can be summed over rolling windows (see [`rolling_sum`]).
+pub trait RollingSum: NativeType {
+    /// Returns `self + rhs`, or `None` if it overflows.
+    fn add_checked(self, rhs: Self) -> Option<Self>;
+
+    /// Returns `self - rhs`, or `None` if it overflows.
+    fn sub_checked(self, rhs: Self) -> Option<Self>;
+
+    /// Returns whether `self` is finite, i.e. neither NaN nor infinite.
+    fn is_finite(self) -> bool;
+}
+
+macro_rules! impl_rolling_sum_integer {
+    ($($type:ty),*) => {$(
+        impl RollingSum for $type {
+            #[inline]
+            fn add_checked(self, rhs: Self) -> Option<Self> {
+                self.checked_add(rhs)
+            }
+
+            #[inline]
+            fn sub_checked(self, rhs: Self) -> Option<Self> {
+                self.checked_sub(rhs)
+            }
+
+            #[inline]
+            fn is_finite(self) -> bool {
+                true
+            }
+        }
+    )*};
+}
+
+impl_rolling_sum_integer!(i8, i16, i32, i64, i128, u8, u16, u32, u64);
+
+macro_rules! impl_rolling_sum_float {
+    ($($type:ty),*) => {$(
+        impl RollingSum for $type {
+            #[inline]
+            fn add_checked(self, rhs: Self) -> Option<Self> {
+                Some(self + rhs)
+            }
+
+            #[inline]
+            fn sub_checked(self, rhs: Self) -> Option<Self> {
+                Some(self - rhs)
+            }
+
+            #[inline]
+            fn is_finite(self) -> bool {
+                <$type>::is_finite(self)
+            }
+        }
+    )*};
+}
+
+impl_rolling_sum_float!(f32, f64);
+
+/// Sums the finite values of the window. Non-finite values are kept aside, since removing
+/// them from a sum would yield NaN (e.g. `inf - inf`).
+struct Sum<T> {
+    sum: T,
+    non_finite: VecDeque<T>,
+}
+
+impl<T: RollingSum> RollingState<T> for Sum<T> {
+    type Output = T;
+
+    fn push(&mut self, _: usize, value: T) -> Result<()> {
+        if value.is_finite() {
+            self.sum = self.sum.add_checked(value).ok_or(Error::Overflow)?;
+        } else {
+            self.non_finite.push_back(value);
+        }
+        Ok(())
+    }
+
+    fn pop(&mut self, _: usize, value: T) -> Result<()> {
+        if value.is_finite() {
+            self.sum = self.sum.sub_checked(value).ok_or(Error::Overflow)?;
+        } else {
+            self.non_finite.pop_front();
+        }
+        Ok(())
+    }
+
+    fn get(&self, _: usize) -> Option<T> {
+        // non-finite values are never integers, so their sum does not overflow
+        self.non_finite
+            .iter()
+            .try_fold(self.sum, |acc, x| acc.add_checked(*x))
+    }
+}
+
+struct Count;
+
+impl<T> RollingState<T> for Count {
+    type Output = u64;
+
+    fn push(&mut self, _: usize, _: T) -> Result<()> {
+        Ok(())
+    }
+
+    fn pop(&mut self, _: usize, _: T) -> Result<()> {
+        Ok(())
+    }
+
+    fn get(&self, count: usize) -> Option<u64> {
+        Some(count as u64)
+    }
+}
+
+/// Welford's online algorithm over the finite values of the window, extended to the removal
+/// of values. Like in [`Sum`], non-finite values are kept aside.
+#[derive(Default)]
+struct Moments {
+    count: usize,
+    mean: f64,
+    m2: f64,
+    non_finite: VecDeque<f64>,
+}
+
+impl<T: AsPrimitive<f64>> RollingState<T> for Moments {
+    type Output = f64;
+
+    fn push(&mut self, _: usize, value: T) -> Result<()> {
+        let value = value.as_();
+        if !value.is_finite() {
+            self.non_finite.push_back(value);
+            return Ok(());
+        }
+        self.count += 1;
+        let delta = value - self.mean;
+        self.mean += delta / self.count as f64;
+        self.m2 += delta * (value - self.mean);
+        Ok(())
+    }
+
+    fn pop(&mut self, _: usize, value: T) -> Result<()> {
+        let value = value.as_();
+        if !value.is_finite() {
+            self.non_finite.pop_front();
+            return Ok(());
+        }
+        self.count -= 1;
+        if self.count == 0 {
+            (self.mean, self.m2) = (0.0, 0.0);
+        } else {
+            let delta = value - self.mean;
+            self.mean -= delta / self.count as f64;
+            self.m2 = (self.m2 - delta * (value - self.mean)).max(0.0);
+        }
+        Ok(())
+    }
+
+    fn get(&self, count: usize) -> Option<f64> {
+        if self.non_finite.is_empty() {
+            (count > 0).then_some(self.mean)
+        } else {
+            // the mean of the finite values does not change the sum of the non-finite ones
+            Some(self.non_finite.iter().sum())
+        }
+    }
+}
+
+struct Std(Moments);
+
+impl<T: AsPrimitive<f64>> RollingState<T> for Std {
+    type Output = f64;
+
+    fn push(&mut self, index: usize, value: T) -> Result<()> {
+        self.0.push(index, value)
+    }
+
+    fn pop(&mut self, index: usize, value: T) -> Result<()> {
+        self.0.pop(index, value)
+    }
+
+    fn get(&self, count: usize) -> Option<f64> {
+        if count < 2 {
+            None
+        } else if self.0.non_finite.is_empty() {
+            Some((self.0.m2 / (count - 1) as f64).sqrt())
+        } else {
+            Some(f64::NAN)
+        }
+    }
+}
+
+/// A monotonic deque of the candidates for the extremum of the window: `is_before(a, b)`
+/// returns whether `a` takes precedence over `b`.
+struct Extremum<T> {
+    candidates: VecDeque<(usize, T)>,
+    is_before: fn(&T, &T) -> bool,
+}
+
+impl<T: NativeType> RollingState<T> for Extremum<T> {
+    type Output = T;
+
+    fn push(&mut self, index: usize, value: T) -> Result<()> {
+        while let Some((_, last)) = self.candidates.back() {
+            if (self.is_before)(&value, last) {
+                self.candidates.pop_back();
+            } else {
+                break;
+            }
+        }
+        self.candidates.push_back((index, value));
+        Ok(())
+    }
+
+    fn pop(&mut self, index: usize, _: T) -> Result<()> {
+        if self.candidates.front().map(|x| x.0) == Some(index) {
+            self.candidates.pop_front();
+        }
+        Ok(())
+    }
+
+    fn get(&self, _: usize) -> Option<T> {
+        self.candidates.front().map(|x| x.1)
+    }
+}
+
+/// Returns the rolling sum of `array`, of the same data type as `array`.
+/// The sum of a window without non-null values is zero; the sum of a window with NaN or
+/// infinite values is computed as usual (e.g. NaN when it contains both infinities).
+/// # Errors
+/// This function errors iff the window is invalid (see [`RollingWindow`]), or the sum of
+/// consecutive integers of a window overflows.
+/// # Examples
+/// ```
+/// use re_arrow2::array::Int32Array;
+/// use re_arrow2::compute::window::{rolling_sum, RollingOptions};
+///
+/// let array = Int32Array::from(&[Some(1), Some(2), None, Some(4)]);
+/// let options = RollingOptions {
+///     min_periods: 1,
+///     ..RollingOptions::fixed(2)
+/// };
+/// let result = rolling_sum(&array, &options).unwrap();
+/// assert_eq!(result, Int32Array::from(&[Some(1), Some(3), Some(2), Some(4)]));
+/// ```
+pub fn rolling_sum<T>(
+    array: &PrimitiveArray<T>,
+    options: &RollingOptions,
+) -> Result<PrimitiveArray<T>>
+where
+    T: RollingSum,
+{
+    let state = Sum {
+        sum: T::default(),
+        non_finite: VecDeque::new(),
+    };
+    rolling(array, options, state, array.data_type().clone())
+}
+
+/// Returns the rolling number of non-null values of `array`.
+/// # Errors
+/// This function errors iff the window is invalid (see [`RollingWindow`]).
+pub fn rolling_count<T: NativeType>(
+    array: &PrimitiveArray<T>,
+    options: &RollingOptions,
+) -> Result<PrimitiveArray<u64>> {
+    rolling(array, options, Count, DataType::UInt64)
+}
+
+/// Returns the rolling mean of `array`.
+/// # Errors
+/// This function errors iff the window is invalid (see [`RollingWindow`]).
+/// # Examples
+/// ```
+/// use re_arrow2::array::{Float64Array, Int64Array};
+/// use re_arrow2::compute::window::{rolling_mean, RollingOptions};
+///
+/// let timestamps = Int64Array::from_slice([0, 10, 20, 40]);
+/// let array = Int64Array::from_slice([1, 2, 3, 4]);
+/// // values of the last 20 time units
+/// let result = rolling_mean(&array, &RollingOptions::time(&timestamps, 20)).unwrap();
+/// assert_eq!(result, Float64Array::from_slice([1.0, 1.5, 2.5, 4.0]));
+/// ```
+pub fn rolling_mean<T>(
+    array: &PrimitiveArray<T>,
+    options: &RollingOptions,
+) -> Result<PrimitiveArray<f64>>
+where
+    T: NativeType + AsPrimitive<f64>,
+{
+    rolling(array, options, Moments::default(), DataType::Float64)
+}
+
+/// Returns the rolling sample standard deviation of `array`.
+/// The standard deviation of a window with less than two non-null values is null.
+/// # Errors
+/// This function errors iff the window is invalid (see [`RollingWindow`]).
+pub fn rolling_std<T>(
+    array: &PrimitiveArray<T>,
+    options: &RollingOptions,
+) -> Result<PrimitiveArray<f64>>
+where
+    T: NativeType + AsPrimitive<f64>,
+{
+    rolling(array, options, Std(Moments::default()), DataType::Float64)
+}
+
+/// Returns the rolling minimum of `array`, of the same data type as `array`.
+/// # Errors
+/// This function errors iff the window is invalid (see [`RollingWindow`]).
+/// # Examples
+/// ```
+/// use re_arrow2::array::Int32Array;
+/// use re_arrow2::compute::window::{rolling_min, RollingOptions};
+///
+/// let array = Int32Array::from_slice([3, 1, 4, 1, 5]);
+/// let options = RollingOptions {
+///     center: true,
+///     ..RollingOptions::fixed(3)
+/// };
+/// let result = rolling_min(&array, &options).unwrap();
+/// assert_eq!(result, Int32Array::from(&[None, Some(1), Some(1), Some(1), None]));
+/// ```
+pub fn rolling_min<T>(
+    array: &PrimitiveArray<T>,
+    options: &RollingOptions,
+) -> Result<PrimitiveArray<T>>
+where
+    T: NativeType + PartialOrd,
+{
+    let state = Extremum {
+        candidates: VecDeque::new(),
+        is_before: |lhs: &T, rhs: &T| lhs < rhs,
+    };
+    rolling(array, options, state, array.data_type().clone())
+}
+
+/// Returns the rolling maximum of `array`, of the same data type as `array`.
+/// # Errors
+/// This function errors iff the window is invalid (see [`RollingWindow`]).
+pub fn rolling_max<T>(
+    array: &PrimitiveArray<T>,
+    options: &RollingOptions,
+) -> Result<PrimitiveArray<T>>
+where
+    T: NativeType + PartialOrd,
+{
+    let state = Extremum {
+        candidates: VecDeque::new(),
+        is_before: |lhs: &T, rhs: &T| lhs > rhs,
+    };
+    rolling(array, options, state, array.data_type().clone())
+}
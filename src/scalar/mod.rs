@@ -2,7 +2,14 @@
 //! as well as concrete implementations such as [`BooleanScalar`].
 use std::any::Any;
 
-use crate::{array::*, datatypes::*};
+use crate::{
+    array::*,
+    bitmap::Bitmap,
+    datatypes::*,
+    error::{Error, Result},
+    offset::{Offset, Offsets},
+    types::NativeType,
+};
 
 mod dictionary;
 pub use dictionary::*;
@@ -184,3 +191,242 @@ pub fn new_scalar(array: &dyn Array, index: usize) -> Box<dyn Scalar> {
         }),
     }
 }
+
+/// Returns the number of items of `length` repetitions of `len` items.
+fn repeated_len(len: usize, length: usize) -> Result<usize> {
+    len.checked_mul(length).ok_or(Error::Overflow)
+}
+
+/// Returns the offsets of `length` repetitions of `len` items.
+fn repeated_offsets<O: Offset>(len: usize, length: usize) -> Result<Offsets<O>> {
+    // checked upfront, so that offsets that overflow are not allocated
+    O::from_usize(repeated_len(len, length)?).ok_or(Error::Overflow)?;
+    Offsets::try_from_lengths(std::iter::repeat(len).take(length))
+}
+
+/// Returns `values` repeated `length` times.
+fn repeat(values: &dyn Array, length: usize) -> Result<Box<dyn Array>> {
+    let capacity = repeated_len(values.len(), length)?;
+    let mut growable = growable::make_growable(&[values], false, capacity);
+    (0..length).for_each(|_| growable.extend(0, 0, values.len()));
+    Ok(growable.as_box())
+}
+
+macro_rules! dyn_to_array_utf8 {
+    ($scalar:expr, $length:expr, $type:ty) => {{
+        let value = $scalar
+            .as_any()
+            .downcast_ref::<Utf8Scalar<$type>>()
+            .unwrap()
+            .value()
+            .unwrap();
+        let offsets = repeated_offsets::<$type>(value.len(), $length)?;
+        let values = value.as_bytes().repeat($length);
+        Box::new(Utf8Array::<$type>::new(
+            $scalar.data_type().clone(),
+            offsets.into(),
+            values.into(),
+            None,
+        )) as Box<dyn Array>
+    }};
+}
+
+macro_rules! dyn_to_array_binary {
+    ($scalar:expr, $length:expr, $type:ty) => {{
+        let value = $scalar
+            .as_any()
+            .downcast_ref::<BinaryScalar<$type>>()
+            .unwrap()
+            .value()
+            .unwrap();
+        let offsets = repeated_offsets::<$type>(value.len(), $length)?;
+        let values = value.repeat($length);
+        Box::new(BinaryArray::<$type>::new(
+            $scalar.data_type().clone(),
+            offsets.into(),
+            values.into(),
+            None,
+        )) as Box<dyn Array>
+    }};
+}
+
+macro_rules! dyn_to_array_list {
+    ($scalar:expr, $length:expr, $type:ty) => {{
+        let values = $scalar
+            .as_any()
+            .downcast_ref::<ListScalar<$type>>()
+            .unwrap()
+            .values();
+        let offsets = repeated_offsets::<$type>(values.len(), $length)?;
+        Box::new(ListArray::<$type>::new(
+            $scalar.data_type().clone(),
+            offsets.into(),
+            repeat(values.as_ref(), $length)?,
+            None,
+        )) as Box<dyn Array>
+    }};
+}
+
+/// Creates a new [`Array`] of `length` slots, all equal to `scalar`.
+/// This is the inverse of [`new_scalar`].
+/// # Example
+/// ```
+/// use re_arrow2::array::Int32Array;
+/// use re_arrow2::datatypes::DataType;
+/// use re_arrow2::scalar::{to_array, PrimitiveScalar};
+///
+/// let scalar = PrimitiveScalar::new(DataType::Int32, Some(1i32));
+/// let array = to_array(&scalar, 3).unwrap();
+/// assert_eq!(array.as_ref(), &Int32Array::from_slice([1, 1, 1]) as &dyn re_arrow2::array::Array);
+/// ```
+/// # Errors
+/// This function errors with [`Error::Overflow`] iff the `length` repetitions of the value
+/// overflow the offsets of its type (e.g. `i32` for [`DataType::Utf8`]), or `usize`.
+pub fn to_array(scalar: &dyn Scalar, length: usize) -> Result<Box<dyn Array>> {
+    use PhysicalType::*;
+    let data_type = scalar.data_type();
+    if !scalar.is_valid() {
+        return Ok(new_null_array(data_type.clone(), length));
+    }
+    Ok(match data_type.to_physical_type() {
+        Null => new_null_array(data_type.clone(), length),
+        Boolean => {
+            let value = scalar
+                .as_any()
+                .downcast_ref::<BooleanScalar>()
+                .unwrap()
+                .value()
+                .unwrap();
+            Box::new(BooleanArray::new(
+                data_type.clone(),
+                Bitmap::new_constant(value, length),
+                None,
+            ))
+        }
+        Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
+            let value = scalar
+                .as_any()
+                .downcast_ref::<PrimitiveScalar<$T>>()
+                .unwrap()
+                .value()
+                .unwrap();
+            Box::new(PrimitiveArray::<$T>::new(
+                data_type.clone(),
+                vec![value; length].into(),
+                None,
+            ))
+        }),
+        Utf8 => dyn_to_array_utf8!(scalar, length, i32),
+        LargeUtf8 => dyn_to_array_utf8!(scalar, length, i64),
+        Binary => dyn_to_array_binary!(scalar, length, i32),
+        LargeBinary => dyn_to_array_binary!(scalar, length, i64),
+        List => dyn_to_array_list!(scalar, length, i32),
+        LargeList => dyn_to_array_list!(scalar, length, i64),
+        FixedSizeBinary => {
+            let value = scalar
+                .as_any()
+                .downcast_ref::<FixedSizeBinaryScalar>()
+                .unwrap()
+                .value()
+                .unwrap();
+            repeated_len(value.len(), length)?;
+            Box::new(FixedSizeBinaryArray::new(
+                data_type.clone(),
+                value.repeat(length).into(),
+                None,
+            ))
+        }
+        FixedSizeList => {
+            let values = scalar
+                .as_any()
+                .downcast_ref::<FixedSizeListScalar>()
+                .unwrap()
+                .values()
+                .unwrap();
+            Box::new(FixedSizeListArray::new(
+                data_type.clone(),
+                repeat(values.as_ref(), length)?,
+                None,
+            ))
+        }
+        Map => {
+            let values = scalar
+                .as_any()
+                .downcast_ref::<MapScalar>()
+                .unwrap()
+                .values();
+            let offsets = repeated_offsets::<i32>(values.len(), length)?;
+            Box::new(MapArray::new(
+                data_type.clone(),
+                offsets.into(),
+                repeat(values.as_ref(), length)?,
+                None,
+            ))
+        }
+        Struct => {
+            let values = scalar
+                .as_any()
+                .downcast_ref::<StructScalar>()
+                .unwrap()
+                .values()
+                .iter()
+                .map(|x| to_array(x.as_ref(), length))
+                .collect::<Result<_>>()?;
+            Box::new(StructArray::new(data_type.clone(), values, None))
+        }
+        Union => {
+            let scalar = scalar.as_any().downcast_ref::<UnionScalar>().unwrap();
+            let (fields, ids, mode) = match data_type.to_logical_type() {
+                DataType::Union(fields, ids, mode) => (fields, ids, mode),
+                _ => unreachable!(),
+            };
+            let index = ids
+                .as_ref()
+                .and_then(|ids| ids.iter().position(|id| *id == scalar.type_() as i32))
+                .unwrap_or(scalar.type_() as usize);
+            let offsets = if mode.is_sparse() {
+                None
+            } else {
+                // the offsets of a dense union are `i32`
+                let length = i32::try_from(length).map_err(|_| Error::Overflow)?;
+                Some((0..length).collect::<Vec<_>>().into())
+            };
+            let fields = fields
+                .iter()
+                .enumerate()
+                .map(|(i, field)| match (i == index, mode.is_sparse()) {
+                    (true, _) => to_array(scalar.value().as_ref(), length),
+                    (false, true) => Ok(new_null_array(field.data_type().clone(), length)),
+                    (false, false) => Ok(new_empty_array(field.data_type().clone())),
+                })
+                .collect::<Result<_>>()?;
+            Box::new(UnionArray::new(
+                data_type.clone(),
+                vec![scalar.type_(); length].into(),
+                fields,
+                offsets,
+            ))
+        }
+        Dictionary(key_type) => match_integer_type!(key_type, |$T| {
+            let value = scalar
+                .as_any()
+                .downcast_ref::<DictionaryScalar<$T>>()
+                .unwrap()
+                .value()
+                .unwrap();
+            let keys = PrimitiveArray::<$T>::new(
+                $T::PRIMITIVE.into(),
+                vec![$T::default(); length].into(),
+                None,
+            );
+            Box::new(
+                DictionaryArray::<$T>::try_new(
+                    data_type.clone(),
+                    keys,
+                    to_array(value.as_ref(), 1)?,
+                )
+                .unwrap(),
+            )
+        }),
+    })
+}
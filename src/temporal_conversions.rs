@@ -58,13 +58,18 @@ pub fn date32_to_date_opt(days: i32) -> Option<NaiveDate> {
 /// converts a `i64` representing a `date64` to [`NaiveDateTime`]
 #[inline]
 pub fn date64_to_datetime(v: i64) -> NaiveDateTime {
+    date64_to_datetime_opt(v).expect("invalid or out-of-range datetime")
+}
+
+/// converts a `i64` representing a `date64` to [`NaiveDateTime`]
+#[inline]
+pub fn date64_to_datetime_opt(v: i64) -> Option<NaiveDateTime> {
     NaiveDateTime::from_timestamp_opt(
         // extract seconds from milliseconds
         v / MILLISECONDS,
         // discard extracted seconds and convert milliseconds to nanoseconds
         (v % MILLISECONDS * MICROSECONDS) as u32,
     )
-    .expect("invalid or out-of-range datetime")
 }
 
 /// converts a `i64` representing a `date64` to [`NaiveDate`]
@@ -73,10 +78,22 @@ pub fn date64_to_date(milliseconds: i64) -> NaiveDate {
     date64_to_datetime(milliseconds).date()
 }
 
+/// converts a `i64` representing a `date64` to [`NaiveDate`]
+#[inline]
+pub fn date64_to_date_opt(milliseconds: i64) -> Option<NaiveDate> {
+    date64_to_datetime_opt(milliseconds).map(|datetime| datetime.date())
+}
+
 /// converts a `i32` representing a `time32(s)` to [`NaiveTime`]
 #[inline]
 pub fn time32s_to_time(v: i32) -> NaiveTime {
-    NaiveTime::from_num_seconds_from_midnight_opt(v as u32, 0).expect("invalid time")
+    time32s_to_time_opt(v).expect("invalid time")
+}
+
+/// converts a `i32` representing a `time32(s)` to [`NaiveTime`]
+#[inline]
+pub fn time32s_to_time_opt(v: i32) -> Option<NaiveTime> {
+    NaiveTime::from_num_seconds_from_midnight_opt(v as u32, 0)
 }
 
 /// converts a `i64` representing a `duration(s)` to [`Duration`]
@@ -106,13 +123,18 @@ pub fn duration_ns_to_duration(v: i64) -> Duration {
 /// converts a `i32` representing a `time32(ms)` to [`NaiveTime`]
 #[inline]
 pub fn time32ms_to_time(v: i32) -> NaiveTime {
+    time32ms_to_time_opt(v).expect("invalid time")
+}
+
+/// converts a `i32` representing a `time32(ms)` to [`NaiveTime`]
+#[inline]
+pub fn time32ms_to_time_opt(v: i32) -> Option<NaiveTime> {
     let v = v as i64;
     let seconds = v / MILLISECONDS;
 
     let milli_to_nano = 1_000_000;
     let nano = (v - seconds * MILLISECONDS) * milli_to_nano;
     NaiveTime::from_num_seconds_from_midnight_opt(seconds as u32, nano as u32)
-        .expect("invalid time")
 }
 
 /// converts a `i64` representing a `time64(us)` to [`NaiveTime`]
@@ -279,6 +301,33 @@ pub fn timestamp_to_datetime<T: chrono::TimeZone>(
     timezone.from_utc_datetime(&timestamp_to_naive_datetime(timestamp, time_unit))
 }
 
+/// Converts a timestamp in `time_unit` into [`chrono::NaiveDateTime`], or `None` if it is
+/// out of range.
+#[inline]
+pub fn timestamp_to_naive_datetime_opt(
+    timestamp: i64,
+    time_unit: TimeUnit,
+) -> Option<chrono::NaiveDateTime> {
+    match time_unit {
+        TimeUnit::Second => timestamp_s_to_datetime_opt(timestamp),
+        TimeUnit::Millisecond => timestamp_ms_to_datetime_opt(timestamp),
+        TimeUnit::Microsecond => timestamp_us_to_datetime_opt(timestamp),
+        TimeUnit::Nanosecond => timestamp_ns_to_datetime_opt(timestamp),
+    }
+}
+
+/// Converts a timestamp in `time_unit` and `timezone` into [`chrono::DateTime`], or `None`
+/// if it is out of range.
+#[inline]
+pub fn timestamp_to_datetime_opt<T: chrono::TimeZone>(
+    timestamp: i64,
+    time_unit: TimeUnit,
+    timezone: &T,
+) -> Option<chrono::DateTime<T>> {
+    timestamp_to_naive_datetime_opt(timestamp, time_unit)
+        .map(|datetime| timezone.from_utc_datetime(&datetime))
+}
+
 /// Calculates the scale factor between two TimeUnits. The function returns the
 /// scale that should multiply the TimeUnit "b" to have the same time scale as
 /// the TimeUnit "a".
@@ -479,6 +528,96 @@ pub fn utf8_to_naive_timestamp_ns<O: Offset>(
     PrimitiveArray::from_trusted_len_iter(iter).to(DataType::Timestamp(TimeUnit::Nanosecond, None))
 }
 
+/// Splits `value` into its `(number, designator)` components, e.g. `1Y2M` into `("1", 'Y')` and
+/// `("2", 'M')`, returning the index of each designator in `designators`.
+/// Returns `None` if a designator is unknown, repeated or out of order, or a number is empty.
+fn iso8601_components<'a>(mut value: &'a str, designators: &str) -> Option<Vec<(&'a str, usize)>> {
+    let mut components = vec![];
+    let mut next = 0;
+    while !value.is_empty() {
+        let end = value.find(|c: char| c.is_ascii_alphabetic())?;
+        let (number, rest) = value.split_at(end);
+        let designator = designators[next..].find(rest.chars().next()?)? + next;
+        if number.is_empty() {
+            return None;
+        }
+        components.push((number, designator));
+        next = designator + 1;
+        value = &rest[1..];
+    }
+    Some(components)
+}
+
+fn iso8601_integer<T: std::str::FromStr>(number: &str) -> Option<T> {
+    if number.bytes().all(|x| x.is_ascii_digit()) {
+        number.parse().ok()
+    } else {
+        None
+    }
+}
+
+/// Parses `number` as a (possibly fractional) number of seconds to nanoseconds. Digits beyond
+/// the nanosecond are truncated.
+fn iso8601_seconds_to_ns(number: &str) -> Option<i64> {
+    let (seconds, fraction) = number.split_once(['.', ',']).unwrap_or((number, ""));
+    let nanos = if fraction.is_empty() {
+        0
+    } else {
+        let fraction = &fraction[..fraction.len().min(9)];
+        let nanos: i64 = iso8601_integer(fraction)?;
+        nanos * 10_i64.pow(9 - fraction.len() as u32)
+    };
+    iso8601_integer::<i64>(seconds)?
+        .checked_mul(NANOSECONDS)?
+        .checked_add(nanos)
+}
+
+/// Parses an ISO 8601 duration such as `P1Y2M3W4DT5H6M7.5S` (optionally prefixed by a sign)
+/// to a [`months_days_ns`]. Years are counted as 12 months and weeks as 7 days; only seconds
+/// may be fractional.
+/// Returns `None` if `value` is not a valid duration or if it overflows.
+pub fn parse_iso8601_duration(value: &str) -> Option<months_days_ns> {
+    let (negative, value) = match value.strip_prefix('-') {
+        Some(value) => (true, value),
+        None => (false, value.strip_prefix('+').unwrap_or(value)),
+    };
+    let value = value.strip_prefix('P')?;
+    let (date, time) = match value.split_once('T') {
+        Some((_, "")) => return None,
+        Some((date, time)) => (date, time),
+        None if value.is_empty() => return None,
+        None => (value, ""),
+    };
+
+    let mut months = 0i32;
+    let mut days = 0i32;
+    for (number, designator) in iso8601_components(date, "YMWD")? {
+        let number: i32 = iso8601_integer(number)?;
+        match designator {
+            0 => months = months.checked_add(number.checked_mul(12)?)?,
+            1 => months = months.checked_add(number)?,
+            2 => days = days.checked_add(number.checked_mul(7)?)?,
+            _ => days = days.checked_add(number)?,
+        }
+    }
+
+    let mut nanos = 0i64;
+    for (number, designator) in iso8601_components(time, "HMS")? {
+        let number = match designator {
+            0 => iso8601_integer::<i64>(number)?.checked_mul(3_600 * NANOSECONDS)?,
+            1 => iso8601_integer::<i64>(number)?.checked_mul(60 * NANOSECONDS)?,
+            _ => iso8601_seconds_to_ns(number)?,
+        };
+        nanos = nanos.checked_add(number)?;
+    }
+
+    Some(if negative {
+        months_days_ns::new(-months, -days, -nanos)
+    } else {
+        months_days_ns::new(months, days, nanos)
+    })
+}
+
 fn add_month(year: i32, month: u32, months: i32) -> chrono::NaiveDate {
     let new_year = (year * 12 + (month - 1) as i32 + months) / 12;
     let new_month = (year * 12 + (month - 1) as i32 + months) % 12 + 1;
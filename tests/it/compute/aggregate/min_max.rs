@@ -32,6 +32,30 @@ fn decimal() {
     assert_eq!(Some(5), max_primitive(&a));
 }
 
+#[test]
+fn min_max_f16() {
+    use re_arrow2::compute::aggregate::{max, min};
+    use re_arrow2::scalar::PrimitiveScalar;
+    use re_arrow2::types::f16;
+
+    let a = Float16Array::from(&[
+        None,
+        Some(f16::from_f32(5.0)),
+        Some(f16::NAN),
+        Some(f16::from_f32(-2.0)),
+    ]);
+    assert_eq!(Some(f16::from_f32(-2.0)), min_primitive(&a));
+    assert_eq!(Some(f16::from_f32(5.0)), max_primitive(&a));
+    assert_eq!(
+        &PrimitiveScalar::from(Some(f16::from_f32(5.0))) as &dyn re_arrow2::scalar::Scalar,
+        max(&a).unwrap().as_ref()
+    );
+    assert_eq!(
+        &PrimitiveScalar::from(Some(f16::from_f32(-2.0))) as &dyn re_arrow2::scalar::Scalar,
+        min(&a).unwrap().as_ref()
+    );
+}
+
 #[test]
 fn min_max_f32() {
     let a = Float32Array::from(&[None, None, Some(5.0), Some(2.0)]);
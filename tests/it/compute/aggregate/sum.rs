@@ -1,5 +1,5 @@
 use re_arrow2::array::*;
-use re_arrow2::compute::aggregate::{sum, sum_primitive};
+use re_arrow2::compute::aggregate::{sum, sum_f16, sum_primitive};
 use re_arrow2::compute::arithmetics;
 use re_arrow2::datatypes::DataType;
 use re_arrow2::scalar::{PrimitiveScalar, Scalar};
@@ -52,3 +52,19 @@ fn test_primitive_array_sum_large_64() {
         sum_primitive(&c)
     );
 }
+
+#[test]
+fn test_f16_sum() {
+    use re_arrow2::types::f16;
+    let a = Float16Array::from(&[Some(f16::from_f32(1.0)), None, Some(f16::from_f32(2.5))]);
+    assert_eq!(
+        &PrimitiveScalar::<f16>::from(Some(f16::from_f32(3.5))) as &dyn Scalar,
+        sum(&a).unwrap().as_ref()
+    );
+
+    // summing in `f16` would stop at 2048, where adding 1 is lost to rounding
+    let a = Float16Array::from_vec(vec![f16::ONE; 3000]);
+    assert_eq!(Some(f16::from_f32(3000.0)), sum_f16(&a));
+
+    assert_eq!(None, sum_f16(&Float16Array::from(&[None])));
+}
@@ -0,0 +1,165 @@
+#![allow(clippy::zero_prefixed_literal, clippy::inconsistent_digit_grouping)]
+
+use re_arrow2::array::*;
+use re_arrow2::compute::arithmetics::decimal::decimal256::*;
+use re_arrow2::compute::arithmetics::{self, ArrayAdd, ArrayCheckedDiv};
+use re_arrow2::datatypes::DataType;
+use re_arrow2::types::i256;
+
+fn decimal(values: &[Option<i128>], precision: usize, scale: usize) -> PrimitiveArray<i256> {
+    values
+        .iter()
+        .map(|x| x.map(|x| i256::from_words(if x < 0 { -1 } else { 0 }, x)))
+        .collect::<PrimitiveArray<i256>>()
+        .to(DataType::Decimal256(precision, scale))
+}
+
+#[test]
+fn add_sub() {
+    let a = decimal(&[Some(111_11), Some(-222_00), None], 5, 2);
+    let b = decimal(&[Some(222_22), Some(111_00), Some(1)], 5, 2);
+
+    let expected = decimal(&[Some(333_33), Some(-111_00), None], 5, 2);
+    assert_eq!(add(&a, &b), expected);
+    assert_eq!(a.add(&b), expected);
+    assert_eq!(checked_add(&a, &b), expected);
+
+    let expected = decimal(&[Some(-111_11), Some(-333_00), None], 5, 2);
+    assert_eq!(sub(&a, &b), expected);
+}
+
+#[test]
+#[should_panic(expected = "Overflow in addition presented for precision 5")]
+fn add_panics_on_overflow() {
+    let a = decimal(&[Some(999_99)], 5, 2);
+    let b = decimal(&[Some(1)], 5, 2);
+    add(&a, &b);
+}
+
+#[test]
+fn checked_and_saturating() {
+    let a = decimal(&[Some(999_00), Some(-999_00), Some(100)], 5, 2);
+    let b = decimal(&[Some(10_00), Some(10_00), Some(100)], 5, 2);
+
+    assert_eq!(
+        checked_add(&a, &b),
+        decimal(&[None, Some(-989_00), Some(200)], 5, 2)
+    );
+    assert_eq!(
+        saturating_add(&a, &b),
+        decimal(&[Some(999_99), Some(-989_00), Some(200)], 5, 2)
+    );
+    assert_eq!(
+        saturating_sub(&a, &b),
+        decimal(&[Some(989_00), Some(-999_99), Some(0)], 5, 2)
+    );
+    assert_eq!(checked_mul(&a, &b), decimal(&[None, None, Some(100)], 5, 2));
+    assert_eq!(
+        saturating_mul(&a, &b),
+        decimal(&[Some(999_99), Some(-999_99), Some(100)], 5, 2)
+    );
+}
+
+#[test]
+fn mul_div() {
+    let a = decimal(&[Some(1_50), Some(-6_00), None], 5, 2);
+    let b = decimal(&[Some(2_00), Some(4_00), Some(1_00)], 5, 2);
+
+    assert_eq!(
+        mul(&a, &b),
+        decimal(&[Some(3_00), Some(-24_00), None], 5, 2)
+    );
+    assert_eq!(div(&a, &b), decimal(&[Some(0_75), Some(-1_50), None], 5, 2));
+
+    let zero = decimal(&[Some(0), Some(4_00), Some(1_00)], 5, 2);
+    assert_eq!(
+        checked_div(&a, &zero),
+        decimal(&[None, Some(-1_50), None], 5, 2)
+    );
+    assert_eq!(
+        a.checked_div(&zero),
+        decimal(&[None, Some(-1_50), None], 5, 2)
+    );
+    assert_eq!(
+        saturating_div(&a, &zero),
+        decimal(&[Some(0), Some(-1_50), None], 5, 2)
+    );
+}
+
+#[test]
+fn large_precision() {
+    // values that do not fit in a `Decimal`
+    let big = i256::from_words(1, 0);
+    let a = PrimitiveArray::from_slice([big]).to(DataType::Decimal256(76, 0));
+    let b = decimal(&[Some(2)], 76, 0);
+    let result = mul(&a, &b);
+    assert_eq!(result.value(0), i256::from_words(2, 0));
+}
+
+#[test]
+fn adaptive() {
+    let a = decimal(&[Some(11111_11), None], 7, 2);
+    let b = decimal(&[Some(11111_111), Some(1)], 8, 3);
+    assert_eq!(
+        adaptive_add(&a, &b).unwrap(),
+        decimal(&[Some(22222_221), None], 8, 3)
+    );
+    assert_eq!(
+        adaptive_sub(&a, &b).unwrap(),
+        decimal(&[Some(-1), None], 8, 3)
+    );
+
+    let a = decimal(&[Some(99_99)], 4, 2);
+    let b = decimal(&[Some(99_99)], 4, 2);
+    assert_eq!(
+        adaptive_add(&a, &b).unwrap(),
+        decimal(&[Some(199_98)], 5, 2)
+    );
+
+    let a = decimal(&[Some(11111_0)], 6, 1);
+    let b = decimal(&[Some(10_002)], 5, 3);
+    assert_eq!(
+        adaptive_mul(&a, &b).unwrap(),
+        decimal(&[Some(111132_222)], 9, 3)
+    );
+
+    let a = decimal(&[Some(1000_00)], 7, 2);
+    let b = decimal(&[Some(10_0000)], 6, 4);
+    assert_eq!(
+        adaptive_div(&a, &b).unwrap(),
+        decimal(&[Some(100_0000)], 9, 4)
+    );
+
+    let zero = decimal(&[Some(0)], 6, 4);
+    assert!(adaptive_div(&a, &zero).is_err());
+}
+
+#[test]
+fn dynamic() {
+    let a = decimal(&[Some(1_00), None], 5, 2);
+    let b = decimal(&[Some(2_00), Some(1)], 5, 2);
+    assert!(arithmetics::can_add(a.data_type(), b.data_type()));
+    assert_eq!(
+        decimal(&[Some(3_00), None], 5, 2),
+        arithmetics::add(&a, &b).as_ref()
+    );
+    assert_eq!(
+        decimal(&[Some(2_00), None], 5, 2),
+        arithmetics::mul(&a, &b).as_ref()
+    );
+
+    let max = decimal(&[Some(999_99)], 5, 2);
+    let one = decimal(&[Some(1)], 5, 2);
+    let mode = arithmetics::OverflowMode::Error;
+    assert!(arithmetics::try_add(&max, &one, mode).is_err());
+    assert_eq!(
+        decimal(&[Some(999_99)], 5, 2),
+        arithmetics::try_add(&max, &one, arithmetics::OverflowMode::Saturate)
+            .unwrap()
+            .as_ref()
+    );
+    assert_eq!(
+        decimal(&[Some(-999_99)], 5, 2),
+        arithmetics::try_neg(&max, mode).unwrap().as_ref()
+    );
+}
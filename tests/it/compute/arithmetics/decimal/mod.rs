@@ -1,4 +1,6 @@
 mod add;
+mod decimal256;
 mod div;
 mod mul;
+mod round;
 mod sub;
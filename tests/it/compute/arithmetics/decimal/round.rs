@@ -0,0 +1,119 @@
+#![allow(clippy::zero_prefixed_literal, clippy::inconsistent_digit_grouping)]
+
+use re_arrow2::array::*;
+use re_arrow2::compute::arithmetics::decimal::{ceil, floor, rescale, round};
+use re_arrow2::datatypes::DataType;
+use re_arrow2::types::i256;
+
+#[test]
+fn round_half_away_from_zero() {
+    let array = Int128Array::from(&[
+        Some(1_2345),
+        Some(1_2350),
+        Some(-1_2350),
+        Some(-1_2349),
+        None,
+    ])
+    .to(DataType::Decimal(6, 4));
+
+    let result = round(&array, 2).unwrap();
+    let expected = Int128Array::from(&[
+        Some(1_2300),
+        Some(1_2400),
+        Some(-1_2400),
+        Some(-1_2300),
+        None,
+    ])
+    .to(DataType::Decimal(6, 4));
+    assert_eq!(expected, result.as_ref());
+
+    let result = round(&array, 0).unwrap();
+    let expected = Int128Array::from(&[
+        Some(1_0000),
+        Some(1_0000),
+        Some(-1_0000),
+        Some(-1_0000),
+        None,
+    ])
+    .to(DataType::Decimal(6, 4));
+    assert_eq!(expected, result.as_ref());
+
+    // more decimals than the scale leave the array unchanged
+    assert_eq!(array, round(&array, 4).unwrap().as_ref());
+}
+
+#[test]
+fn round_overflow() {
+    let array = Int128Array::from(&[Some(9_99)]).to(DataType::Decimal(3, 2));
+    assert!(round(&array, 1).is_err());
+    assert!(ceil(&array).is_err());
+    assert!(floor(&array).is_ok());
+}
+
+#[test]
+fn floor_ceil() {
+    let array =
+        Int128Array::from(&[Some(1_50), Some(-1_50), Some(2_00), None]).to(DataType::Decimal(5, 2));
+
+    let expected =
+        Int128Array::from(&[Some(1_00), Some(-2_00), Some(2_00), None]).to(DataType::Decimal(5, 2));
+    assert_eq!(expected, floor(&array).unwrap().as_ref());
+
+    let expected =
+        Int128Array::from(&[Some(2_00), Some(-1_00), Some(2_00), None]).to(DataType::Decimal(5, 2));
+    assert_eq!(expected, ceil(&array).unwrap().as_ref());
+}
+
+#[test]
+fn rescale_decimal() {
+    let array = Int128Array::from(&[Some(1_25), Some(-1_25), None]).to(DataType::Decimal(5, 2));
+
+    let expected =
+        Int128Array::from(&[Some(1_2500), Some(-1_2500), None]).to(DataType::Decimal(7, 4));
+    assert_eq!(expected, rescale(&array, 7, 4).unwrap().as_ref());
+
+    let expected = Int128Array::from(&[Some(1_3), Some(-1_3), None]).to(DataType::Decimal(2, 1));
+    assert_eq!(expected, rescale(&array, 2, 1).unwrap().as_ref());
+
+    // does not fit in the new precision
+    assert!(rescale(&array, 3, 3).is_err());
+    // invalid precision and scale
+    assert!(rescale(&array, 39, 2).is_err());
+    assert!(rescale(&array, 2, 3).is_err());
+    assert!(rescale(&Int32Array::from_slice([1]), 5, 2).is_err());
+}
+
+#[test]
+fn decimal256() {
+    let array = Int256Array::from(&[
+        Some(i256::from_words(0, 1_2350)),
+        Some(i256::from_words(-1, -1_2350)),
+        None,
+    ])
+    .to(DataType::Decimal256(40, 4));
+
+    let expected = Int256Array::from(&[
+        Some(i256::from_words(0, 1_2400)),
+        Some(i256::from_words(-1, -1_2400)),
+        None,
+    ])
+    .to(DataType::Decimal256(40, 4));
+    assert_eq!(expected, round(&array, 2).unwrap().as_ref());
+
+    let expected = Int256Array::from(&[
+        Some(i256::from_words(0, 1_0000)),
+        Some(i256::from_words(-1, -2_0000)),
+        None,
+    ])
+    .to(DataType::Decimal256(40, 4));
+    assert_eq!(expected, floor(&array).unwrap().as_ref());
+
+    let expected = Int256Array::from(&[
+        Some(i256::from_words(0, 1_24)),
+        Some(i256::from_words(-1, -1_24)),
+        None,
+    ])
+    .to(DataType::Decimal256(76, 2));
+    assert_eq!(expected, rescale(&array, 76, 2).unwrap().as_ref());
+    assert!(rescale(&array, 77, 2).is_err());
+}
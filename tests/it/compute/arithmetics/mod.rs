@@ -6,6 +6,7 @@ use re_arrow2::array::*;
 use re_arrow2::compute::arithmetics::*;
 use re_arrow2::datatypes::DataType::*;
 use re_arrow2::datatypes::{IntervalUnit, TimeUnit};
+use re_arrow2::error::Error;
 use re_arrow2::scalar::PrimitiveScalar;
 
 #[test]
@@ -26,6 +27,63 @@ fn test_add_scalar() {
     assert_eq!(expected, result.as_ref());
 }
 
+#[test]
+fn test_decimal_scalar() {
+    let a = Int128Array::from(&[Some(1_50), None, Some(-3_00)]).to(Decimal(5, 2));
+    let b = PrimitiveScalar::new(Decimal(5, 2), Some(2_00i128));
+    let expected = |values: &[Option<i128>]| Int128Array::from(values).to(Decimal(5, 2));
+    assert_eq!(
+        expected(&[Some(3_50), None, Some(-1_00)]),
+        add_scalar(&a, &b).as_ref()
+    );
+    assert_eq!(
+        expected(&[Some(-50), None, Some(-5_00)]),
+        sub_scalar(&a, &b).as_ref()
+    );
+    assert_eq!(
+        expected(&[Some(3_00), None, Some(-6_00)]),
+        mul_scalar(&a, &b).as_ref()
+    );
+    assert_eq!(
+        expected(&[Some(75), None, Some(-1_50)]),
+        div_scalar(&a, &b).as_ref()
+    );
+
+    let null = PrimitiveScalar::<i128>::new(Decimal(5, 2), None);
+    assert_eq!(
+        expected(&[None, None, None]),
+        add_scalar(&a, &null).as_ref()
+    );
+
+    use re_arrow2::types::i256;
+    let decimal256 = |values: &[Option<i128>]| {
+        values
+            .iter()
+            .map(|x| x.map(|x| i256::from_words(if x < 0 { -1 } else { 0 }, x)))
+            .collect::<PrimitiveArray<i256>>()
+            .to(Decimal256(5, 2))
+    };
+    let a = decimal256(&[Some(1_50), None, Some(-3_00)]);
+    let b = PrimitiveScalar::new(Decimal256(5, 2), Some(i256::from_words(0, 2_00)));
+    assert!(can_add(a.data_type(), &Decimal256(5, 2)));
+    assert_eq!(
+        decimal256(&[Some(3_50), None, Some(-1_00)]),
+        add_scalar(&a, &b).as_ref()
+    );
+    assert_eq!(
+        decimal256(&[Some(-50), None, Some(-5_00)]),
+        sub_scalar(&a, &b).as_ref()
+    );
+    assert_eq!(
+        decimal256(&[Some(3_00), None, Some(-6_00)]),
+        mul_scalar(&a, &b).as_ref()
+    );
+    assert_eq!(
+        decimal256(&[Some(75), None, Some(-1_50)]),
+        div_scalar(&a, &b).as_ref()
+    );
+}
+
 #[test]
 fn consistency() {
     let datatypes = vec![
@@ -108,3 +166,339 @@ fn test_neg_dict() {
     .unwrap();
     assert_eq!(expected, result.as_ref());
 }
+
+#[test]
+fn test_try_overflow_modes() {
+    let a = Int8Array::from(&[Some(100), Some(-100), None, Some(1)]);
+    let b = Int8Array::from(&[Some(100), Some(100), Some(1), Some(2)]);
+
+    assert!(try_add(&a, &b, OverflowMode::Error).is_err());
+    assert_eq!(
+        Int8Array::from(&[None, Some(0), None, Some(3)]),
+        try_add(&a, &b, OverflowMode::Null).unwrap().as_ref()
+    );
+    assert_eq!(
+        Int8Array::from(&[Some(-56), Some(0), None, Some(3)]),
+        try_add(&a, &b, OverflowMode::Wrap).unwrap().as_ref()
+    );
+    assert_eq!(
+        Int8Array::from(&[Some(127), Some(0), None, Some(3)]),
+        try_add(&a, &b, OverflowMode::Saturate).unwrap().as_ref()
+    );
+    assert_eq!(
+        Int8Array::from(&[Some(0), Some(-128), None, Some(-1)]),
+        try_sub(&a, &b, OverflowMode::Saturate).unwrap().as_ref()
+    );
+    assert_eq!(
+        Int8Array::from(&[None, None, None, Some(2)]),
+        try_mul(&a, &b, OverflowMode::Null).unwrap().as_ref()
+    );
+
+    // nulls never overflow
+    let a = Int8Array::from(&[None, Some(1)]);
+    let b = Int8Array::from(&[Some(127), Some(1)]);
+    assert_eq!(
+        Int8Array::from(&[None, Some(2)]),
+        try_add(&a, &b, OverflowMode::Error).unwrap().as_ref()
+    );
+}
+
+#[test]
+fn test_try_div_rem() {
+    let a = Int32Array::from_slice([7, 1, i32::MIN]);
+    let b = Int32Array::from_slice([2, 0, -1]);
+
+    assert!(try_div(&a, &b, OverflowMode::Error).is_err());
+    assert!(try_rem(&a, &b, OverflowMode::Error).is_err());
+    assert_eq!(
+        Int32Array::from(&[Some(3), None, Some(i32::MAX)]),
+        try_div(&a, &b, OverflowMode::Saturate).unwrap().as_ref()
+    );
+    assert_eq!(
+        Int32Array::from(&[Some(1), None, Some(0)]),
+        try_rem(&a, &b, OverflowMode::Wrap).unwrap().as_ref()
+    );
+
+    // floats follow IEEE 754
+    let a = Float64Array::from_slice([1.0]);
+    let b = Float64Array::from_slice([0.0]);
+    assert_eq!(
+        Float64Array::from_slice([f64::INFINITY]),
+        try_div(&a, &b, OverflowMode::Error).unwrap().as_ref()
+    );
+}
+
+#[test]
+fn test_try_neg() {
+    let a = Int16Array::from(&[Some(1), None, Some(i16::MIN)]);
+    assert!(try_neg(&a, OverflowMode::Error).is_err());
+    assert_eq!(
+        Int16Array::from(&[Some(-1), None, None]),
+        try_neg(&a, OverflowMode::Null).unwrap().as_ref()
+    );
+    assert_eq!(
+        Int16Array::from(&[Some(-1), None, Some(i16::MAX)]),
+        try_neg(&a, OverflowMode::Saturate).unwrap().as_ref()
+    );
+
+    let a = DictionaryArray::try_from_keys(
+        UInt8Array::from_slice([0, 0, 1]),
+        Int8Array::from_slice([1, 2]).boxed(),
+    )
+    .unwrap();
+    assert_eq!(
+        neg(&a).as_ref(),
+        try_neg(&a, OverflowMode::Error).unwrap().as_ref()
+    );
+
+    assert!(try_neg(&UInt8Array::from_slice([1]), OverflowMode::Error).is_err());
+
+    // null slots may hold the minimum value
+    use re_arrow2::types::i256;
+    let a = PrimitiveArray::<i256>::new(
+        Decimal256(76, 0),
+        vec![i256::from_words(i128::MIN, 0), i256::from_words(0, 1)].into(),
+        Some([false, true].into()),
+    );
+    assert_eq!(
+        PrimitiveArray::<i256>::from([None, Some(i256::from_words(-1, -1))]).to(Decimal256(76, 0)),
+        try_neg(&a, OverflowMode::Error).unwrap().as_ref()
+    );
+}
+
+#[test]
+fn test_coerce_types() {
+    assert_eq!(Some(Int64), coerce_types(&Int32, &Int64));
+    assert_eq!(Some(Int16), coerce_types(&UInt8, &Int8));
+    assert_eq!(Some(Int64), coerce_types(&UInt32, &Int64));
+    assert_eq!(None, coerce_types(&UInt64, &Int8));
+    assert_eq!(Some(Float32), coerce_types(&Int16, &Float32));
+    assert_eq!(Some(Float64), coerce_types(&Int32, &Float32));
+    assert_eq!(Some(Float64), coerce_types(&Float32, &Float64));
+    assert_eq!(Some(Float32), coerce_types(&Float16, &Float32));
+    assert_eq!(Some(Float32), coerce_types(&UInt8, &Float16));
+    assert_eq!(Some(Float64), coerce_types(&Int64, &Float16));
+    assert_eq!(None, coerce_types(&Utf8, &Int32));
+
+    let a = Int32Array::from(&[Some(1), None]);
+    let b = Float32Array::from(&[Some(0.5), Some(1.0)]);
+    assert_eq!(
+        Float64Array::from(&[Some(0.5), None]),
+        try_mul(&a, &b, OverflowMode::Error).unwrap().as_ref()
+    );
+
+    let a = UInt8Array::from_slice([200]);
+    let b = Int8Array::from_slice([-100]);
+    assert_eq!(
+        Int16Array::from_slice([100]),
+        try_add(&b, &a, OverflowMode::Error).unwrap().as_ref()
+    );
+}
+
+#[test]
+fn test_try_decimal() {
+    let a = Int128Array::from(&[Some(99900), Some(100)]).to(Decimal(5, 2));
+    let b = Int128Array::from(&[Some(200), Some(100)]).to(Decimal(5, 2));
+    assert!(try_add(&a, &b, OverflowMode::Error).is_err());
+    assert_eq!(
+        Int128Array::from(&[None, Some(200)]).to(Decimal(5, 2)),
+        try_add(&a, &b, OverflowMode::Null).unwrap().as_ref()
+    );
+    assert_eq!(
+        Int128Array::from(&[Some(99999), Some(200)]).to(Decimal(5, 2)),
+        try_add(&a, &b, OverflowMode::Saturate).unwrap().as_ref()
+    );
+    assert!(try_add(&a, &b, OverflowMode::Wrap).is_err());
+    assert!(try_rem(&a, &b, OverflowMode::Error).is_err());
+
+    let zero = Int128Array::from(&[Some(0), Some(100)]).to(Decimal(5, 2));
+    assert!(try_div(&a, &zero, OverflowMode::Error).is_err());
+}
+
+#[test]
+fn test_try_decimal_adaptive() {
+    // 1.00 / null, with a zero under the null slot
+    let a = Int128Array::from_slice([100]).to(Decimal(5, 2));
+    let null = Int128Array::new(Decimal(6, 3), vec![0].into(), Some([false].into()));
+    assert_eq!(
+        Int128Array::from(&[None]).to(Decimal(6, 3)),
+        try_div(&a, &null, OverflowMode::Error).unwrap().as_ref()
+    );
+
+    let zero = Int128Array::from_slice([0]).to(Decimal(6, 3));
+    assert!(try_div(&a, &zero, OverflowMode::Error).is_err());
+    assert_eq!(
+        Int128Array::from(&[None]).to(Decimal(6, 3)),
+        try_div(&a, &zero, OverflowMode::Null).unwrap().as_ref()
+    );
+
+    let max = 10i128.pow(38) - 1;
+    let a = Int128Array::from_slice([10i128.pow(37), -(10i128.pow(37))]).to(Decimal(38, 0));
+    let b = Int128Array::from_slice([10i128.pow(37), 10i128.pow(37)]).to(Decimal(38, 1));
+    assert!(matches!(
+        try_mul(&a, &b, OverflowMode::Error),
+        Err(Error::Overflow)
+    ));
+    assert_eq!(
+        Int128Array::from(&[None, None]).to(Decimal(38, 1)),
+        try_mul(&a, &b, OverflowMode::Null).unwrap().as_ref()
+    );
+    assert_eq!(
+        Int128Array::from_slice([max, -max]).to(Decimal(38, 1)),
+        try_mul(&a, &b, OverflowMode::Saturate).unwrap().as_ref()
+    );
+}
+
+#[test]
+fn test_try_decimal_saturate_div() {
+    let max = 10i128.pow(38) - 1;
+    let a = Int128Array::from_slice([10i128.pow(37), 10i128.pow(37)]).to(Decimal(38, 10));
+    let b = Int128Array::from_slice([3, -3]).to(Decimal(38, 10));
+    assert_eq!(
+        Int128Array::from_slice([max, -max]).to(Decimal(38, 10)),
+        try_div(&a, &b, OverflowMode::Saturate).unwrap().as_ref()
+    );
+
+    // a division by zero is null
+    let b = Int128Array::from_slice([0, 3]).to(Decimal(38, 10));
+    assert_eq!(
+        Int128Array::from(&[None, Some(max)]).to(Decimal(38, 10)),
+        try_div(&a, &b, OverflowMode::Saturate).unwrap().as_ref()
+    );
+
+    // also when the scales differ
+    let a = Int128Array::from_slice([1_00, 1_00]).to(Decimal(5, 2));
+    let b = Int128Array::from_slice([0, 2_0]).to(Decimal(5, 1));
+    let result = try_div(&a, &b, OverflowMode::Saturate).unwrap();
+    let result = result.as_any().downcast_ref::<Int128Array>().unwrap();
+    assert!(result.is_null(0));
+    assert!(result.is_valid(1));
+}
+
+#[test]
+fn test_try_temporal_scale() {
+    let timestamp = Int64Array::from_slice([0]).to(Timestamp(TimeUnit::Nanosecond, None));
+    let duration = Int64Array::from_slice([i64::MAX / 10]).to(Duration(TimeUnit::Second));
+    assert!(matches!(
+        try_add(&timestamp, &duration, OverflowMode::Error),
+        Err(Error::Overflow)
+    ));
+    assert_eq!(
+        Int64Array::from(&[None]).to(Timestamp(TimeUnit::Nanosecond, None)),
+        try_add(&timestamp, &duration, OverflowMode::Null)
+            .unwrap()
+            .as_ref()
+    );
+    assert_eq!(
+        Int64Array::from_slice([i64::MAX]).to(Timestamp(TimeUnit::Nanosecond, None)),
+        try_add(&timestamp, &duration, OverflowMode::Saturate)
+            .unwrap()
+            .as_ref()
+    );
+
+    // durations are not rounded to floats
+    let timestamp = Int64Array::from_slice([1]).to(Timestamp(TimeUnit::Nanosecond, None));
+    let duration = Int64Array::from_slice([(1 << 53) + 1]).to(Duration(TimeUnit::Nanosecond));
+    assert_eq!(
+        Int64Array::from_slice([(1 << 53) + 2]).to(Timestamp(TimeUnit::Nanosecond, None)),
+        try_add(&timestamp, &duration, OverflowMode::Error)
+            .unwrap()
+            .as_ref()
+    );
+
+    // 1 day and 1 second later
+    let date = Int32Array::from_slice([0]).to(Date32);
+    let duration = Int64Array::from_slice([86_401_000]).to(Duration(TimeUnit::Millisecond));
+    assert_eq!(
+        Int32Array::from_slice([1]).to(Date32),
+        try_add(&date, &duration, OverflowMode::Error)
+            .unwrap()
+            .as_ref()
+    );
+}
+
+#[test]
+fn test_try_temporal() {
+    let timestamp =
+        Int64Array::from_slice([1_000, i64::MAX]).to(Timestamp(TimeUnit::Millisecond, None));
+    let duration = Int64Array::from_slice([2, 1]).to(Duration(TimeUnit::Second));
+
+    assert!(try_add(&timestamp, &duration, OverflowMode::Error).is_err());
+    assert_eq!(
+        Int64Array::from(&[Some(3_000), None]).to(Timestamp(TimeUnit::Millisecond, None)),
+        try_add(&timestamp, &duration, OverflowMode::Null)
+            .unwrap()
+            .as_ref()
+    );
+
+    let other = Int64Array::from_slice([1, 0]).to(Timestamp(TimeUnit::Second, None));
+    assert_eq!(
+        Int64Array::from_slice([0, i64::MAX]).to(Duration(TimeUnit::Millisecond)),
+        try_sub(&timestamp, &other, OverflowMode::Error)
+            .unwrap()
+            .as_ref()
+    );
+}
+
+#[test]
+fn test_try_errors_instead_of_panics() {
+    let a = Utf8Array::<i32>::from_slice(["a"]);
+    assert!(try_add(&a, &a, OverflowMode::Error).is_err());
+
+    let a = Int32Array::from_slice([1, 2]);
+    let b = Int32Array::from_slice([1]);
+    assert!(try_add(&a, &b, OverflowMode::Error).is_err());
+
+    // multiplication of durations is not supported
+    let a = Int64Array::from_slice([1]).to(Duration(TimeUnit::Second));
+    assert!(try_mul(&a, &a, OverflowMode::Error).is_err());
+
+    let a = Int64Array::from_slice([1]).to(Timestamp(
+        TimeUnit::Second,
+        Some("+01:00".to_string().into()),
+    ));
+    let b = Int64Array::from_slice([1]).to(Timestamp(TimeUnit::Second, None));
+    assert!(try_sub(&a, &b, OverflowMode::Error).is_err());
+}
+
+#[test]
+fn test_float16() {
+    use re_arrow2::types::f16;
+    let f = |values: &[Option<f32>]| {
+        Float16Array::from_iter(values.iter().map(|x| x.map(f16::from_f32)))
+    };
+
+    let a = f(&[Some(1.5), Some(-2.0), None]);
+    let b = f(&[Some(0.5), Some(4.0), Some(1.0)]);
+    assert!(can_add(&Float16, &Float16));
+    assert!(can_rem(&Float16, &Float16));
+    assert!(can_neg(&Float16));
+
+    assert_eq!(f(&[Some(2.0), Some(2.0), None]), add(&a, &b).as_ref());
+    assert_eq!(f(&[Some(1.0), Some(-6.0), None]), sub(&a, &b).as_ref());
+    assert_eq!(f(&[Some(0.75), Some(-8.0), None]), mul(&a, &b).as_ref());
+    assert_eq!(f(&[Some(3.0), Some(-0.5), None]), div(&a, &b).as_ref());
+    assert_eq!(f(&[Some(0.0), Some(-2.0), None]), rem(&a, &b).as_ref());
+    assert_eq!(f(&[Some(-1.5), Some(2.0), None]), neg(&a).as_ref());
+
+    let scalar = PrimitiveScalar::from(Some(f16::from_f32(2.0)));
+    assert_eq!(
+        f(&[Some(3.5), Some(0.0), None]),
+        add_scalar(&a, &scalar).as_ref()
+    );
+    assert_eq!(
+        f(&[Some(0.75), Some(-1.0), None]),
+        div_scalar(&a, &scalar).as_ref()
+    );
+
+    // the fallible kernels coerce to the wider float
+    let c = Float32Array::from_slice([1.0, 1.0, 1.0]);
+    assert_eq!(
+        Float32Array::from(&[Some(2.5), Some(-1.0), None]),
+        try_add(&a, &c, OverflowMode::Error).unwrap().as_ref()
+    );
+    assert_eq!(
+        f(&[Some(-1.5), Some(2.0), None]),
+        try_neg(&a, OverflowMode::Error).unwrap().as_ref()
+    );
+}
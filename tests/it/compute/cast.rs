@@ -1,10 +1,11 @@
 use std::sync::Arc;
 
 use re_arrow2::array::*;
-use re_arrow2::compute::cast::{can_cast_types, cast, CastOptions};
+use re_arrow2::compute::cast::{can_cast_types, cast, dictionary_to_dictionary_keys, CastOptions};
 use re_arrow2::datatypes::DataType::LargeList;
 use re_arrow2::datatypes::*;
-use re_arrow2::types::{days_ms, months_days_ns, NativeType};
+use re_arrow2::error::Error;
+use re_arrow2::types::{days_ms, i256, months_days_ns, NativeType};
 
 #[test]
 fn i32_to_f64() {
@@ -533,6 +534,8 @@ fn consistency() {
         Time32(TimeUnit::Millisecond),
         Decimal(1, 2),
         Decimal(2, 2),
+        Decimal256(2, 2),
+        Decimal256(50, 10),
         Date64,
         Utf8,
         LargeUtf8,
@@ -542,6 +545,7 @@ fn consistency() {
         Duration(TimeUnit::Millisecond),
         Duration(TimeUnit::Microsecond),
         Duration(TimeUnit::Nanosecond),
+        Interval(IntervalUnit::MonthDayNano),
         List(std::sync::Arc::new(Field::new("a", Utf8, true))),
         LargeList(std::sync::Arc::new(Field::new("a", Utf8, true))),
     ];
@@ -608,6 +612,111 @@ fn months_to_months_days_ns() {
     );
 }
 
+#[test]
+fn duration_to_duration() {
+    test_primitive_to_primitive(
+        &[1i64, -2],
+        DataType::Duration(TimeUnit::Second),
+        &[1_000_000i64, -2_000_000],
+        DataType::Duration(TimeUnit::Microsecond),
+    );
+    test_primitive_to_primitive(
+        &[1_999i64, -1_999],
+        DataType::Duration(TimeUnit::Millisecond),
+        &[1i64, -1],
+        DataType::Duration(TimeUnit::Second),
+    );
+
+    // overflow
+    let array = Int64Array::from_slice([i64::MAX]).to(DataType::Duration(TimeUnit::Second));
+    let result = cast(
+        &array,
+        &DataType::Duration(TimeUnit::Nanosecond),
+        CastOptions::default(),
+    )
+    .unwrap();
+    assert_eq!(
+        Int64Array::from(&[None]).to(DataType::Duration(TimeUnit::Nanosecond)),
+        result.as_ref()
+    );
+}
+
+#[test]
+fn duration_to_from_months_days_ns() {
+    test_primitive_to_primitive(
+        &[2i64, -3],
+        DataType::Duration(TimeUnit::Millisecond),
+        &[
+            months_days_ns::new(0, 0, 2_000_000),
+            months_days_ns::new(0, 0, -3_000_000),
+        ],
+        DataType::Interval(IntervalUnit::MonthDayNano),
+    );
+
+    let array = PrimitiveArray::from_slice([
+        months_days_ns::new(0, 1, 1_000_000_000),
+        months_days_ns::new(1, 0, 0),
+    ])
+    .to(DataType::Interval(IntervalUnit::MonthDayNano));
+    let result = cast(
+        &array,
+        &DataType::Duration(TimeUnit::Second),
+        CastOptions::default(),
+    )
+    .unwrap();
+    assert_eq!(
+        Int64Array::from(&[Some(86_401), None]).to(DataType::Duration(TimeUnit::Second)),
+        result.as_ref()
+    );
+}
+
+#[test]
+fn utf8_to_duration_and_interval() {
+    let array = Utf8Array::<i32>::from([
+        Some("PT1H30M"),
+        Some("-P1DT0.5S"),
+        Some("P1W"),
+        Some("P1M"),
+        Some("P"),
+        Some("1H"),
+        None,
+    ]);
+
+    let result = cast(
+        &array,
+        &DataType::Duration(TimeUnit::Millisecond),
+        CastOptions::default(),
+    )
+    .unwrap();
+    let expected = Int64Array::from(&[
+        Some(5_400_000),
+        Some(-86_400_500),
+        Some(604_800_000),
+        None,
+        None,
+        None,
+        None,
+    ])
+    .to(DataType::Duration(TimeUnit::Millisecond));
+    assert_eq!(expected, result.as_ref());
+
+    let array = Utf8Array::<i64>::from([Some("P1Y2M3DT4H"), Some("PT1.5S"), Some("P1D1Y"), None]);
+    let result = cast(
+        &array,
+        &DataType::Interval(IntervalUnit::MonthDayNano),
+        CastOptions::default(),
+    )
+    .unwrap();
+    let expected = PrimitiveArray::from([
+        Some(months_days_ns::new(14, 3, 4 * 3_600_000_000_000)),
+        Some(months_days_ns::new(0, 0, 1_500_000_000)),
+        None,
+        None,
+    ])
+    .to(DataType::Interval(IntervalUnit::MonthDayNano));
+    assert_eq!(expected, result.as_ref());
+}
+
 #[test]
 fn date64_to_date32() {
     test_primitive_to_primitive(
@@ -915,6 +1024,266 @@ fn utf8_to_date64() {
     assert_eq!(&expected, c);
 }
 
+#[test]
+fn utf8_to_date_with_format() {
+    let array = Utf8Array::<i32>::from_slice(["02/01/1970", "1970-01-02"]);
+    let options = CastOptions {
+        date_format: Some("%d/%m/%Y".into()),
+        ..Default::default()
+    };
+
+    let result = cast(&array, &DataType::Date32, options.clone()).unwrap();
+    let expected = Int32Array::from(&[Some(1), None]).to(DataType::Date32);
+    assert_eq!(expected, result.as_ref());
+
+    let result = cast(&array, &DataType::Date64, options).unwrap();
+    let expected = Int64Array::from(&[Some(86400000), None]).to(DataType::Date64);
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+fn utf8_to_time() {
+    let array = Utf8Array::<i32>::from([Some("01:02:03.5"), Some("1:02"), None]);
+
+    let result = cast(
+        &array,
+        &DataType::Time32(TimeUnit::Millisecond),
+        CastOptions::default(),
+    )
+    .unwrap();
+    let expected = Int32Array::from(&[Some(3_723_500), None, None])
+        .to(DataType::Time32(TimeUnit::Millisecond));
+    assert_eq!(expected, result.as_ref());
+
+    let result = cast(
+        &array,
+        &DataType::Time64(TimeUnit::Nanosecond),
+        CastOptions {
+            time_format: Some("%H:%M".into()),
+            ..Default::default()
+        },
+    )
+    .unwrap();
+    let expected = Int64Array::from(&[None, Some(3_720_000_000_000), None])
+        .to(DataType::Time64(TimeUnit::Nanosecond));
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+fn utf8_to_timestamp_with_format() {
+    let array = Utf8Array::<i32>::from_slice(["1996/12/19 16:39:57", "1996-12-19T16:39:57"]);
+    let options = CastOptions {
+        timestamp_format: Some("%Y/%m/%d %H:%M:%S".into()),
+        ..Default::default()
+    };
+
+    let expected = Int64Array::from(&[Some(851013597000000000), None])
+        .to(DataType::Timestamp(TimeUnit::Nanosecond, None));
+    let result = cast(&array, expected.data_type(), options).unwrap();
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+fn utf8_to_timestamp_with_default_timezone() {
+    let tz = Arc::new("+01:00".to_string());
+    let array = Utf8Array::<i32>::from_slice(["1996-12-19T16:39:57-02:00", "1996-12-19T16:39:57"]);
+    let data_type = DataType::Timestamp(TimeUnit::Nanosecond, Some(tz));
+
+    // naive strings are null unless a default timezone is provided
+    let expected = Int64Array::from(&[Some(851020797000000000), None]).to(data_type.clone());
+    let result = cast(&array, &data_type, CastOptions::default()).unwrap();
+    assert_eq!(expected, result.as_ref());
+
+    let options = CastOptions {
+        timezone: Some("-02:00".into()),
+        ..Default::default()
+    };
+    let expected =
+        Int64Array::from_slice([851020797000000000, 851020797000000000]).to(data_type.clone());
+    let result = cast(&array, &data_type, options).unwrap();
+    assert_eq!(expected, result.as_ref());
+
+    let options = CastOptions {
+        timezone: Some("not a timezone".into()),
+        ..Default::default()
+    };
+    assert!(cast(&array, &data_type, options).is_err());
+}
+
+#[test]
+fn utf8_to_timestamp_with_default_timezone_rejects_trailing() {
+    let tz = Arc::new("+01:00".to_string());
+    let data_type = DataType::Timestamp(TimeUnit::Nanosecond, Some(tz));
+    let array = Utf8Array::<i32>::from_slice([
+        "2020-01-01 10:00",
+        "2020-01-01 10:00 garbage",
+        "2020-01-01 10",
+    ]);
+    let options = CastOptions {
+        timestamp_format: Some("%Y-%m-%d %H:%M".into()),
+        timezone: Some("-02:00".into()),
+        ..Default::default()
+    };
+
+    let expected = Int64Array::from(&[Some(1577880000000000000), None, None]).to(data_type.clone());
+    let result = cast(&array, &data_type, options.clone()).unwrap();
+    assert_eq!(expected, result.as_ref());
+
+    let options = CastOptions {
+        strict: true,
+        ..options
+    };
+    assert!(cast(&array, &data_type, options).is_err());
+}
+
+#[test]
+fn strict() {
+    let options = CastOptions {
+        strict: true,
+        ..Default::default()
+    };
+
+    let array = Utf8Array::<i32>::from([Some("1"), None, Some("a"), Some("b")]);
+    let error = cast(&array, &DataType::Int32, options.clone()).unwrap_err();
+    assert!(error.to_string().contains("\"a\" at row 2"));
+
+    let array = Int32Array::from(&[Some(1), None, Some(300)]);
+    assert!(cast(&array, &DataType::UInt8, options.clone()).is_err());
+
+    // nulls are not failures
+    let array = Int32Array::from(&[Some(1), None]);
+    let result = cast(&array, &DataType::UInt8, options).unwrap();
+    assert_eq!(UInt8Array::from(&[Some(1), None]), result.as_ref());
+}
+
+#[test]
+fn decimal256_to_from_numeric() {
+    let array = Int32Array::from(&[Some(1), Some(-2), Some(1000), None]);
+    let result = cast(&array, &DataType::Decimal256(5, 2), CastOptions::default()).unwrap();
+    let expected = Int256Array::from(&[
+        Some(i256::from_words(0, 100)),
+        Some(i256::from_words(-1, -200)),
+        None,
+        None,
+    ])
+    .to(DataType::Decimal256(5, 2));
+    assert_eq!(expected, result.as_ref());
+
+    let result = cast(result.as_ref(), &DataType::Int64, CastOptions::default()).unwrap();
+    assert_eq!(
+        Int64Array::from(&[Some(1), Some(-2), None, None]),
+        result.as_ref()
+    );
+
+    let array = Float64Array::from(&[Some(1.25), Some(f64::NAN), Some(1e80)]);
+    let result = cast(&array, &DataType::Decimal256(76, 2), CastOptions::default()).unwrap();
+    let expected = Int256Array::from(&[Some(i256::from_words(0, 125)), None, None])
+        .to(DataType::Decimal256(76, 2));
+    assert_eq!(expected, result.as_ref());
+
+    let result = cast(result.as_ref(), &DataType::Float32, CastOptions::default()).unwrap();
+    assert_eq!(
+        Float32Array::from(&[Some(1.25), None, None]),
+        result.as_ref()
+    );
+}
+
+#[test]
+fn decimal_to_from_decimal256() {
+    let array = Int128Array::from(&[Some(1_25), Some(-1_25), None]).to(DataType::Decimal(5, 2));
+    let result = cast(&array, &DataType::Decimal256(40, 3), CastOptions::default()).unwrap();
+    let expected = Int256Array::from(&[
+        Some(i256::from_words(0, 1_250)),
+        Some(i256::from_words(-1, -1_250)),
+        None,
+    ])
+    .to(DataType::Decimal256(40, 3));
+    assert_eq!(expected, result.as_ref());
+
+    // decreasing the scale truncates
+    let result = cast(
+        result.as_ref(),
+        &DataType::Decimal256(40, 1),
+        CastOptions::default(),
+    )
+    .unwrap();
+    let expected = Int256Array::from(&[
+        Some(i256::from_words(0, 12)),
+        Some(i256::from_words(-1, -12)),
+        None,
+    ])
+    .to(DataType::Decimal256(40, 1));
+    assert_eq!(expected, result.as_ref());
+
+    let result = cast(
+        result.as_ref(),
+        &DataType::Decimal(2, 1),
+        CastOptions::default(),
+    )
+    .unwrap();
+    let expected = Int128Array::from(&[Some(12), Some(-12), None]).to(DataType::Decimal(2, 1));
+    assert_eq!(expected, result.as_ref());
+
+    // overflows the precision
+    let result = cast(&array, &DataType::Decimal256(3, 2), CastOptions::default()).unwrap();
+    let expected = Int256Array::from(&[
+        Some(i256::from_words(0, 1_25)),
+        Some(i256::from_words(-1, -1_25)),
+        None,
+    ])
+    .to(DataType::Decimal256(3, 2));
+    assert_eq!(expected, result.as_ref());
+    let result = cast(&array, &DataType::Decimal256(2, 2), CastOptions::default()).unwrap();
+    assert_eq!(3, result.null_count());
+}
+
+#[test]
+fn decimal_to_from_utf8() {
+    let array = Utf8Array::<i32>::from([
+        Some("1.5"),
+        Some("-0.015"),
+        Some("+12"),
+        Some(".5"),
+        Some("1e2"),
+        Some("1000"),
+        None,
+    ]);
+    let result = cast(&array, &DataType::Decimal(5, 2), CastOptions::default()).unwrap();
+    let expected = Int128Array::from(&[
+        Some(1_50),
+        Some(-1),
+        Some(12_00),
+        Some(50),
+        None,
+        None,
+        None,
+    ])
+    .to(DataType::Decimal(5, 2));
+    assert_eq!(expected, result.as_ref());
+
+    let result = cast(result.as_ref(), &DataType::Utf8, CastOptions::default()).unwrap();
+    let expected = Utf8Array::<i32>::from([
+        Some("1.50"),
+        Some("-0.01"),
+        Some("12.00"),
+        Some("0.50"),
+        None,
+        None,
+        None,
+    ]);
+    assert_eq!(expected, result.as_ref());
+
+    let array = Utf8Array::<i64>::from_slice(["-12345678901234567890123456789012345678901.2"]);
+    let result = cast(&array, &DataType::Decimal256(76, 1), CastOptions::default()).unwrap();
+    let result = cast(
+        result.as_ref(),
+        &DataType::LargeUtf8,
+        CastOptions::default(),
+    )
+    .unwrap();
+    assert_eq!(array, result.as_ref());
+}
+
 #[test]
 fn dict_keys() {
     let mut array = MutableDictionaryArray::<u8, MutableUtf8Array<i32>>::new();
@@ -938,3 +1307,19 @@ fn dict_keys() {
 
     assert_eq!(expected, result.as_ref());
 }
+
+#[test]
+fn dict_keys_overflow() {
+    let values = Int32Array::from_vec((0..201).collect()).boxed();
+    let array =
+        DictionaryArray::try_from_keys(Int32Array::from(&[Some(200), None]), values).unwrap();
+
+    let result = dictionary_to_dictionary_keys::<i32, u8>(&array).unwrap();
+    assert_eq!(result.keys(), &UInt8Array::from(&[Some(200), None]));
+    assert_eq!(result.values(), array.values());
+
+    assert!(matches!(
+        dictionary_to_dictionary_keys::<i32, i8>(&array),
+        Err(Error::Overflow)
+    ));
+}
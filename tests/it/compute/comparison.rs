@@ -3,7 +3,7 @@ use std::sync::Arc;
 use re_arrow2::array::*;
 use re_arrow2::bitmap::Bitmap;
 use re_arrow2::compute::comparison::{self, boolean::*, primitive, utf8};
-use re_arrow2::datatypes::{DataType, DataType::*, IntegerType, IntervalUnit, TimeUnit};
+use re_arrow2::datatypes::{DataType, DataType::*, Field, IntegerType, IntervalUnit, TimeUnit};
 use re_arrow2::scalar::new_scalar;
 
 #[test]
@@ -45,17 +45,52 @@ fn consistency() {
         Duration(TimeUnit::Microsecond),
         Duration(TimeUnit::Nanosecond),
         Dictionary(IntegerType::Int32, Arc::new(LargeBinary), false),
+        Dictionary(IntegerType::UInt8, Arc::new(Float16), false),
+        Dictionary(
+            IntegerType::Int32,
+            Arc::new(Interval(IntervalUnit::DayTime)),
+            false,
+        ),
+        FixedSizeBinary(3),
+        Decimal256(5, 2),
+        List(Arc::new(Field::new("a", Int32, true))),
+        LargeList(Arc::new(Field::new("a", Utf8, true))),
+        FixedSizeList(Arc::new(Field::new("a", Float16, true)), 2),
+        Struct(Arc::new(vec![
+            Field::new("a", Int32, true),
+            Field::new("b", Utf8, true),
+        ])),
+        Map(
+            Arc::new(Field::new(
+                "entries",
+                Struct(Arc::new(vec![
+                    Field::new("key", Utf8, false),
+                    Field::new("value", Int32, true),
+                ])),
+                false,
+            )),
+            false,
+        ),
+        Union(
+            Arc::new(vec![Field::new("a", Int32, true)]),
+            None,
+            re_arrow2::datatypes::UnionMode::Dense,
+        ),
     ];
 
     // array <> array
     datatypes.clone().into_iter().for_each(|d1| {
         let array = new_null_array(d1.clone(), 10);
-        if can_eq(&d1) {
-            eq(array.as_ref(), array.as_ref());
-        }
-        if can_lt_eq(&d1) {
-            lt_eq(array.as_ref(), array.as_ref());
-        }
+        assert_eq!(
+            can_eq(&d1),
+            eq(array.as_ref(), array.as_ref()).is_ok(),
+            "{d1:?}"
+        );
+        assert_eq!(
+            can_lt_eq(&d1),
+            lt_eq(array.as_ref(), array.as_ref()).is_ok(),
+            "{d1:?}"
+        );
     });
 
     // array <> scalar
@@ -63,10 +98,10 @@ fn consistency() {
         let array = new_null_array(d1.clone(), 10);
         let scalar = new_scalar(array.as_ref(), 0);
         if can_eq_scalar(&d1) {
-            eq_scalar(array.as_ref(), scalar.as_ref());
+            eq_scalar(array.as_ref(), scalar.as_ref()).unwrap();
         }
         if can_lt_eq_scalar(&d1) {
-            lt_eq_scalar(array.as_ref(), scalar.as_ref());
+            lt_eq_scalar(array.as_ref(), scalar.as_ref()).unwrap();
         }
     });
 }
@@ -416,3 +451,182 @@ fn primitive_and_validity() {
     let expected = BooleanArray::from_slice([false, true]);
     assert_eq!(primitive::eq_and_validity(&a1, &a2), expected);
 }
+
+#[test]
+fn dictionary_eq_and_ord() {
+    let mut lhs = MutableDictionaryArray::<i32, MutableUtf8Array<i32>>::new();
+    lhs.try_extend([Some("b"), Some("a"), None, Some("c")])
+        .unwrap();
+    let lhs: DictionaryArray<i32> = lhs.into();
+    let mut rhs = MutableDictionaryArray::<i32, MutableUtf8Array<i32>>::new();
+    rhs.try_extend([Some("a"), Some("a"), Some("a"), Some("d")])
+        .unwrap();
+    let rhs: DictionaryArray<i32> = rhs.into();
+
+    assert_eq!(
+        comparison::eq(&lhs, &rhs).unwrap(),
+        BooleanArray::from([Some(false), Some(true), None, Some(false)])
+    );
+    assert_eq!(
+        comparison::gt(&lhs, &rhs).unwrap(),
+        BooleanArray::from([Some(true), Some(false), None, Some(false)])
+    );
+
+    let scalar = new_scalar(&rhs, 0);
+    assert_eq!(
+        comparison::lt_eq_scalar(&lhs, scalar.as_ref()).unwrap(),
+        BooleanArray::from([Some(false), Some(true), None, Some(false)])
+    );
+}
+
+#[test]
+fn struct_eq() {
+    let fields = vec![Field::new("a", Int32, true), Field::new("b", Utf8, true)];
+    let lhs = StructArray::new(
+        Struct(Arc::new(fields.clone())),
+        vec![
+            Int32Array::from([Some(1), None, Some(3), Some(4)]).boxed(),
+            Utf8Array::<i32>::from([Some("a"), Some("b"), Some("c"), Some("d")]).boxed(),
+        ],
+        Some([true, true, true, false].into()),
+    );
+    let rhs = StructArray::new(
+        Struct(Arc::new(fields)),
+        vec![
+            Int32Array::from([Some(1), None, Some(3), Some(4)]).boxed(),
+            Utf8Array::<i32>::from([Some("a"), Some("b"), Some("x"), Some("d")]).boxed(),
+        ],
+        None,
+    );
+
+    assert_eq!(
+        comparison::eq(&lhs, &rhs).unwrap(),
+        BooleanArray::from([Some(true), Some(true), Some(false), None])
+    );
+    assert_eq!(
+        comparison::neq(&lhs, &rhs).unwrap(),
+        BooleanArray::from([Some(false), Some(false), Some(true), None])
+    );
+    assert_eq!(
+        comparison::eq_and_validity(&lhs, &rhs).unwrap(),
+        BooleanArray::from_slice([true, true, false, false])
+    );
+    // structs are ordered field by field
+    assert_eq!(
+        comparison::lt(&lhs, &rhs).unwrap(),
+        BooleanArray::from([Some(false), Some(false), Some(true), None])
+    );
+
+    let scalar = new_scalar(&rhs, 2);
+    assert_eq!(
+        comparison::eq_scalar(&lhs, scalar.as_ref()).unwrap(),
+        BooleanArray::from([Some(false), Some(false), Some(false), None])
+    );
+}
+
+#[test]
+fn list_eq() {
+    let mut lhs = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+    lhs.try_extend(vec![
+        Some(vec![Some(1), Some(2)]),
+        Some(vec![Some(1)]),
+        Some(vec![None]),
+        None,
+    ])
+    .unwrap();
+    let lhs: ListArray<i32> = lhs.into();
+    let mut rhs = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+    rhs.try_extend(vec![
+        Some(vec![Some(1), Some(2)]),
+        Some(vec![Some(1), Some(2)]),
+        Some(vec![None]),
+        Some(vec![]),
+    ])
+    .unwrap();
+    let rhs: ListArray<i32> = rhs.into();
+
+    assert_eq!(
+        comparison::eq(&lhs, &rhs).unwrap(),
+        BooleanArray::from([Some(true), Some(false), Some(true), None])
+    );
+    assert_eq!(
+        comparison::neq_and_validity(&lhs, &rhs).unwrap(),
+        BooleanArray::from_slice([false, true, false, true])
+    );
+}
+
+#[test]
+fn fixed_size_binary_ord() {
+    let lhs = FixedSizeBinaryArray::from([Some([1, 2]), Some([3, 4]), None]);
+    let rhs = FixedSizeBinaryArray::from([Some([1, 3]), Some([3, 4]), Some([0, 0])]);
+    assert_eq!(
+        comparison::lt(&lhs, &rhs).unwrap(),
+        BooleanArray::from([Some(true), Some(false), None])
+    );
+    assert_eq!(
+        comparison::eq(&lhs, &rhs).unwrap(),
+        BooleanArray::from([Some(false), Some(true), None])
+    );
+}
+
+#[test]
+fn float16_ord() {
+    use re_arrow2::types::f16;
+    let lhs = Float16Array::from([
+        Some(f16::from_f32(1.0)),
+        Some(f16::from_f32(-2.0)),
+        Some(f16::from_f32(f32::NAN)),
+        None,
+    ]);
+    let rhs = Float16Array::from_slice([f16::from_f32(1.5); 4]);
+    assert_eq!(
+        comparison::lt(&lhs, &rhs).unwrap(),
+        BooleanArray::from([Some(true), Some(true), Some(false), None])
+    );
+    let scalar = new_scalar(&rhs, 0);
+    assert_eq!(
+        comparison::gt_eq_scalar(&lhs, scalar.as_ref()).unwrap(),
+        BooleanArray::from([Some(false), Some(false), Some(true), None])
+    );
+}
+
+#[test]
+fn errors_instead_of_panics() {
+    use re_arrow2::types::days_ms;
+    let array = DaysMsArray::from_slice([days_ms::new(1, 1)]);
+    assert!(comparison::lt(&array, &array).is_err());
+    assert!(comparison::eq(&array, &array).unwrap().value(0));
+
+    let lhs = Int32Array::from_slice([1, 2]);
+    let rhs = Int64Array::from_slice([1, 2]);
+    assert!(comparison::eq(&lhs, &rhs).is_err());
+    assert!(comparison::eq(&lhs, &lhs.clone().sliced(0, 1)).is_err());
+
+    let scalar = new_scalar(&rhs, 0);
+    assert!(comparison::eq_scalar(&lhs, scalar.as_ref()).is_err());
+}
+
+#[test]
+fn test_between() {
+    let array = Int32Array::from(&[Some(1), Some(2), None, Some(5), Some(3)]);
+    let low = new_scalar(&Int32Array::from_slice([2]), 0);
+    let high = new_scalar(&Int32Array::from_slice([3]), 0);
+    let result = comparison::between(&array, low.as_ref(), high.as_ref()).unwrap();
+    assert_eq!(
+        result,
+        BooleanArray::from(&[Some(false), Some(true), None, Some(false), Some(true)])
+    );
+
+    let array = Utf8Array::<i32>::from_slice(["a", "bb", "c"]);
+    let low = new_scalar(&Utf8Array::<i32>::from_slice(["b"]), 0);
+    let high = new_scalar(&Utf8Array::<i32>::from_slice(["c"]), 0);
+    let result = comparison::between(&array, low.as_ref(), high.as_ref()).unwrap();
+    assert_eq!(result, BooleanArray::from_slice([false, true, true]));
+
+    assert!(comparison::can_between(&Int32));
+    assert!(!comparison::can_between(&Union(
+        Arc::new(vec![Field::new("a", Int32, true)]),
+        None,
+        re_arrow2::datatypes::UnionMode::Dense,
+    )));
+}
@@ -1,5 +1,6 @@
 use re_arrow2::array::*;
-use re_arrow2::compute::contains::contains;
+use re_arrow2::compute::contains::{contains, is_in, is_in_sql};
+use re_arrow2::datatypes::DataType;
 
 // disable wrapping inside literal vectors used for test data and assertions
 #[rustfmt::skip::macros(vec)]
@@ -59,3 +60,89 @@ fn test_contains_binary() {
 
     assert_eq!(result, expected);
 }
+
+#[test]
+fn test_is_in() {
+    let array = Int32Array::from(&[Some(1), Some(2), None, Some(4)]);
+    let values = Int32Array::from(&[Some(4), Some(1)]);
+    let expected = BooleanArray::from_slice([true, false, false, true]);
+    assert_eq!(is_in(&array, &values).unwrap(), expected);
+
+    // nulls are equal to nulls
+    let values = Int32Array::from(&[Some(4), None]);
+    let expected = BooleanArray::from_slice([false, false, true, true]);
+    assert_eq!(is_in(&array, &values).unwrap(), expected);
+}
+
+#[test]
+fn test_is_in_sql() {
+    let array = Int32Array::from(&[Some(1), Some(2), None]);
+
+    let values = Int32Array::from(&[Some(1), Some(3)]);
+    let expected = BooleanArray::from(&[Some(true), Some(false), None]);
+    assert_eq!(is_in_sql(&array, &values).unwrap(), expected);
+
+    let values = Int32Array::from(&[Some(1), None]);
+    let expected = BooleanArray::from(&[Some(true), None, None]);
+    assert_eq!(is_in_sql(&array, &values).unwrap(), expected);
+}
+
+#[test]
+fn test_is_in_utf8() {
+    let array = Utf8Array::<i64>::from([Some("a"), Some("b"), None, Some("c")]);
+    let values = Utf8Array::<i64>::from_slice(["c", "a", "a"]);
+    let expected = BooleanArray::from_slice([true, false, false, true]);
+    assert_eq!(is_in(&array, &values).unwrap(), expected);
+
+    let array = BinaryArray::<i32>::from_slice([b"a".as_ref(), b"b"]);
+    let values = BinaryArray::<i32>::from_slice([b"b".as_ref()]);
+    let expected = BooleanArray::from_slice([false, true]);
+    assert_eq!(is_in(&array, &values).unwrap(), expected);
+}
+
+#[test]
+fn test_is_in_floats() {
+    let array = Float64Array::from_slice([0.0, -0.0, f64::NAN, 1.5]);
+    let values = Float64Array::from_slice([-0.0, f64::NAN]);
+    let expected = BooleanArray::from_slice([true, true, true, false]);
+    assert_eq!(is_in(&array, &values).unwrap(), expected);
+}
+
+#[test]
+fn test_is_in_decimal() {
+    let array = Int128Array::from_slice([100, 250, 300]).to(DataType::Decimal(5, 2));
+    let values = Int128Array::from_slice([300, 100]).to(DataType::Decimal(5, 2));
+    let expected = BooleanArray::from_slice([true, false, true]);
+    assert_eq!(is_in(&array, &values).unwrap(), expected);
+}
+
+#[test]
+fn test_is_in_dictionary() {
+    let mut array = MutableDictionaryArray::<i32, MutableUtf8Array<i32>>::new();
+    array
+        .try_extend([Some("a"), None, Some("b"), Some("a")])
+        .unwrap();
+    let array: DictionaryArray<i32> = array.into();
+
+    let mut values = MutableDictionaryArray::<u8, MutableUtf8Array<i32>>::new();
+    values.try_extend([Some("a"), Some("c")]).unwrap();
+    let values: DictionaryArray<u8> = values.into();
+
+    let expected = BooleanArray::from_slice([true, false, false, true]);
+    assert_eq!(is_in(&array, &values).unwrap(), expected);
+
+    // dictionaries can be mixed with their values
+    let values = Utf8Array::<i32>::from([Some("b"), None]);
+    let expected = BooleanArray::from(&[None, None, Some(true), None]);
+    assert_eq!(is_in_sql(&array, &values).unwrap(), expected);
+}
+
+#[test]
+fn test_is_in_errors() {
+    let array = Int32Array::from_slice([1]);
+    let values = Int64Array::from_slice([1]);
+    assert!(is_in(&array, &values).is_err());
+
+    let array = new_null_array(DataType::Null, 1);
+    assert!(is_in(array.as_ref(), array.as_ref()).is_err());
+}
@@ -0,0 +1,174 @@
+use re_arrow2::array::*;
+use re_arrow2::compute::cumulative::*;
+use re_arrow2::compute::partition::lexicographical_partition_ranges;
+use re_arrow2::compute::sort::SortColumn;
+use re_arrow2::datatypes::DataType;
+use re_arrow2::error::Error;
+
+#[test]
+fn primitive() {
+    let array = Int32Array::from(&[Some(2), None, Some(-1), Some(3)]);
+    let options = CumulativeOptions::default();
+
+    let result = cumsum(&array, &options).unwrap();
+    assert_eq!(result, Int32Array::from(&[Some(2), None, Some(1), Some(4)]));
+
+    let result = cumprod(&array, &options).unwrap();
+    assert_eq!(
+        result,
+        Int32Array::from(&[Some(2), None, Some(-2), Some(-6)])
+    );
+
+    let result = cummin(&array, &options).unwrap();
+    assert_eq!(
+        result,
+        Int32Array::from(&[Some(2), None, Some(-1), Some(-1)])
+    );
+
+    let result = cummax(&array, &options).unwrap();
+    assert_eq!(result, Int32Array::from(&[Some(2), None, Some(2), Some(3)]));
+
+    let result = cumcount(&array, &options).unwrap();
+    assert_eq!(
+        result,
+        UInt64Array::from(&[Some(1), None, Some(2), Some(3)])
+    );
+}
+
+#[test]
+fn reverse_and_propagate() {
+    let array = Float64Array::from(&[Some(1.0), Some(2.0), None, Some(4.0)]);
+
+    let options = CumulativeOptions {
+        reverse: true,
+        ..Default::default()
+    };
+    let result = cumsum(&array, &options).unwrap();
+    assert_eq!(
+        result,
+        Float64Array::from(&[Some(7.0), Some(6.0), None, Some(4.0)])
+    );
+
+    let options = CumulativeOptions {
+        nulls: CumulativeNulls::Propagate,
+        ..Default::default()
+    };
+    let result = cumsum(&array, &options).unwrap();
+    assert_eq!(
+        result,
+        Float64Array::from(&[Some(1.0), Some(3.0), None, None])
+    );
+}
+
+#[test]
+fn partitions() {
+    let keys = Utf8Array::<i32>::from_slice(["a", "a", "b", "b", "b", "c"]);
+    let partitions = lexicographical_partition_ranges(&[SortColumn {
+        values: &keys,
+        options: None,
+    }])
+    .unwrap()
+    .collect::<Vec<_>>();
+
+    let array = Int64Array::from(&[Some(1), Some(2), None, Some(3), Some(4), Some(5)]);
+    let options = CumulativeOptions {
+        partitions: Some(&partitions),
+        nulls: CumulativeNulls::Propagate,
+        ..Default::default()
+    };
+    let result = cumsum(&array, &options).unwrap();
+    assert_eq!(
+        result,
+        Int64Array::from(&[Some(1), Some(3), None, None, None, Some(5)])
+    );
+
+    let options = CumulativeOptions {
+        partitions: Some(&partitions),
+        reverse: true,
+        ..Default::default()
+    };
+    let result = cumcount(&array, &options).unwrap();
+    assert_eq!(
+        result,
+        UInt64Array::from(&[Some(2), Some(1), None, Some(2), Some(1), Some(1)])
+    );
+}
+
+#[test]
+fn invalid_partitions() {
+    let array = Int32Array::from_slice([1, 2, 3]);
+    let partitions = [0..1, 2..3];
+    let options = CumulativeOptions {
+        partitions: Some(&partitions),
+        ..Default::default()
+    };
+    assert!(cumsum(&array, &options).is_err());
+
+    let partitions = [0..1, 1..2];
+    let options = CumulativeOptions {
+        partitions: Some(&partitions),
+        ..Default::default()
+    };
+    assert!(cumsum(&array, &options).is_err());
+}
+
+#[test]
+fn decimal() {
+    let array =
+        Int128Array::from(&[Some(150), None, Some(-200), Some(1000)]).to(DataType::Decimal(5, 2));
+    let options = CumulativeOptions::default();
+
+    let result = cumsum_decimal(&array, &options).unwrap();
+    let expected =
+        Int128Array::from(&[Some(150), None, Some(-50), Some(950)]).to(DataType::Decimal(5, 2));
+    // the generic kernels would not check the precision or the scale
+    assert!(cumsum(&array, &options).is_err());
+    assert!(cumprod(&array, &options).is_err());
+    assert_eq!(result, expected);
+
+    let result = cumprod_decimal(&array, &options).unwrap();
+    let expected =
+        Int128Array::from(&[Some(150), None, Some(-300), Some(-3000)]).to(DataType::Decimal(5, 2));
+    assert_eq!(result, expected);
+
+    let result = cummin(&array, &options).unwrap();
+    let expected =
+        Int128Array::from(&[Some(150), None, Some(-200), Some(-200)]).to(DataType::Decimal(5, 2));
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn decimal_overflow() {
+    let array = Int128Array::from_slice([99900, 200]).to(DataType::Decimal(5, 2));
+    assert!(cumprod_decimal(&array, &Default::default()).is_err());
+    assert!(matches!(
+        cumsum_decimal(&array, &Default::default()),
+        Err(Error::Overflow)
+    ));
+
+    // the intermediate product does not fit in an `i128`, but the result fits the precision
+    let data_type = DataType::Decimal(38, 10);
+    let array = Int128Array::from_slice([10i128.pow(37), 10i128.pow(10)]).to(data_type.clone());
+    let result = cumprod_decimal(&array, &Default::default()).unwrap();
+    let expected = Int128Array::from_slice([10i128.pow(37), 10i128.pow(37)]).to(data_type.clone());
+    assert_eq!(result, expected);
+    let array = Int128Array::from_slice([10i128.pow(37), 10i128.pow(12)]).to(data_type);
+    assert!(cumprod_decimal(&array, &Default::default()).is_err());
+}
+
+#[test]
+fn primitive_overflow() {
+    let array = Int32Array::from_slice([i32::MAX, 1]);
+    assert!(matches!(
+        cumsum(&array, &Default::default()),
+        Err(Error::Overflow)
+    ));
+    assert!(matches!(
+        cumprod(&Int8Array::from_slice([16, 8]), &Default::default()),
+        Err(Error::Overflow)
+    ));
+
+    let array = Float32Array::from_slice([f32::MAX, f32::MAX]);
+    let result = cumsum(&array, &Default::default()).unwrap();
+    assert_eq!(result, Float32Array::from_slice([f32::MAX, f32::INFINITY]));
+}
@@ -0,0 +1,163 @@
+use re_arrow2::array::*;
+use re_arrow2::compute::dictionary::*;
+use re_arrow2::datatypes::DataType;
+use re_arrow2::error::Error;
+
+fn dictionary<K: DictionaryKey>(keys: PrimitiveArray<K>, values: &dyn Array) -> DictionaryArray<K> {
+    DictionaryArray::try_from_keys(keys, values.to_boxed()).unwrap()
+}
+
+#[test]
+fn unify_utf8() {
+    let a = dictionary(
+        Int8Array::from(&[Some(0), None, Some(1), Some(2)]),
+        &Utf8Array::<i32>::from([Some("a"), Some("b"), None]),
+    );
+    let b = dictionary(
+        Int8Array::from_slice([1, 0, 1]),
+        &Utf8Array::<i32>::from_slice(["c", "b"]),
+    );
+
+    let result = unify_dictionaries(&[&a, &b]).unwrap();
+
+    let values = Utf8Array::<i32>::from_slice(["a", "b", "c"]);
+    assert_eq!(values, result[0].values().as_ref());
+    assert_eq!(values, result[1].values().as_ref());
+    assert_eq!(
+        result[0].keys(),
+        &Int8Array::from(&[Some(0), None, Some(1), None])
+    );
+    assert_eq!(result[1].keys(), &Int8Array::from_slice([1, 2, 1]));
+    // the rows are unchanged
+    assert_eq!(result[0], a);
+    assert_eq!(result[1], b);
+}
+
+#[test]
+fn unify_primitive() {
+    let data_type = DataType::Date32;
+    let a = dictionary(
+        Int32Array::from_slice([0, 1]),
+        &Int32Array::from_slice([10, 20]).to(data_type.clone()),
+    );
+    let b = dictionary(
+        Int32Array::from_slice([0, 1]),
+        &Int32Array::from_slice([30, 10]).to(data_type.clone()),
+    );
+
+    let result = unify_dictionaries(&[&a.clone().sliced(1, 1), &b]).unwrap();
+
+    assert_eq!(
+        Int32Array::from_slice([20, 30, 10]).to(data_type),
+        result[0].values().as_ref()
+    );
+    assert_eq!(result[0].keys(), &Int32Array::from_slice([0]));
+    assert_eq!(result[1].keys(), &Int32Array::from_slice([1, 2]));
+}
+
+#[test]
+fn unify_drops_unused() {
+    let a = dictionary(
+        Int32Array::from(&[Some(1), None]),
+        &Utf8Array::<i32>::from_slice(["a", "b", "c"]),
+    );
+    let b = dictionary(
+        Int32Array::from_slice([0, 0]),
+        &Utf8Array::<i32>::from_slice(["c", "d"]),
+    );
+
+    let result = unify_dictionaries(&[&a, &b]).unwrap();
+
+    let values = Utf8Array::<i32>::from_slice(["b", "c"]);
+    assert_eq!(values, result[0].values().as_ref());
+    assert_eq!(result[0].keys(), &Int32Array::from(&[Some(0), None]));
+    assert_eq!(result[1].keys(), &Int32Array::from_slice([1, 1]));
+    assert_eq!(result[0], a);
+    assert_eq!(result[1], b);
+
+    // unused values don't count towards the capacity of the keys
+    let a = dictionary(
+        Int8Array::from_slice([99]),
+        &Int32Array::from_vec((0..100).collect()),
+    );
+    let b = dictionary(
+        Int8Array::from_slice([0]),
+        &Int32Array::from_vec((100..200).collect()),
+    );
+    let result = unify_dictionaries(&[&a, &b]).unwrap();
+    assert_eq!(
+        Int32Array::from_slice([99, 100]),
+        result[0].values().as_ref()
+    );
+}
+
+#[test]
+fn unify_concatenates_floats() {
+    let a = dictionary(
+        UInt8Array::from_slice([1, 0]),
+        &Float32Array::from_slice([1.0, 2.0]),
+    );
+    let b = dictionary(
+        UInt8Array::from_slice([0]),
+        &Float32Array::from_slice([1.0]),
+    );
+
+    let result = unify_dictionaries(&[&a, &b]).unwrap();
+
+    assert_eq!(
+        Float32Array::from_slice([1.0, 2.0, 1.0]),
+        result[1].values().as_ref()
+    );
+    assert_eq!(result[0].keys(), &UInt8Array::from_slice([1, 0]));
+    assert_eq!(result[1].keys(), &UInt8Array::from_slice([2]));
+}
+
+#[test]
+fn unify_errors() {
+    let a = dictionary(Int8Array::from_slice([0]), &Int32Array::from_slice([1]));
+    let b = dictionary(Int8Array::from_slice([0]), &Int64Array::from_slice([1]));
+    assert!(unify_dictionaries(&[&a, &b]).is_err());
+
+    let keys = Int8Array::from_vec((0..100).collect());
+    let a = dictionary(keys.clone(), &Int32Array::from_vec((0..100).collect()));
+    let b = dictionary(keys, &Int32Array::from_vec((100..200).collect()));
+    assert!(matches!(
+        unify_dictionaries(&[&a, &b]),
+        Err(Error::Overflow)
+    ));
+
+    assert!(unify_dictionaries::<i32>(&[]).unwrap().is_empty());
+}
+
+#[test]
+fn compact() {
+    let array = dictionary(
+        Int32Array::from(&[Some(3), Some(1), None, Some(3), Some(0)]),
+        &Utf8Array::<i32>::from_slice(["a", "b", "c", "d"]),
+    );
+
+    let result = compact_dictionary(&array.clone().sliced(0, 4));
+    assert_eq!(
+        Utf8Array::<i32>::from_slice(["b", "d"]),
+        result.values().as_ref()
+    );
+    assert_eq!(
+        result.keys(),
+        &Int32Array::from(&[Some(1), Some(0), None, Some(1)])
+    );
+    assert_eq!(result, array.clone().sliced(0, 4));
+
+    let result = compact_dictionary(&array.clone().sliced(2, 1));
+    assert_eq!(result.values().len(), 1);
+    assert_eq!(result, array.clone().sliced(2, 1));
+
+    let result = compact_dictionary(&array);
+    assert_eq!(
+        Utf8Array::<i32>::from_slice(["a", "b", "d"]),
+        result.values().as_ref()
+    );
+    assert_eq!(result, array);
+
+    let array = array.clone().sliced(1, 4);
+    assert_eq!(compact_dictionary(&array), array);
+}
@@ -0,0 +1,156 @@
+use std::sync::Arc;
+
+use re_arrow2::array::*;
+use re_arrow2::compute::fill_null::*;
+use re_arrow2::datatypes::{DataType, Field};
+use re_arrow2::scalar::{BooleanScalar, PrimitiveScalar, StructScalar, Utf8Scalar};
+
+#[test]
+fn primitive() {
+    let array = Int32Array::from(&[Some(1), None, Some(3), None]);
+    let result = fill_null(&array, &PrimitiveScalar::new(DataType::Int32, Some(0))).unwrap();
+
+    let expected = Int32Array::from_slice([1, 0, 3, 0]);
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+fn primitive_sliced() {
+    let array = Int32Array::from(&[Some(1), None, Some(3), None]).sliced(1, 2);
+    let result = fill_null_primitive(&array, 5);
+
+    assert_eq!(result, Int32Array::from_slice([5, 3]));
+}
+
+#[test]
+fn boolean() {
+    let array = BooleanArray::from(&[Some(true), None, Some(false), None]);
+
+    let result = fill_null(&array, &BooleanScalar::new(Some(true))).unwrap();
+    let expected = BooleanArray::from_slice([true, true, false, true]);
+    assert_eq!(expected, result.as_ref());
+
+    let result = fill_null(&array, &BooleanScalar::new(Some(false))).unwrap();
+    let expected = BooleanArray::from_slice([true, false, false, false]);
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+fn utf8() {
+    let array = Utf8Array::<i64>::from([None, Some("a"), None]);
+    let result = fill_null(&array, &Utf8Scalar::<i64>::new(Some("b"))).unwrap();
+
+    let expected = Utf8Array::<i64>::from_slice(["b", "a", "b"]);
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+fn null_value() {
+    let array = Int32Array::from(&[Some(1), None]);
+    let result = fill_null(&array, &PrimitiveScalar::<i32>::new(DataType::Int32, None)).unwrap();
+    assert_eq!(array, result.as_ref());
+}
+
+#[test]
+fn wrong_type() {
+    let array = Int32Array::from(&[Some(1), None]);
+    assert!(fill_null(&array, &PrimitiveScalar::new(DataType::Int64, Some(1i64))).is_err());
+}
+
+#[test]
+fn struct_() {
+    let fields = vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Boolean, true),
+    ];
+    let data_type = DataType::Struct(Arc::new(fields));
+    let array = StructArray::new(
+        data_type.clone(),
+        vec![
+            Int32Array::from(&[Some(1), None, Some(3)]).boxed(),
+            BooleanArray::from(&[Some(true), None, None]).boxed(),
+        ],
+        Some([true, false, true].into()),
+    );
+    let value = StructScalar::new(
+        data_type.clone(),
+        Some(vec![
+            Box::new(PrimitiveScalar::new(DataType::Int32, Some(0))),
+            Box::new(BooleanScalar::new(Some(false))),
+        ]),
+    );
+    let result = fill_null(&array, &value).unwrap();
+
+    let expected = StructArray::new(
+        data_type,
+        vec![
+            Int32Array::from(&[Some(1), Some(0), Some(3)]).boxed(),
+            BooleanArray::from(&[Some(true), Some(false), None]).boxed(),
+        ],
+        None,
+    );
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+fn forward() {
+    let array = Int32Array::from(&[None, Some(1), None, None, None, Some(5), None]);
+
+    let result = fill_null_forward(&array, None);
+    let expected = Int32Array::from(&[None, Some(1), Some(1), Some(1), Some(1), Some(5), Some(5)]);
+    assert_eq!(expected, result.as_ref());
+
+    let result = fill_null_forward(&array, Some(2));
+    let expected = Int32Array::from(&[None, Some(1), Some(1), Some(1), None, Some(5), Some(5)]);
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+fn backward() {
+    let array = Utf8Array::<i32>::from([None, Some("a"), None, None, Some("b"), None]);
+
+    let result = fill_null_backward(&array, Some(1));
+    let expected = Utf8Array::<i32>::from([Some("a"), Some("a"), None, Some("b"), Some("b"), None]);
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+fn forward_list() {
+    let data = vec![Some(vec![Some(1), Some(2)]), None, Some(vec![])];
+    let mut array = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+    array.try_extend(data).unwrap();
+    let array: ListArray<i32> = array.into();
+
+    let result = fill_null_forward(&array, None);
+
+    let data = vec![
+        Some(vec![Some(1), Some(2)]),
+        Some(vec![Some(1), Some(2)]),
+        Some(vec![]),
+    ];
+    let mut expected = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+    expected.try_extend(data).unwrap();
+    let expected: ListArray<i32> = expected.into();
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+fn coalesce_many() {
+    let a = Int32Array::from(&[Some(1), None, None, None]);
+    let b = Int32Array::from(&[Some(10), Some(20), None, None]);
+    let c = Int32Array::from(&[None, Some(200), Some(300), None]);
+    let result = coalesce(&[&a, &b, &c]).unwrap();
+
+    let expected = Int32Array::from(&[Some(1), Some(20), Some(300), None]);
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+fn coalesce_errors() {
+    let a = Int32Array::from(&[Some(1), None]);
+    let b = Int64Array::from(&[Some(1), None]);
+    let c = Int32Array::from(&[Some(1)]);
+    assert!(coalesce(&[]).is_err());
+    assert!(coalesce(&[&a, &b]).is_err());
+    assert!(coalesce(&[&a, &c]).is_err());
+}
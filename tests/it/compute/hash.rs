@@ -1,7 +1,8 @@
-use re_arrow2::array::new_null_array;
+use re_arrow2::array::*;
 use re_arrow2::compute::hash::*;
 use re_arrow2::datatypes::DataType::*;
-use re_arrow2::datatypes::TimeUnit;
+use re_arrow2::datatypes::{Field, TimeUnit};
+use re_arrow2::types::i256;
 
 #[test]
 fn consistency() {
@@ -47,3 +48,232 @@ fn consistency() {
         }
     });
 }
+
+#[test]
+fn xxhash64_vectors() {
+    let array = BinaryArray::<i32>::from_slice([
+        b"".as_ref(),
+        b"a",
+        b"abc",
+        b"Nobody inspects the spammish repetition",
+    ]);
+    let result = hash_columns(&[&array], HashAlgorithm::XxHash64, 0).unwrap();
+    assert_eq!(
+        result,
+        UInt64Array::from_slice([
+            0xEF46DB3751D8E999,
+            0xD24EC4F1A98C6E5B,
+            0x44BC2CF5AD770999,
+            0xFBCEA83C8A378BF1
+        ])
+    );
+}
+
+#[test]
+fn hash_columns_combines() {
+    let a = Int32Array::from(&[Some(1), Some(1), None, Some(1)]);
+    let b = Utf8Array::<i32>::from([Some("x"), Some("y"), Some("x"), None]);
+    let result = hash_columns(&[&a, &b], HashAlgorithm::XxHash64, 42).unwrap();
+
+    // null values leave the hash unchanged
+    assert_eq!(
+        result.value(2),
+        hash_columns(&[&b], HashAlgorithm::XxHash64, 42)
+            .unwrap()
+            .value(0)
+    );
+    assert_eq!(
+        result.value(3),
+        hash_columns(&[&a], HashAlgorithm::XxHash64, 42)
+            .unwrap()
+            .value(0)
+    );
+    assert_ne!(result.value(0), result.value(1));
+    assert_eq!(result.null_count(), 0);
+
+    // the seed changes the hashes
+    assert_ne!(
+        hash_columns(&[&a, &b], HashAlgorithm::XxHash64, 0).unwrap(),
+        result
+    );
+}
+
+#[test]
+fn hash_columns_stable_encoding() {
+    // integers of up to 32 bits hash like 32 bit integers
+    let int8 = Int8Array::from_slice([-1, 2]);
+    let int32 = Int32Array::from_slice([-1, 2]);
+    let boolean = BooleanArray::from_slice([false, true]);
+    assert_eq!(
+        hash_columns(&[&int8], HashAlgorithm::XxHash64, 42).unwrap(),
+        hash_columns(&[&int32], HashAlgorithm::XxHash64, 42).unwrap()
+    );
+    assert_eq!(
+        hash_columns(&[&boolean], HashAlgorithm::XxHash64, 42)
+            .unwrap()
+            .value(1),
+        hash_columns(&[&Int32Array::from_slice([1])], HashAlgorithm::XxHash64, 42)
+            .unwrap()
+            .value(0)
+    );
+
+    // decimals that fit in 64 bits hash like their unscaled 64 bit integer
+    let decimal = Int128Array::from_slice([-5, 7]).to(Decimal(10, 2));
+    let int64 = Int64Array::from_slice([-5, 7]);
+    assert_eq!(
+        hash_columns(&[&decimal], HashAlgorithm::XxHash64, 42).unwrap(),
+        hash_columns(&[&int64], HashAlgorithm::XxHash64, 42).unwrap()
+    );
+}
+
+#[test]
+fn hash_columns_floats() {
+    let array = Float64Array::from_slice([0.0, -0.0, f64::NAN, -f64::NAN, 1.0]);
+    let result = hash_columns(&[&array], HashAlgorithm::XxHash64, 42).unwrap();
+    assert_eq!(result.value(0), result.value(1));
+    assert_eq!(result.value(2), result.value(3));
+    assert_ne!(result.value(0), result.value(4));
+}
+
+#[test]
+fn hash_columns_dictionary() {
+    let mut dictionary = MutableDictionaryArray::<i32, MutableUtf8Array<i32>>::new();
+    dictionary
+        .try_extend([Some("a"), None, Some("b"), Some("a")])
+        .unwrap();
+    let dictionary: DictionaryArray<i32> = dictionary.into();
+    let values = Utf8Array::<i32>::from([Some("a"), None, Some("b"), Some("a")]);
+
+    assert_eq!(
+        hash_columns(&[&dictionary], HashAlgorithm::XxHash64, 42).unwrap(),
+        hash_columns(&[&values], HashAlgorithm::XxHash64, 42).unwrap()
+    );
+}
+
+#[test]
+fn hash_columns_errors() {
+    assert!(hash_columns(&[], HashAlgorithm::XxHash64, 42).is_err());
+
+    let a = Int32Array::from_slice([1, 2]);
+    let b = Int32Array::from_slice([1]);
+    assert!(hash_columns(&[&a, &b], HashAlgorithm::XxHash64, 42).is_err());
+}
+
+fn hash_i32(columns: &[&dyn Array], algorithm: HashAlgorithm, seed: u64) -> Vec<i32> {
+    hash_columns(columns, algorithm, seed)
+        .unwrap()
+        .values_iter()
+        .map(|x| *x as u32 as i32)
+        .collect()
+}
+
+#[test]
+fn spark_compatible() {
+    // Spark: SELECT hash('Spark', array(123), 2), xxhash64('Spark', array(123), 2)
+    let string = Utf8Array::<i32>::from_slice(["Spark"]);
+    let mut list = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+    list.try_extend([Some(vec![Some(123)])]).unwrap();
+    let list: ListArray<i32> = list.into();
+    let int = Int32Array::from_slice([2]);
+    let columns: [&dyn Array; 3] = [&string, &list, &int];
+
+    assert_eq!(
+        hash_i32(&columns, HashAlgorithm::SparkMurmur3, 42),
+        vec![-1321691492]
+    );
+    let result = hash_columns(&columns, HashAlgorithm::XxHash64, 42).unwrap();
+    assert_eq!(result.value(0), 5602566077635097486);
+}
+
+#[test]
+fn iceberg_compatible() {
+    // the examples of Iceberg's specification of the bucket transform
+    let algorithm = HashAlgorithm::IcebergMurmur3;
+    let int = Int32Array::from_slice([34]);
+    assert_eq!(hash_i32(&[&int], algorithm, 0), vec![2017239379]);
+
+    let long = Int64Array::from_slice([34]);
+    assert_eq!(hash_i32(&[&long], algorithm, 0), vec![2017239379]);
+
+    let decimal = Int128Array::from_slice([1420]).to(Decimal(9, 2));
+    assert_eq!(hash_i32(&[&decimal], algorithm, 0), vec![-500754589]);
+
+    let date = Int32Array::from_slice([17486]).to(Date32);
+    assert_eq!(hash_i32(&[&date], algorithm, 0), vec![-653330422]);
+
+    let time = Int64Array::from_slice([81068000000]).to(Time64(TimeUnit::Microsecond));
+    assert_eq!(hash_i32(&[&time], algorithm, 0), vec![-662762989]);
+
+    let timestamp =
+        Int64Array::from_slice([1510871468000000]).to(Timestamp(TimeUnit::Microsecond, None));
+    assert_eq!(hash_i32(&[&timestamp], algorithm, 0), vec![-2047944441]);
+
+    let string = Utf8Array::<i32>::from_slice(["iceberg"]);
+    assert_eq!(hash_i32(&[&string], algorithm, 0), vec![1210000089]);
+
+    let binary = BinaryArray::<i32>::from_slice([[0u8, 1, 2, 3]]);
+    assert_eq!(hash_i32(&[&binary], algorithm, 0), vec![-188683207]);
+}
+
+#[test]
+fn nested() {
+    let a = Int32Array::from_slice([1, 2]).boxed();
+    let b = Utf8Array::<i32>::from_slice(["x", "y"]).boxed();
+    let fields = vec![Field::new("a", Int32, false), Field::new("b", Utf8, false)];
+    let array = StructArray::new(Struct(fields.into()), vec![a.clone(), b.clone()], None);
+
+    // a struct hashes like its fields
+    for algorithm in [
+        HashAlgorithm::XxHash64,
+        HashAlgorithm::SparkMurmur3,
+        HashAlgorithm::IcebergMurmur3,
+    ] {
+        assert_eq!(
+            hash_columns(&[&array], algorithm, 42).unwrap(),
+            hash_columns(&[a.as_ref(), b.as_ref()], algorithm, 42).unwrap()
+        );
+    }
+
+    // null structs and null list elements leave the hash unchanged
+    let array = array.with_validity(Some([true, false].into()));
+    let result = hash_columns(&[&array], HashAlgorithm::XxHash64, 42).unwrap();
+    assert_eq!(result.value(1), 42);
+
+    let mut list = MutableListArray::<i64, MutablePrimitiveArray<i32>>::new();
+    list.try_extend([
+        Some(vec![Some(1), None, Some(2)]),
+        Some(vec![Some(1), Some(2)]),
+    ])
+    .unwrap();
+    let list: ListArray<i64> = list.into();
+    let result = hash_columns(&[&list], HashAlgorithm::XxHash64, 42).unwrap();
+    assert_eq!(result.value(0), result.value(1));
+}
+
+#[test]
+fn decimal256() {
+    let array = Int256Array::from_slice([i256::from_words(0, 1420), i256::from_words(-1, -1)])
+        .to(Decimal256(40, 2));
+    let decimal = Int128Array::from_slice([1420, -1]).to(Decimal(38, 2));
+    // both decimal widths hash their unscaled minimal big-endian bytes
+    assert_eq!(
+        hash_columns(&[&array], HashAlgorithm::SparkMurmur3, 42).unwrap(),
+        hash_columns(&[&decimal], HashAlgorithm::SparkMurmur3, 42).unwrap()
+    );
+}
+
+#[test]
+fn hash_floats_and_nested() {
+    let array = Float32Array::from(&[Some(1.0), None]);
+    let result = hash(&array).unwrap();
+    assert!(result.is_valid(0));
+    assert!(result.is_null(1));
+
+    let values = Int32Array::from_slice([1, 2, 3]).boxed();
+    let array = FixedSizeListArray::new(
+        FixedSizeList(std::sync::Arc::new(Field::new("item", Int32, false)), 3),
+        values,
+        None,
+    );
+    assert!(hash(&array).is_ok());
+}
@@ -1,5 +1,5 @@
 use re_arrow2::array::*;
-use re_arrow2::compute::if_then_else::if_then_else;
+use re_arrow2::compute::if_then_else::{case_when, if_then_else};
 use re_arrow2::error::Result;
 
 #[test]
@@ -40,3 +40,53 @@ fn basics_nulls_pred() -> Result<()> {
     assert_eq!(expected, result.as_ref());
     Ok(())
 }
+
+#[test]
+fn case_when_basics() -> Result<()> {
+    let a = Int32Array::from(&[Some(1), Some(2), None, Some(4)]);
+    let b = Int32Array::from_slice([5, 6, 7, 8]);
+    let otherwise = Int32Array::from(&[Some(9), Some(10), Some(11), None]);
+    let branches = [
+        (
+            BooleanArray::from(&[Some(true), None, Some(true), Some(false)]),
+            &a as &dyn Array,
+        ),
+        (
+            BooleanArray::from(&[Some(true), Some(true), Some(false), None]),
+            &b as &dyn Array,
+        ),
+    ];
+    let result = case_when(&branches, &otherwise)?;
+
+    let expected = Int32Array::from(&[Some(1), Some(6), None, None]);
+
+    assert_eq!(expected, result.as_ref());
+
+    let result = case_when(&[], &otherwise)?;
+    assert_eq!(otherwise, result.as_ref());
+    Ok(())
+}
+
+#[test]
+fn case_when_utf8() -> Result<()> {
+    let a = Utf8Array::<i32>::from_slice(["a", "b", "c"]);
+    let otherwise = Utf8Array::<i32>::from_slice(["x", "y", "z"]);
+    let predicate = BooleanArray::from_slice([false, true, true]);
+    let result = case_when(&[(predicate, &a as &dyn Array)], &otherwise)?;
+
+    let expected = Utf8Array::<i32>::from_slice(["x", "b", "c"]);
+
+    assert_eq!(expected, result.as_ref());
+    Ok(())
+}
+
+#[test]
+fn case_when_errors() {
+    let a = Int32Array::from_slice([1, 2]);
+    let b = Int64Array::from_slice([1, 2]);
+    let predicate = BooleanArray::from_slice([true, false]);
+    assert!(case_when(&[(predicate.clone(), &b as &dyn Array)], &a).is_err());
+
+    let predicate = BooleanArray::from_slice([true]);
+    assert!(case_when(&[(predicate, &a as &dyn Array)], &a).is_err());
+}
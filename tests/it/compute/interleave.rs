@@ -0,0 +1,40 @@
+use re_arrow2::array::*;
+use re_arrow2::compute::interleave::interleave;
+use re_arrow2::error::Result;
+
+#[test]
+fn primitive() -> Result<()> {
+    let a = Int32Array::from(&[Some(1), None, Some(3)]);
+    let b = Int32Array::from_slice([4, 5]);
+    let result = interleave(&[&a, &b], &[(1, 1), (0, 0), (0, 1), (0, 2), (1, 0)])?;
+
+    let expected = Int32Array::from(&[Some(5), Some(1), None, Some(3), Some(4)]);
+
+    assert_eq!(expected, result.as_ref());
+    Ok(())
+}
+
+#[test]
+fn utf8_sliced() -> Result<()> {
+    let a = Utf8Array::<i32>::from([Some("a"), Some("b"), None]).sliced(1, 2);
+    let b = Utf8Array::<i32>::from_slice(["c"]);
+    let result = interleave(&[&a, &b], &[(0, 1), (1, 0), (0, 0)])?;
+
+    let expected = Utf8Array::<i32>::from([None, Some("c"), Some("b")]);
+
+    assert_eq!(expected, result.as_ref());
+
+    let result = interleave(&[&a], &[])?;
+    assert_eq!(result.len(), 0);
+    Ok(())
+}
+
+#[test]
+fn errors() {
+    let a = Int32Array::from_slice([1, 2]);
+    let b = Int64Array::from_slice([1, 2]);
+    assert!(interleave(&[&a, &b], &[(0, 0)]).is_err());
+    assert!(interleave(&[&a], &[(0, 2)]).is_err());
+    assert!(interleave(&[&a], &[(1, 0)]).is_err());
+    assert!(interleave(&[], &[]).is_err());
+}
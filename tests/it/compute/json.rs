@@ -0,0 +1,155 @@
+use std::sync::Arc;
+
+use re_arrow2::array::*;
+use re_arrow2::compute::json::*;
+use re_arrow2::datatypes::{DataType, Field, TimeUnit};
+
+fn struct_type() -> DataType {
+    DataType::Struct(Arc::new(vec![
+        Field::new("a", DataType::Int64, true),
+        Field::new(
+            "b",
+            DataType::List(Arc::new(Field::new("item", DataType::Utf8, true))),
+            true,
+        ),
+    ]))
+}
+
+#[test]
+fn parse_struct() {
+    let array = Utf8Array::<i32>::from([
+        Some(r#"{"a": 1, "b": ["x", "y"]}"#),
+        Some(r#"{"b": [1.5]}"#),
+        Some("not json"),
+        Some("null"),
+        None,
+    ]);
+    let result = parse_json(&array, struct_type(), false).unwrap();
+
+    let a = Int64Array::from(&[Some(1), None, None, None, None]);
+    let mut b = MutableListArray::<i32, MutableUtf8Array<i32>>::new();
+    b.try_extend([
+        Some(vec![Some("x"), Some("y")]),
+        Some(vec![Some("1.5")]),
+        None,
+        None,
+        None,
+    ])
+    .unwrap();
+    let b: ListArray<i32> = b.into();
+    let expected = StructArray::new(
+        struct_type(),
+        vec![a.boxed(), b.boxed()],
+        Some([true, true, false, false, false].into()),
+    );
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+fn parse_primitive() {
+    let array = Utf8Array::<i64>::from([Some("1"), Some("\"a\""), Some("true"), None]);
+    let result = parse_json(&array, DataType::Int32, false).unwrap();
+    assert_eq!(
+        Int32Array::from(&[Some(1), None, Some(1), None]),
+        result.as_ref()
+    );
+
+    let array = Utf8Array::<i32>::from_slice(["\"1996-12-19T16:39:57-02:00\""]);
+    let data_type = DataType::Timestamp(TimeUnit::Second, Some(Arc::new("+00:00".to_string())));
+    let result = parse_json(&array, data_type.clone(), false).unwrap();
+    assert_eq!(
+        Int64Array::from_slice([851020797]).to(data_type),
+        result.as_ref()
+    );
+}
+
+#[test]
+fn parse_strict() {
+    let array = Utf8Array::<i32>::from([Some("1"), None, Some("null"), Some("{")]);
+    let error = parse_json(&array, DataType::Int64, true).unwrap_err();
+    assert!(error.to_string().contains("Row 3"));
+
+    let array = Utf8Array::<i32>::from([Some("1"), Some("\"a\"")]);
+    assert!(parse_json(&array, DataType::Int64, false).is_ok());
+    let error = parse_json(&array, DataType::Int64, true).unwrap_err();
+    assert!(error.to_string().contains("Row 1"));
+
+    let array = Utf8Array::<i32>::from([Some("1"), None, Some("null")]);
+    assert!(parse_json(&array, DataType::Int64, true).is_ok());
+}
+
+#[test]
+fn parse_strict_nested() {
+    let array = Utf8Array::<i32>::from_slice([
+        r#"{"a": 1, "b": ["x", null]}"#,
+        r#"{"b": null}"#,
+        r#"{"a": "x"}"#,
+    ]);
+    assert!(parse_json(&array, struct_type(), false).is_ok());
+    let error = parse_json(&array, struct_type(), true).unwrap_err();
+    assert!(error.to_string().contains("Row 2"));
+
+    let array = Utf8Array::<i32>::from_slice([r#"{"a": 1}"#, r#"{"b": ["x", {}]}"#]);
+    let error = parse_json(&array, struct_type(), true).unwrap_err();
+    assert!(error.to_string().contains("Row 1"));
+
+    let array = Utf8Array::<i32>::from_slice([r#"{"a": 1, "b": ["x", null]}"#, r#"{"b": null}"#]);
+    assert!(parse_json(&array, struct_type(), true).is_ok());
+}
+
+#[test]
+fn parse_unsupported() {
+    let array = Utf8Array::<i32>::from_slice(["1"]);
+    assert!(parse_json(&array, DataType::Float16, false).is_err());
+    assert!(parse_json(&array, DataType::Decimal(5, 2), false).is_err());
+    assert!(!can_parse_json(&DataType::Float16));
+    assert!(can_parse_json(&struct_type()));
+}
+
+#[test]
+fn infer() {
+    let array = Utf8Array::<i32>::from([
+        Some(r#"{"a": 1}"#),
+        Some(r#"{"b": ["x"]}"#),
+        Some("null"),
+        Some("not json"),
+        None,
+    ]);
+    assert_eq!(infer_json_type(&array).unwrap(), struct_type());
+
+    let array = Utf8Array::<i32>::from([Some("1"), Some("1.5")]);
+    assert_eq!(infer_json_type(&array).unwrap(), DataType::Float64);
+
+    let array = Utf8Array::<i32>::from([Some("null"), None]);
+    assert_eq!(infer_json_type(&array).unwrap(), DataType::Null);
+}
+
+#[test]
+fn to_json_round_trip() {
+    let array = Utf8Array::<i32>::from([
+        Some(r#"{"a":1,"b":["x","y"]}"#),
+        Some(r#"{"a":null,"b":null}"#),
+        None,
+    ]);
+    let parsed = parse_json(&array, struct_type(), true).unwrap();
+    let result = to_json(parsed.as_ref()).unwrap();
+    assert_eq!(array, result);
+}
+
+#[test]
+fn to_json_primitive() {
+    let array = Utf8Array::<i32>::from([Some("a\"b"), None]);
+    assert_eq!(
+        Utf8Array::<i32>::from([Some(r#""a\"b""#), None]),
+        to_json(&array).unwrap()
+    );
+
+    let array = BooleanArray::from(&[Some(true), None]);
+    assert_eq!(
+        Utf8Array::<i32>::from([Some("true"), None]),
+        to_json(&array).unwrap()
+    );
+
+    let array = Int128Array::from_slice([1]).to(DataType::Decimal(5, 2));
+    assert!(to_json(&array).is_err());
+}
@@ -0,0 +1,236 @@
+use std::sync::Arc;
+
+use re_arrow2::array::*;
+use re_arrow2::compute::list::*;
+use re_arrow2::compute::sort::SortOptions;
+use re_arrow2::datatypes::{DataType, Field};
+use re_arrow2::error::Error;
+
+fn list(data: Vec<Option<Vec<Option<i32>>>>) -> ListArray<i32> {
+    let mut array = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+    array.try_extend(data).unwrap();
+    array.into()
+}
+
+fn large_list(data: Vec<Option<Vec<Option<i32>>>>) -> ListArray<i64> {
+    let mut array = MutableListArray::<i64, MutablePrimitiveArray<i32>>::new();
+    array.try_extend(data).unwrap();
+    array.into()
+}
+
+fn fixed_size_list() -> FixedSizeListArray {
+    let values = Int32Array::from(&[Some(1), Some(2), None, Some(4), Some(5), Some(6)]).boxed();
+    FixedSizeListArray::new(
+        DataType::FixedSizeList(Arc::new(Field::new("item", DataType::Int32, true)), 2),
+        values,
+        Some([true, true, false].into()),
+    )
+}
+
+#[test]
+fn flatten_sliced() {
+    let array = list(vec![
+        Some(vec![Some(0)]),
+        Some(vec![Some(1), Some(2)]),
+        None,
+        Some(vec![]),
+        Some(vec![Some(3), None]),
+    ]);
+    let array = array.sliced(1, 4);
+
+    let result = flatten(&array).unwrap();
+    let expected = Int32Array::from(&[Some(1), Some(2), Some(3), None]);
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+fn flatten_fixed_size() {
+    let result = flatten(&fixed_size_list()).unwrap();
+    let expected = Int32Array::from(&[Some(1), Some(2), None, Some(4)]);
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+fn flatten_not_a_list() {
+    assert!(flatten(&Int32Array::from_slice([1])).is_err());
+}
+
+#[test]
+fn explode_large() {
+    let array = large_list(vec![
+        Some(vec![Some(1), Some(2)]),
+        Some(vec![]),
+        None,
+        Some(vec![Some(3)]),
+    ]);
+    let (indices, values) = explode(&array).unwrap();
+
+    assert_eq!(indices, UInt64Array::from_slice([0, 0, 3]));
+    assert_eq!(Int32Array::from_slice([1, 2, 3]), values.as_ref());
+}
+
+#[test]
+fn value_length() {
+    let array = list(vec![Some(vec![Some(1), Some(2)]), None, Some(vec![])]);
+    let result = list_value_length(&array).unwrap();
+    assert_eq!(Int32Array::from(&[Some(2), None, Some(0)]), result.as_ref());
+
+    let array = large_list(vec![Some(vec![Some(1)])]);
+    let result = list_value_length(&array).unwrap();
+    assert_eq!(Int64Array::from_slice([1]), result.as_ref());
+
+    let result = list_value_length(&fixed_size_list()).unwrap();
+    assert_eq!(Int32Array::from(&[Some(2), Some(2), None]), result.as_ref());
+}
+
+#[test]
+fn get() {
+    let array = list(vec![
+        Some(vec![Some(9)]),
+        Some(vec![Some(1), Some(2), Some(3)]),
+        None,
+        Some(vec![Some(4)]),
+        Some(vec![]),
+    ]);
+    let array = array.sliced(1, 4);
+
+    let result = list_get(&array, 1).unwrap();
+    assert_eq!(
+        Int32Array::from(&[Some(2), None, None, None]),
+        result.as_ref()
+    );
+
+    let result = list_get(&array, -1).unwrap();
+    assert_eq!(
+        Int32Array::from(&[Some(3), None, Some(4), None]),
+        result.as_ref()
+    );
+
+    let result = list_get(&fixed_size_list(), 0).unwrap();
+    assert_eq!(Int32Array::from(&[Some(1), None, None]), result.as_ref());
+}
+
+#[test]
+fn slice() {
+    let array = list(vec![
+        Some(vec![Some(1), Some(2), Some(3)]),
+        None,
+        Some(vec![Some(4)]),
+    ]);
+
+    let result = list_slice(&array, 1, None).unwrap();
+    let expected = list(vec![Some(vec![Some(2), Some(3)]), None, Some(vec![])]);
+    assert_eq!(expected, result.as_ref());
+
+    let result = list_slice(&array, -2, Some(1)).unwrap();
+    let expected = list(vec![Some(vec![Some(2)]), None, Some(vec![Some(4)])]);
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+fn slice_fixed_size() {
+    let result = list_slice(&fixed_size_list(), 1, Some(5)).unwrap();
+    let expected = list(vec![Some(vec![Some(2)]), Some(vec![Some(4)]), None]);
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+fn sum_min_max() {
+    let array = list(vec![
+        Some(vec![Some(1), None, Some(3)]),
+        None,
+        Some(vec![]),
+        Some(vec![None]),
+        Some(vec![Some(-2)]),
+    ]);
+
+    let result = list_sum(&array).unwrap();
+    let expected = Int32Array::from(&[Some(4), None, None, None, Some(-2)]);
+    assert_eq!(expected, result.as_ref());
+
+    let result = list_min(&array).unwrap();
+    let expected = Int32Array::from(&[Some(1), None, None, None, Some(-2)]);
+    assert_eq!(expected, result.as_ref());
+
+    let result = list_max(&array).unwrap();
+    let expected = Int32Array::from(&[Some(3), None, None, None, Some(-2)]);
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+fn sum_unsupported() {
+    let mut array = MutableListArray::<i32, MutableUtf8Array<i32>>::new();
+    array.try_extend(vec![Some(vec![Some("a")])]).unwrap();
+    let array: ListArray<i32> = array.into();
+    assert!(list_sum(&array).is_err());
+}
+
+#[test]
+fn sum_overflow() {
+    let mut array = MutableListArray::<i32, MutablePrimitiveArray<i8>>::new();
+    array
+        .try_extend(vec![Some(vec![Some(100i8), Some(100)])])
+        .unwrap();
+    let array: ListArray<i32> = array.into();
+    assert!(matches!(list_sum(&array), Err(Error::Overflow)));
+
+    let result = list_max(&array).unwrap();
+    assert_eq!(Int8Array::from_slice([100]), result.as_ref());
+}
+
+#[test]
+fn min_max_nan() {
+    let mut array = MutableListArray::<i32, MutablePrimitiveArray<f64>>::new();
+    array
+        .try_extend(vec![
+            Some(vec![Some(f64::NAN), Some(1.0), Some(2.0)]),
+            Some(vec![Some(1.0), Some(f64::NAN), Some(2.0)]),
+            Some(vec![Some(1.0), Some(2.0), Some(f64::NAN)]),
+        ])
+        .unwrap();
+    let array: ListArray<i32> = array.into();
+
+    let result = list_min(&array).unwrap();
+    assert_eq!(Float64Array::from_slice([1.0, 1.0, 1.0]), result.as_ref());
+    let result = list_max(&array).unwrap();
+    assert_eq!(Float64Array::from_slice([2.0, 2.0, 2.0]), result.as_ref());
+
+    let mut array = MutableListArray::<i32, MutablePrimitiveArray<f32>>::new();
+    array.try_extend(vec![Some(vec![Some(f32::NAN)])]).unwrap();
+    let array: ListArray<i32> = array.into();
+    let result = list_min(&array).unwrap();
+    let result = result.as_any().downcast_ref::<Float32Array>().unwrap();
+    assert!(result.value(0).is_nan());
+}
+
+#[test]
+fn sort() {
+    let array = list(vec![
+        Some(vec![Some(0)]),
+        Some(vec![Some(3), None, Some(1)]),
+        None,
+        Some(vec![Some(2), Some(1)]),
+    ]);
+    let array = array.sliced(1, 3);
+
+    let result = list_sort(&array, &SortOptions::default()).unwrap();
+    let expected = list(vec![
+        Some(vec![None, Some(1), Some(3)]),
+        None,
+        Some(vec![Some(1), Some(2)]),
+    ]);
+    assert_eq!(expected, result.as_ref());
+
+    let options = SortOptions {
+        descending: true,
+        nulls_first: false,
+    };
+    let result = list_sort(&fixed_size_list(), &options).unwrap();
+    let values = Int32Array::from(&[Some(2), Some(1), Some(4), None, Some(5), Some(6)]).boxed();
+    let expected = FixedSizeListArray::new(
+        DataType::FixedSizeList(Arc::new(Field::new("item", DataType::Int32, true)), 2),
+        values,
+        Some([true, true, false].into()),
+    );
+    assert_eq!(expected, result.as_ref());
+}
@@ -0,0 +1,324 @@
+use re_arrow2::array::*;
+use re_arrow2::compute::math::*;
+use re_arrow2::datatypes::DataType;
+use re_arrow2::scalar::PrimitiveScalar;
+use re_arrow2::types::{f16, i256};
+
+#[test]
+fn test_abs() {
+    let array = Int8Array::from(&[Some(-1), Some(0), Some(i8::MAX), None]);
+    let result = abs(&array).unwrap();
+    assert_eq!(
+        Int8Array::from(&[Some(1), Some(0), Some(i8::MAX), None]),
+        result.as_ref()
+    );
+
+    // overflows, except in null slots
+    assert!(abs(&Int8Array::from_slice([i8::MIN])).is_err());
+    let array = Int8Array::from_slice([i8::MIN, 1]).with_validity(Some([false, true].into()));
+    assert!(abs(&array).is_ok());
+
+    let array = Float32Array::from(&[Some(-1.5), Some(-0.0), None]);
+    let result = abs(&array).unwrap();
+    assert_eq!(
+        Float32Array::from(&[Some(1.5), Some(0.0), None]),
+        result.as_ref()
+    );
+
+    let array = Float16Array::from_slice([f16::from_f32(-2.0)]);
+    let result = abs(&array).unwrap();
+    assert_eq!(
+        Float16Array::from_slice([f16::from_f32(2.0)]),
+        result.as_ref()
+    );
+
+    let array = Int128Array::from(&[Some(-1_50), None]).to(DataType::Decimal(5, 2));
+    let result = abs(&array).unwrap();
+    assert_eq!(
+        Int128Array::from(&[Some(1_50), None]).to(DataType::Decimal(5, 2)),
+        result.as_ref()
+    );
+
+    let array = Int256Array::from_slice([i256::from_words(-1, -1)]).to(DataType::Decimal256(5, 2));
+    let result = abs(&array).unwrap();
+    assert_eq!(
+        Int256Array::from_slice([i256::from_words(0, 1)]).to(DataType::Decimal256(5, 2)),
+        result.as_ref()
+    );
+}
+
+#[test]
+fn test_sign() {
+    let array = Int32Array::from(&[Some(-5), Some(0), Some(7), None]);
+    let result = sign(&array).unwrap();
+    assert_eq!(
+        Int32Array::from(&[Some(-1), Some(0), Some(1), None]),
+        result.as_ref()
+    );
+
+    let array = UInt16Array::from_slice([0, 9]);
+    let result = sign(&array).unwrap();
+    assert_eq!(UInt16Array::from_slice([0, 1]), result.as_ref());
+
+    let array = Float64Array::from_slice([-2.5, 0.0, 3.0, f64::INFINITY]);
+    let result = sign(&array).unwrap();
+    assert_eq!(
+        Float64Array::from_slice([-1.0, 0.0, 1.0, 1.0]),
+        result.as_ref()
+    );
+    let result = sign(&Float64Array::from_slice([f64::NAN])).unwrap();
+    assert!(is_nan(result.as_ref()).unwrap().value(0));
+
+    let array =
+        Int128Array::from(&[Some(-1_50), Some(0), Some(2), None]).to(DataType::Decimal(5, 2));
+    let result = sign(&array).unwrap();
+    assert_eq!(
+        Int8Array::from(&[Some(-1), Some(0), Some(1), None]),
+        result.as_ref()
+    );
+}
+
+#[test]
+fn test_float_functions() {
+    let array = Float64Array::from(&[Some(4.0), Some(-1.0), None]);
+    let result = sqrt(&array).unwrap();
+    let result = result.as_any().downcast_ref::<Float64Array>().unwrap();
+    assert_eq!(result.value(0), 2.0);
+    assert!(result.value(1).is_nan());
+    assert!(result.is_null(2));
+
+    // integers and decimals are computed as `Float64`
+    let array = Int32Array::from(&[Some(1), Some(100), None]);
+    let result = log10(&array).unwrap();
+    assert_eq!(
+        Float64Array::from(&[Some(0.0), Some(2.0), None]),
+        result.as_ref()
+    );
+
+    let array = Int128Array::from_slice([8_000]).to(DataType::Decimal(5, 3));
+    let result = cbrt(&array).unwrap();
+    assert_eq!(Float64Array::from_slice([2.0]), result.as_ref());
+
+    let array = UInt8Array::from_slice([0]);
+    assert_eq!(
+        Float64Array::from_slice([f64::NEG_INFINITY]),
+        ln(&array).unwrap().as_ref()
+    );
+    assert_eq!(
+        Float64Array::from_slice([1.0]),
+        exp(&array).unwrap().as_ref()
+    );
+    assert_eq!(
+        Float64Array::from_slice([1.0]),
+        cos(&array).unwrap().as_ref()
+    );
+
+    // floats keep their type
+    let array = Float32Array::from_slice([8.0]);
+    assert_eq!(
+        Float32Array::from_slice([3.0]),
+        log2(&array).unwrap().as_ref()
+    );
+    let array = Float16Array::from_slice([f16::from_f32(4.0)]);
+    assert_eq!(
+        Float16Array::from_slice([f16::from_f32(2.0)]),
+        sqrt(&array).unwrap().as_ref()
+    );
+
+    assert!(sqrt(&Utf8Array::<i32>::from_slice(["a"])).is_err());
+    assert!(sin(&Int64Array::from_slice([1]).to(DataType::Date64)).is_err());
+}
+
+#[test]
+fn test_atan2() {
+    let y = Int32Array::from(&[Some(1), Some(-1), None]);
+    let x = Int32Array::from(&[Some(1), Some(0), Some(1)]);
+    let result = atan2(&y, &x).unwrap();
+    assert_eq!(
+        Float64Array::from(&[
+            Some(std::f64::consts::FRAC_PI_4),
+            Some(-std::f64::consts::FRAC_PI_2),
+            None
+        ]),
+        result.as_ref()
+    );
+
+    assert!(atan2(&y, &Int64Array::from_slice([1, 1, 1])).is_err());
+    assert!(atan2(&y, &Int32Array::from_slice([1])).is_err());
+}
+
+#[test]
+fn test_round() {
+    let array = Float64Array::from(&[Some(2.5), Some(-2.5), Some(1.234), None]);
+    let result = round(&array, 0).unwrap();
+    assert_eq!(
+        Float64Array::from(&[Some(3.0), Some(-3.0), Some(1.0), None]),
+        result.as_ref()
+    );
+    let result = round(&array, 2).unwrap();
+    assert_eq!(
+        Float64Array::from(&[Some(2.5), Some(-2.5), Some(1.23), None]),
+        result.as_ref()
+    );
+
+    let array = Float64Array::from_slice([1234.5, f64::INFINITY]);
+    let result = round(&array, -2).unwrap();
+    assert_eq!(
+        Float64Array::from_slice([1200.0, f64::INFINITY]),
+        result.as_ref()
+    );
+    let result = round(&array, -400).unwrap();
+    assert_eq!(
+        Float64Array::from_slice([0.0, f64::INFINITY]),
+        result.as_ref()
+    );
+    let result = round(&array, 400).unwrap();
+    assert_eq!(array, result.as_ref());
+
+    let array = Int16Array::from(&[Some(15), Some(-25), Some(14), None]);
+    let result = round(&array, -1).unwrap();
+    assert_eq!(
+        Int16Array::from(&[Some(20), Some(-30), Some(10), None]),
+        result.as_ref()
+    );
+    assert_eq!(array, round(&array, 2).unwrap().as_ref());
+    assert!(round(&Int8Array::from_slice([125]), -1).is_err());
+    assert_eq!(
+        UInt64Array::from_slice([0]),
+        round(&UInt64Array::from_slice([u64::MAX]), -40)
+            .unwrap()
+            .as_ref()
+    );
+
+    let array = Int128Array::from_slice([1_25]).to(DataType::Decimal(5, 2));
+    let result = round(&array, 1).unwrap();
+    assert_eq!(
+        Int128Array::from_slice([1_30]).to(DataType::Decimal(5, 2)),
+        result.as_ref()
+    );
+    assert!(round(&array, -1).is_err());
+}
+
+#[test]
+fn test_floor_ceil_trunc() {
+    let array = Float32Array::from(&[Some(1.5), Some(-1.5), None]);
+    assert_eq!(
+        Float32Array::from(&[Some(1.0), Some(-2.0), None]),
+        floor(&array).unwrap().as_ref()
+    );
+    assert_eq!(
+        Float32Array::from(&[Some(2.0), Some(-1.0), None]),
+        ceil(&array).unwrap().as_ref()
+    );
+    assert_eq!(
+        Float32Array::from(&[Some(1.0), Some(-1.0), None]),
+        trunc(&array).unwrap().as_ref()
+    );
+
+    let array = Int32Array::from_slice([1, -1]);
+    assert_eq!(array, floor(&array).unwrap().as_ref());
+
+    let array = Int128Array::from_slice([1_50, -1_50]).to(DataType::Decimal(5, 2));
+    assert_eq!(
+        Int128Array::from_slice([1_00, -2_00]).to(DataType::Decimal(5, 2)),
+        floor(&array).unwrap().as_ref()
+    );
+    assert_eq!(
+        Int128Array::from_slice([2_00, -1_00]).to(DataType::Decimal(5, 2)),
+        ceil(&array).unwrap().as_ref()
+    );
+    assert_eq!(
+        Int128Array::from_slice([1_00, -1_00]).to(DataType::Decimal(5, 2)),
+        trunc(&array).unwrap().as_ref()
+    );
+}
+
+#[test]
+fn test_clip() {
+    let array = Float64Array::from(&[Some(-1.0), Some(0.5), Some(2.0), Some(f64::NAN), None]);
+    let result = clip_primitive(&array, Some(0.0), Some(1.0));
+    assert_eq!(result.value(0), 0.0);
+    assert_eq!(result.value(1), 0.5);
+    assert_eq!(result.value(2), 1.0);
+    assert!(result.value(3).is_nan());
+    assert!(result.is_null(4));
+
+    let array = Int32Array::from(&[Some(-5), Some(3), Some(10), None]);
+    let result = clip(
+        &array,
+        &PrimitiveScalar::<i32>::from(Some(0)),
+        &PrimitiveScalar::<i32>::from(None),
+    )
+    .unwrap();
+    assert_eq!(
+        Int32Array::from(&[Some(0), Some(3), Some(10), None]),
+        result.as_ref()
+    );
+
+    let array = Int128Array::from_slice([1_00, 5_00]).to(DataType::Decimal(5, 2));
+    let bound = |x| PrimitiveScalar::<i128>::from(Some(x)).to(DataType::Decimal(5, 2));
+    let result = clip(&array, &bound(2_00), &bound(3_00)).unwrap();
+    assert_eq!(
+        Int128Array::from_slice([2_00, 3_00]).to(DataType::Decimal(5, 2)),
+        result.as_ref()
+    );
+
+    // wrong bound type and inverted bounds
+    let min = PrimitiveScalar::<i64>::from(Some(0));
+    let max = PrimitiveScalar::<i64>::from(Some(1));
+    assert!(clip(&Int32Array::from_slice([1]), &min, &max).is_err());
+    assert!(clip(&Int64Array::from_slice([1]), &max, &min).is_err());
+}
+
+#[test]
+fn test_float_predicates() {
+    let array = Float64Array::from(&[
+        Some(1.0),
+        Some(f64::NAN),
+        Some(f64::INFINITY),
+        Some(f64::NEG_INFINITY),
+        None,
+    ]);
+    assert_eq!(
+        is_nan(&array).unwrap(),
+        BooleanArray::from(&[Some(false), Some(true), Some(false), Some(false), None])
+    );
+    assert_eq!(
+        is_finite(&array).unwrap(),
+        BooleanArray::from(&[Some(true), Some(false), Some(false), Some(false), None])
+    );
+    assert_eq!(
+        is_infinite(&array).unwrap(),
+        BooleanArray::from(&[Some(false), Some(false), Some(true), Some(true), None])
+    );
+
+    let array = Float16Array::from_slice([f16::NAN, f16::ONE]);
+    assert_eq!(
+        is_nan(&array).unwrap(),
+        BooleanArray::from_slice([true, false])
+    );
+
+    let array = Int32Array::from(&[Some(1), None]);
+    assert_eq!(
+        is_finite(&array).unwrap(),
+        BooleanArray::from(&[Some(true), None])
+    );
+    assert_eq!(
+        is_nan(&array).unwrap(),
+        BooleanArray::from(&[Some(false), None])
+    );
+
+    assert!(is_nan(&BooleanArray::from_slice([true])).is_err());
+}
+
+#[test]
+fn test_can_math() {
+    assert!(can_math(&DataType::Int8));
+    assert!(can_math(&DataType::Float16));
+    assert!(can_math(&DataType::Decimal256(76, 2)));
+    assert!(!can_math(&DataType::Utf8));
+    assert!(!can_math(&DataType::Date32));
+
+    assert!(can_atan2(&DataType::Float32, &DataType::Float32));
+    assert!(!can_atan2(&DataType::Float32, &DataType::Float64));
+}
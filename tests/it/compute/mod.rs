@@ -16,22 +16,40 @@ mod comparison;
 mod concatenate;
 #[cfg(feature = "compute_contains")]
 mod contains;
+#[cfg(feature = "compute_cumulative")]
+mod cumulative;
+#[cfg(feature = "compute_dictionary")]
+mod dictionary;
+#[cfg(feature = "compute_fill_null")]
+mod fill_null;
 #[cfg(feature = "compute_filter")]
 mod filter;
 #[cfg(feature = "compute_hash")]
 mod hash;
 #[cfg(feature = "compute_if_then_else")]
 mod if_then_else;
+#[cfg(feature = "compute_interleave")]
+mod interleave;
+#[cfg(feature = "compute_json")]
+mod json;
 #[cfg(feature = "compute_length")]
 mod length;
 #[cfg(feature = "compute_like")]
 mod like;
 #[cfg(feature = "compute_limit")]
 mod limit;
+#[cfg(feature = "compute_list")]
+mod list;
+#[cfg(feature = "compute_math")]
+mod math;
 #[cfg(feature = "compute_merge_sort")]
 mod merge_sort;
+#[cfg(feature = "compute_nested")]
+mod nested;
 #[cfg(feature = "compute_partition")]
 mod partition;
+#[cfg(feature = "compute_rank")]
+mod rank;
 #[cfg(feature = "compute_regex_match")]
 mod regex_match;
 #[cfg(feature = "compute_sort")]
@@ -42,6 +60,8 @@ mod substring;
 mod take;
 #[cfg(feature = "compute_temporal")]
 mod temporal;
+#[cfg(feature = "compute_unique")]
+mod unique;
 #[cfg(feature = "compute_utf8")]
 mod utf8;
 #[cfg(feature = "compute_window")]
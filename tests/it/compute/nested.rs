@@ -0,0 +1,192 @@
+use std::sync::Arc;
+
+use re_arrow2::array::*;
+use re_arrow2::compute::nested::*;
+use re_arrow2::datatypes::{DataType, Field, UnionMode};
+use re_arrow2::scalar::{PrimitiveScalar, Utf8Scalar};
+
+fn struct_array() -> StructArray {
+    let data_type = DataType::Struct(Arc::new(vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ]));
+    StructArray::new(
+        data_type,
+        vec![
+            Int32Array::from(&[Some(1), None, Some(3)]).boxed(),
+            Utf8Array::<i32>::from([Some("x"), Some("y"), Some("z")]).boxed(),
+        ],
+        Some([true, true, false].into()),
+    )
+}
+
+#[test]
+fn struct_field_and_flatten() {
+    let array = struct_array();
+
+    let result = struct_field(&array, "b").unwrap();
+    assert_eq!(
+        Utf8Array::<i32>::from([Some("x"), Some("y"), None]),
+        result.as_ref()
+    );
+    assert!(struct_field(&array, "c").is_err());
+    assert!(struct_field(&Int32Array::from_slice([1]), "a").is_err());
+
+    let result = struct_flatten(&array.sliced(1, 2)).unwrap();
+    assert_eq!(Int32Array::from(&[None, None]), result[0].as_ref());
+    assert_eq!(
+        Utf8Array::<i32>::from([Some("y"), None]),
+        result[1].as_ref()
+    );
+}
+
+#[test]
+fn struct_from_columns_() {
+    let columns = vec![
+        Int32Array::from(&[Some(1), None, Some(3)]).boxed(),
+        Utf8Array::<i32>::from([Some("x"), Some("y"), Some("z")]).boxed(),
+    ];
+    let result = struct_from_columns(
+        &["a", "b"],
+        columns.clone(),
+        Some([true, true, false].into()),
+    )
+    .unwrap();
+    assert_eq!(struct_array(), result);
+
+    assert!(struct_from_columns(&["a"], columns.clone(), None).is_err());
+    let columns = vec![columns[0].clone(), columns[1].sliced(0, 2)];
+    assert!(struct_from_columns(&["a", "b"], columns, None).is_err());
+}
+
+fn map_array() -> MapArray {
+    let entries = DataType::Struct(Arc::new(vec![
+        Field::new("keys", DataType::Utf8, false),
+        Field::new("values", DataType::Int32, true),
+    ]));
+    let data_type = DataType::Map(
+        Arc::new(Field::new("entries", entries.clone(), false)),
+        false,
+    );
+    let field = StructArray::new(
+        entries,
+        vec![
+            Utf8Array::<i32>::from_slice(["a", "b", "a", "c", "b"]).boxed(),
+            Int32Array::from(&[Some(1), Some(2), Some(3), Some(4), None]).boxed(),
+        ],
+        None,
+    );
+    MapArray::new(
+        data_type,
+        vec![0, 2, 3, 3, 5].try_into().unwrap(),
+        field.boxed(),
+        Some([true, true, true, false].into()),
+    )
+}
+
+#[test]
+fn map_keys_and_values() {
+    let array = map_array();
+
+    let mut expected = MutableListArray::<i32, MutableUtf8Array<i32>>::new_from(
+        MutableUtf8Array::new(),
+        DataType::List(Arc::new(Field::new("keys", DataType::Utf8, false))),
+        0,
+    );
+    expected
+        .try_extend([
+            Some(vec![Some("a"), Some("b")]),
+            Some(vec![Some("a")]),
+            Some(vec![]),
+            None,
+        ])
+        .unwrap();
+    let expected: ListArray<i32> = expected.into();
+    assert_eq!(expected, map_keys(&array).unwrap().as_ref());
+
+    let result = map_values(&array.sliced(1, 2)).unwrap();
+    let result = result.as_any().downcast_ref::<ListArray<i32>>().unwrap();
+    assert_eq!(result.len(), 2);
+    assert_eq!(Int32Array::from(&[Some(3)]), result.value(0).as_ref());
+    assert_eq!(result.value(1).len(), 0);
+
+    assert!(map_keys(&Int32Array::from_slice([1])).is_err());
+}
+
+#[test]
+fn map_get_() {
+    let array = map_array();
+
+    let result = map_get(&array, &Utf8Scalar::<i32>::new(Some("a"))).unwrap();
+    assert_eq!(
+        Int32Array::from(&[Some(1), Some(3), None, None]),
+        result.as_ref()
+    );
+
+    assert!(map_get(
+        &array,
+        &PrimitiveScalar::<i32>::new(DataType::Int32, Some(1))
+    )
+    .is_err());
+
+    let result = map_get(&array.sliced(1, 3), &Utf8Scalar::<i32>::new(Some("b"))).unwrap();
+    assert_eq!(Int32Array::from(&[None, None, None]), result.as_ref());
+}
+
+fn union_array(mode: UnionMode) -> UnionArray {
+    let fields = vec![
+        Field::new("a", DataType::Int32, false),
+        Field::new("b", DataType::Utf8, false),
+    ];
+    let data_type = DataType::Union(Arc::new(fields), Some(Arc::new(vec![5, 7])), mode);
+    let types = vec![5, 7, 5, 7].into();
+    if mode.is_sparse() {
+        let fields = vec![
+            Int32Array::from_slice([1, 0, 3, 0]).boxed(),
+            Utf8Array::<i32>::from_slice(["", "x", "", "y"]).boxed(),
+        ];
+        UnionArray::new(data_type, types, fields, None)
+    } else {
+        let fields = vec![
+            Int32Array::from_slice([1, 3]).boxed(),
+            Utf8Array::<i32>::from_slice(["x", "y"]).boxed(),
+        ];
+        UnionArray::new(data_type, types, fields, Some(vec![0, 0, 1, 1].into()))
+    }
+}
+
+#[test]
+fn union_variant_() {
+    for mode in [UnionMode::Sparse, UnionMode::Dense] {
+        let array = union_array(mode);
+
+        let result = union_variant(&array, 5).unwrap();
+        assert_eq!(
+            Int32Array::from(&[Some(1), None, Some(3), None]),
+            result.as_ref()
+        );
+
+        assert!(union_variant(&array, 0).is_err());
+
+        let result = union_variant(&array.sliced(1, 2), 7).unwrap();
+        assert_eq!(Utf8Array::<i32>::from([Some("x"), None]), result.as_ref());
+    }
+}
+
+#[test]
+fn union_to_struct_() {
+    for mode in [UnionMode::Sparse, UnionMode::Dense] {
+        let result = union_to_struct(&union_array(mode)).unwrap();
+
+        let expected = struct_from_columns(
+            &["a", "b"],
+            vec![
+                Int32Array::from(&[Some(1), None, Some(3), None]).boxed(),
+                Utf8Array::<i32>::from([None, Some("x"), None, Some("y")]).boxed(),
+            ],
+            None,
+        )
+        .unwrap();
+        assert_eq!(expected, result);
+    }
+}
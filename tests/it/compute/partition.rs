@@ -1,4 +1,6 @@
 use re_arrow2::array::*;
+use re_arrow2::chunk::Chunk;
+use re_arrow2::compute::hash::{hash_columns, HashAlgorithm};
 use re_arrow2::compute::partition::*;
 use re_arrow2::compute::sort::{SortColumn, SortOptions};
 use re_arrow2::datatypes::DataType;
@@ -189,3 +191,76 @@ fn lexicographical_partition_non_unique_column_1() -> Result<()> {
     }
     Ok(())
 }
+
+#[test]
+fn hash_partition_by_key() -> Result<()> {
+    let keys = Utf8Array::<i32>::from_slice(["a", "b", "a", "c", "b", "a"]);
+    let values = Int32Array::from_slice([0, 1, 2, 3, 4, 5]);
+    let chunk = Chunk::new(vec![keys.boxed(), values.boxed()]);
+
+    let partitions = hash_partition(&chunk, &[0], 3)?;
+    assert_eq!(partitions.len(), 3);
+    assert_eq!(partitions.iter().map(|x| x.len()).sum::<usize>(), 6);
+
+    for partition in &partitions {
+        let keys = partition.arrays()[0]
+            .as_any()
+            .downcast_ref::<Utf8Array<i32>>()
+            .unwrap();
+        let values = partition.arrays()[1]
+            .as_any()
+            .downcast_ref::<Int32Array>()
+            .unwrap();
+        // rows keep their order and their columns stay aligned
+        assert!(values.values().windows(2).all(|x| x[0] < x[1]));
+        for (key, value) in keys.values_iter().zip(values.values_iter()) {
+            let expected = ["a", "b", "a", "c", "b", "a"][*value as usize];
+            assert_eq!(key, expected);
+        }
+    }
+
+    // the same key lands in the same partition
+    let a = partitions
+        .iter()
+        .position(|partition| {
+            partition.arrays()[1]
+                .as_any()
+                .downcast_ref::<Int32Array>()
+                .unwrap()
+                .values()
+                .contains(&0)
+        })
+        .unwrap();
+    let a_values = partitions[a].arrays()[1]
+        .as_any()
+        .downcast_ref::<Int32Array>()
+        .unwrap();
+    assert!([0, 2, 5].iter().all(|x| a_values.values().contains(x)));
+    Ok(())
+}
+
+#[test]
+fn hash_partition_deterministic() -> Result<()> {
+    let keys = Int64Array::from_slice([10, 20, 30, 40]);
+    let chunk = Chunk::new(vec![keys.boxed()]);
+
+    let partitions = hash_partition(&chunk, &[0], 2)?;
+    let expected = hash_columns(
+        &[chunk.arrays()[0].as_ref()],
+        HashAlgorithm::XxHash64,
+        HASH_PARTITION_SEED,
+    )?;
+    for (row, hash) in expected.values_iter().enumerate() {
+        let partition = &partitions[(hash % 2) as usize].arrays()[0];
+        let partition = partition.as_any().downcast_ref::<Int64Array>().unwrap();
+        assert!(partition.values().contains(&((row as i64 + 1) * 10)));
+    }
+    Ok(())
+}
+
+#[test]
+fn hash_partition_errors() {
+    let chunk = Chunk::new(vec![Int32Array::from_slice([1, 2]).boxed()]);
+    assert!(hash_partition(&chunk, &[0], 0).is_err());
+    assert!(hash_partition(&chunk, &[1], 2).is_err());
+}
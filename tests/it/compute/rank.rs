@@ -0,0 +1,93 @@
+use re_arrow2::array::*;
+use re_arrow2::compute::rank::*;
+use re_arrow2::compute::sort::{SortColumn, SortOptions};
+
+fn order_by(values: &dyn Array, descending: bool) -> [SortColumn<'_>; 1] {
+    [SortColumn {
+        values,
+        options: Some(SortOptions {
+            descending,
+            nulls_first: true,
+        }),
+    }]
+}
+
+#[test]
+fn ranks_per_partition() {
+    let partition = Utf8Array::<i32>::from_slice(["a", "a", "b", "a", "b", "a"]);
+    let order = Int32Array::from(&[Some(10), Some(20), Some(5), Some(10), Some(5), None]);
+    let order_by = order_by(&order, false);
+
+    // partition "a": rows 5 (null), 0 and 3 (10), 1 (20); partition "b": rows 2 and 4 (5)
+    let result = rank(&[&partition], &order_by).unwrap();
+    assert_eq!(result, UInt64Array::from_slice([2, 4, 1, 2, 1, 1]));
+
+    let result = dense_rank(&[&partition], &order_by).unwrap();
+    assert_eq!(result, UInt64Array::from_slice([2, 3, 1, 2, 1, 1]));
+
+    let result = percent_rank(&[&partition], &order_by).unwrap();
+    assert_eq!(
+        result,
+        Float64Array::from_slice([1.0 / 3.0, 1.0, 0.0, 1.0 / 3.0, 0.0, 0.0])
+    );
+
+    let result = row_number(&[&partition], &order_by).unwrap();
+    assert_eq!(result.value(1), 4);
+    assert_eq!(result.value(5), 1);
+    let mut peers = [result.value(0), result.value(3)];
+    peers.sort_unstable();
+    assert_eq!(peers, [2, 3]);
+    let mut peers = [result.value(2), result.value(4)];
+    peers.sort_unstable();
+    assert_eq!(peers, [1, 2]);
+}
+
+#[test]
+fn descending() {
+    let order = Float64Array::from_slice([1.0, 3.0, 2.0]);
+    let result = row_number(&[], &order_by(&order, true)).unwrap();
+    assert_eq!(result, UInt64Array::from_slice([3, 1, 2]));
+}
+
+#[test]
+fn without_order() {
+    // all rows of a partition are peers
+    let partition = Int32Array::from(&[Some(1), None, Some(1), None]);
+    let result = rank(&[&partition], &[]).unwrap();
+    assert_eq!(result, UInt64Array::from_slice([1, 1, 1, 1]));
+
+    let result = percent_rank(&[&partition], &[]).unwrap();
+    assert_eq!(result, Float64Array::from_slice([0.0, 0.0, 0.0, 0.0]));
+}
+
+#[test]
+fn ntile_buckets() {
+    let partition = BooleanArray::from_slice([true, true, true, true, true, false, false]);
+    let order = Int32Array::from_slice([1, 2, 3, 4, 5, 1, 2]);
+    let order_by = order_by(&order, false);
+
+    let result = ntile(&[&partition], &order_by, 3).unwrap();
+    assert_eq!(result, UInt64Array::from_slice([1, 1, 2, 2, 3, 1, 2]));
+
+    // more buckets than rows
+    let result = ntile(&[&partition], &order_by, 10).unwrap();
+    assert_eq!(result, UInt64Array::from_slice([1, 2, 3, 4, 5, 1, 2]));
+
+    assert!(ntile(&[&partition], &order_by, 0).is_err());
+}
+
+#[test]
+fn errors() {
+    assert!(rank(&[], &[]).is_err());
+
+    let partition = Int32Array::from_slice([1, 2]);
+    let order = Int32Array::from_slice([1, 2, 3]);
+    assert!(rank(&[&partition], &order_by(&order, false)).is_err());
+}
+
+#[test]
+fn empty() {
+    let order = Int32Array::from_slice([]);
+    let result = dense_rank(&[], &order_by(&order, false)).unwrap();
+    assert_eq!(result, UInt64Array::from_slice([]));
+}
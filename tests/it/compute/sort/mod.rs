@@ -1,5 +1,7 @@
 mod lex_sort;
 mod row;
+mod search_sorted;
+mod top_k;
 
 use re_arrow2::array::*;
 use re_arrow2::compute::sort::*;
@@ -585,6 +587,27 @@ fn consistency() {
         Duration(TimeUnit::Millisecond),
         Duration(TimeUnit::Microsecond),
         Duration(TimeUnit::Nanosecond),
+        Timestamp(
+            TimeUnit::Second,
+            Some(std::sync::Arc::new("+01:00".to_string())),
+        ),
+        Decimal(10, 2),
+        Decimal256(40, 2),
+        Float16,
+        FixedSizeBinary(3),
+        Struct(std::sync::Arc::new(vec![
+            Field::new("a", Float64, true),
+            Field::new("b", Utf8, true),
+        ])),
+        List(std::sync::Arc::new(Field::new("a", Utf8, true))),
+        LargeList(std::sync::Arc::new(Field::new("a", Float32, true))),
+        FixedSizeList(std::sync::Arc::new(Field::new("a", Boolean, true)), 2),
+        Dictionary(IntegerType::Int32, std::sync::Arc::new(Float64), false),
+        Dictionary(
+            IntegerType::Int32,
+            std::sync::Arc::new(Interval(IntervalUnit::DayTime)),
+            false,
+        ),
     ];
 
     datatypes.into_iter().for_each(|d1| {
@@ -600,3 +623,136 @@ fn consistency() {
         }
     });
 }
+
+#[test]
+fn timestamp_with_timezone() {
+    let data_type = DataType::Timestamp(
+        TimeUnit::Millisecond,
+        Some(std::sync::Arc::new("UTC".to_string())),
+    );
+    let array = Int64Array::from(&[Some(3), None, Some(1), Some(2)]).to(data_type.clone());
+    let options = SortOptions {
+        descending: false,
+        nulls_first: false,
+    };
+    let result = sort(&array, &options, None).unwrap();
+    let expected = Int64Array::from(&[Some(1), Some(2), Some(3), None]).to(data_type);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+}
+
+#[test]
+fn decimals() {
+    let array = Int128Array::from(&[Some(300), Some(-100), None]).to(DataType::Decimal(5, 2));
+    let result = sort_to_indices::<u32>(&array, &SortOptions::default(), None).unwrap();
+    assert_eq!(result, UInt32Array::from_slice([2, 1, 0]));
+
+    use re_arrow2::types::i256;
+    let array = Int256Array::from(&[Some(i256::from_words(0, 3)), Some(i256::from_words(-1, 0))])
+        .to(DataType::Decimal256(40, 2));
+    let result = sort_to_indices::<u32>(&array, &SortOptions::default(), None).unwrap();
+    assert_eq!(result, UInt32Array::from_slice([1, 0]));
+}
+
+#[test]
+fn float16() {
+    use re_arrow2::types::f16;
+    let array = Float16Array::from(&[
+        Some(f16::from_f32(1.5)),
+        Some(f16::from_f32(f32::NAN)),
+        None,
+        Some(f16::from_f32(-0.5)),
+    ]);
+    let options = SortOptions {
+        descending: true,
+        nulls_first: false,
+    };
+    let result = sort_to_indices::<u32>(&array, &options, None).unwrap();
+    assert_eq!(result, UInt32Array::from_slice([1, 0, 3, 2]));
+}
+
+#[test]
+fn fixed_size_binary() {
+    let array = FixedSizeBinaryArray::from([Some([2, 0]), None, Some([1, 9]), Some([2, 1])]);
+    let result = sort(&array, &SortOptions::default(), None).unwrap();
+    let expected = FixedSizeBinaryArray::from([None, Some([1, 9]), Some([2, 0]), Some([2, 1])]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+}
+
+#[test]
+fn structs() {
+    let fields = vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ];
+    let array = StructArray::new(
+        DataType::Struct(std::sync::Arc::new(fields)),
+        vec![
+            Int32Array::from(&[Some(2), Some(1), None, Some(1), Some(0)]).boxed(),
+            Utf8Array::<i32>::from([Some("a"), Some("b"), Some("c"), Some("a"), Some("z")]).boxed(),
+        ],
+        Some([true, true, true, true, false].into()),
+    );
+    let options = SortOptions {
+        descending: false,
+        nulls_first: false,
+    };
+    // null fields sort before values; null structs follow `nulls_first`
+    let result = sort_to_indices::<u32>(&array, &options, None).unwrap();
+    assert_eq!(result, UInt32Array::from_slice([2, 3, 1, 0, 4]));
+
+    let result = sort_to_indices::<u32>(&array, &options, Some(2)).unwrap();
+    assert_eq!(result, UInt32Array::from_slice([2, 3]));
+}
+
+#[test]
+fn list_of_strings() {
+    let mut array = MutableListArray::<i32, MutableUtf8Array<i32>>::new();
+    array
+        .try_extend(vec![
+            Some(vec![Some("b")]),
+            Some(vec![Some("a"), Some("c")]),
+            None,
+            Some(vec![Some("a")]),
+            Some(vec![]),
+        ])
+        .unwrap();
+    let array: ListArray<i32> = array.into();
+    let result = sort_to_indices::<u32>(&array, &SortOptions::default(), None).unwrap();
+    assert_eq!(result, UInt32Array::from_slice([2, 4, 3, 1, 0]));
+}
+
+#[test]
+fn dictionary_of_primitives() {
+    let mut array = MutableDictionaryArray::<u8, MutablePrimitiveArray<i64>>::new();
+    array
+        .try_extend([Some(3), None, Some(-1), Some(3), Some(2)])
+        .unwrap();
+    let array: DictionaryArray<u8> = array.into();
+    let options = SortOptions {
+        descending: true,
+        nulls_first: true,
+    };
+    let result = sort_to_indices::<u32>(&array, &options, None).unwrap();
+    assert_eq!(result.value(0), 1);
+    let mut ties = result.values()[1..3].to_vec();
+    ties.sort();
+    assert_eq!(ties, vec![0, 3]);
+    assert_eq!(&result.values()[3..], &[4, 2]);
+
+    let result = sort(&array, &options, None).unwrap();
+    let result = result
+        .as_any()
+        .downcast_ref::<DictionaryArray<u8>>()
+        .unwrap();
+    let values = result
+        .values()
+        .as_any()
+        .downcast_ref::<Int64Array>()
+        .unwrap();
+    let values = result
+        .keys()
+        .iter()
+        .map(|key| key.map(|key| values.value(*key as usize)))
+        .collect::<Vec<_>>();
+    assert_eq!(values, vec![None, Some(3), Some(3), Some(2), Some(-1)]);
+}
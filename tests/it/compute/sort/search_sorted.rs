@@ -0,0 +1,134 @@
+use re_arrow2::array::*;
+use re_arrow2::compute::sort::*;
+use re_arrow2::datatypes::{DataType, TimeUnit};
+
+fn search(
+    sorted: &dyn Array,
+    needles: &dyn Array,
+    side: SearchSide,
+    options: SortOptions,
+) -> Vec<u64> {
+    search_sorted(sorted, needles, side, options)
+        .unwrap()
+        .values()
+        .to_vec()
+}
+
+#[test]
+fn primitive() {
+    let sorted = Int64Array::from_slice([1, 2, 2, 2, 4])
+        .to(DataType::Timestamp(TimeUnit::Millisecond, None));
+    let needles =
+        Int64Array::from_slice([0, 2, 3, 5]).to(DataType::Timestamp(TimeUnit::Millisecond, None));
+    let options = SortOptions::default();
+
+    assert_eq!(
+        search(&sorted, &needles, SearchSide::Left, options),
+        vec![0, 1, 4, 5]
+    );
+    assert_eq!(
+        search(&sorted, &needles, SearchSide::Right, options),
+        vec![0, 4, 4, 5]
+    );
+}
+
+#[test]
+fn descending_nulls_last() {
+    let sorted = Int32Array::from(&[Some(5), Some(3), Some(3), None, None]);
+    let needles = Int32Array::from(&[Some(3), Some(6), Some(0), None]);
+    let options = SortOptions {
+        descending: true,
+        nulls_first: false,
+    };
+
+    assert_eq!(
+        search(&sorted, &needles, SearchSide::Left, options),
+        vec![1, 0, 3, 3]
+    );
+    assert_eq!(
+        search(&sorted, &needles, SearchSide::Right, options),
+        vec![3, 0, 3, 5]
+    );
+}
+
+#[test]
+fn utf8() {
+    let sorted = Utf8Array::<i32>::from_slice(["a", "b", "b", "d"]);
+    let needles = Utf8Array::<i32>::from_slice(["b", "c", "", "e"]);
+    let options = SortOptions::default();
+
+    assert_eq!(
+        search(&sorted, &needles, SearchSide::Left, options),
+        vec![1, 3, 0, 4]
+    );
+    assert_eq!(
+        search(&sorted, &needles, SearchSide::Right, options),
+        vec![3, 3, 0, 4]
+    );
+}
+
+#[test]
+fn floats() {
+    let sorted = Float64Array::from_slice([-1.0, 0.0, 1.0, f64::NAN]);
+    let needles = Float64Array::from_slice([f64::NAN, 0.5, f64::INFINITY]);
+    let options = SortOptions::default();
+
+    assert_eq!(
+        search(&sorted, &needles, SearchSide::Left, options),
+        vec![3, 2, 3]
+    );
+}
+
+#[test]
+fn dictionary() {
+    let mut sorted = MutableDictionaryArray::<i32, MutableUtf8Array<i32>>::new();
+    sorted
+        .try_extend([Some("a"), Some("c"), Some("c"), Some("e")])
+        .unwrap();
+    let sorted: DictionaryArray<i32> = sorted.into();
+    let mut needles = MutableDictionaryArray::<i32, MutableUtf8Array<i32>>::new();
+    needles.try_extend([Some("c"), Some("d")]).unwrap();
+    let needles: DictionaryArray<i32> = needles.into();
+
+    assert_eq!(
+        search(&sorted, &needles, SearchSide::Right, SortOptions::default()),
+        vec![3, 3]
+    );
+}
+
+#[test]
+fn consistent_with_sort() {
+    let array = Int32Array::from(&[Some(3), None, Some(1), Some(3), Some(-2), None, Some(7)]);
+    for descending in [false, true] {
+        for nulls_first in [false, true] {
+            let options = SortOptions {
+                descending,
+                nulls_first,
+            };
+            let sorted = sort(&array, &options, None).unwrap();
+
+            // each value is found at its position in the sorted array
+            let left = search(sorted.as_ref(), sorted.as_ref(), SearchSide::Left, options);
+            let right = search(sorted.as_ref(), sorted.as_ref(), SearchSide::Right, options);
+            for (i, (left, right)) in left.into_iter().zip(right).enumerate() {
+                assert!(left as usize <= i && i < right as usize);
+            }
+        }
+    }
+}
+
+#[test]
+fn errors() {
+    let sorted = Int32Array::from_slice([1, 2]);
+    let needles = Int64Array::from_slice([1]);
+    assert!(search_sorted(&sorted, &needles, SearchSide::Left, SortOptions::default()).is_err());
+
+    let sorted = new_null_array(DataType::Null, 2);
+    assert!(search_sorted(
+        sorted.as_ref(),
+        sorted.as_ref(),
+        SearchSide::Left,
+        SortOptions::default()
+    )
+    .is_err());
+}
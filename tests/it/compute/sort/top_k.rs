@@ -0,0 +1,112 @@
+use re_arrow2::array::*;
+use re_arrow2::chunk::Chunk;
+use re_arrow2::compute::sort::row::SortField;
+use re_arrow2::compute::sort::*;
+use re_arrow2::datatypes::DataType;
+
+#[test]
+fn same_as_lexsort() {
+    let a = Int32Array::from(&[Some(3), None, Some(1), Some(3), Some(2), None, Some(1)]);
+    let b = Utf8Array::<i32>::from([
+        Some("x"),
+        Some("y"),
+        None,
+        Some("a"),
+        Some("b"),
+        Some("c"),
+        Some("z"),
+    ]);
+    for descending in [false, true] {
+        for nulls_first in [false, true] {
+            let options = SortOptions {
+                descending,
+                nulls_first,
+            };
+            let columns = [
+                SortColumn {
+                    values: &a,
+                    options: Some(options),
+                },
+                SortColumn {
+                    values: &b,
+                    options: Some(options),
+                },
+            ];
+            for k in 0..=8 {
+                let expected = lexsort::<u32>(&columns, Some(k.min(a.len()))).unwrap();
+                let result = top_k(&columns, k).unwrap();
+                assert_eq!(result, expected);
+            }
+        }
+    }
+}
+
+#[test]
+fn ties_keep_order() {
+    let a = Int64Array::from_slice([1, 0, 1, 0, 1]);
+    let columns = [SortColumn {
+        values: &a,
+        options: None,
+    }];
+    let result = top_k_to_indices::<u64>(&columns, 4).unwrap();
+    assert_eq!(result, UInt64Array::from_slice([1, 3, 0, 2]));
+}
+
+#[test]
+fn errors() {
+    assert!(top_k(&[], 1).is_err());
+
+    let a = Int32Array::from_slice([1, 2]);
+    let b = Int32Array::from_slice([1]);
+    let columns = [
+        SortColumn {
+            values: &a,
+            options: None,
+        },
+        SortColumn {
+            values: &b,
+            options: None,
+        },
+    ];
+    assert!(top_k(&columns, 1).is_err());
+}
+
+fn chunk(keys: &[Option<i32>], values: &[&str]) -> Chunk<Box<dyn Array>> {
+    Chunk::new(vec![
+        Utf8Array::<i32>::from_slice(values).boxed(),
+        Int32Array::from(keys).boxed(),
+    ])
+}
+
+#[test]
+fn streaming() {
+    let options = SortOptions {
+        descending: true,
+        nulls_first: false,
+    };
+    let mut top = TopK::new(
+        vec![(1, SortField::new_with_options(DataType::Int32, options))],
+        3,
+    );
+    assert!(top.top().is_none());
+
+    top.push(&chunk(&[Some(1), None, Some(5)], &["a", "b", "c"]))
+        .unwrap();
+    top.push(&chunk(&[Some(5), Some(2)], &["d", "e"])).unwrap();
+    top.push(&chunk(&[], &[])).unwrap();
+    top.push(&chunk(&[Some(0), Some(7), Some(2)], &["f", "g", "h"]))
+        .unwrap();
+
+    let top = top.finish().unwrap();
+    // ties keep the order in which rows were pushed
+    assert_eq!(top, chunk(&[Some(7), Some(5), Some(5)], &["g", "c", "d"]));
+}
+
+#[test]
+fn streaming_errors() {
+    let mut top = TopK::new(vec![(2, SortField::new(DataType::Int32))], 3);
+    assert!(top.push(&chunk(&[Some(1)], &["a"])).is_err());
+
+    let mut top = TopK::new(vec![(1, SortField::new(DataType::Int64))], 3);
+    assert!(top.push(&chunk(&[Some(1)], &["a"])).is_err());
+}
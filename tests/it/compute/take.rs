@@ -324,3 +324,12 @@ fn test_nested() {
 
     assert_eq!(expected, result.as_ref());
 }
+
+#[test]
+fn fixed_size_binary() {
+    let values = FixedSizeBinaryArray::from([Some([1, 2]), None, Some([3, 4])]);
+    let indices = Int32Array::from(&[Some(2), Some(1), None, Some(0)]);
+    let result = take(&values, &indices).unwrap();
+    let expected = FixedSizeBinaryArray::from([Some([3, 4]), None, None, Some([1, 2])]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+}
@@ -380,3 +380,186 @@ fn consistency_check<O: re_arrow2::types::NativeType>(
         }
     });
 }
+
+#[test]
+fn date_trunc_units() {
+    // Wed May 26 2021 17:25:30.123 GMT+0000
+    let data_type = DataType::Timestamp(TimeUnit::Millisecond, None);
+    let array = PrimitiveArray::<i64>::from([Some(1622049930123), None]).to(data_type.clone());
+
+    let cases = [
+        (TruncUnit::Second, 1622049930),
+        (TruncUnit::Minute, 1622049900),
+        (TruncUnit::Hour, 1622048400),
+        (TruncUnit::Day, 1621987200),
+        (TruncUnit::Week, 1621814400),
+        (TruncUnit::Month, 1619827200),
+        (TruncUnit::Year, 1609459200),
+    ];
+    for (unit, expected) in cases {
+        let result = date_trunc(&array, unit).unwrap();
+        let expected =
+            PrimitiveArray::<i64>::from([Some(expected * 1000), None]).to(data_type.clone());
+        assert_eq!(result, expected, "{unit:?}");
+    }
+}
+
+#[test]
+fn date_trunc_offset() {
+    // Wed May 26 2021 01:00:00 GMT+0000 is still May 25 at -02:00
+    let data_type = DataType::Timestamp(TimeUnit::Second, Some(Arc::new("-02:00".to_string())));
+    let array = PrimitiveArray::<i64>::from_slice([1621990800]).to(data_type.clone());
+    let result = date_trunc(&array, TruncUnit::Day).unwrap();
+    // Tue May 25 2021 00:00:00 GMT-0200
+    let expected = PrimitiveArray::<i64>::from_slice([1621908000]).to(data_type);
+    assert_eq!(result, expected);
+}
+
+#[cfg(feature = "chrono-tz")]
+#[test]
+fn date_trunc_tz_dst() {
+    // Sun Mar 14 2021 12:00:00 EDT; the day started in EST
+    let data_type = DataType::Timestamp(
+        TimeUnit::Second,
+        Some(Arc::new("America/New_York".to_string())),
+    );
+    let array = PrimitiveArray::<i64>::from_slice([1615737600]).to(data_type.clone());
+    let result = date_trunc(&array, TruncUnit::Day).unwrap();
+    let expected = PrimitiveArray::<i64>::from_slice([1615698000]).to(data_type);
+    assert_eq!(result, expected);
+}
+
+#[cfg(feature = "chrono-tz")]
+#[test]
+fn date_trunc_tz_ambiguous() {
+    // Sun Nov 07 2021 01:30:00 EST; 01:00 happened twice, first in EDT
+    let data_type = DataType::Timestamp(
+        TimeUnit::Second,
+        Some(Arc::new("America/New_York".to_string())),
+    );
+    let array = PrimitiveArray::<i64>::from_slice([1636266600]).to(data_type.clone());
+    let result = date_trunc(&array, TruncUnit::Hour).unwrap();
+    // Sun Nov 07 2021 01:00:00 EST
+    let expected = PrimitiveArray::<i64>::from_slice([1636264800]).to(data_type);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn date_trunc_out_of_range() {
+    let data_type = DataType::Timestamp(TimeUnit::Second, None);
+    let array = PrimitiveArray::<i64>::new(
+        data_type.clone(),
+        vec![0, i64::MAX].into(),
+        Some([true, false].into()),
+    );
+    let result = date_trunc(&array, TruncUnit::Day).unwrap();
+    let expected = PrimitiveArray::<i64>::from([Some(0), None]).to(data_type.clone());
+    assert_eq!(result, expected);
+
+    let array = PrimitiveArray::<i64>::from_slice([i64::MAX]).to(data_type);
+    assert!(date_trunc(&array, TruncUnit::Day).is_err());
+}
+
+#[test]
+fn date_trunc_unsupported() {
+    let array = Int64Array::from_slice([1]);
+    assert!(date_trunc(&array, TruncUnit::Day).is_err());
+
+    let data_type = DataType::Timestamp(TimeUnit::Second, Some(Arc::new("+1".to_string())));
+    let array = PrimitiveArray::<i64>::from_slice([1]).to(data_type);
+    assert!(date_trunc(&array, TruncUnit::Day).is_err());
+}
+
+#[test]
+fn date_bin_origin() {
+    let data_type = DataType::Timestamp(TimeUnit::Nanosecond, None);
+    let array = PrimitiveArray::<i64>::from([Some(0), Some(9), Some(10), None, Some(-6)])
+        .to(data_type.clone());
+    let result = date_bin(&array, 7, 3).unwrap();
+    let expected =
+        PrimitiveArray::<i64>::from([Some(-4), Some(3), Some(10), None, Some(-11)]).to(data_type);
+    assert_eq!(result, expected);
+
+    assert!(date_bin(&array, 0, 0).is_err());
+    let array = PrimitiveArray::<i64>::from_slice([i64::MIN])
+        .to(DataType::Timestamp(TimeUnit::Second, None));
+    assert!(date_bin(&array, 7, 3).is_err());
+
+    // null slots are not binned
+    let data_type = DataType::Timestamp(TimeUnit::Second, None);
+    let array = PrimitiveArray::<i64>::new(
+        data_type.clone(),
+        vec![i64::MIN, 8].into(),
+        Some([false, true].into()),
+    );
+    let result = date_bin(&array, 7, 3).unwrap();
+    assert_eq!(
+        result,
+        PrimitiveArray::<i64>::from([None, Some(3)]).to(data_type)
+    );
+}
+
+#[test]
+fn strftime_types() {
+    let array = Int32Array::from([Some(18773), None]).to(DataType::Date32);
+    let result = strftime(&array, "%d/%m/%Y").unwrap();
+    assert_eq!(result, Utf8Array::<i32>::from([Some("26/05/2021"), None]));
+
+    let array = Int64Array::from_slice([3_600_000_001]).to(DataType::Time64(TimeUnit::Microsecond));
+    let result = strftime(&array, "%H:%M:%S%.6f").unwrap();
+    assert_eq!(result, Utf8Array::<i32>::from_slice(["01:00:00.000001"]));
+
+    // times do not have years
+    assert!(strftime(&array, "%Y").is_err());
+    // invalid format
+    assert!(strftime(&array, "%Q").is_err());
+}
+
+#[test]
+fn strftime_out_of_range() {
+    let array = PrimitiveArray::<i64>::new(
+        DataType::Timestamp(TimeUnit::Second, None),
+        vec![0, i64::MAX].into(),
+        Some([true, false].into()),
+    );
+    let result = strftime(&array, "%Y").unwrap();
+    assert_eq!(result, Utf8Array::<i32>::from([Some("1970"), None]));
+
+    let array = Int32Array::from_slice([i32::MAX]).to(DataType::Time32(TimeUnit::Second));
+    assert!(strftime(&array, "%H").is_err());
+}
+
+#[cfg(feature = "chrono-tz")]
+#[test]
+fn strftime_tz() {
+    let data_type = DataType::Timestamp(
+        TimeUnit::Second,
+        Some(Arc::new("Europe/Lisbon".to_string())),
+    );
+    // Wed May 26 2021 17:25:30 GMT+0000
+    let array = PrimitiveArray::<i64>::from_slice([1622049930]).to(data_type);
+    let result = strftime(&array, "%Y-%m-%dT%H:%M:%S%:z").unwrap();
+    assert_eq!(
+        result,
+        Utf8Array::<i32>::from_slice(["2021-05-26T18:25:30+01:00"])
+    );
+}
+
+#[test]
+fn convert_timezone_keeps_instants() {
+    let array = PrimitiveArray::<i64>::from([Some(1622049930), None])
+        .to(DataType::Timestamp(TimeUnit::Second, None));
+    let timezone = Some(Arc::new("+05:30".to_string()));
+    let result = convert_timezone(&array, timezone.clone()).unwrap();
+    assert_eq!(result.values(), array.values());
+    assert_eq!(
+        result.data_type(),
+        &DataType::Timestamp(TimeUnit::Second, timezone)
+    );
+    assert_eq!(hour(&result).unwrap().value(0), 22);
+
+    let result = convert_timezone(&result, None).unwrap();
+    assert_eq!(result, array);
+
+    assert!(convert_timezone(&array, Some(Arc::new("invalid".to_string()))).is_err());
+}
@@ -0,0 +1,198 @@
+use std::sync::Arc;
+
+use re_arrow2::array::*;
+use re_arrow2::compute::unique::*;
+use re_arrow2::datatypes::{DataType, Field, UnionMode};
+use re_arrow2::error::Result;
+
+#[test]
+fn unique_primitive() -> Result<()> {
+    let array = Int32Array::from(&[Some(2), None, Some(1), Some(2), None, Some(3)]);
+    let result = unique(&array)?;
+
+    let expected = Int32Array::from(&[Some(2), None, Some(1), Some(3)]);
+    assert_eq!(expected, result.as_ref());
+    Ok(())
+}
+
+#[test]
+fn unique_float() -> Result<()> {
+    let array = Float64Array::from_slice([1.0, f64::NAN, 0.0, -0.0, f64::NAN, 1.0]);
+    let result = unique(&array)?;
+    let result = result.as_any().downcast_ref::<Float64Array>().unwrap();
+
+    assert_eq!(result.len(), 4);
+    assert_eq!(result.value(0), 1.0);
+    assert!(result.value(1).is_nan());
+    assert!(result.value(2).is_sign_positive());
+    assert!(result.value(3).is_sign_negative());
+
+    let array = Float64Array::from_slice([f64::NAN, -f64::NAN, 0.0, -f64::NAN]);
+    let result = value_counts(&array)?;
+    let counts = result.values()[1]
+        .as_any()
+        .downcast_ref::<UInt64Array>()
+        .unwrap();
+    assert_eq!(counts, &UInt64Array::from_slice([3, 1]));
+    Ok(())
+}
+
+#[test]
+fn unique_sliced_utf8() -> Result<()> {
+    let array = Utf8Array::<i32>::from([Some("a"), Some("b"), None, Some("b"), Some("c")]);
+    let array = array.sliced(1, 3);
+    let result = unique(&array)?;
+
+    let expected = Utf8Array::<i32>::from([Some("b"), None]);
+    assert_eq!(expected, result.as_ref());
+    Ok(())
+}
+
+#[test]
+fn unique_list() -> Result<()> {
+    let data = vec![
+        Some(vec![Some(1), Some(2)]),
+        None,
+        Some(vec![Some(1)]),
+        Some(vec![Some(1), Some(2)]),
+        Some(vec![]),
+        Some(vec![Some(1), None]),
+        Some(vec![]),
+    ];
+    let mut array = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+    array.try_extend(data)?;
+    let array: ListArray<i32> = array.into();
+    let array = array.sliced(1, 6);
+
+    let result = unique(&array)?;
+
+    let expected = vec![
+        None,
+        Some(vec![Some(1)]),
+        Some(vec![Some(1), Some(2)]),
+        Some(vec![]),
+        Some(vec![Some(1), None]),
+    ];
+    let mut expected_array = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+    expected_array.try_extend(expected)?;
+    let expected: ListArray<i32> = expected_array.into();
+    assert_eq!(expected, result.as_ref());
+    Ok(())
+}
+
+#[test]
+fn unique_struct() -> Result<()> {
+    let fields = vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ];
+    let array = StructArray::new(
+        DataType::Struct(Arc::new(fields.clone())),
+        vec![
+            Int32Array::from(&[Some(1), Some(1), Some(2), Some(1)]).boxed(),
+            Utf8Array::<i32>::from([Some("x"), Some("y"), Some("x"), Some("x")]).boxed(),
+        ],
+        Some([true, true, false, true].into()),
+    );
+    let result = unique(&array)?;
+
+    let expected = StructArray::new(
+        DataType::Struct(Arc::new(fields)),
+        vec![
+            Int32Array::from(&[Some(1), Some(1), Some(2)]).boxed(),
+            Utf8Array::<i32>::from([Some("x"), Some("y"), Some("x")]).boxed(),
+        ],
+        Some([true, true, false].into()),
+    );
+    assert_eq!(expected, result.as_ref());
+    Ok(())
+}
+
+#[test]
+fn unique_dictionary() -> Result<()> {
+    let keys = Int32Array::from(&[Some(0), Some(1), None, Some(2), Some(1)]);
+    let values = Utf8Array::<i32>::from_slice(["a", "b", "a"]).boxed();
+    let data_type = DataType::Dictionary(
+        re_arrow2::datatypes::IntegerType::Int32,
+        Arc::new(DataType::Utf8),
+        false,
+    );
+    let array = DictionaryArray::try_new(data_type, keys, values)?;
+
+    let result = value_counts(&array)?;
+    let result = result.values()[1]
+        .as_any()
+        .downcast_ref::<UInt64Array>()
+        .unwrap();
+    assert_eq!(result, &UInt64Array::from_slice([2, 2, 1]));
+    Ok(())
+}
+
+#[test]
+fn value_counts_utf8() -> Result<()> {
+    let array = Utf8Array::<i64>::from([Some("a"), None, Some("b"), Some("a"), None, Some("a")]);
+    let result = value_counts(&array)?;
+
+    assert_eq!(
+        result.values()[0].as_ref(),
+        &Utf8Array::<i64>::from([Some("a"), None, Some("b")]) as &dyn Array
+    );
+    assert_eq!(
+        result.values()[1].as_ref(),
+        &UInt64Array::from_slice([3, 2, 1]) as &dyn Array
+    );
+    Ok(())
+}
+
+#[test]
+fn dictionary_encode_primitive() -> Result<()> {
+    let array = Int64Array::from(&[Some(5), None, Some(6), Some(5), None, Some(7)]);
+    let result = dictionary_encode::<u8>(&array)?;
+
+    assert_eq!(
+        result.keys(),
+        &UInt8Array::from(&[Some(0), None, Some(1), Some(0), None, Some(2)])
+    );
+    assert_eq!(
+        result.values().as_ref(),
+        &Int64Array::from_slice([5, 6, 7]) as &dyn Array
+    );
+    Ok(())
+}
+
+#[test]
+fn dictionary_encode_overflow() {
+    let array = Int32Array::from_vec((0..300).collect());
+    assert!(dictionary_encode::<i8>(&array).is_err());
+}
+
+#[test]
+fn consistency() {
+    let datatypes = vec![
+        DataType::Null,
+        DataType::Boolean,
+        DataType::Int8,
+        DataType::Float16,
+        DataType::Float64,
+        DataType::Decimal256(10, 2),
+        DataType::FixedSizeBinary(3),
+        DataType::LargeUtf8,
+        DataType::List(Arc::new(Field::new("item", DataType::Float32, true))),
+        DataType::FixedSizeList(Arc::new(Field::new("item", DataType::Int64, true)), 2),
+        DataType::Struct(Arc::new(vec![Field::new("a", DataType::Binary, true)])),
+        DataType::Union(
+            Arc::new(vec![Field::new("a", DataType::Int32, true)]),
+            None,
+            UnionMode::Sparse,
+        ),
+    ];
+
+    datatypes.into_iter().for_each(|d1| {
+        let array = new_null_array(d1.clone(), 10);
+        if can_unique(&d1) {
+            assert_eq!(unique(array.as_ref()).unwrap().len(), 1);
+        } else {
+            assert!(unique(array.as_ref()).is_err());
+        }
+    });
+}
@@ -369,3 +369,262 @@ fn consistency_upper() {
         }
     });
 }
+
+fn utf8_dictionary(values: &[Option<&str>]) -> DictionaryArray<i32> {
+    let mut array = MutableDictionaryArray::<i32, MutableUtf8Array<i32>>::new();
+    array.try_extend(values.iter().copied()).unwrap();
+    array.into()
+}
+
+#[test]
+fn trim_variants() {
+    let array = Utf8Array::<i64>::from([Some("  a b "), None, Some("\tc")]);
+    assert_eq!(
+        utf8_trim(&array),
+        Utf8Array::<i64>::from([Some("a b"), None, Some("c")])
+    );
+    assert_eq!(
+        utf8_ltrim(&array),
+        Utf8Array::<i64>::from([Some("a b "), None, Some("c")])
+    );
+    assert_eq!(
+        utf8_rtrim(&array),
+        Utf8Array::<i64>::from([Some("  a b"), None, Some("\tc")])
+    );
+}
+
+#[test]
+fn trim_dictionary() {
+    let array = utf8_dictionary(&[Some(" a"), None, Some(" a"), Some("b ")]);
+    let result = trim(&array).unwrap();
+    let expected = utf8_dictionary(&[Some("a"), None, Some("a"), Some("b")]);
+    let result = result
+        .as_any()
+        .downcast_ref::<DictionaryArray<i32>>()
+        .unwrap();
+    assert_eq!(result.keys(), expected.keys());
+    assert_eq!(result.values(), expected.values());
+}
+
+#[test]
+fn split_utf8() {
+    let array = Utf8Array::<i32>::from([Some("a,b,,c"), None, Some(""), Some("d")]);
+    let result = utf8_split(&array, ",").unwrap();
+
+    let mut expected = MutableListArray::<i32, MutableUtf8Array<i32>>::new();
+    expected
+        .try_extend(vec![
+            Some(vec![Some("a"), Some("b"), Some(""), Some("c")]),
+            None,
+            Some(vec![Some("")]),
+            Some(vec![Some("d")]),
+        ])
+        .unwrap();
+    let expected: ListArray<i32> = expected.into();
+    assert_eq!(result, expected);
+
+    assert!(utf8_split(&array, "").is_err());
+}
+
+#[test]
+fn split_large() {
+    let array = Utf8Array::<i64>::from([Some("a b")]);
+    let result = split(&array, " ").unwrap();
+    let result = result.as_any().downcast_ref::<ListArray<i64>>().unwrap();
+    assert_eq!(
+        result.values().as_ref(),
+        &Utf8Array::<i64>::from_slice(["a", "b"]) as &dyn Array
+    );
+}
+
+#[test]
+fn replace_utf8() {
+    let array = Utf8Array::<i32>::from([Some("aXbXc"), None]);
+    let result = replace(&array, "X", "--").unwrap();
+    let expected = Utf8Array::<i32>::from([Some("a--b--c"), None]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+}
+
+#[test]
+#[cfg(feature = "regex")]
+fn regex_replace_utf8() {
+    let array = Utf8Array::<i32>::from([Some("2020-01-31"), None]);
+    let result = utf8_regex_replace(&array, r"(\d+)-(\d+)-(\d+)", "$3/$2/$1").unwrap();
+    assert_eq!(result, Utf8Array::<i32>::from([Some("31/01/2020"), None]));
+
+    assert!(utf8_regex_replace(&array, "(", "").is_err());
+}
+
+#[test]
+fn concat_utf8() {
+    let a = Utf8Array::<i32>::from([Some("a"), None, Some("c")]);
+    let b = Utf8Array::<i32>::from([Some("1"), Some("2"), None]);
+
+    let result = utf8_concat(&[&a, &b]).unwrap();
+    assert_eq!(result, Utf8Array::<i32>::from([Some("a1"), None, None]));
+
+    let result = utf8_concat_ws(&[&a, &b], "-").unwrap();
+    assert_eq!(result, Utf8Array::<i32>::from_slice(["a-1", "2", "c"]));
+
+    let c = Utf8Array::<i32>::from_slice(["x"]);
+    assert!(utf8_concat(&[&a, &c]).is_err());
+    assert!(concat(&[]).is_err());
+    assert!(concat(&[&a, &Utf8Array::<i64>::from_slice(["a", "b", "c"])]).is_err());
+}
+
+#[test]
+fn pad_utf8() {
+    let array = Utf8Array::<i32>::from([Some("ab"), None, Some("abcde"), Some("é")]);
+    assert_eq!(
+        utf8_lpad(&array, 3, '*'),
+        Utf8Array::<i32>::from([Some("*ab"), None, Some("abc"), Some("**é")])
+    );
+    assert_eq!(
+        utf8_rpad(&array, 3, '*'),
+        Utf8Array::<i32>::from([Some("ab*"), None, Some("abc"), Some("é**")])
+    );
+}
+
+#[test]
+fn predicates_scalar() {
+    let array = Utf8Array::<i32>::from([Some("arrow"), None, Some("parquet")]);
+    assert_eq!(
+        starts_with_scalar(&array, "arr").unwrap(),
+        BooleanArray::from([Some(true), None, Some(false)])
+    );
+    assert_eq!(
+        ends_with_scalar(&array, "quet").unwrap(),
+        BooleanArray::from([Some(false), None, Some(true)])
+    );
+    assert_eq!(
+        contains_scalar(&array, "r").unwrap(),
+        BooleanArray::from([Some(true), None, Some(true)])
+    );
+}
+
+#[test]
+fn predicates_dictionary() {
+    let array = utf8_dictionary(&[Some("arrow"), None, Some("parquet"), Some("arrow")]);
+    assert_eq!(
+        contains_scalar(&array, "row").unwrap(),
+        BooleanArray::from([Some(true), None, Some(false), Some(true)])
+    );
+}
+
+#[test]
+fn predicates_array() {
+    let lhs = Utf8Array::<i64>::from([Some("arrow"), Some("arrow"), None, Some("a")]);
+    let rhs = Utf8Array::<i64>::from([Some("ar"), Some("ow"), Some("a"), Some("")]);
+    assert_eq!(
+        starts_with(&lhs, &rhs).unwrap(),
+        BooleanArray::from([Some(true), Some(false), None, Some(true)])
+    );
+    assert_eq!(
+        ends_with(&lhs, &rhs).unwrap(),
+        BooleanArray::from([Some(false), Some(true), None, Some(true)])
+    );
+    assert_eq!(
+        contains(&lhs, &rhs).unwrap(),
+        BooleanArray::from([Some(true), Some(true), None, Some(true)])
+    );
+
+    let other = Utf8Array::<i32>::from_slice(["a", "b", "c", "d"]);
+    assert!(contains(&lhs, &other).is_err());
+    assert!(utf8_contains(&lhs, &lhs.clone().sliced(0, 1)).is_err());
+}
+
+#[test]
+fn reverse_utf8() {
+    let array = Utf8Array::<i32>::from([Some("abé"), None, Some("")]);
+    let result = reverse(&array).unwrap();
+    let expected = Utf8Array::<i32>::from([Some("éba"), None, Some("")]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+}
+
+#[test]
+fn char_length_utf8() {
+    let array = Utf8Array::<i64>::from([Some("héllo"), None, Some("")]);
+    let result = char_length(&array).unwrap();
+    let expected = Int64Array::from([Some(5), None, Some(0)]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+    let array = utf8_dictionary(&[Some("héllo"), None, Some("ab"), Some("héllo")]);
+    let result = char_length(&array).unwrap();
+    let expected = Int32Array::from([Some(5), None, Some(2), Some(5)]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+}
+
+#[test]
+fn strpos_utf8() {
+    let array = Utf8Array::<i32>::from([Some("héllo"), None, Some("abc"), Some("ll")]);
+    let result = utf8_strpos(&array, "l");
+    assert_eq!(result, Int32Array::from([Some(3), None, Some(0), Some(1)]));
+}
+
+#[test]
+fn consistency_unary() {
+    use re_arrow2::datatypes::{DataType::*, IntegerType};
+    let datatypes = vec![
+        Null,
+        Boolean,
+        Int32,
+        Utf8,
+        LargeUtf8,
+        Binary,
+        Dictionary(IntegerType::Int32, std::sync::Arc::new(Utf8), false),
+        Dictionary(IntegerType::UInt8, std::sync::Arc::new(LargeUtf8), false),
+        Dictionary(IntegerType::Int32, std::sync::Arc::new(Int32), false),
+    ];
+
+    datatypes.into_iter().for_each(|d1| {
+        let array = new_null_array(d1.clone(), 10);
+        if can_utf8_unary(&d1) {
+            assert!(trim(array.as_ref()).is_ok());
+            assert!(char_length(array.as_ref()).is_ok());
+            assert!(split(array.as_ref(), ",").is_ok());
+        } else {
+            assert!(trim(array.as_ref()).is_err());
+            assert!(char_length(array.as_ref()).is_err());
+            assert!(split(array.as_ref(), ",").is_err());
+        }
+    });
+}
+
+#[test]
+fn concat_dictionary() {
+    let a = utf8_dictionary(&[Some("a"), None, Some("a")]);
+    let b = Utf8Array::<i32>::from([Some("1"), Some("2"), Some("3")]);
+
+    let result = concat(&[&a, &b]).unwrap();
+    let expected = Utf8Array::<i32>::from([Some("a1"), None, Some("a3")]);
+    assert_eq!(expected, result.as_ref());
+
+    let result = concat_ws(&[&a, &a], "-").unwrap();
+    let expected = Utf8Array::<i32>::from([Some("a-a"), Some(""), Some("a-a")]);
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+fn binary_predicates_dictionary() {
+    let lhs = utf8_dictionary(&[Some("arrow"), Some("parquet"), None, Some("arrow")]);
+    let rhs = utf8_dictionary(&[Some("ar"), Some("ar"), Some("ar"), Some("ow")]);
+
+    let result = starts_with(&lhs, &rhs).unwrap();
+    assert_eq!(
+        result,
+        BooleanArray::from([Some(true), Some(false), None, Some(false)])
+    );
+
+    let rhs = Utf8Array::<i32>::from([Some("ow"), Some("quet"), Some("a"), None]);
+    let result = ends_with(&lhs, &rhs).unwrap();
+    assert_eq!(
+        result,
+        BooleanArray::from([Some(true), Some(true), None, None])
+    );
+
+    let result = contains(&rhs, &lhs).unwrap();
+    assert_eq!(
+        result,
+        BooleanArray::from([Some(false), Some(false), None, None])
+    );
+}
@@ -1,6 +1,7 @@
-use re_arrow2::array::{new_null_array, Int32Array};
+use re_arrow2::array::{new_null_array, Float64Array, Int32Array, Int64Array, UInt64Array};
 use re_arrow2::compute::window::*;
-use re_arrow2::datatypes::DataType;
+use re_arrow2::datatypes::{DataType, TimeUnit};
+use re_arrow2::error::Error;
 
 #[test]
 fn shift_pos() {
@@ -27,3 +28,205 @@ fn shift_max() {
 
     assert_eq!(expected.as_ref(), result.as_ref());
 }
+
+#[test]
+fn rolling_fixed() {
+    let array = Int32Array::from(&[Some(1), Some(2), None, Some(4), Some(5)]);
+    let options = RollingOptions::fixed(2);
+
+    let result = rolling_sum(&array, &options).unwrap();
+    assert_eq!(
+        result,
+        Int32Array::from(&[None, Some(3), None, None, Some(9)])
+    );
+
+    let result = rolling_count(&array, &options).unwrap();
+    assert_eq!(
+        result,
+        UInt64Array::from(&[None, Some(2), None, None, Some(2)])
+    );
+
+    let options = RollingOptions {
+        min_periods: 1,
+        ..options
+    };
+    let result = rolling_min(&array, &options).unwrap();
+    assert_eq!(
+        result,
+        Int32Array::from(&[Some(1), Some(1), Some(2), Some(4), Some(4)])
+    );
+
+    let result = rolling_max(&array, &options).unwrap();
+    assert_eq!(
+        result,
+        Int32Array::from(&[Some(1), Some(2), Some(2), Some(4), Some(5)])
+    );
+
+    let result = rolling_mean(&array, &options).unwrap();
+    assert_eq!(
+        result,
+        Float64Array::from(&[Some(1.0), Some(1.5), Some(2.0), Some(4.0), Some(4.5)])
+    );
+}
+
+#[test]
+fn rolling_min_periods_zero() {
+    let array = Int32Array::from(&[None, None, Some(3)]);
+    let options = RollingOptions {
+        min_periods: 0,
+        ..RollingOptions::fixed(2)
+    };
+
+    let result = rolling_sum(&array, &options).unwrap();
+    assert_eq!(result, Int32Array::from_slice([0, 0, 3]));
+
+    let result = rolling_count(&array, &options).unwrap();
+    assert_eq!(result, UInt64Array::from_slice([0, 0, 1]));
+
+    // there is no mean of an empty window
+    let result = rolling_mean(&array, &options).unwrap();
+    assert_eq!(result, Float64Array::from(&[None, None, Some(3.0)]));
+}
+
+#[test]
+fn rolling_center() {
+    let array = Int32Array::from_slice([1, 2, 3, 4, 5, 6]);
+    let options = RollingOptions {
+        min_periods: 1,
+        center: true,
+        ..RollingOptions::fixed(4)
+    };
+    // windows are [i - 2, i + 1]
+    let result = rolling_sum(&array, &options).unwrap();
+    assert_eq!(result, Int32Array::from_slice([3, 6, 10, 14, 18, 15]));
+}
+
+#[test]
+fn rolling_std_values() {
+    let array = Float64Array::from(&[Some(1.0), Some(3.0), None, Some(5.0), Some(9.0)]);
+    let options = RollingOptions {
+        min_periods: 1,
+        ..RollingOptions::fixed(3)
+    };
+    let result = rolling_std(&array, &options).unwrap();
+    let expected = [
+        None,
+        Some(2.0f64.sqrt()),
+        Some(2.0f64.sqrt()),
+        Some(2.0f64.sqrt()),
+        Some(8.0f64.sqrt()),
+    ];
+    assert_eq!(result.len(), expected.len());
+    for (result, expected) in result.iter().zip(expected) {
+        match (result, expected) {
+            (Some(result), Some(expected)) => assert!((result - expected).abs() < 1e-12),
+            (result, expected) => assert_eq!(result.copied(), expected),
+        }
+    }
+}
+
+#[test]
+fn rolling_sum_overflow() {
+    let array = Int32Array::from_slice([i32::MAX, 1]);
+    assert!(matches!(
+        rolling_sum(&array, &RollingOptions::fixed(2)),
+        Err(Error::Overflow)
+    ));
+
+    let array = Int32Array::from_slice([i32::MAX, i32::MIN, i32::MAX]);
+    let result = rolling_sum(&array, &RollingOptions::fixed(2)).unwrap();
+    assert_eq!(result, Int32Array::from(&[None, Some(-1), Some(-1)]));
+}
+
+#[test]
+fn rolling_non_finite() {
+    let array = Float64Array::from_slice([1.0, f64::INFINITY, 1.0, 1.0, 1.0]);
+    let options = RollingOptions::fixed(2);
+
+    let result = rolling_sum(&array, &options).unwrap();
+    let expected = [
+        None,
+        Some(f64::INFINITY),
+        Some(f64::INFINITY),
+        Some(2.0),
+        Some(2.0),
+    ];
+    assert_eq!(result, Float64Array::from(&expected));
+
+    let result = rolling_mean(&array, &options).unwrap();
+    let expected = [
+        None,
+        Some(f64::INFINITY),
+        Some(f64::INFINITY),
+        Some(1.0),
+        Some(1.0),
+    ];
+    assert_eq!(result, Float64Array::from(&expected));
+
+    let result = rolling_std(&array, &options).unwrap();
+    assert!(result.value(1).is_nan() && result.value(2).is_nan());
+    assert_eq!(result.sliced(3, 2), Float64Array::from_slice([0.0, 0.0]));
+
+    let array = Float64Array::from_slice([f64::INFINITY, f64::NEG_INFINITY, f64::NAN, 1.0, 1.0]);
+    let result = rolling_sum(&array, &options).unwrap();
+    assert!(result.value(1).is_nan() && result.value(2).is_nan());
+    assert!(result.value(3).is_nan());
+    assert_eq!(result.value(4), 2.0);
+}
+
+#[test]
+fn rolling_time() {
+    let timestamps =
+        Int64Array::from_slice([0, 1, 2, 5, 5, 10]).to(DataType::Timestamp(TimeUnit::Second, None));
+    let array = Int64Array::from(&[Some(1), Some(2), Some(3), None, Some(5), Some(6)]);
+
+    let options = RollingOptions::time(&timestamps, 3);
+    let result = rolling_sum(&array, &options).unwrap();
+    assert_eq!(
+        result,
+        Int64Array::from(&[Some(1), Some(3), Some(6), Some(5), Some(5), Some(6)])
+    );
+
+    let result = rolling_max(&array, &options).unwrap();
+    assert_eq!(
+        result,
+        Int64Array::from(&[Some(1), Some(2), Some(3), Some(5), Some(5), Some(6)])
+    );
+
+    // windows are (t - 2, t + 1]
+    let options = RollingOptions {
+        center: true,
+        ..options
+    };
+    let result = rolling_count(&array, &options).unwrap();
+    assert_eq!(result, UInt64Array::from_slice([2, 3, 2, 1, 1, 1]));
+
+    // a gap larger than the window
+    let options = RollingOptions {
+        min_periods: 2,
+        ..RollingOptions::time(&timestamps, 1)
+    };
+    let result = rolling_count(&array, &options).unwrap();
+    assert_eq!(
+        result,
+        UInt64Array::from(&[None, None, None, None, None, None])
+    );
+}
+
+#[test]
+fn rolling_invalid_windows() {
+    let array = Int32Array::from_slice([1, 2, 3]);
+    assert!(rolling_sum(&array, &RollingOptions::fixed(0)).is_err());
+
+    let timestamps = Int64Array::from_slice([0, 2, 1]);
+    assert!(rolling_sum(&array, &RollingOptions::time(&timestamps, 1)).is_err());
+
+    let timestamps = Int64Array::from_slice([0, 1, 2]);
+    assert!(rolling_sum(&array, &RollingOptions::time(&timestamps, 0)).is_err());
+
+    let timestamps = Int64Array::from(&[Some(0), None, Some(2)]);
+    assert!(rolling_sum(&array, &RollingOptions::time(&timestamps, 1)).is_err());
+
+    let timestamps = Int64Array::from_slice([0, 1]);
+    assert!(rolling_sum(&array, &RollingOptions::time(&timestamps, 1)).is_err());
+}
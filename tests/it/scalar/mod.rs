@@ -7,6 +7,7 @@ mod map;
 mod null;
 mod primitive;
 mod struct_;
+mod to_array;
 mod utf8;
 
 // check that `PartialEq` can be derived
@@ -0,0 +1,88 @@
+use std::sync::Arc;
+
+use re_arrow2::array::*;
+use re_arrow2::datatypes::{DataType, Field, IntegerType};
+use re_arrow2::error::Error;
+use re_arrow2::scalar::{new_scalar, to_array};
+
+fn round_trip(array: &dyn Array) {
+    for i in 0..array.len() {
+        let scalar = new_scalar(array, i);
+        let result = to_array(scalar.as_ref(), 3).unwrap();
+        assert_eq!(result.len(), 3);
+        assert_eq!(result.data_type(), array.data_type());
+        for j in 0..result.len() {
+            assert_eq!(new_scalar(result.as_ref(), j), scalar);
+        }
+    }
+}
+
+#[test]
+fn primitive() {
+    round_trip(&Int32Array::from(&[Some(1), None]));
+    round_trip(&Float64Array::from(&[Some(1.5), None]).to(DataType::Float64));
+}
+
+#[test]
+fn boolean() {
+    round_trip(&BooleanArray::from(&[Some(true), Some(false), None]));
+}
+
+#[test]
+fn utf8_and_binary() {
+    round_trip(&Utf8Array::<i32>::from([Some("ab"), Some(""), None]));
+    round_trip(&Utf8Array::<i64>::from([Some("ab"), None]));
+    round_trip(&BinaryArray::<i32>::from([Some(b"ab".as_ref()), None]));
+    round_trip(&FixedSizeBinaryArray::from([Some([1u8, 2]), None]));
+}
+
+#[test]
+fn list() {
+    let data = vec![Some(vec![Some(1), None]), None, Some(vec![])];
+    let mut array = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+    array.try_extend(data).unwrap();
+    let array: ListArray<i32> = array.into();
+    round_trip(&array);
+}
+
+#[test]
+fn struct_() {
+    let fields = vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ];
+    let array = StructArray::new(
+        DataType::Struct(Arc::new(fields)),
+        vec![
+            Int32Array::from(&[Some(1), None]).boxed(),
+            Utf8Array::<i32>::from([Some("x"), Some("y")]).boxed(),
+        ],
+        Some([true, false].into()),
+    );
+    round_trip(&array);
+}
+
+#[test]
+fn dictionary() {
+    let keys = Int32Array::from(&[Some(1), None, Some(0)]);
+    let values = Utf8Array::<i32>::from_slice(["a", "b"]).boxed();
+    let data_type = DataType::Dictionary(IntegerType::Int32, Arc::new(DataType::Utf8), false);
+    let array = DictionaryArray::try_new(data_type, keys, values).unwrap();
+    round_trip(&array);
+}
+
+#[test]
+fn overflow() {
+    let scalar = new_scalar(&Utf8Array::<i32>::from_slice(["ab"]), 0);
+    let length = i32::MAX as usize / 2 + 1;
+    assert!(matches!(
+        to_array(scalar.as_ref(), length),
+        Err(Error::Overflow)
+    ));
+
+    let scalar = new_scalar(&BinaryArray::<i64>::from_slice([b"ab"]), 0);
+    assert!(matches!(
+        to_array(scalar.as_ref(), usize::MAX),
+        Err(Error::Overflow)
+    ));
+}